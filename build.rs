@@ -0,0 +1,43 @@
+//! Embeds the distribution files in `data/` into the crate, pre-parsed into static tables, so
+//! that the binaries do not need the source tree at runtime.
+
+use std::fmt::Write as _;
+use std::path::Path;
+use std::{env, fs};
+
+#[path = "src/distribution/dst_parser.rs"]
+#[allow(dead_code)]
+mod dst_parser;
+
+fn main() {
+    let data_dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("data");
+    println!("cargo:rerun-if-changed=data");
+    println!("cargo:rerun-if-changed=src/distribution/dst_parser.rs");
+
+    let mut file_names: Vec<String> = fs::read_dir(&data_dir)
+        .expect("data directory should exist")
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(".dst"))
+        .collect();
+    file_names.sort();
+
+    let mut code = String::from(
+        "/// Distribution files compiled into the binary, sorted by file name\n\
+         pub static EMBEDDED_DISTRIBUTIONS: &[EmbeddedDistribution] = &[\n",
+    );
+    for file_name in &file_names {
+        let bytes = fs::read(data_dir.join(file_name)).unwrap();
+        let lines = dst_parser::parse_distribution(&dst_parser::decode_latin1(&bytes))
+            .unwrap_or_else(|e| panic!("Failed to parse distribution file {}: {}", file_name, e));
+
+        writeln!(code, "    ({:?}, &[", file_name).unwrap();
+        for (values, weights) in lines {
+            writeln!(code, "        (&{:?}, &{:?}),", values, weights).unwrap();
+        }
+        code.push_str("    ]),\n");
+    }
+    code.push_str("];\n");
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("embedded_distributions.rs");
+    fs::write(out_path, code).unwrap();
+}
@@ -6,6 +6,8 @@
 #   ./scripts/generate-fixtures.sh              # Generate all tables
 #   ./scripts/generate-fixtures.sh --quiet      # Generate all tables (quiet mode)
 #   ./scripts/generate-fixtures.sh table1 ...   # Generate specific tables
+#   ./scripts/generate-fixtures.sh --scale 10   # Generate into tests/fixtures/scale-10
+#   ./scripts/generate-fixtures.sh --parallelism 4 table1  # Also generate each of 4 chunks
 #   ./scripts/generate-fixtures.sh --help       # Show help
 
 set -euo pipefail
@@ -25,6 +27,7 @@ JAVA_DIR="$PROJECT_ROOT/../tpcds"
 # Configuration
 SCALE_FACTOR=1
 FIXTURE_DIR="$PROJECT_ROOT/tests/fixtures/scale-$SCALE_FACTOR"
+PARALLELISM=1
 QUIET=0
 
 # All TPC-DS tables (24 base tables)
@@ -85,6 +88,8 @@ Usage:
     $(basename "$0") [OPTIONS] [TABLES...]
 
 Options:
+    --scale N       Scale factor (Default: 1), written to tests/fixtures/scale-N
+    --parallelism N Also generate each of N chunks as TABLE_CHUNK_N.dat
     --quiet         Quiet mode (minimal output)
     --help          Show this help message
 
@@ -183,6 +188,29 @@ generate_table() {
         return 1
     fi
 
+    # Generate each chunk of a parallel run (chunks without rows produce no file)
+    if [[ $PARALLELISM -gt 1 ]]; then
+        local chunk
+        for ((chunk = 1; chunk <= PARALLELISM; chunk++)); do
+            if ! java -jar "$jar_file" \
+                --table "$table" \
+                --scale "$SCALE_FACTOR" \
+                --parallelism "$PARALLELISM" \
+                --chunk-number "$chunk" \
+                --overwrite \
+                --directory "$temp_dir" >/dev/null 2>&1; then
+                log_error "Failed to generate chunk $chunk of $PARALLELISM of $table"
+                rm -rf "$temp_dir"
+                return 1
+            fi
+            local chunk_file="$temp_dir/${table}_${chunk}_${PARALLELISM}.dat"
+            if [[ -f "$chunk_file" ]]; then
+                mv "$chunk_file" "$FIXTURE_DIR/"
+            fi
+        done
+        log_success "$table generated in $PARALLELISM chunks"
+    fi
+
     # Clean up temp directory
     rm -rf "$temp_dir"
     return 0
@@ -199,6 +227,15 @@ main() {
     # Parse arguments
     while [[ $# -gt 0 ]]; do
         case $1 in
+            --scale)
+                SCALE_FACTOR=$2
+                FIXTURE_DIR="$PROJECT_ROOT/tests/fixtures/scale-$SCALE_FACTOR"
+                shift 2
+                ;;
+            --parallelism)
+                PARALLELISM=$2
+                shift 2
+                ;;
             --quiet)
                 QUIET=1
                 shift
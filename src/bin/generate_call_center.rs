@@ -15,7 +15,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let num_call_centers = 6;
 
-    println!("Generating {} call center rows...", num_call_centers);
+    eprintln!("Generating {} call center rows...", num_call_centers);
 
     for row_number in 1..=num_call_centers {
         let result = generator.generate_row_and_child_rows(row_number, &session, None, None)?;
@@ -31,17 +31,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             writeln!(writer, "{}|", csv_line)?;
 
             if row_number <= 3 {
-                println!("Row {}: {}", row_number, csv_line);
+                eprintln!("Row {}: {}", row_number, csv_line);
             }
         }
     }
 
     writer.flush()?;
-    println!(
+    eprintln!(
         "✓ Generated call center data written to: {}",
         output_path.display()
     );
-    println!("✓ File contains {} rows", num_call_centers);
+    eprintln!("✓ File contains {} rows", num_call_centers);
 
     Ok(())
 }
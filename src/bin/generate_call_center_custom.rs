@@ -31,7 +31,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let file = File::create(output_path)?;
     let mut writer = BufWriter::new(file);
 
-    println!(
+    eprintln!(
         "Generating {} call center rows to '{}'...",
         num_rows, output_file
     );
@@ -52,7 +52,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
             // Print progress for larger datasets
             if row_number <= 5 || row_number % 1000 == 0 {
-                println!(
+                eprintln!(
                     "Generated row {}: {} characters",
                     row_number,
                     csv_line.len()
@@ -63,27 +63,27 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     writer.flush()?;
 
-    println!(
+    eprintln!(
         "✓ Generated call center data written to: {}",
         output_path.display()
     );
-    println!("✓ File contains {} rows", num_rows);
+    eprintln!("✓ File contains {} rows", num_rows);
 
     // Show file size
     let metadata = std::fs::metadata(output_path)?;
-    println!("✓ File size: {} bytes", metadata.len());
+    eprintln!("✓ File size: {} bytes", metadata.len());
 
     // Show first few lines
     if num_rows <= 20 {
-        println!("\nGenerated data:");
+        eprintln!("\nGenerated data:");
         let content = std::fs::read_to_string(output_path)?;
         for (i, line) in content.lines().enumerate() {
             if i < 3 {
-                println!("Row {}: {}", i + 1, line);
+                eprintln!("Row {}: {}", i + 1, line);
             }
         }
         if num_rows > 3 {
-            println!("... ({} more rows)", num_rows - 3);
+            eprintln!("... ({} more rows)", num_rows - 3);
         }
     }
 
@@ -17,7 +17,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .get_scaling()
         .get_row_count(tpcdsgen::config::Table::CustomerDemographics);
 
-    println!("Generating {} customer demographics rows...", num_rows);
+    eprintln!("Generating {} customer demographics rows...", num_rows);
 
     for row_number in 1..=num_rows {
         let result = generator.generate_row_and_child_rows(row_number, &session, None, None)?;
@@ -34,16 +34,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         if row_number % 100_000 == 0 {
-            println!("Progress: {} rows generated", row_number);
+            eprintln!("Progress: {} rows generated", row_number);
         }
     }
 
     writer.flush()?;
-    println!(
+    eprintln!(
         "Generated customer demographics data written to: {}",
         output_path.display()
     );
-    println!("File contains {} rows", num_rows);
+    eprintln!("File contains {} rows", num_rows);
 
     Ok(())
 }
@@ -15,7 +15,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Get number of rows for date_dim at scale 1
     let num_rows = session.get_scaling().get_row_count(Table::DateDim);
 
-    println!("Generating {} date_dim rows...", num_rows);
+    eprintln!("Generating {} date_dim rows...", num_rows);
 
     for row_number in 1..=num_rows {
         let result = generator.generate_row_and_child_rows(row_number, &session, None, None)?;
@@ -31,16 +31,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         if row_number % 10000 == 0 {
-            println!("Progress: {} rows generated", row_number);
+            eprintln!("Progress: {} rows generated", row_number);
         }
     }
 
     writer.flush()?;
-    println!(
+    eprintln!(
         "Generated date_dim data written to: {}",
         output_path.display()
     );
-    println!("File contains {} rows", num_rows);
+    eprintln!("File contains {} rows", num_rows);
 
     Ok(())
 }
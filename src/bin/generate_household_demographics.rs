@@ -31,7 +31,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .get_scaling()
         .get_row_count(tpcdsgen::config::Table::HouseholdDemographics);
 
-    println!("Generating {} household demographics rows...", num_rows);
+    eprintln!("Generating {} household demographics rows...", num_rows);
 
     for row_number in 1..=num_rows {
         let result = generator.generate_row_and_child_rows(row_number, &session, None, None)?;
@@ -48,16 +48,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
 
         if row_number % 1000 == 0 {
-            println!("Progress: {} rows generated", row_number);
+            eprintln!("Progress: {} rows generated", row_number);
         }
     }
 
     writer.flush()?;
-    println!(
+    eprintln!(
         "Generated household demographics data written to: {}",
         output_path.display()
     );
-    println!("File contains {} rows", num_rows);
+    eprintln!("File contains {} rows", num_rows);
 
     Ok(())
 }
@@ -15,7 +15,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let num_rows = 35; // Scale 1 has 35 rows per specification
 
-    println!("Generating {} reason rows...", num_rows);
+    eprintln!("Generating {} reason rows...", num_rows);
 
     for row_number in 1..=num_rows {
         let result = generator.generate_row_and_child_rows(row_number, &session, None, None)?;
@@ -31,17 +31,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             writeln!(writer, "{}|", csv_line)?;
 
             if row_number <= 10 {
-                println!("Row {}: {}", row_number, csv_line);
+                eprintln!("Row {}: {}", row_number, csv_line);
             }
         }
     }
 
     writer.flush()?;
-    println!(
+    eprintln!(
         "Generated reason data written to: {}",
         output_path.display()
     );
-    println!("File contains {} rows", num_rows);
+    eprintln!("File contains {} rows", num_rows);
 
     Ok(())
 }
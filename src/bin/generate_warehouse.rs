@@ -15,7 +15,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let num_warehouses = 5;
 
-    println!("Generating {} warehouse rows...", num_warehouses);
+    eprintln!("Generating {} warehouse rows...", num_warehouses);
 
     for row_number in 1..=num_warehouses {
         let result = generator.generate_row_and_child_rows(row_number, &session, None, None)?;
@@ -30,16 +30,16 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             let csv_line = values.join("|");
             writeln!(writer, "{}|", csv_line)?;
 
-            println!("Row {}: {}", row_number, csv_line);
+            eprintln!("Row {}: {}", row_number, csv_line);
         }
     }
 
     writer.flush()?;
-    println!(
+    eprintln!(
         "Generated warehouse data written to: {}",
         output_path.display()
     );
-    println!("File contains {} rows", num_warehouses);
+    eprintln!("File contains {} rows", num_warehouses);
 
     Ok(())
 }
@@ -17,7 +17,7 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
         .get_scaling()
         .get_row_count(tpcdsgen::config::Table::WebPage);
 
-    println!("Generating {} web_page rows...", num_rows);
+    eprintln!("Generating {} web_page rows...", num_rows);
 
     for row_number in 1..=num_rows {
         let result = generator.generate_row_and_child_rows(row_number, &session, None, None)?;
@@ -31,17 +31,17 @@ fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
             writeln!(writer, "{}|", csv_line)?;
 
             if row_number <= 3 {
-                println!("Row {}: {}", row_number, csv_line);
+                eprintln!("Row {}: {}", row_number, csv_line);
             }
         }
     }
 
     writer.flush()?;
-    println!(
+    eprintln!(
         "✓ Generated web_page data written to: {}",
         output_path.display()
     );
-    println!("✓ File contains {} rows", num_rows);
+    eprintln!("✓ File contains {} rows", num_rows);
 
     Ok(())
 }
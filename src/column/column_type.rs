@@ -1,4 +1,4 @@
-use crate::{check_state, error::Result, TpcdsError};
+use crate::{check_state, error::Result};
 
 /// SQL column type base enumeration (ColumnType.Base)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -0,0 +1,164 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Customer Demographics table columns (CustomerDemographicsColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CustomerDemographicsColumn {
+    CdDemoSk,
+    CdGender,
+    CdMaritalStatus,
+    CdEducationStatus,
+    CdPurchaseEstimate,
+    CdCreditRating,
+    CdDepCount,
+    CdDepEmployedCount,
+    CdDepCollegeCount,
+}
+
+impl CustomerDemographicsColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [CustomerDemographicsColumn] {
+        use CustomerDemographicsColumn::*;
+        static VALUES: &[CustomerDemographicsColumn] = &[
+            CdDemoSk,
+            CdGender,
+            CdMaritalStatus,
+            CdEducationStatus,
+            CdPurchaseEstimate,
+            CdCreditRating,
+            CdDepCount,
+            CdDepEmployedCount,
+            CdDepCollegeCount,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            CustomerDemographicsColumn::CdDemoSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            CustomerDemographicsColumn::CdGender => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            CustomerDemographicsColumn::CdMaritalStatus => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            CustomerDemographicsColumn::CdEducationStatus => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+            CustomerDemographicsColumn::CdPurchaseEstimate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            CustomerDemographicsColumn::CdCreditRating => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(10))
+            }
+            CustomerDemographicsColumn::CdDepCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            CustomerDemographicsColumn::CdDepEmployedCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            CustomerDemographicsColumn::CdDepCollegeCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+        }
+    }
+}
+
+impl Column for CustomerDemographicsColumn {
+    fn get_table(&self) -> Table {
+        Table::CustomerDemographics
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            CustomerDemographicsColumn::CdDemoSk => "cd_demo_sk",
+            CustomerDemographicsColumn::CdGender => "cd_gender",
+            CustomerDemographicsColumn::CdMaritalStatus => "cd_marital_status",
+            CustomerDemographicsColumn::CdEducationStatus => "cd_education_status",
+            CustomerDemographicsColumn::CdPurchaseEstimate => "cd_purchase_estimate",
+            CustomerDemographicsColumn::CdCreditRating => "cd_credit_rating",
+            CustomerDemographicsColumn::CdDepCount => "cd_dep_count",
+            CustomerDemographicsColumn::CdDepEmployedCount => "cd_dep_employed_count",
+            CustomerDemographicsColumn::CdDepCollegeCount => "cd_dep_college_count",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_customer_demographics_columns() {
+        assert_eq!(CustomerDemographicsColumn::values().len(), 9);
+        assert_eq!(
+            CustomerDemographicsColumn::CdDemoSk.get_name(),
+            "cd_demo_sk"
+        );
+        assert_eq!(
+            CustomerDemographicsColumn::CdDepCollegeCount.get_name(),
+            "cd_dep_college_count"
+        );
+        assert_eq!(
+            CustomerDemographicsColumn::CdDepCollegeCount.get_position(),
+            8
+        );
+        assert_eq!(
+            CustomerDemographicsColumn::CdDemoSk.get_table(),
+            Table::CustomerDemographics
+        );
+    }
+
+    #[test]
+    fn test_customer_demographics_column_types() {
+        assert_eq!(
+            CustomerDemographicsColumn::CdDemoSk.get_type().get_base(),
+            ColumnTypeBase::Identifier
+        );
+        assert_eq!(
+            CustomerDemographicsColumn::CdGender.get_type().get_base(),
+            ColumnTypeBase::Char
+        );
+        assert_eq!(
+            CustomerDemographicsColumn::CdGender
+                .get_type()
+                .get_precision(),
+            Some(1)
+        );
+    }
+}
@@ -0,0 +1,280 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Date Dimension table columns (DateDimColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateDimColumn {
+    DDateSk,
+    DDateId,
+    DDate,
+    DMonthSeq,
+    DWeekSeq,
+    DQuarterSeq,
+    DYear,
+    DDow,
+    DMoy,
+    DDom,
+    DQoy,
+    DFyYear,
+    DFyQuarterSeq,
+    DFyWeekSeq,
+    DDayName,
+    DQuarterName,
+    DHoliday,
+    DWeekend,
+    DFollowingHoliday,
+    DFirstDom,
+    DLastDom,
+    DSameDayLy,
+    DSameDayLq,
+    DCurrentDay,
+    DCurrentWeek,
+    DCurrentMonth,
+    DCurrentQuarter,
+    DCurrentYear,
+}
+
+impl DateDimColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [DateDimColumn] {
+        use DateDimColumn::*;
+        static VALUES: &[DateDimColumn] = &[
+            DDateSk,
+            DDateId,
+            DDate,
+            DMonthSeq,
+            DWeekSeq,
+            DQuarterSeq,
+            DYear,
+            DDow,
+            DMoy,
+            DDom,
+            DQoy,
+            DFyYear,
+            DFyQuarterSeq,
+            DFyWeekSeq,
+            DDayName,
+            DQuarterName,
+            DHoliday,
+            DWeekend,
+            DFollowingHoliday,
+            DFirstDom,
+            DLastDom,
+            DSameDayLy,
+            DSameDayLq,
+            DCurrentDay,
+            DCurrentWeek,
+            DCurrentMonth,
+            DCurrentQuarter,
+            DCurrentYear,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            DateDimColumn::DDateSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            DateDimColumn::DDateId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            DateDimColumn::DDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            DateDimColumn::DMonthSeq => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DWeekSeq => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DQuarterSeq => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DYear => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DDow => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DMoy => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DDom => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DQoy => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DFyYear => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DFyQuarterSeq => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DFyWeekSeq => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DDayName => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(9))
+            }
+            DateDimColumn::DQuarterName => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(6))
+            }
+            DateDimColumn::DHoliday => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            DateDimColumn::DWeekend => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            DateDimColumn::DFollowingHoliday => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            DateDimColumn::DFirstDom => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DLastDom => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DSameDayLy => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DSameDayLq => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            DateDimColumn::DCurrentDay => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            DateDimColumn::DCurrentWeek => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            DateDimColumn::DCurrentMonth => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            DateDimColumn::DCurrentQuarter => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            DateDimColumn::DCurrentYear => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+        }
+    }
+}
+
+impl Column for DateDimColumn {
+    fn get_table(&self) -> Table {
+        Table::DateDim
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            DateDimColumn::DDateSk => "d_date_sk",
+            DateDimColumn::DDateId => "d_date_id",
+            DateDimColumn::DDate => "d_date",
+            DateDimColumn::DMonthSeq => "d_month_seq",
+            DateDimColumn::DWeekSeq => "d_week_seq",
+            DateDimColumn::DQuarterSeq => "d_quarter_seq",
+            DateDimColumn::DYear => "d_year",
+            DateDimColumn::DDow => "d_dow",
+            DateDimColumn::DMoy => "d_moy",
+            DateDimColumn::DDom => "d_dom",
+            DateDimColumn::DQoy => "d_qoy",
+            DateDimColumn::DFyYear => "d_fy_year",
+            DateDimColumn::DFyQuarterSeq => "d_fy_quarter_seq",
+            DateDimColumn::DFyWeekSeq => "d_fy_week_seq",
+            DateDimColumn::DDayName => "d_day_name",
+            DateDimColumn::DQuarterName => "d_quarter_name",
+            DateDimColumn::DHoliday => "d_holiday",
+            DateDimColumn::DWeekend => "d_weekend",
+            DateDimColumn::DFollowingHoliday => "d_following_holiday",
+            DateDimColumn::DFirstDom => "d_first_dom",
+            DateDimColumn::DLastDom => "d_last_dom",
+            DateDimColumn::DSameDayLy => "d_same_day_ly",
+            DateDimColumn::DSameDayLq => "d_same_day_lq",
+            DateDimColumn::DCurrentDay => "d_current_day",
+            DateDimColumn::DCurrentWeek => "d_current_week",
+            DateDimColumn::DCurrentMonth => "d_current_month",
+            DateDimColumn::DCurrentQuarter => "d_current_quarter",
+            DateDimColumn::DCurrentYear => "d_current_year",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_date_dim_columns() {
+        assert_eq!(DateDimColumn::values().len(), 28);
+        assert_eq!(DateDimColumn::DDateSk.get_name(), "d_date_sk");
+        assert_eq!(DateDimColumn::DCurrentYear.get_name(), "d_current_year");
+        assert_eq!(DateDimColumn::DCurrentYear.get_position(), 27);
+        assert_eq!(DateDimColumn::DDateSk.get_table(), Table::DateDim);
+    }
+
+    #[test]
+    fn test_date_dim_column_types() {
+        assert_eq!(
+            DateDimColumn::DDateSk.get_type().get_base(),
+            ColumnTypeBase::Identifier
+        );
+        assert_eq!(
+            DateDimColumn::DDateId.get_type().get_base(),
+            ColumnTypeBase::Char
+        );
+        assert_eq!(DateDimColumn::DDateId.get_type().get_precision(), Some(16));
+    }
+}
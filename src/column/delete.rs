@@ -0,0 +1,118 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Columns of the sales date ranges deleted by a refresh (delete)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeleteColumn {
+    Date1,
+    Date2,
+}
+
+impl DeleteColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [DeleteColumn] {
+        use DeleteColumn::*;
+        static VALUES: &[DeleteColumn] = &[Date1, Date2];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            DeleteColumn::Date1 => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            DeleteColumn::Date2 => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+        }
+    }
+}
+
+impl Column for DeleteColumn {
+    fn get_table(&self) -> Table {
+        Table::Delete
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            DeleteColumn::Date1 => "date1",
+            DeleteColumn::Date2 => "date2",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+/// Columns of the inventory date ranges deleted by a refresh (inventory_delete)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InventoryDeleteColumn {
+    Date1,
+    Date2,
+}
+
+impl InventoryDeleteColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [InventoryDeleteColumn] {
+        use InventoryDeleteColumn::*;
+        static VALUES: &[InventoryDeleteColumn] = &[Date1, Date2];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            InventoryDeleteColumn::Date1 => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            InventoryDeleteColumn::Date2 => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+        }
+    }
+}
+
+impl Column for InventoryDeleteColumn {
+    fn get_table(&self) -> Table {
+        Table::InventoryDelete
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            InventoryDeleteColumn::Date1 => "date1",
+            InventoryDeleteColumn::Date2 => "date2",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
@@ -0,0 +1,106 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Income Band table columns (IncomeBandColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IncomeBandColumn {
+    IbIncomeBandSk,
+    IbLowerBound,
+    IbUpperBound,
+}
+
+impl IncomeBandColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [IncomeBandColumn] {
+        use IncomeBandColumn::*;
+        static VALUES: &[IncomeBandColumn] = &[IbIncomeBandSk, IbLowerBound, IbUpperBound];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            IncomeBandColumn::IbIncomeBandSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            IncomeBandColumn::IbLowerBound => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            IncomeBandColumn::IbUpperBound => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+        }
+    }
+}
+
+impl Column for IncomeBandColumn {
+    fn get_table(&self) -> Table {
+        Table::IncomeBand
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            IncomeBandColumn::IbIncomeBandSk => "ib_income_band_sk",
+            IncomeBandColumn::IbLowerBound => "ib_lower_bound",
+            IncomeBandColumn::IbUpperBound => "ib_upper_bound",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_income_band_columns() {
+        assert_eq!(IncomeBandColumn::values().len(), 3);
+        assert_eq!(
+            IncomeBandColumn::IbIncomeBandSk.get_name(),
+            "ib_income_band_sk"
+        );
+        assert_eq!(IncomeBandColumn::IbUpperBound.get_name(), "ib_upper_bound");
+        assert_eq!(IncomeBandColumn::IbUpperBound.get_position(), 2);
+        assert_eq!(
+            IncomeBandColumn::IbIncomeBandSk.get_table(),
+            Table::IncomeBand
+        );
+    }
+
+    #[test]
+    fn test_income_band_column_types() {
+        assert_eq!(
+            IncomeBandColumn::IbIncomeBandSk.get_type().get_base(),
+            ColumnTypeBase::Identifier
+        );
+        assert_eq!(
+            IncomeBandColumn::IbLowerBound.get_type().get_base(),
+            ColumnTypeBase::Integer
+        );
+    }
+}
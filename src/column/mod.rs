@@ -1,15 +1,59 @@
 pub mod call_center;
 pub mod column_type;
 pub mod column_types;
+pub mod customer_demographics;
+pub mod date_dim;
+pub mod delete;
 pub mod household_demographics;
+pub mod income_band;
 pub mod promotion;
+pub mod reason;
+pub mod s_call_center;
+pub mod s_catalog_order;
+pub mod s_catalog_returns;
+pub mod s_inventory;
+pub mod s_item;
+pub mod s_promotion;
+pub mod s_purchase;
+pub mod s_store_returns;
+pub mod s_warehouse;
+pub mod s_web_order;
+pub mod s_web_page;
+pub mod s_web_returns;
+pub mod s_zip_to_gmt;
+pub mod ship_mode;
+pub mod time_dim;
+pub mod warehouse;
+pub mod web_page;
 pub mod web_site;
 
 pub use call_center::CallCenterColumn;
 pub use column_type::{ColumnType, ColumnTypeBase};
 pub use column_types::ColumnTypes;
+pub use customer_demographics::CustomerDemographicsColumn;
+pub use date_dim::DateDimColumn;
+pub use delete::{DeleteColumn, InventoryDeleteColumn};
 pub use household_demographics::HouseholdDemographicsColumn;
+pub use income_band::IncomeBandColumn;
 pub use promotion::PromotionColumn;
+pub use reason::ReasonColumn;
+pub use s_call_center::SCallCenterColumn;
+pub use s_catalog_order::{SCatalogOrderColumn, SCatalogOrderLineitemColumn};
+pub use s_catalog_returns::SCatalogReturnsColumn;
+pub use s_inventory::SInventoryColumn;
+pub use s_item::SItemColumn;
+pub use s_promotion::SPromotionColumn;
+pub use s_purchase::{SPurchaseColumn, SPurchaseLineitemColumn};
+pub use s_store_returns::SStoreReturnsColumn;
+pub use s_warehouse::SWarehouseColumn;
+pub use s_web_order::{SWebOrderColumn, SWebOrderLineitemColumn};
+pub use s_web_page::SWebPageColumn;
+pub use s_web_returns::SWebReturnsColumn;
+pub use s_zip_to_gmt::SZipToGmtColumn;
+pub use ship_mode::ShipModeColumn;
+pub use time_dim::TimeDimColumn;
+pub use warehouse::WarehouseColumn;
+pub use web_page::WebPageColumn;
 pub use web_site::WebSiteColumn;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -27,6 +71,24 @@ pub enum Table {
     Promotion,
     WebPage,
     WebSite,
+    SCallCenter,
+    SCatalogOrder,
+    SCatalogOrderLineitem,
+    SCatalogReturns,
+    SInventory,
+    SItem,
+    SPromotion,
+    SPurchase,
+    SPurchaseLineitem,
+    SStoreReturns,
+    SWarehouse,
+    SWebOrder,
+    SWebOrderLineitem,
+    SWebPage,
+    SWebReturns,
+    SZipToGmt,
+    Delete,
+    InventoryDelete,
     // TODO(clflushopt): Add remaining tables
 }
 
@@ -47,6 +109,24 @@ impl Table {
             Table::Promotion => "promotion",
             Table::WebPage => "web_page",
             Table::WebSite => "web_site",
+            Table::SCallCenter => "s_call_center",
+            Table::SCatalogOrder => "s_catalog_order",
+            Table::SCatalogOrderLineitem => "s_catalog_order_lineitem",
+            Table::SCatalogReturns => "s_catalog_returns",
+            Table::SInventory => "s_inventory",
+            Table::SItem => "s_item",
+            Table::SPromotion => "s_promotion",
+            Table::SPurchase => "s_purchase",
+            Table::SPurchaseLineitem => "s_purchase_lineitem",
+            Table::SStoreReturns => "s_store_returns",
+            Table::SWarehouse => "s_warehouse",
+            Table::SWebOrder => "s_web_order",
+            Table::SWebOrderLineitem => "s_web_order_lineitem",
+            Table::SWebPage => "s_web_page",
+            Table::SWebReturns => "s_web_returns",
+            Table::SZipToGmt => "s_zip_to_gmt",
+            Table::Delete => "delete",
+            Table::InventoryDelete => "inventory_delete",
         }
     }
 }
@@ -0,0 +1,101 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Reason table columns (ReasonColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReasonColumn {
+    RReasonSk,
+    RReasonId,
+    RReasonDesc,
+}
+
+impl ReasonColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [ReasonColumn] {
+        use ReasonColumn::*;
+        static VALUES: &[ReasonColumn] = &[RReasonSk, RReasonId, RReasonDesc];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            ReasonColumn::RReasonSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            ReasonColumn::RReasonId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            ReasonColumn::RReasonDesc => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(100))
+            }
+        }
+    }
+}
+
+impl Column for ReasonColumn {
+    fn get_table(&self) -> Table {
+        Table::Reason
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            ReasonColumn::RReasonSk => "r_reason_sk",
+            ReasonColumn::RReasonId => "r_reason_id",
+            ReasonColumn::RReasonDesc => "r_reason_desc",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_reason_columns() {
+        assert_eq!(ReasonColumn::values().len(), 3);
+        assert_eq!(ReasonColumn::RReasonSk.get_name(), "r_reason_sk");
+        assert_eq!(ReasonColumn::RReasonDesc.get_name(), "r_reason_desc");
+        assert_eq!(ReasonColumn::RReasonDesc.get_position(), 2);
+        assert_eq!(ReasonColumn::RReasonSk.get_table(), Table::Reason);
+    }
+
+    #[test]
+    fn test_reason_column_types() {
+        assert_eq!(
+            ReasonColumn::RReasonSk.get_type().get_base(),
+            ColumnTypeBase::Identifier
+        );
+        assert_eq!(
+            ReasonColumn::RReasonId.get_type().get_base(),
+            ColumnTypeBase::Char
+        );
+        assert_eq!(ReasonColumn::RReasonId.get_type().get_precision(), Some(16));
+    }
+}
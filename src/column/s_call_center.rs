@@ -0,0 +1,147 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Call center refresh source table columns (s_call_center)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SCallCenterColumn {
+    CallCenterId,
+    CallOpenDate,
+    CallClosedDate,
+    CallCenterName,
+    CallCenterClass,
+    CallCenterEmployees,
+    CallCenterSqFt,
+    CallCenterHours,
+    CallCenterManager,
+    CallCenterTaxPercentage,
+}
+
+impl SCallCenterColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SCallCenterColumn] {
+        use SCallCenterColumn::*;
+        static VALUES: &[SCallCenterColumn] = &[
+            CallCenterId,
+            CallOpenDate,
+            CallClosedDate,
+            CallCenterName,
+            CallCenterClass,
+            CallCenterEmployees,
+            CallCenterSqFt,
+            CallCenterHours,
+            CallCenterManager,
+            CallCenterTaxPercentage,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SCallCenterColumn::CallCenterId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCallCenterColumn::CallOpenDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SCallCenterColumn::CallClosedDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SCallCenterColumn::CallCenterName => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(50))
+            }
+            SCallCenterColumn::CallCenterClass => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(50))
+            }
+            SCallCenterColumn::CallCenterEmployees => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCallCenterColumn::CallCenterSqFt => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCallCenterColumn::CallCenterHours => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+            SCallCenterColumn::CallCenterManager => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(40))
+            }
+            SCallCenterColumn::CallCenterTaxPercentage => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(5, 2))
+            }
+        }
+    }
+}
+
+impl Column for SCallCenterColumn {
+    fn get_table(&self) -> Table {
+        Table::SCallCenter
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SCallCenterColumn::CallCenterId => "call_center_id",
+            SCallCenterColumn::CallOpenDate => "call_open_date",
+            SCallCenterColumn::CallClosedDate => "call_closed_date",
+            SCallCenterColumn::CallCenterName => "call_center_name",
+            SCallCenterColumn::CallCenterClass => "call_center_class",
+            SCallCenterColumn::CallCenterEmployees => "call_center_employees",
+            SCallCenterColumn::CallCenterSqFt => "call_center_sq_ft",
+            SCallCenterColumn::CallCenterHours => "call_center_hours",
+            SCallCenterColumn::CallCenterManager => "call_center_manager",
+            SCallCenterColumn::CallCenterTaxPercentage => "call_center_tax_percentage",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_call_center_columns() {
+        assert_eq!(SCallCenterColumn::values().len(), 10);
+        assert_eq!(SCallCenterColumn::CallCenterId.get_name(), "call_center_id");
+        assert_eq!(SCallCenterColumn::CallCenterTaxPercentage.get_position(), 9);
+        assert_eq!(
+            SCallCenterColumn::CallOpenDate.get_type().get_base(),
+            ColumnTypeBase::Date
+        );
+        assert_eq!(
+            SCallCenterColumn::CallCenterId.get_table(),
+            Table::SCallCenter
+        );
+    }
+}
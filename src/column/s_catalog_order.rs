@@ -0,0 +1,267 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Catalog order refresh source table columns (s_catalog_order)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SCatalogOrderColumn {
+    CordOrderId,
+    CordBillCustomerId,
+    CordShipCustomerId,
+    CordOrderDate,
+    CordOrderTime,
+    CordShipModeId,
+    CordCallCenterId,
+    CordOrderComments,
+}
+
+impl SCatalogOrderColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SCatalogOrderColumn] {
+        use SCatalogOrderColumn::*;
+        static VALUES: &[SCatalogOrderColumn] = &[
+            CordOrderId,
+            CordBillCustomerId,
+            CordShipCustomerId,
+            CordOrderDate,
+            CordOrderTime,
+            CordShipModeId,
+            CordCallCenterId,
+            CordOrderComments,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SCatalogOrderColumn::CordOrderId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            SCatalogOrderColumn::CordBillCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogOrderColumn::CordShipCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogOrderColumn::CordOrderDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SCatalogOrderColumn::CordOrderTime => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCatalogOrderColumn::CordShipModeId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogOrderColumn::CordCallCenterId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogOrderColumn::CordOrderComments => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(100))
+            }
+        }
+    }
+}
+
+impl Column for SCatalogOrderColumn {
+    fn get_table(&self) -> Table {
+        Table::SCatalogOrder
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SCatalogOrderColumn::CordOrderId => "cord_order_id",
+            SCatalogOrderColumn::CordBillCustomerId => "cord_bill_customer_id",
+            SCatalogOrderColumn::CordShipCustomerId => "cord_ship_customer_id",
+            SCatalogOrderColumn::CordOrderDate => "cord_order_date",
+            SCatalogOrderColumn::CordOrderTime => "cord_order_time",
+            SCatalogOrderColumn::CordShipModeId => "cord_ship_mode_id",
+            SCatalogOrderColumn::CordCallCenterId => "cord_call_center_id",
+            SCatalogOrderColumn::CordOrderComments => "cord_order_comments",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+/// Catalog order line refresh source table columns (s_catalog_order_lineitem)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SCatalogOrderLineitemColumn {
+    ClinOrderId,
+    ClinLineNumber,
+    ClinItemId,
+    ClinPromotionId,
+    ClinQuantity,
+    ClinSalesPrice,
+    ClinCouponAmt,
+    ClinWarehouseId,
+    ClinShipDate,
+    ClinCatalogNumber,
+    ClinCatalogPageNumber,
+    ClinShipCost,
+}
+
+impl SCatalogOrderLineitemColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SCatalogOrderLineitemColumn] {
+        use SCatalogOrderLineitemColumn::*;
+        static VALUES: &[SCatalogOrderLineitemColumn] = &[
+            ClinOrderId,
+            ClinLineNumber,
+            ClinItemId,
+            ClinPromotionId,
+            ClinQuantity,
+            ClinSalesPrice,
+            ClinCouponAmt,
+            ClinWarehouseId,
+            ClinShipDate,
+            ClinCatalogNumber,
+            ClinCatalogPageNumber,
+            ClinShipCost,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SCatalogOrderLineitemColumn::ClinOrderId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            SCatalogOrderLineitemColumn::ClinLineNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCatalogOrderLineitemColumn::ClinItemId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogOrderLineitemColumn::ClinPromotionId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogOrderLineitemColumn::ClinQuantity => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCatalogOrderLineitemColumn::ClinSalesPrice => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SCatalogOrderLineitemColumn::ClinCouponAmt => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SCatalogOrderLineitemColumn::ClinWarehouseId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogOrderLineitemColumn::ClinShipDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SCatalogOrderLineitemColumn::ClinCatalogNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCatalogOrderLineitemColumn::ClinCatalogPageNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCatalogOrderLineitemColumn::ClinShipCost => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+        }
+    }
+}
+
+impl Column for SCatalogOrderLineitemColumn {
+    fn get_table(&self) -> Table {
+        Table::SCatalogOrderLineitem
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SCatalogOrderLineitemColumn::ClinOrderId => "clin_order_id",
+            SCatalogOrderLineitemColumn::ClinLineNumber => "clin_line_number",
+            SCatalogOrderLineitemColumn::ClinItemId => "clin_item_id",
+            SCatalogOrderLineitemColumn::ClinPromotionId => "clin_promotion_id",
+            SCatalogOrderLineitemColumn::ClinQuantity => "clin_quantity",
+            SCatalogOrderLineitemColumn::ClinSalesPrice => "clin_sales_price",
+            SCatalogOrderLineitemColumn::ClinCouponAmt => "clin_coupon_amt",
+            SCatalogOrderLineitemColumn::ClinWarehouseId => "clin_warehouse_id",
+            SCatalogOrderLineitemColumn::ClinShipDate => "clin_ship_date",
+            SCatalogOrderLineitemColumn::ClinCatalogNumber => "clin_catalog_number",
+            SCatalogOrderLineitemColumn::ClinCatalogPageNumber => "clin_catalog_page_number",
+            SCatalogOrderLineitemColumn::ClinShipCost => "clin_ship_cost",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_catalog_order_columns() {
+        assert_eq!(SCatalogOrderColumn::values().len(), 8);
+        assert_eq!(SCatalogOrderColumn::CordOrderId.get_name(), "cord_order_id");
+        assert_eq!(SCatalogOrderColumn::CordOrderComments.get_position(), 7);
+        assert_eq!(
+            SCatalogOrderColumn::CordOrderId.get_table(),
+            Table::SCatalogOrder
+        );
+        assert_eq!(SCatalogOrderLineitemColumn::values().len(), 12);
+        assert_eq!(
+            SCatalogOrderLineitemColumn::ClinOrderId.get_name(),
+            "clin_order_id"
+        );
+        assert_eq!(SCatalogOrderLineitemColumn::ClinShipCost.get_position(), 11);
+        assert_eq!(
+            SCatalogOrderLineitemColumn::ClinOrderId.get_table(),
+            Table::SCatalogOrderLineitem
+        );
+        assert_eq!(
+            SCatalogOrderColumn::CordOrderDate.get_type().get_base(),
+            ColumnTypeBase::Date
+        );
+    }
+}
@@ -0,0 +1,220 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Catalog returns refresh source table columns (s_catalog_returns)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SCatalogReturnsColumn {
+    CretCallCenterId,
+    CretOrderId,
+    CretLineNumber,
+    CretItemId,
+    CretReturnCustomerId,
+    CretRefundCustomerId,
+    CretReturnDate,
+    CretReturnTime,
+    CretReturnQty,
+    CretReturnAmt,
+    CretReturnTax,
+    CretReturnFee,
+    CretReturnShipCost,
+    CretRefundedCash,
+    CretReversedCharge,
+    CretMerchantCredit,
+    CretReasonId,
+    CretShipmodeId,
+    CretCatalogPageId,
+    CretWarehouseId,
+}
+
+impl SCatalogReturnsColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SCatalogReturnsColumn] {
+        use SCatalogReturnsColumn::*;
+        static VALUES: &[SCatalogReturnsColumn] = &[
+            CretCallCenterId,
+            CretOrderId,
+            CretLineNumber,
+            CretItemId,
+            CretReturnCustomerId,
+            CretRefundCustomerId,
+            CretReturnDate,
+            CretReturnTime,
+            CretReturnQty,
+            CretReturnAmt,
+            CretReturnTax,
+            CretReturnFee,
+            CretReturnShipCost,
+            CretRefundedCash,
+            CretReversedCharge,
+            CretMerchantCredit,
+            CretReasonId,
+            CretShipmodeId,
+            CretCatalogPageId,
+            CretWarehouseId,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SCatalogReturnsColumn::CretCallCenterId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogReturnsColumn::CretOrderId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            SCatalogReturnsColumn::CretLineNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCatalogReturnsColumn::CretItemId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogReturnsColumn::CretReturnCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogReturnsColumn::CretRefundCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogReturnsColumn::CretReturnDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SCatalogReturnsColumn::CretReturnTime => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCatalogReturnsColumn::CretReturnQty => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SCatalogReturnsColumn::CretReturnAmt => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SCatalogReturnsColumn::CretReturnTax => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SCatalogReturnsColumn::CretReturnFee => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SCatalogReturnsColumn::CretReturnShipCost => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SCatalogReturnsColumn::CretRefundedCash => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SCatalogReturnsColumn::CretReversedCharge => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SCatalogReturnsColumn::CretMerchantCredit => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SCatalogReturnsColumn::CretReasonId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogReturnsColumn::CretShipmodeId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogReturnsColumn::CretCatalogPageId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SCatalogReturnsColumn::CretWarehouseId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+        }
+    }
+}
+
+impl Column for SCatalogReturnsColumn {
+    fn get_table(&self) -> Table {
+        Table::SCatalogReturns
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SCatalogReturnsColumn::CretCallCenterId => "cret_call_center_id",
+            SCatalogReturnsColumn::CretOrderId => "cret_order_id",
+            SCatalogReturnsColumn::CretLineNumber => "cret_line_number",
+            SCatalogReturnsColumn::CretItemId => "cret_item_id",
+            SCatalogReturnsColumn::CretReturnCustomerId => "cret_return_customer_id",
+            SCatalogReturnsColumn::CretRefundCustomerId => "cret_refund_customer_id",
+            SCatalogReturnsColumn::CretReturnDate => "cret_return_date",
+            SCatalogReturnsColumn::CretReturnTime => "cret_return_time",
+            SCatalogReturnsColumn::CretReturnQty => "cret_return_qty",
+            SCatalogReturnsColumn::CretReturnAmt => "cret_return_amt",
+            SCatalogReturnsColumn::CretReturnTax => "cret_return_tax",
+            SCatalogReturnsColumn::CretReturnFee => "cret_return_fee",
+            SCatalogReturnsColumn::CretReturnShipCost => "cret_return_ship_cost",
+            SCatalogReturnsColumn::CretRefundedCash => "cret_refunded_cash",
+            SCatalogReturnsColumn::CretReversedCharge => "cret_reversed_charge",
+            SCatalogReturnsColumn::CretMerchantCredit => "cret_merchant_credit",
+            SCatalogReturnsColumn::CretReasonId => "cret_reason_id",
+            SCatalogReturnsColumn::CretShipmodeId => "cret_shipmode_id",
+            SCatalogReturnsColumn::CretCatalogPageId => "cret_catalog_page_id",
+            SCatalogReturnsColumn::CretWarehouseId => "cret_warehouse_id",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_catalog_returns_columns() {
+        assert_eq!(SCatalogReturnsColumn::values().len(), 20);
+        assert_eq!(
+            SCatalogReturnsColumn::CretCallCenterId.get_name(),
+            "cret_call_center_id"
+        );
+        assert_eq!(SCatalogReturnsColumn::CretWarehouseId.get_position(), 19);
+        assert_eq!(
+            SCatalogReturnsColumn::CretCallCenterId.get_table(),
+            Table::SCatalogReturns
+        );
+        assert_eq!(
+            SCatalogReturnsColumn::CretReturnDate.get_type().get_base(),
+            ColumnTypeBase::Date
+        );
+    }
+}
@@ -0,0 +1,80 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Inventory refresh source table columns (s_inventory)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SInventoryColumn {
+    InvnWarehouseId,
+    InvnItemId,
+    InvnDate,
+    InvnQtyOnHand,
+}
+
+impl SInventoryColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SInventoryColumn] {
+        use SInventoryColumn::*;
+        static VALUES: &[SInventoryColumn] =
+            &[InvnWarehouseId, InvnItemId, InvnDate, InvnQtyOnHand];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SInventoryColumn::InvnWarehouseId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SInventoryColumn::InvnItemId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SInventoryColumn::InvnDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SInventoryColumn::InvnQtyOnHand => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+        }
+    }
+}
+
+impl Column for SInventoryColumn {
+    fn get_table(&self) -> Table {
+        Table::SInventory
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SInventoryColumn::InvnWarehouseId => "invn_warehouse_id",
+            SInventoryColumn::InvnItemId => "invn_item_id",
+            SInventoryColumn::InvnDate => "invn_date",
+            SInventoryColumn::InvnQtyOnHand => "invn_qty_on_hand",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
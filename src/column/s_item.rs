@@ -0,0 +1,144 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Item refresh source table columns (s_item)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SItemColumn {
+    ItemItemId,
+    ItemItemDescription,
+    ItemListPrice,
+    ItemWholesaleCost,
+    ItemSize,
+    ItemFormulation,
+    ItemColor,
+    ItemUnits,
+    ItemContainer,
+    ItemManagerId,
+}
+
+impl SItemColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SItemColumn] {
+        use SItemColumn::*;
+        static VALUES: &[SItemColumn] = &[
+            ItemItemId,
+            ItemItemDescription,
+            ItemListPrice,
+            ItemWholesaleCost,
+            ItemSize,
+            ItemFormulation,
+            ItemColor,
+            ItemUnits,
+            ItemContainer,
+            ItemManagerId,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SItemColumn::ItemItemId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SItemColumn::ItemItemDescription => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(200))
+            }
+            SItemColumn::ItemListPrice => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SItemColumn::ItemWholesaleCost => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SItemColumn::ItemSize => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+            SItemColumn::ItemFormulation => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+            SItemColumn::ItemColor => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+            SItemColumn::ItemUnits => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(10))
+            }
+            SItemColumn::ItemContainer => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(10))
+            }
+            SItemColumn::ItemManagerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+        }
+    }
+}
+
+impl Column for SItemColumn {
+    fn get_table(&self) -> Table {
+        Table::SItem
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SItemColumn::ItemItemId => "item_item_id",
+            SItemColumn::ItemItemDescription => "item_item_description",
+            SItemColumn::ItemListPrice => "item_list_price",
+            SItemColumn::ItemWholesaleCost => "item_wholesale_cost",
+            SItemColumn::ItemSize => "item_size",
+            SItemColumn::ItemFormulation => "item_formulation",
+            SItemColumn::ItemColor => "item_color",
+            SItemColumn::ItemUnits => "item_units",
+            SItemColumn::ItemContainer => "item_container",
+            SItemColumn::ItemManagerId => "item_manager_id",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_item_columns() {
+        assert_eq!(SItemColumn::values().len(), 10);
+        assert_eq!(SItemColumn::ItemItemId.get_name(), "item_item_id");
+        assert_eq!(SItemColumn::ItemManagerId.get_position(), 9);
+        assert_eq!(
+            SItemColumn::ItemListPrice.get_type().get_base(),
+            ColumnTypeBase::Decimal
+        );
+        assert_eq!(SItemColumn::ItemItemId.get_table(), Table::SItem);
+    }
+}
@@ -0,0 +1,199 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Promotion refresh source table columns (s_promotion)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SPromotionColumn {
+    PromPromotionId,
+    PromPromotionName,
+    PromStartDate,
+    PromEndDate,
+    PromCost,
+    PromResponseTarget,
+    PromChannelDmail,
+    PromChannelEmail,
+    PromChannelCatalog,
+    PromChannelTv,
+    PromChannelRadio,
+    PromChannelPress,
+    PromChannelEvent,
+    PromChannelDemo,
+    PromChannelDetails,
+    PromPurpose,
+    PromDiscountActive,
+}
+
+impl SPromotionColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SPromotionColumn] {
+        use SPromotionColumn::*;
+        static VALUES: &[SPromotionColumn] = &[
+            PromPromotionId,
+            PromPromotionName,
+            PromStartDate,
+            PromEndDate,
+            PromCost,
+            PromResponseTarget,
+            PromChannelDmail,
+            PromChannelEmail,
+            PromChannelCatalog,
+            PromChannelTv,
+            PromChannelRadio,
+            PromChannelPress,
+            PromChannelEvent,
+            PromChannelDemo,
+            PromChannelDetails,
+            PromPurpose,
+            PromDiscountActive,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SPromotionColumn::PromPromotionId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SPromotionColumn::PromPromotionName => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(50))
+            }
+            SPromotionColumn::PromStartDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SPromotionColumn::PromEndDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SPromotionColumn::PromCost => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(15, 2))
+            }
+            SPromotionColumn::PromResponseTarget => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SPromotionColumn::PromChannelDmail => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            SPromotionColumn::PromChannelEmail => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            SPromotionColumn::PromChannelCatalog => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            SPromotionColumn::PromChannelTv => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            SPromotionColumn::PromChannelRadio => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            SPromotionColumn::PromChannelPress => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            SPromotionColumn::PromChannelEvent => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            SPromotionColumn::PromChannelDemo => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            SPromotionColumn::PromChannelDetails => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(100))
+            }
+            SPromotionColumn::PromPurpose => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(15))
+            }
+            SPromotionColumn::PromDiscountActive => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+        }
+    }
+}
+
+impl Column for SPromotionColumn {
+    fn get_table(&self) -> Table {
+        Table::SPromotion
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SPromotionColumn::PromPromotionId => "prom_promotion_id",
+            SPromotionColumn::PromPromotionName => "prom_promotion_name",
+            SPromotionColumn::PromStartDate => "prom_start_date",
+            SPromotionColumn::PromEndDate => "prom_end_date",
+            SPromotionColumn::PromCost => "prom_cost",
+            SPromotionColumn::PromResponseTarget => "prom_response_target",
+            SPromotionColumn::PromChannelDmail => "prom_channel_dmail",
+            SPromotionColumn::PromChannelEmail => "prom_channel_email",
+            SPromotionColumn::PromChannelCatalog => "prom_channel_catalog",
+            SPromotionColumn::PromChannelTv => "prom_channel_tv",
+            SPromotionColumn::PromChannelRadio => "prom_channel_radio",
+            SPromotionColumn::PromChannelPress => "prom_channel_press",
+            SPromotionColumn::PromChannelEvent => "prom_channel_event",
+            SPromotionColumn::PromChannelDemo => "prom_channel_demo",
+            SPromotionColumn::PromChannelDetails => "prom_channel_details",
+            SPromotionColumn::PromPurpose => "prom_purpose",
+            SPromotionColumn::PromDiscountActive => "prom_discount_active",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_promotion_columns() {
+        assert_eq!(SPromotionColumn::values().len(), 17);
+        assert_eq!(
+            SPromotionColumn::PromPromotionId.get_name(),
+            "prom_promotion_id"
+        );
+        assert_eq!(SPromotionColumn::PromDiscountActive.get_position(), 16);
+        assert_eq!(
+            SPromotionColumn::PromStartDate.get_type().get_base(),
+            ColumnTypeBase::Date
+        );
+        assert_eq!(
+            SPromotionColumn::PromPromotionId.get_table(),
+            Table::SPromotion
+        );
+    }
+}
@@ -0,0 +1,242 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Store purchase refresh source table columns (s_purchase)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SPurchaseColumn {
+    PurcPurchaseId,
+    PurcStoreId,
+    PurcCustomerId,
+    PurcPurchaseDate,
+    PurcPurchaseTime,
+    PurcRegisterId,
+    PurcClerkId,
+    PurcComment,
+}
+
+impl SPurchaseColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SPurchaseColumn] {
+        use SPurchaseColumn::*;
+        static VALUES: &[SPurchaseColumn] = &[
+            PurcPurchaseId,
+            PurcStoreId,
+            PurcCustomerId,
+            PurcPurchaseDate,
+            PurcPurchaseTime,
+            PurcRegisterId,
+            PurcClerkId,
+            PurcComment,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SPurchaseColumn::PurcPurchaseId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            SPurchaseColumn::PurcStoreId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SPurchaseColumn::PurcCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SPurchaseColumn::PurcPurchaseDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SPurchaseColumn::PurcPurchaseTime => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SPurchaseColumn::PurcRegisterId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SPurchaseColumn::PurcClerkId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SPurchaseColumn::PurcComment => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(100))
+            }
+        }
+    }
+}
+
+impl Column for SPurchaseColumn {
+    fn get_table(&self) -> Table {
+        Table::SPurchase
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SPurchaseColumn::PurcPurchaseId => "purc_purchase_id",
+            SPurchaseColumn::PurcStoreId => "purc_store_id",
+            SPurchaseColumn::PurcCustomerId => "purc_customer_id",
+            SPurchaseColumn::PurcPurchaseDate => "purc_purchase_date",
+            SPurchaseColumn::PurcPurchaseTime => "purc_purchase_time",
+            SPurchaseColumn::PurcRegisterId => "purc_register_id",
+            SPurchaseColumn::PurcClerkId => "purc_clerk_id",
+            SPurchaseColumn::PurcComment => "purc_comment",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+/// Store purchase line refresh source table columns (s_purchase_lineitem)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SPurchaseLineitemColumn {
+    PlinPurchaseId,
+    PlinLineNumber,
+    PlinItemId,
+    PlinPromotionId,
+    PlinQuantity,
+    PlinSalePrice,
+    PlinCouponAmt,
+    PlinComment,
+}
+
+impl SPurchaseLineitemColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SPurchaseLineitemColumn] {
+        use SPurchaseLineitemColumn::*;
+        static VALUES: &[SPurchaseLineitemColumn] = &[
+            PlinPurchaseId,
+            PlinLineNumber,
+            PlinItemId,
+            PlinPromotionId,
+            PlinQuantity,
+            PlinSalePrice,
+            PlinCouponAmt,
+            PlinComment,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SPurchaseLineitemColumn::PlinPurchaseId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            SPurchaseLineitemColumn::PlinLineNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SPurchaseLineitemColumn::PlinItemId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SPurchaseLineitemColumn::PlinPromotionId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SPurchaseLineitemColumn::PlinQuantity => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SPurchaseLineitemColumn::PlinSalePrice => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SPurchaseLineitemColumn::PlinCouponAmt => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SPurchaseLineitemColumn::PlinComment => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(100))
+            }
+        }
+    }
+}
+
+impl Column for SPurchaseLineitemColumn {
+    fn get_table(&self) -> Table {
+        Table::SPurchaseLineitem
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SPurchaseLineitemColumn::PlinPurchaseId => "plin_purchase_id",
+            SPurchaseLineitemColumn::PlinLineNumber => "plin_line_number",
+            SPurchaseLineitemColumn::PlinItemId => "plin_item_id",
+            SPurchaseLineitemColumn::PlinPromotionId => "plin_promotion_id",
+            SPurchaseLineitemColumn::PlinQuantity => "plin_quantity",
+            SPurchaseLineitemColumn::PlinSalePrice => "plin_sale_price",
+            SPurchaseLineitemColumn::PlinCouponAmt => "plin_coupon_amt",
+            SPurchaseLineitemColumn::PlinComment => "plin_comment",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_purchase_columns() {
+        assert_eq!(SPurchaseColumn::values().len(), 8);
+        assert_eq!(
+            SPurchaseColumn::PurcPurchaseId.get_name(),
+            "purc_purchase_id"
+        );
+        assert_eq!(SPurchaseColumn::PurcComment.get_position(), 7);
+        assert_eq!(
+            SPurchaseColumn::PurcPurchaseId.get_table(),
+            Table::SPurchase
+        );
+        assert_eq!(SPurchaseLineitemColumn::values().len(), 8);
+        assert_eq!(
+            SPurchaseLineitemColumn::PlinPurchaseId.get_name(),
+            "plin_purchase_id"
+        );
+        assert_eq!(SPurchaseLineitemColumn::PlinComment.get_position(), 7);
+        assert_eq!(
+            SPurchaseLineitemColumn::PlinPurchaseId.get_table(),
+            Table::SPurchaseLineitem
+        );
+        assert_eq!(
+            SPurchaseColumn::PurcPurchaseDate.get_type().get_base(),
+            ColumnTypeBase::Date
+        );
+    }
+}
@@ -0,0 +1,189 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Store returns refresh source table columns (s_store_returns)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SStoreReturnsColumn {
+    SretStoreId,
+    SretPurchaseId,
+    SretLineNumber,
+    SretItemId,
+    SretCustomerId,
+    SretReturnDate,
+    SretReturnTime,
+    SretReturnQty,
+    SretReturnAmt,
+    SretReturnTax,
+    SretReturnFee,
+    SretReturnShipCost,
+    SretRefundedCash,
+    SretReversedCharge,
+    SretStoreCredit,
+    SretReasonId,
+}
+
+impl SStoreReturnsColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SStoreReturnsColumn] {
+        use SStoreReturnsColumn::*;
+        static VALUES: &[SStoreReturnsColumn] = &[
+            SretStoreId,
+            SretPurchaseId,
+            SretLineNumber,
+            SretItemId,
+            SretCustomerId,
+            SretReturnDate,
+            SretReturnTime,
+            SretReturnQty,
+            SretReturnAmt,
+            SretReturnTax,
+            SretReturnFee,
+            SretReturnShipCost,
+            SretRefundedCash,
+            SretReversedCharge,
+            SretStoreCredit,
+            SretReasonId,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SStoreReturnsColumn::SretStoreId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SStoreReturnsColumn::SretPurchaseId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            SStoreReturnsColumn::SretLineNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SStoreReturnsColumn::SretItemId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SStoreReturnsColumn::SretCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SStoreReturnsColumn::SretReturnDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SStoreReturnsColumn::SretReturnTime => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SStoreReturnsColumn::SretReturnQty => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SStoreReturnsColumn::SretReturnAmt => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SStoreReturnsColumn::SretReturnTax => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SStoreReturnsColumn::SretReturnFee => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SStoreReturnsColumn::SretReturnShipCost => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SStoreReturnsColumn::SretRefundedCash => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SStoreReturnsColumn::SretReversedCharge => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SStoreReturnsColumn::SretStoreCredit => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SStoreReturnsColumn::SretReasonId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+        }
+    }
+}
+
+impl Column for SStoreReturnsColumn {
+    fn get_table(&self) -> Table {
+        Table::SStoreReturns
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SStoreReturnsColumn::SretStoreId => "sret_store_id",
+            SStoreReturnsColumn::SretPurchaseId => "sret_purchase_id",
+            SStoreReturnsColumn::SretLineNumber => "sret_line_number",
+            SStoreReturnsColumn::SretItemId => "sret_item_id",
+            SStoreReturnsColumn::SretCustomerId => "sret_customer_id",
+            SStoreReturnsColumn::SretReturnDate => "sret_return_date",
+            SStoreReturnsColumn::SretReturnTime => "sret_return_time",
+            SStoreReturnsColumn::SretReturnQty => "sret_return_qty",
+            SStoreReturnsColumn::SretReturnAmt => "sret_return_amt",
+            SStoreReturnsColumn::SretReturnTax => "sret_return_tax",
+            SStoreReturnsColumn::SretReturnFee => "sret_return_fee",
+            SStoreReturnsColumn::SretReturnShipCost => "sret_return_ship_cost",
+            SStoreReturnsColumn::SretRefundedCash => "sret_refunded_cash",
+            SStoreReturnsColumn::SretReversedCharge => "sret_reversed_charge",
+            SStoreReturnsColumn::SretStoreCredit => "sret_store_credit",
+            SStoreReturnsColumn::SretReasonId => "sret_reason_id",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_store_returns_columns() {
+        assert_eq!(SStoreReturnsColumn::values().len(), 16);
+        assert_eq!(SStoreReturnsColumn::SretStoreId.get_name(), "sret_store_id");
+        assert_eq!(SStoreReturnsColumn::SretReasonId.get_position(), 15);
+        assert_eq!(
+            SStoreReturnsColumn::SretStoreId.get_table(),
+            Table::SStoreReturns
+        );
+        assert_eq!(
+            SStoreReturnsColumn::SretReturnDate.get_type().get_base(),
+            ColumnTypeBase::Date
+        );
+    }
+}
@@ -0,0 +1,98 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Warehouse refresh source table columns (s_warehouse)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWarehouseColumn {
+    WrhsWarehouseId,
+    WrhsWarehouseDesc,
+    WrhsWarehouseSqFt,
+}
+
+impl SWarehouseColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SWarehouseColumn] {
+        use SWarehouseColumn::*;
+        static VALUES: &[SWarehouseColumn] =
+            &[WrhsWarehouseId, WrhsWarehouseDesc, WrhsWarehouseSqFt];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SWarehouseColumn::WrhsWarehouseId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWarehouseColumn::WrhsWarehouseDesc => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(200))
+            }
+            SWarehouseColumn::WrhsWarehouseSqFt => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+        }
+    }
+}
+
+impl Column for SWarehouseColumn {
+    fn get_table(&self) -> Table {
+        Table::SWarehouse
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SWarehouseColumn::WrhsWarehouseId => "wrhs_warehouse_id",
+            SWarehouseColumn::WrhsWarehouseDesc => "wrhs_warehouse_desc",
+            SWarehouseColumn::WrhsWarehouseSqFt => "wrhs_warehouse_sq_ft",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_warehouse_columns() {
+        assert_eq!(SWarehouseColumn::values().len(), 3);
+        assert_eq!(
+            SWarehouseColumn::WrhsWarehouseId.get_name(),
+            "wrhs_warehouse_id"
+        );
+        assert_eq!(SWarehouseColumn::WrhsWarehouseSqFt.get_position(), 2);
+        assert_eq!(
+            SWarehouseColumn::WrhsWarehouseSqFt.get_type().get_base(),
+            ColumnTypeBase::Integer
+        );
+        assert_eq!(
+            SWarehouseColumn::WrhsWarehouseId.get_table(),
+            Table::SWarehouse
+        );
+    }
+}
@@ -0,0 +1,257 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Web order refresh source table columns (s_web_order)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWebOrderColumn {
+    WordOrderId,
+    WordBillCustomerId,
+    WordShipCustomerId,
+    WordOrderDate,
+    WordOrderTime,
+    WordShipModeId,
+    WordWebSiteId,
+    WordOrderComments,
+}
+
+impl SWebOrderColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SWebOrderColumn] {
+        use SWebOrderColumn::*;
+        static VALUES: &[SWebOrderColumn] = &[
+            WordOrderId,
+            WordBillCustomerId,
+            WordShipCustomerId,
+            WordOrderDate,
+            WordOrderTime,
+            WordShipModeId,
+            WordWebSiteId,
+            WordOrderComments,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SWebOrderColumn::WordOrderId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            SWebOrderColumn::WordBillCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebOrderColumn::WordShipCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebOrderColumn::WordOrderDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SWebOrderColumn::WordOrderTime => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SWebOrderColumn::WordShipModeId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebOrderColumn::WordWebSiteId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebOrderColumn::WordOrderComments => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(100))
+            }
+        }
+    }
+}
+
+impl Column for SWebOrderColumn {
+    fn get_table(&self) -> Table {
+        Table::SWebOrder
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SWebOrderColumn::WordOrderId => "word_order_id",
+            SWebOrderColumn::WordBillCustomerId => "word_bill_customer_id",
+            SWebOrderColumn::WordShipCustomerId => "word_ship_customer_id",
+            SWebOrderColumn::WordOrderDate => "word_order_date",
+            SWebOrderColumn::WordOrderTime => "word_order_time",
+            SWebOrderColumn::WordShipModeId => "word_ship_mode_id",
+            SWebOrderColumn::WordWebSiteId => "word_web_site_id",
+            SWebOrderColumn::WordOrderComments => "word_order_comments",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+/// Web order line refresh source table columns (s_web_order_lineitem)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWebOrderLineitemColumn {
+    WlinOrderId,
+    WlinLineNumber,
+    WlinItemId,
+    WlinPromotionId,
+    WlinQuantity,
+    WlinSalesPrice,
+    WlinCouponAmt,
+    WlinWarehouseId,
+    WlinShipDate,
+    WlinShipCost,
+    WlinWebPageId,
+}
+
+impl SWebOrderLineitemColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SWebOrderLineitemColumn] {
+        use SWebOrderLineitemColumn::*;
+        static VALUES: &[SWebOrderLineitemColumn] = &[
+            WlinOrderId,
+            WlinLineNumber,
+            WlinItemId,
+            WlinPromotionId,
+            WlinQuantity,
+            WlinSalesPrice,
+            WlinCouponAmt,
+            WlinWarehouseId,
+            WlinShipDate,
+            WlinShipCost,
+            WlinWebPageId,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SWebOrderLineitemColumn::WlinOrderId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            SWebOrderLineitemColumn::WlinLineNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SWebOrderLineitemColumn::WlinItemId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebOrderLineitemColumn::WlinPromotionId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebOrderLineitemColumn::WlinQuantity => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SWebOrderLineitemColumn::WlinSalesPrice => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebOrderLineitemColumn::WlinCouponAmt => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebOrderLineitemColumn::WlinWarehouseId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebOrderLineitemColumn::WlinShipDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SWebOrderLineitemColumn::WlinShipCost => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebOrderLineitemColumn::WlinWebPageId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+        }
+    }
+}
+
+impl Column for SWebOrderLineitemColumn {
+    fn get_table(&self) -> Table {
+        Table::SWebOrderLineitem
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SWebOrderLineitemColumn::WlinOrderId => "wlin_order_id",
+            SWebOrderLineitemColumn::WlinLineNumber => "wlin_line_number",
+            SWebOrderLineitemColumn::WlinItemId => "wlin_item_id",
+            SWebOrderLineitemColumn::WlinPromotionId => "wlin_promotion_id",
+            SWebOrderLineitemColumn::WlinQuantity => "wlin_quantity",
+            SWebOrderLineitemColumn::WlinSalesPrice => "wlin_sales_price",
+            SWebOrderLineitemColumn::WlinCouponAmt => "wlin_coupon_amt",
+            SWebOrderLineitemColumn::WlinWarehouseId => "wlin_warehouse_id",
+            SWebOrderLineitemColumn::WlinShipDate => "wlin_ship_date",
+            SWebOrderLineitemColumn::WlinShipCost => "wlin_ship_cost",
+            SWebOrderLineitemColumn::WlinWebPageId => "wlin_web_page_id",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_web_order_columns() {
+        assert_eq!(SWebOrderColumn::values().len(), 8);
+        assert_eq!(SWebOrderColumn::WordOrderId.get_name(), "word_order_id");
+        assert_eq!(SWebOrderColumn::WordOrderComments.get_position(), 7);
+        assert_eq!(SWebOrderColumn::WordOrderId.get_table(), Table::SWebOrder);
+        assert_eq!(SWebOrderLineitemColumn::values().len(), 11);
+        assert_eq!(
+            SWebOrderLineitemColumn::WlinOrderId.get_name(),
+            "wlin_order_id"
+        );
+        assert_eq!(SWebOrderLineitemColumn::WlinWebPageId.get_position(), 10);
+        assert_eq!(
+            SWebOrderLineitemColumn::WlinOrderId.get_table(),
+            Table::SWebOrderLineitem
+        );
+        assert_eq!(
+            SWebOrderColumn::WordOrderDate.get_type().get_base(),
+            ColumnTypeBase::Date
+        );
+    }
+}
@@ -0,0 +1,144 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Web page refresh source table columns (s_web_page)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWebPageColumn {
+    WpagWebPageId,
+    WpagCreateDate,
+    WpagAccessDate,
+    WpagAutogenFlag,
+    WpagUrl,
+    WpagType,
+    WpagCharCount,
+    WpagLinkCount,
+    WpagImageCount,
+    WpagMaxAdCount,
+}
+
+impl SWebPageColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SWebPageColumn] {
+        use SWebPageColumn::*;
+        static VALUES: &[SWebPageColumn] = &[
+            WpagWebPageId,
+            WpagCreateDate,
+            WpagAccessDate,
+            WpagAutogenFlag,
+            WpagUrl,
+            WpagType,
+            WpagCharCount,
+            WpagLinkCount,
+            WpagImageCount,
+            WpagMaxAdCount,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SWebPageColumn::WpagWebPageId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebPageColumn::WpagCreateDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SWebPageColumn::WpagAccessDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SWebPageColumn::WpagAutogenFlag => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            SWebPageColumn::WpagUrl => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(100))
+            }
+            SWebPageColumn::WpagType => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(50))
+            }
+            SWebPageColumn::WpagCharCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SWebPageColumn::WpagLinkCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SWebPageColumn::WpagImageCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SWebPageColumn::WpagMaxAdCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+        }
+    }
+}
+
+impl Column for SWebPageColumn {
+    fn get_table(&self) -> Table {
+        Table::SWebPage
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SWebPageColumn::WpagWebPageId => "wpag_web_page_id",
+            SWebPageColumn::WpagCreateDate => "wpag_create_date",
+            SWebPageColumn::WpagAccessDate => "wpag_access_date",
+            SWebPageColumn::WpagAutogenFlag => "wpag_autogen_flag",
+            SWebPageColumn::WpagUrl => "wpag_url",
+            SWebPageColumn::WpagType => "wpag_type",
+            SWebPageColumn::WpagCharCount => "wpag_char_count",
+            SWebPageColumn::WpagLinkCount => "wpag_link_count",
+            SWebPageColumn::WpagImageCount => "wpag_image_count",
+            SWebPageColumn::WpagMaxAdCount => "wpag_max_ad_count",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_web_page_columns() {
+        assert_eq!(SWebPageColumn::values().len(), 10);
+        assert_eq!(SWebPageColumn::WpagWebPageId.get_name(), "wpag_web_page_id");
+        assert_eq!(SWebPageColumn::WpagMaxAdCount.get_position(), 9);
+        assert_eq!(
+            SWebPageColumn::WpagCreateDate.get_type().get_base(),
+            ColumnTypeBase::Date
+        );
+        assert_eq!(SWebPageColumn::WpagWebPageId.get_table(), Table::SWebPage);
+    }
+}
@@ -0,0 +1,199 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Web returns refresh source table columns (s_web_returns)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWebReturnsColumn {
+    WretWebPageId,
+    WretOrderId,
+    WretLineNumber,
+    WretItemId,
+    WretReturnCustomerId,
+    WretRefundCustomerId,
+    WretReturnDate,
+    WretReturnTime,
+    WretReturnQty,
+    WretReturnAmt,
+    WretReturnTax,
+    WretReturnFee,
+    WretReturnShipCost,
+    WretRefundedCash,
+    WretReversedCharge,
+    WretAccountCredit,
+    WretReasonId,
+}
+
+impl SWebReturnsColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SWebReturnsColumn] {
+        use SWebReturnsColumn::*;
+        static VALUES: &[SWebReturnsColumn] = &[
+            WretWebPageId,
+            WretOrderId,
+            WretLineNumber,
+            WretItemId,
+            WretReturnCustomerId,
+            WretRefundCustomerId,
+            WretReturnDate,
+            WretReturnTime,
+            WretReturnQty,
+            WretReturnAmt,
+            WretReturnTax,
+            WretReturnFee,
+            WretReturnShipCost,
+            WretRefundedCash,
+            WretReversedCharge,
+            WretAccountCredit,
+            WretReasonId,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SWebReturnsColumn::WretWebPageId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebReturnsColumn::WretOrderId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            SWebReturnsColumn::WretLineNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SWebReturnsColumn::WretItemId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebReturnsColumn::WretReturnCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebReturnsColumn::WretRefundCustomerId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            SWebReturnsColumn::WretReturnDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            SWebReturnsColumn::WretReturnTime => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SWebReturnsColumn::WretReturnQty => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            SWebReturnsColumn::WretReturnAmt => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebReturnsColumn::WretReturnTax => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebReturnsColumn::WretReturnFee => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebReturnsColumn::WretReturnShipCost => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebReturnsColumn::WretRefundedCash => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebReturnsColumn::WretReversedCharge => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebReturnsColumn::WretAccountCredit => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(7, 2))
+            }
+            SWebReturnsColumn::WretReasonId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+        }
+    }
+}
+
+impl Column for SWebReturnsColumn {
+    fn get_table(&self) -> Table {
+        Table::SWebReturns
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SWebReturnsColumn::WretWebPageId => "wret_web_page_id",
+            SWebReturnsColumn::WretOrderId => "wret_order_id",
+            SWebReturnsColumn::WretLineNumber => "wret_line_number",
+            SWebReturnsColumn::WretItemId => "wret_item_id",
+            SWebReturnsColumn::WretReturnCustomerId => "wret_return_customer_id",
+            SWebReturnsColumn::WretRefundCustomerId => "wret_refund_customer_id",
+            SWebReturnsColumn::WretReturnDate => "wret_return_date",
+            SWebReturnsColumn::WretReturnTime => "wret_return_time",
+            SWebReturnsColumn::WretReturnQty => "wret_return_qty",
+            SWebReturnsColumn::WretReturnAmt => "wret_return_amt",
+            SWebReturnsColumn::WretReturnTax => "wret_return_tax",
+            SWebReturnsColumn::WretReturnFee => "wret_return_fee",
+            SWebReturnsColumn::WretReturnShipCost => "wret_return_ship_cost",
+            SWebReturnsColumn::WretRefundedCash => "wret_refunded_cash",
+            SWebReturnsColumn::WretReversedCharge => "wret_reversed_charge",
+            SWebReturnsColumn::WretAccountCredit => "wret_account_credit",
+            SWebReturnsColumn::WretReasonId => "wret_reason_id",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_web_returns_columns() {
+        assert_eq!(SWebReturnsColumn::values().len(), 17);
+        assert_eq!(
+            SWebReturnsColumn::WretWebPageId.get_name(),
+            "wret_web_page_id"
+        );
+        assert_eq!(SWebReturnsColumn::WretReasonId.get_position(), 16);
+        assert_eq!(
+            SWebReturnsColumn::WretWebPageId.get_table(),
+            Table::SWebReturns
+        );
+        assert_eq!(
+            SWebReturnsColumn::WretReturnDate.get_type().get_base(),
+            ColumnTypeBase::Date
+        );
+    }
+}
@@ -0,0 +1,85 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Zip code to GMT offset refresh source table columns (s_zip_to_gmt)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SZipToGmtColumn {
+    ZipgZip,
+    ZipgGmtOffset,
+}
+
+impl SZipToGmtColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [SZipToGmtColumn] {
+        use SZipToGmtColumn::*;
+        static VALUES: &[SZipToGmtColumn] = &[ZipgZip, ZipgGmtOffset];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            SZipToGmtColumn::ZipgZip => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(5))
+            }
+            SZipToGmtColumn::ZipgGmtOffset => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+        }
+    }
+}
+
+impl Column for SZipToGmtColumn {
+    fn get_table(&self) -> Table {
+        Table::SZipToGmt
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            SZipToGmtColumn::ZipgZip => "zipg_zip",
+            SZipToGmtColumn::ZipgGmtOffset => "zipg_gmt_offset",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_s_zip_to_gmt_columns() {
+        assert_eq!(SZipToGmtColumn::values().len(), 2);
+        assert_eq!(SZipToGmtColumn::ZipgZip.get_name(), "zipg_zip");
+        assert_eq!(SZipToGmtColumn::ZipgGmtOffset.get_position(), 1);
+        assert_eq!(
+            SZipToGmtColumn::ZipgGmtOffset.get_type().get_base(),
+            ColumnTypeBase::Integer
+        );
+        assert_eq!(SZipToGmtColumn::ZipgZip.get_table(), Table::SZipToGmt);
+    }
+}
@@ -0,0 +1,129 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Ship Mode table columns (ShipModeColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShipModeColumn {
+    SmShipModeSk,
+    SmShipModeId,
+    SmType,
+    SmCode,
+    SmCarrier,
+    SmContract,
+}
+
+impl ShipModeColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [ShipModeColumn] {
+        use ShipModeColumn::*;
+        static VALUES: &[ShipModeColumn] = &[
+            SmShipModeSk,
+            SmShipModeId,
+            SmType,
+            SmCode,
+            SmCarrier,
+            SmContract,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            ShipModeColumn::SmShipModeSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            ShipModeColumn::SmShipModeId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            ShipModeColumn::SmType => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(30))
+            }
+            ShipModeColumn::SmCode => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(10))
+            }
+            ShipModeColumn::SmCarrier => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+            ShipModeColumn::SmContract => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+        }
+    }
+}
+
+impl Column for ShipModeColumn {
+    fn get_table(&self) -> Table {
+        Table::ShipMode
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            ShipModeColumn::SmShipModeSk => "sm_ship_mode_sk",
+            ShipModeColumn::SmShipModeId => "sm_ship_mode_id",
+            ShipModeColumn::SmType => "sm_type",
+            ShipModeColumn::SmCode => "sm_code",
+            ShipModeColumn::SmCarrier => "sm_carrier",
+            ShipModeColumn::SmContract => "sm_contract",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_ship_mode_columns() {
+        assert_eq!(ShipModeColumn::values().len(), 6);
+        assert_eq!(ShipModeColumn::SmShipModeSk.get_name(), "sm_ship_mode_sk");
+        assert_eq!(ShipModeColumn::SmContract.get_name(), "sm_contract");
+        assert_eq!(ShipModeColumn::SmContract.get_position(), 5);
+        assert_eq!(ShipModeColumn::SmShipModeSk.get_table(), Table::ShipMode);
+    }
+
+    #[test]
+    fn test_ship_mode_column_types() {
+        assert_eq!(
+            ShipModeColumn::SmShipModeSk.get_type().get_base(),
+            ColumnTypeBase::Identifier
+        );
+        assert_eq!(
+            ShipModeColumn::SmShipModeId.get_type().get_base(),
+            ColumnTypeBase::Char
+        );
+        assert_eq!(
+            ShipModeColumn::SmShipModeId.get_type().get_precision(),
+            Some(16)
+        );
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Time Dimension table columns (TimeDimColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeDimColumn {
+    TTimeSk,
+    TTimeId,
+    TTime,
+    THour,
+    TMinute,
+    TSecond,
+    TAmPm,
+    TShift,
+    TSubShift,
+    TMealTime,
+}
+
+impl TimeDimColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [TimeDimColumn] {
+        use TimeDimColumn::*;
+        static VALUES: &[TimeDimColumn] = &[
+            TTimeSk, TTimeId, TTime, THour, TMinute, TSecond, TAmPm, TShift, TSubShift, TMealTime,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            TimeDimColumn::TTimeSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            TimeDimColumn::TTimeId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            TimeDimColumn::TTime => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            TimeDimColumn::THour => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            TimeDimColumn::TMinute => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            TimeDimColumn::TSecond => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            TimeDimColumn::TAmPm => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(2))
+            }
+            TimeDimColumn::TShift => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+            TimeDimColumn::TSubShift => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+            TimeDimColumn::TMealTime => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(20))
+            }
+        }
+    }
+}
+
+impl Column for TimeDimColumn {
+    fn get_table(&self) -> Table {
+        Table::TimeDim
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            TimeDimColumn::TTimeSk => "t_time_sk",
+            TimeDimColumn::TTimeId => "t_time_id",
+            TimeDimColumn::TTime => "t_time",
+            TimeDimColumn::THour => "t_hour",
+            TimeDimColumn::TMinute => "t_minute",
+            TimeDimColumn::TSecond => "t_second",
+            TimeDimColumn::TAmPm => "t_am_pm",
+            TimeDimColumn::TShift => "t_shift",
+            TimeDimColumn::TSubShift => "t_sub_shift",
+            TimeDimColumn::TMealTime => "t_meal_time",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_time_dim_columns() {
+        assert_eq!(TimeDimColumn::values().len(), 10);
+        assert_eq!(TimeDimColumn::TTimeSk.get_name(), "t_time_sk");
+        assert_eq!(TimeDimColumn::TMealTime.get_name(), "t_meal_time");
+        assert_eq!(TimeDimColumn::TMealTime.get_position(), 9);
+        assert_eq!(TimeDimColumn::TTimeSk.get_table(), Table::TimeDim);
+    }
+
+    #[test]
+    fn test_time_dim_column_types() {
+        assert_eq!(
+            TimeDimColumn::TTimeSk.get_type().get_base(),
+            ColumnTypeBase::Identifier
+        );
+        assert_eq!(
+            TimeDimColumn::TTimeId.get_type().get_base(),
+            ColumnTypeBase::Char
+        );
+        assert_eq!(TimeDimColumn::TTimeId.get_type().get_precision(), Some(16));
+    }
+}
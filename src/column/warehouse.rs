@@ -0,0 +1,185 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Warehouse table columns (WarehouseColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WarehouseColumn {
+    WWarehouseSk,
+    WWarehouseId,
+    WWarehouseName,
+    WWarehouseSqFt,
+    WStreetNumber,
+    WStreetName,
+    WStreetType,
+    WSuiteNumber,
+    WCity,
+    WCounty,
+    WState,
+    WZip,
+    WCountry,
+    WGmtOffset,
+}
+
+impl WarehouseColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [WarehouseColumn] {
+        use WarehouseColumn::*;
+        static VALUES: &[WarehouseColumn] = &[
+            WWarehouseSk,
+            WWarehouseId,
+            WWarehouseName,
+            WWarehouseSqFt,
+            WStreetNumber,
+            WStreetName,
+            WStreetType,
+            WSuiteNumber,
+            WCity,
+            WCounty,
+            WState,
+            WZip,
+            WCountry,
+            WGmtOffset,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            WarehouseColumn::WWarehouseSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            WarehouseColumn::WWarehouseId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            WarehouseColumn::WWarehouseName => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(20))
+            }
+            WarehouseColumn::WWarehouseSqFt => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            WarehouseColumn::WStreetNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(10))
+            }
+            WarehouseColumn::WStreetName => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(60))
+            }
+            WarehouseColumn::WStreetType => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(15))
+            }
+            WarehouseColumn::WSuiteNumber => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(10))
+            }
+            WarehouseColumn::WCity => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(60))
+            }
+            WarehouseColumn::WCounty => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(30))
+            }
+            WarehouseColumn::WState => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(2))
+            }
+            WarehouseColumn::WZip => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(10))
+            }
+            WarehouseColumn::WCountry => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(20))
+            }
+            WarehouseColumn::WGmtOffset => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::decimal(5, 2))
+            }
+        }
+    }
+}
+
+impl Column for WarehouseColumn {
+    fn get_table(&self) -> Table {
+        Table::Warehouse
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            WarehouseColumn::WWarehouseSk => "w_warehouse_sk",
+            WarehouseColumn::WWarehouseId => "w_warehouse_id",
+            WarehouseColumn::WWarehouseName => "w_warehouse_name",
+            WarehouseColumn::WWarehouseSqFt => "w_warehouse_sq_ft",
+            WarehouseColumn::WStreetNumber => "w_street_number",
+            WarehouseColumn::WStreetName => "w_street_name",
+            WarehouseColumn::WStreetType => "w_street_type",
+            WarehouseColumn::WSuiteNumber => "w_suite_number",
+            WarehouseColumn::WCity => "w_city",
+            WarehouseColumn::WCounty => "w_county",
+            WarehouseColumn::WState => "w_state",
+            WarehouseColumn::WZip => "w_zip",
+            WarehouseColumn::WCountry => "w_country",
+            WarehouseColumn::WGmtOffset => "w_gmt_offset",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_warehouse_columns() {
+        assert_eq!(WarehouseColumn::values().len(), 14);
+        assert_eq!(WarehouseColumn::WWarehouseSk.get_name(), "w_warehouse_sk");
+        assert_eq!(WarehouseColumn::WGmtOffset.get_name(), "w_gmt_offset");
+        assert_eq!(WarehouseColumn::WGmtOffset.get_position(), 13);
+        assert_eq!(WarehouseColumn::WWarehouseSk.get_table(), Table::Warehouse);
+    }
+
+    #[test]
+    fn test_warehouse_column_types() {
+        assert_eq!(
+            WarehouseColumn::WWarehouseSk.get_type().get_base(),
+            ColumnTypeBase::Identifier
+        );
+        assert_eq!(
+            WarehouseColumn::WWarehouseId.get_type().get_base(),
+            ColumnTypeBase::Char
+        );
+        assert_eq!(
+            WarehouseColumn::WWarehouseId.get_type().get_precision(),
+            Some(16)
+        );
+    }
+}
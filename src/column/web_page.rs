@@ -0,0 +1,185 @@
+/*
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::column::{Column, ColumnType, ColumnTypes, Table};
+use std::sync::OnceLock;
+
+/// Web Page table columns (WebPageColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WebPageColumn {
+    WpWebPageSk,
+    WpWebPageId,
+    WpRecStartDate,
+    WpRecEndDate,
+    WpCreationDateSk,
+    WpAccessDateSk,
+    WpAutogenFlag,
+    WpCustomerSk,
+    WpUrl,
+    WpType,
+    WpCharCount,
+    WpLinkCount,
+    WpImageCount,
+    WpMaxAdCount,
+}
+
+impl WebPageColumn {
+    /// Get all columns in order
+    pub fn values() -> &'static [WebPageColumn] {
+        use WebPageColumn::*;
+        static VALUES: &[WebPageColumn] = &[
+            WpWebPageSk,
+            WpWebPageId,
+            WpRecStartDate,
+            WpRecEndDate,
+            WpCreationDateSk,
+            WpAccessDateSk,
+            WpAutogenFlag,
+            WpCustomerSk,
+            WpUrl,
+            WpType,
+            WpCharCount,
+            WpLinkCount,
+            WpImageCount,
+            WpMaxAdCount,
+        ];
+        VALUES
+    }
+
+    /// Get the column type for this column
+    fn get_column_type(&self) -> &'static ColumnType {
+        match self {
+            WebPageColumn::WpWebPageSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            WebPageColumn::WpWebPageId => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(16))
+            }
+            WebPageColumn::WpRecStartDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            WebPageColumn::WpRecEndDate => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::date().clone())
+            }
+            WebPageColumn::WpCreationDateSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            WebPageColumn::WpAccessDateSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            WebPageColumn::WpAutogenFlag => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(1))
+            }
+            WebPageColumn::WpCustomerSk => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::identifier().clone())
+            }
+            WebPageColumn::WpUrl => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::varchar(100))
+            }
+            WebPageColumn::WpType => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::character(50))
+            }
+            WebPageColumn::WpCharCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            WebPageColumn::WpLinkCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            WebPageColumn::WpImageCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+            WebPageColumn::WpMaxAdCount => {
+                static TYPE: OnceLock<ColumnType> = OnceLock::new();
+                TYPE.get_or_init(|| ColumnTypes::integer().clone())
+            }
+        }
+    }
+}
+
+impl Column for WebPageColumn {
+    fn get_table(&self) -> Table {
+        Table::WebPage
+    }
+
+    fn get_name(&self) -> &'static str {
+        match self {
+            WebPageColumn::WpWebPageSk => "wp_web_page_sk",
+            WebPageColumn::WpWebPageId => "wp_web_page_id",
+            WebPageColumn::WpRecStartDate => "wp_rec_start_date",
+            WebPageColumn::WpRecEndDate => "wp_rec_end_date",
+            WebPageColumn::WpCreationDateSk => "wp_creation_date_sk",
+            WebPageColumn::WpAccessDateSk => "wp_access_date_sk",
+            WebPageColumn::WpAutogenFlag => "wp_autogen_flag",
+            WebPageColumn::WpCustomerSk => "wp_customer_sk",
+            WebPageColumn::WpUrl => "wp_url",
+            WebPageColumn::WpType => "wp_type",
+            WebPageColumn::WpCharCount => "wp_char_count",
+            WebPageColumn::WpLinkCount => "wp_link_count",
+            WebPageColumn::WpImageCount => "wp_image_count",
+            WebPageColumn::WpMaxAdCount => "wp_max_ad_count",
+        }
+    }
+
+    fn get_type(&self) -> &ColumnType {
+        self.get_column_type()
+    }
+
+    fn get_position(&self) -> i32 {
+        *self as i32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::column::ColumnTypeBase;
+
+    #[test]
+    fn test_web_page_columns() {
+        assert_eq!(WebPageColumn::values().len(), 14);
+        assert_eq!(WebPageColumn::WpWebPageSk.get_name(), "wp_web_page_sk");
+        assert_eq!(WebPageColumn::WpMaxAdCount.get_name(), "wp_max_ad_count");
+        assert_eq!(WebPageColumn::WpMaxAdCount.get_position(), 13);
+        assert_eq!(WebPageColumn::WpWebPageSk.get_table(), Table::WebPage);
+    }
+
+    #[test]
+    fn test_web_page_column_types() {
+        assert_eq!(
+            WebPageColumn::WpWebPageSk.get_type().get_base(),
+            ColumnTypeBase::Identifier
+        );
+        assert_eq!(
+            WebPageColumn::WpWebPageId.get_type().get_base(),
+            ColumnTypeBase::Char
+        );
+        assert_eq!(
+            WebPageColumn::WpWebPageId.get_type().get_precision(),
+            Some(16)
+        );
+    }
+}
@@ -3,7 +3,7 @@ pub mod scaling;
 pub mod session;
 pub mod table;
 
-pub use options::Options;
+pub use options::{Command, DistCommand, Options};
 pub use scaling::Scaling;
 pub use session::Session;
 pub use table::Table;
@@ -1,6 +1,8 @@
 use crate::config::{Session, Table};
 use crate::error::{InvalidOptionError, Result};
-use clap::Parser;
+use crate::output::{Compression, OutputFormat};
+use crate::progress::ProgressMode;
+use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug, Clone)]
 #[command(name = "tpcdsgen")]
@@ -10,11 +12,15 @@ pub struct Options {
     #[arg(long = "scale", short = 's', default_value = "1")]
     pub scale: f64,
 
-    /// Directory to put generated files (Default: .)
+    /// Directory to put generated files, or - to write a single table to stdout (Default: .)
     #[arg(long = "directory", short = 'd', default_value = ".")]
     pub directory: String,
 
-    /// Suffix for generated data files (Default: .dat)
+    /// Write the table selected with --table to stdout (same as --directory -)
+    #[arg(long = "stdout")]
+    pub stdout: bool,
+
+    /// Suffix for generated data files (Default: .dat, or the extension of --format)
     #[arg(long = "suffix", default_value = ".dat")]
     pub suffix: String,
 
@@ -46,12 +52,130 @@ pub struct Options {
     /// Overwrite existing data files for tables
     #[arg(long = "overwrite")]
     pub overwrite: bool,
+
+    /// Compress data files with none, gzip, zstd or lz4 (Default: none)
+    #[arg(long = "compression", default_value = "none")]
+    pub compression: String,
+
+    /// Output format: text, sql, jsonl or pgcopy (Default: text)
+    #[arg(long = "format", default_value = "text")]
+    pub format: String,
+
+    /// Number of rows per INSERT statement with --format sql (Default: 100)
+    #[arg(long = "sql-batch-size", default_value = "100")]
+    pub sql_batch_size: i32,
+
+    /// Write decimals as JSON strings instead of numbers with --format jsonl
+    #[arg(long = "json-decimals-as-strings")]
+    pub json_decimals_as_strings: bool,
+
+    /// Directory of .dst files replacing the built-in distributions (output is not TPC-DS compliant)
+    #[arg(
+        long = "distributions-dir",
+        env = crate::distribution::DistributionFileLoader::OVERRIDE_DIRECTORY_VARIABLE
+    )]
+    pub distributions_dir: Option<String>,
+
+    /// Fail when a generator column draws more random numbers for a row than it declares
+    #[arg(long = "audit-seeds")]
+    pub audit_seeds: bool,
+
+    /// Generate the refresh data set <n> for data maintenance instead of the base data
+    #[arg(long = "update")]
+    pub update: Option<i32>,
+
+    /// Report progress on stderr as text, json (one object per line) or none (Default: text)
+    #[arg(long = "progress", default_value = "text")]
+    pub progress: String,
+
+    /// Keep the chunks completed by an interrupted run and generate only the others
+    #[arg(long = "resume")]
+    pub resume: bool,
+
+    /// With --resume, keep files that have the recorded size without checking their SHA-256
+    #[arg(long = "resume-skip-checksums")]
+    pub resume_skip_checksums: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Tools run instead of generating data
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum Command {
+    /// Inspect the distributions used by the generators
+    Dist {
+        #[command(subcommand)]
+        command: DistCommand,
+    },
+    /// Check the foreign keys of the data files in a directory against their parent tables.
+    /// Files are read with --separator and --suffix.
+    VerifyRi {
+        /// Directory of generated data files
+        directory: String,
+    },
+    /// Generate query streams from dsqgen query templates, written as query_<stream>.sql.
+    /// Substitutions depend on --scale.
+    Query {
+        /// Directory of the query templates (.tpl)
+        #[arg(long = "templates")]
+        templates: String,
+        /// File listing the templates of a stream, one per line (Default: every .tpl file)
+        #[arg(long = "input")]
+        input: Option<String>,
+        /// Number of query streams
+        #[arg(long = "streams", default_value = "1")]
+        streams: usize,
+        /// Seed of the substitutions and of the query order of streams after the first
+        #[arg(long = "seed", default_value_t = crate::query::QueryGenerator::DEFAULT_SEED)]
+        seed: i32,
+        /// Directory to write the streams to, or - for stdout
+        #[arg(long = "output", default_value = ".")]
+        output: String,
+        /// dsdgen distribution file, such as tpcds.dst, to take distributions from
+        #[arg(long = "distributions")]
+        distributions: Option<String>,
+        /// SQL dialect: ansi, duckdb, postgres, spark, trino, or a dialect template (.tpl)
+        #[arg(long = "dialect", default_value = "ansi")]
+        dialect: String,
+        /// File of fixed substitution values to use instead of random ones, or bundled for the
+        /// qualification values bundled for a few templates; generates stream 0 only
+        #[arg(long = "substitution-values")]
+        substitution_values: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug, Clone, PartialEq)]
+pub enum DistCommand {
+    /// List the distributions with their number of entries, value fields and weight fields
+    List,
+    /// Print the values, weights and cumulative weights of a distribution
+    Show {
+        /// Distribution name, e.g. call_centers
+        name: String,
+    },
+    /// Draw random entries from a distribution and compare their frequency to the weights
+    Sample {
+        /// Distribution name, e.g. call_centers
+        name: String,
+        /// Weight set to draw with: its number, starting at 1, or its name if the distribution
+        /// file names its weight sets
+        #[arg(long = "weights", default_value = "1")]
+        weights: String,
+        /// Number of entries to draw
+        #[arg(short = 'n', default_value = "1000")]
+        count: usize,
+        /// Seed of the random number stream
+        #[arg(long = "seed", default_value_t = crate::distribution::DistributionInspector::DEFAULT_SEED)]
+        seed: i32,
+    },
 }
 
 impl Options {
     // Default constants (matching Java implementation)
     pub const DEFAULT_SCALE: f64 = 1.0;
     pub const DEFAULT_DIRECTORY: &'static str = ".";
+    pub const STDOUT_DIRECTORY: &'static str = "-";
     pub const DEFAULT_SUFFIX: &'static str = ".dat";
     pub const DEFAULT_NULL_STRING: &'static str = "";
     pub const DEFAULT_SEPARATOR: char = '|';
@@ -59,11 +183,21 @@ impl Options {
     pub const DEFAULT_NO_SEXISM: bool = false;
     pub const DEFAULT_PARALLELISM: i32 = 1;
     pub const DEFAULT_OVERWRITE: bool = false;
+    pub const DEFAULT_COMPRESSION: Compression = Compression::None;
+    pub const DEFAULT_FORMAT: OutputFormat = OutputFormat::Text;
+    pub const DEFAULT_SQL_BATCH_SIZE: i32 = 100;
+    pub const DEFAULT_JSON_DECIMALS_AS_STRINGS: bool = false;
+    pub const DEFAULT_AUDIT_SEEDS: bool = false;
+    pub const DEFAULT_UPDATE: i32 = 0;
+    pub const DEFAULT_PROGRESS: ProgressMode = ProgressMode::Text;
+    pub const DEFAULT_RESUME: bool = false;
+    pub const DEFAULT_RESUME_SKIP_CHECKSUMS: bool = false;
 
     pub fn new() -> Self {
         Self {
             scale: Self::DEFAULT_SCALE,
             directory: Self::DEFAULT_DIRECTORY.to_string(),
+            stdout: false,
             suffix: Self::DEFAULT_SUFFIX.to_string(),
             table: None,
             null_string: Self::DEFAULT_NULL_STRING.to_string(),
@@ -72,6 +206,17 @@ impl Options {
             no_sexism: Self::DEFAULT_NO_SEXISM,
             parallelism: Self::DEFAULT_PARALLELISM,
             overwrite: Self::DEFAULT_OVERWRITE,
+            compression: Self::DEFAULT_COMPRESSION.to_string(),
+            format: Self::DEFAULT_FORMAT.to_string(),
+            sql_batch_size: Self::DEFAULT_SQL_BATCH_SIZE,
+            json_decimals_as_strings: Self::DEFAULT_JSON_DECIMALS_AS_STRINGS,
+            distributions_dir: None,
+            audit_seeds: Self::DEFAULT_AUDIT_SEEDS,
+            update: None,
+            progress: Self::DEFAULT_PROGRESS.to_string(),
+            resume: Self::DEFAULT_RESUME,
+            resume_skip_checksums: Self::DEFAULT_RESUME_SKIP_CHECKSUMS,
+            command: None,
         }
     }
 
@@ -97,10 +242,27 @@ impl Options {
             .into());
         };
 
+        let compression = self.parse_compression(&self.compression)?;
+        let format = self.parse_format(&self.format)?;
+        let progress = self.parse_progress(&self.progress)?;
+
+        // Without an explicit suffix the files are named after their format
+        let suffix = if self.suffix == Self::DEFAULT_SUFFIX {
+            format.get_suffix().to_string()
+        } else {
+            self.suffix.clone()
+        };
+
+        let directory = if self.stdout {
+            Self::STDOUT_DIRECTORY.to_string()
+        } else {
+            self.directory.clone()
+        };
+
         Ok(Session::new(
             self.scale,
-            self.directory.clone(),
-            self.suffix.clone(),
+            directory,
+            suffix,
             table_option,
             self.null_string.clone(),
             separator_char,
@@ -108,7 +270,17 @@ impl Options {
             self.no_sexism,
             self.parallelism,
             self.overwrite,
-        ))
+        )
+        .with_compression(compression)
+        .with_format(format)
+        .with_sql_batch_size(self.sql_batch_size)
+        .with_json_decimals_as_strings(self.json_decimals_as_strings)
+        .with_distributions_directory(self.distributions_dir.clone())
+        .with_seed_audit(self.audit_seeds)
+        .with_update(self.update.unwrap_or(Self::DEFAULT_UPDATE))
+        .with_progress(progress)
+        .with_resume(self.resume)
+        .with_resume_skip_checksums(self.resume_skip_checksums))
     }
 
     /// Parse table name to Table enum (case-insensitive)
@@ -118,6 +290,42 @@ impl Options {
             .map_err(|_| InvalidOptionError::new("table", table_str).into())
     }
 
+    /// Parse compression codec name (case-insensitive)
+    fn parse_compression(&self, compression_str: &str) -> Result<Compression> {
+        compression_str.parse::<Compression>().map_err(|_| {
+            InvalidOptionError::with_message(
+                "compression",
+                compression_str,
+                "Compression must be one of none, gzip, zstd, lz4",
+            )
+            .into()
+        })
+    }
+
+    /// Parse output format name (case-insensitive)
+    fn parse_format(&self, format_str: &str) -> Result<OutputFormat> {
+        format_str.parse::<OutputFormat>().map_err(|_| {
+            InvalidOptionError::with_message(
+                "format",
+                format_str,
+                "Format must be one of text, sql, jsonl, pgcopy",
+            )
+            .into()
+        })
+    }
+
+    /// Parse progress mode name (case-insensitive)
+    fn parse_progress(&self, progress_str: &str) -> Result<ProgressMode> {
+        progress_str.parse::<ProgressMode>().map_err(|_| {
+            InvalidOptionError::with_message(
+                "progress",
+                progress_str,
+                "Progress must be one of text, json, none",
+            )
+            .into()
+        })
+    }
+
     /// Validate all properties (matching Java validation rules)
     fn validate_properties(&self) -> Result<()> {
         // Scale validation
@@ -170,6 +378,78 @@ impl Options {
             .into());
         }
 
+        // SQL batch size validation
+        if self.sql_batch_size < 1 {
+            return Err(InvalidOptionError::with_message(
+                "sql-batch-size",
+                &self.sql_batch_size.to_string(),
+                "SQL batch size must be >= 1",
+            )
+            .into());
+        }
+
+        // Update validation
+        if let Some(update) = self.update {
+            if update < 1 {
+                return Err(InvalidOptionError::with_message(
+                    "update",
+                    &update.to_string(),
+                    "Update must be >= 1",
+                )
+                .into());
+            }
+        }
+
+        // Resume validation
+        if self.resume && (self.stdout || self.directory == Self::STDOUT_DIRECTORY) {
+            return Err(InvalidOptionError::with_message(
+                "resume",
+                "true",
+                "Resume needs a target directory, not stdout",
+            )
+            .into());
+        }
+        if self.resume_skip_checksums && !self.resume {
+            return Err(InvalidOptionError::with_message(
+                "resume-skip-checksums",
+                "true",
+                "Skipping checksums needs --resume",
+            )
+            .into());
+        }
+
+        // Distributions directory validation
+        if let Some(directory) = &self.distributions_dir {
+            if !std::path::Path::new(directory).is_dir() {
+                return Err(InvalidOptionError::with_message(
+                    "distributions-dir",
+                    directory,
+                    "Distributions directory does not exist",
+                )
+                .into());
+            }
+        }
+
+        // Stdout validation: rows of several tables or chunks cannot share one stream
+        if self.stdout || self.directory == Self::STDOUT_DIRECTORY {
+            if self.table.is_none() {
+                return Err(InvalidOptionError::with_message(
+                    "directory",
+                    Self::STDOUT_DIRECTORY,
+                    "Writing to stdout requires a single table selected with --table",
+                )
+                .into());
+            }
+            if self.parallelism != 1 {
+                return Err(InvalidOptionError::with_message(
+                    "parallelism",
+                    &self.parallelism.to_string(),
+                    "Writing to stdout requires --parallelism 1",
+                )
+                .into());
+            }
+        }
+
         Ok(())
     }
 }
@@ -189,6 +469,7 @@ mod tests {
         let options = Options::new();
         assert_eq!(options.scale, 1.0);
         assert_eq!(options.directory, ".");
+        assert!(!options.stdout);
         assert_eq!(options.suffix, ".dat");
         assert_eq!(options.table, None);
         assert_eq!(options.null_string, "");
@@ -197,6 +478,10 @@ mod tests {
         assert!(!options.no_sexism);
         assert_eq!(options.parallelism, 1);
         assert!(!options.overwrite);
+        assert_eq!(options.compression, "none");
+        assert_eq!(options.format, "text");
+        assert_eq!(options.sql_batch_size, 100);
+        assert!(!options.json_decimals_as_strings);
     }
 
     #[test]
@@ -225,6 +510,177 @@ mod tests {
         assert!(options.to_session().is_err());
     }
 
+    #[test]
+    fn test_compression_parsing() {
+        let mut options = Options::new();
+        options.compression = "zstd".to_string();
+        let session = options.to_session().unwrap();
+        assert_eq!(session.get_compression(), Compression::Zstd);
+
+        options.compression = "bzip2".to_string();
+        assert!(options.to_session().is_err());
+    }
+
+    #[test]
+    fn test_resume_needs_directory() {
+        let mut options = Options::new();
+        options.resume = true;
+        assert!(options.to_session().unwrap().should_resume());
+        options.stdout = true;
+        assert!(options.to_session().is_err());
+
+        let mut options = Options::new();
+        options.resume_skip_checksums = true;
+        assert!(options.to_session().is_err());
+        options.resume = true;
+        assert!(options.to_session().unwrap().should_skip_resume_checksums());
+    }
+
+    #[test]
+    fn test_progress_parsing() {
+        let mut options = Options::new();
+        assert_eq!(
+            options.to_session().unwrap().get_progress(),
+            ProgressMode::Text
+        );
+        options.progress = "json".to_string();
+        let session = options.to_session().unwrap();
+        assert_eq!(session.get_progress(), ProgressMode::Json);
+        assert_eq!(session.get_command_line_arguments(), "--progress json");
+
+        options.progress = "verbose".to_string();
+        assert!(options.to_session().is_err());
+    }
+
+    #[test]
+    fn test_format_parsing() {
+        let mut options = Options::new();
+        assert_eq!(options.to_session().unwrap().get_suffix(), ".dat");
+
+        // The default suffix follows the format, an explicit one is kept
+        options.format = "sql".to_string();
+        let session = options.to_session().unwrap();
+        assert_eq!(session.get_format(), OutputFormat::Sql);
+        assert_eq!(session.get_suffix(), ".sql");
+
+        options.suffix = ".txt".to_string();
+        assert_eq!(options.to_session().unwrap().get_suffix(), ".txt");
+
+        options.format = "jsonl".to_string();
+        options.suffix = Options::DEFAULT_SUFFIX.to_string();
+        options.json_decimals_as_strings = true;
+        let session = options.to_session().unwrap();
+        assert_eq!(session.get_suffix(), ".jsonl");
+        assert!(session.json_decimals_as_strings());
+
+        options.format = "xml".to_string();
+        assert!(options.to_session().is_err());
+    }
+
+    #[test]
+    fn test_sql_batch_size_validation() {
+        let mut options = Options::new();
+        options.sql_batch_size = 0;
+        assert!(options.validate_properties().is_err());
+
+        options.sql_batch_size = 1;
+        assert_eq!(options.to_session().unwrap().get_sql_batch_size(), 1);
+    }
+
+    #[test]
+    fn test_update_validation() {
+        let mut options = Options::new();
+        assert_eq!(options.to_session().unwrap().get_update(), 0);
+
+        options.update = Some(0);
+        assert!(options.validate_properties().is_err());
+
+        options.update = Some(2);
+        assert_eq!(options.to_session().unwrap().get_update(), 2);
+    }
+
+    #[test]
+    fn test_dist_command_parsing() {
+        let options = Options::try_parse_from(["tpcdsgen", "--scale", "2"]).unwrap();
+        assert_eq!(options.command, None);
+
+        let options = Options::try_parse_from([
+            "tpcdsgen",
+            "dist",
+            "sample",
+            "call_centers",
+            "--weights",
+            "2",
+            "-n",
+            "50",
+        ])
+        .unwrap();
+        assert_eq!(
+            options.command,
+            Some(Command::Dist {
+                command: DistCommand::Sample {
+                    name: "call_centers".to_string(),
+                    weights: "2".to_string(),
+                    count: 50,
+                    seed: 19620718,
+                }
+            })
+        );
+
+        assert!(Options::try_parse_from(["tpcdsgen", "dist", "show"]).is_err());
+
+        let options =
+            Options::try_parse_from(["tpcdsgen", "--separator", ",", "verify-ri", "out"]).unwrap();
+        assert_eq!(options.separator, ",");
+        assert_eq!(
+            options.command,
+            Some(Command::VerifyRi {
+                directory: "out".to_string()
+            })
+        );
+
+        let options = Options::try_parse_from([
+            "tpcdsgen",
+            "--scale",
+            "10",
+            "query",
+            "--templates",
+            "templates",
+            "--streams",
+            "4",
+        ])
+        .unwrap();
+        assert_eq!(options.scale, 10.0);
+        assert_eq!(
+            options.command,
+            Some(Command::Query {
+                templates: "templates".to_string(),
+                input: None,
+                streams: 4,
+                seed: 19620718,
+                output: ".".to_string(),
+                distributions: None,
+                dialect: "ansi".to_string(),
+                substitution_values: None,
+            })
+        );
+        assert!(Options::try_parse_from(["tpcdsgen", "query"]).is_err());
+    }
+
+    #[test]
+    fn test_distributions_dir_validation() {
+        let mut options = Options::new();
+        options.distributions_dir = Some("/nonexistent/distributions".to_string());
+        assert!(options.validate_properties().is_err());
+
+        options.distributions_dir = Some(env!("CARGO_MANIFEST_DIR").to_string());
+        let session = options.to_session().unwrap();
+        assert_eq!(
+            session.get_distributions_directory(),
+            Some(env!("CARGO_MANIFEST_DIR"))
+        );
+    }
+
     #[test]
     fn test_scale_validation() {
         let mut options = Options::new();
@@ -255,6 +711,31 @@ mod tests {
         assert!(options.validate_properties().is_err());
     }
 
+    #[test]
+    fn test_stdout_validation() {
+        let mut options = Options::new();
+
+        // Stdout needs a single table
+        options.directory = "-".to_string();
+        assert!(options.validate_properties().is_err());
+
+        options.table = Some("reason".to_string());
+        assert!(options.validate_properties().is_ok());
+        assert!(options.to_session().unwrap().write_to_stdout());
+
+        // Chunks cannot share stdout
+        options.parallelism = 2;
+        assert!(options.validate_properties().is_err());
+
+        // --stdout is the same as --directory -
+        let mut options = Options::new();
+        options.stdout = true;
+        options.table = Some("reason".to_string());
+        let session = options.to_session().unwrap();
+        assert_eq!(session.get_target_directory(), "-");
+        assert!(session.write_to_stdout());
+    }
+
     #[test]
     fn test_suffix_validation() {
         let mut options = Options::new();
@@ -80,7 +80,32 @@ impl Scaling {
             Table::SClass => 100,
             Table::SCompany => 100,
             Table::SCustomer => 100000,
+            Table::SDivision => 100,
             Table::SInventory => 1000000,
+            Table::SItem => 17999,
+            Table::SManager => 100,
+            Table::SManufacturer => 1000,
+            Table::SMarket => 100,
+            Table::SProduct => 1000,
+            Table::SPromotion => 300,
+            Table::SPurchase => 240000,
+            Table::SPurchaseLineitem => 2880000,
+            Table::SReason => 35,
+            Table::SStore => 12,
+            Table::SStorePromotionalItem => 10000,
+            Table::SStoreReturns => 24000,
+            Table::SSubcategory => 1000,
+            Table::SSubclass => 1000,
+            Table::SWarehouse => 5,
+            Table::SWebOrder => 60000,
+            Table::SWebOrderLineitem => 720000,
+            Table::SWebPage => 60,
+            Table::SWebPromotionalItem => 10000,
+            Table::SWebReturns => 6000,
+            Table::SWebSite => 30,
+            Table::SZipToGmt => 99999,
+            Table::Delete => 3,
+            Table::InventoryDelete => 3,
         }
     }
 }
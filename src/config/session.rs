@@ -1,4 +1,6 @@
 use crate::config::{Options, Scaling, Table};
+use crate::output::{Compression, OutputFormat};
+use crate::progress::ProgressMode;
 
 #[derive(Debug, Clone)]
 pub struct Session {
@@ -13,6 +15,16 @@ pub struct Session {
     parallelism: i32,
     chunk_number: i32,
     overwrite: bool,
+    compression: Compression,
+    format: OutputFormat,
+    sql_batch_size: i32,
+    json_decimals_as_strings: bool,
+    distributions_directory: Option<String>,
+    audit_seeds: bool,
+    update: i32,
+    progress: ProgressMode,
+    resume: bool,
+    resume_skip_checksums: bool,
 }
 
 impl Session {
@@ -70,6 +82,16 @@ impl Session {
             parallelism,
             chunk_number,
             overwrite,
+            compression: Options::DEFAULT_COMPRESSION,
+            format: Options::DEFAULT_FORMAT,
+            sql_batch_size: Options::DEFAULT_SQL_BATCH_SIZE,
+            json_decimals_as_strings: Options::DEFAULT_JSON_DECIMALS_AS_STRINGS,
+            distributions_directory: None,
+            audit_seeds: Options::DEFAULT_AUDIT_SEEDS,
+            update: Options::DEFAULT_UPDATE,
+            progress: Options::DEFAULT_PROGRESS,
+            resume: Options::DEFAULT_RESUME,
+            resume_skip_checksums: Options::DEFAULT_RESUME_SKIP_CHECKSUMS,
         }
     }
 
@@ -114,6 +136,76 @@ impl Session {
         }
     }
 
+    pub fn with_compression(&self, compression: Compression) -> Self {
+        Session {
+            compression,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_format(&self, format: OutputFormat) -> Self {
+        Session {
+            format,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_sql_batch_size(&self, sql_batch_size: i32) -> Self {
+        Session {
+            sql_batch_size,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_json_decimals_as_strings(&self, json_decimals_as_strings: bool) -> Self {
+        Session {
+            json_decimals_as_strings,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_distributions_directory(&self, distributions_directory: Option<String>) -> Self {
+        Session {
+            distributions_directory,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_seed_audit(&self, audit_seeds: bool) -> Self {
+        Session {
+            audit_seeds,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_update(&self, update: i32) -> Self {
+        Session {
+            update,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_progress(&self, progress: ProgressMode) -> Self {
+        Session {
+            progress,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_resume(&self, resume: bool) -> Self {
+        Session {
+            resume,
+            ..self.clone()
+        }
+    }
+
+    pub fn with_resume_skip_checksums(&self, resume_skip_checksums: bool) -> Self {
+        Session {
+            resume_skip_checksums,
+            ..self.clone()
+        }
+    }
+
     // Accessor methods
     pub fn get_scaling(&self) -> &Scaling {
         &self.scaling
@@ -123,6 +215,11 @@ impl Session {
         &self.target_directory
     }
 
+    /// Check if the table should be written to stdout instead of a file (`--directory -`)
+    pub fn write_to_stdout(&self) -> bool {
+        self.target_directory == Options::STDOUT_DIRECTORY
+    }
+
     pub fn get_suffix(&self) -> &str {
         &self.suffix
     }
@@ -168,6 +265,52 @@ impl Session {
         self.overwrite
     }
 
+    pub fn get_compression(&self) -> Compression {
+        self.compression
+    }
+
+    pub fn get_format(&self) -> OutputFormat {
+        self.format
+    }
+
+    pub fn get_sql_batch_size(&self) -> i32 {
+        self.sql_batch_size
+    }
+
+    pub fn json_decimals_as_strings(&self) -> bool {
+        self.json_decimals_as_strings
+    }
+
+    /// Directory of .dst files that replace the built-in distributions, if any
+    pub fn get_distributions_directory(&self) -> Option<&str> {
+        self.distributions_directory.as_deref()
+    }
+
+    /// Check if generators should fail on columns drawing more seeds than their budget
+    pub fn should_audit_seeds(&self) -> bool {
+        self.audit_seeds
+    }
+
+    /// Get the refresh data set to generate, starting at 1; 0 generates the base data
+    pub fn get_update(&self) -> i32 {
+        self.update
+    }
+
+    /// Get how generation progress is reported on stderr
+    pub fn get_progress(&self) -> ProgressMode {
+        self.progress
+    }
+
+    /// Check if chunks completed by a previous run should be kept instead of generated again
+    pub fn should_resume(&self) -> bool {
+        self.resume
+    }
+
+    /// Check if resumed files are trusted by their size, without checking their SHA-256
+    pub fn should_skip_resume_checksums(&self) -> bool {
+        self.resume_skip_checksums
+    }
+
     /// Reconstruct command line arguments that would produce this session
     pub fn get_command_line_arguments(&self) -> String {
         let mut output = Vec::new();
@@ -202,6 +345,36 @@ impl Session {
         if self.overwrite != Options::DEFAULT_OVERWRITE {
             output.push("--overwrite".to_string());
         }
+        if self.compression != Options::DEFAULT_COMPRESSION {
+            output.push(format!("--compression {}", self.compression));
+        }
+        if self.format != Options::DEFAULT_FORMAT {
+            output.push(format!("--format {}", self.format));
+        }
+        if self.sql_batch_size != Options::DEFAULT_SQL_BATCH_SIZE {
+            output.push(format!("--sql-batch-size {}", self.sql_batch_size));
+        }
+        if self.json_decimals_as_strings != Options::DEFAULT_JSON_DECIMALS_AS_STRINGS {
+            output.push("--json-decimals-as-strings".to_string());
+        }
+        if let Some(directory) = &self.distributions_directory {
+            output.push(format!("--distributions-dir {}", directory));
+        }
+        if self.audit_seeds != Options::DEFAULT_AUDIT_SEEDS {
+            output.push("--audit-seeds".to_string());
+        }
+        if self.update != Options::DEFAULT_UPDATE {
+            output.push(format!("--update {}", self.update));
+        }
+        if self.progress != Options::DEFAULT_PROGRESS {
+            output.push(format!("--progress {}", self.progress));
+        }
+        if self.resume != Options::DEFAULT_RESUME {
+            output.push("--resume".to_string());
+        }
+        if self.resume_skip_checksums != Options::DEFAULT_RESUME_SKIP_CHECKSUMS {
+            output.push("--resume-skip-checksums".to_string());
+        }
 
         output.join(" ")
     }
@@ -269,6 +442,32 @@ mod tests {
 
         let session_with_no_sexism = session.with_no_sexism(true);
         assert!(!session_with_no_sexism.is_sexist());
+
+        let session_with_compression = session.with_compression(Compression::Gzip);
+        assert_eq!(
+            session_with_compression.get_compression(),
+            Compression::Gzip
+        );
+
+        let session_with_format = session.with_format(OutputFormat::Sql);
+        assert_eq!(session_with_format.get_format(), OutputFormat::Sql);
+
+        let session_with_batch_size = session.with_sql_batch_size(500);
+        assert_eq!(session_with_batch_size.get_sql_batch_size(), 500);
+
+        let session_with_json_decimals = session.with_json_decimals_as_strings(true);
+        assert!(session_with_json_decimals.json_decimals_as_strings());
+
+        let session_with_distributions =
+            session.with_distributions_directory(Some("dists".to_string()));
+        assert_eq!(
+            session_with_distributions.get_distributions_directory(),
+            Some("dists")
+        );
+        assert_eq!(
+            session_with_distributions.get_command_line_arguments(),
+            "--distributions-dir dists"
+        );
     }
 
     #[test]
@@ -323,7 +522,11 @@ mod tests {
             true,
             4,
             true,
-        );
+        )
+        .with_compression(Compression::Lz4)
+        .with_format(OutputFormat::Sql)
+        .with_sql_batch_size(10)
+        .with_update(2);
 
         let args = session.get_command_line_arguments();
         assert!(args.contains("--scale 2"));
@@ -336,6 +539,10 @@ mod tests {
         assert!(args.contains("--no-sexism"));
         assert!(args.contains("--parallelism 4"));
         assert!(args.contains("--overwrite"));
+        assert!(args.contains("--compression lz4"));
+        assert!(args.contains("--format sql"));
+        assert!(args.contains("--sql-batch-size 10"));
+        assert!(args.contains("--update 2"));
     }
 
     #[test]
@@ -31,7 +31,7 @@ pub enum Table {
     WebSite,
     DbgenVersion,
 
-    // Source tables (for updates - simplified for now)
+    // Source tables of the data maintenance (update) data sets
     SBrand,
     SCustomerAddress,
     SCallCenter,
@@ -45,8 +45,34 @@ pub enum Table {
     SClass,
     SCompany,
     SCustomer,
+    SDivision,
     SInventory,
-    // TODO(clflushopt): Add remaining tables
+    SItem,
+    SManager,
+    SManufacturer,
+    SMarket,
+    SProduct,
+    SPromotion,
+    SPurchase,
+    SPurchaseLineitem,
+    SReason,
+    SStore,
+    SStorePromotionalItem,
+    SStoreReturns,
+    SSubcategory,
+    SSubclass,
+    SWarehouse,
+    SWebOrder,
+    SWebOrderLineitem,
+    SWebPage,
+    SWebPromotionalItem,
+    SWebReturns,
+    SWebSite,
+    SZipToGmt,
+
+    // Date ranges deleted by a refresh
+    Delete,
+    InventoryDelete,
 }
 
 impl Table {
@@ -91,7 +117,32 @@ impl Table {
             Table::SClass => "s_class",
             Table::SCompany => "s_company",
             Table::SCustomer => "s_customer",
+            Table::SDivision => "s_division",
             Table::SInventory => "s_inventory",
+            Table::SItem => "s_item",
+            Table::SManager => "s_manager",
+            Table::SManufacturer => "s_manufacturer",
+            Table::SMarket => "s_market",
+            Table::SProduct => "s_product",
+            Table::SPromotion => "s_promotion",
+            Table::SPurchase => "s_purchase",
+            Table::SPurchaseLineitem => "s_purchase_lineitem",
+            Table::SReason => "s_reason",
+            Table::SStore => "s_store",
+            Table::SStorePromotionalItem => "s_store_promotional_item",
+            Table::SStoreReturns => "s_store_returns",
+            Table::SSubcategory => "s_subcategory",
+            Table::SSubclass => "s_subclass",
+            Table::SWarehouse => "s_warehouse",
+            Table::SWebOrder => "s_web_order",
+            Table::SWebOrderLineitem => "s_web_order_lineitem",
+            Table::SWebPage => "s_web_page",
+            Table::SWebPromotionalItem => "s_web_promotional_item",
+            Table::SWebReturns => "s_web_returns",
+            Table::SWebSite => "s_web_site",
+            Table::SZipToGmt => "s_zip_to_gmt",
+            Table::Delete => "delete",
+            Table::InventoryDelete => "inventory_delete",
         }
     }
 
@@ -143,7 +194,32 @@ impl Table {
                 | Table::SClass
                 | Table::SCompany
                 | Table::SCustomer
+                | Table::SDivision
                 | Table::SInventory
+                | Table::SItem
+                | Table::SManager
+                | Table::SManufacturer
+                | Table::SMarket
+                | Table::SProduct
+                | Table::SPromotion
+                | Table::SPurchase
+                | Table::SPurchaseLineitem
+                | Table::SReason
+                | Table::SStore
+                | Table::SStorePromotionalItem
+                | Table::SStoreReturns
+                | Table::SSubcategory
+                | Table::SSubclass
+                | Table::SWarehouse
+                | Table::SWebOrder
+                | Table::SWebOrderLineitem
+                | Table::SWebPage
+                | Table::SWebPromotionalItem
+                | Table::SWebReturns
+                | Table::SWebSite
+                | Table::SZipToGmt
+                | Table::Delete
+                | Table::InventoryDelete
         )
     }
 
@@ -217,7 +293,32 @@ impl FromStr for Table {
             "S_CLASS" => Ok(Table::SClass),
             "S_COMPANY" => Ok(Table::SCompany),
             "S_CUSTOMER" => Ok(Table::SCustomer),
+            "S_DIVISION" => Ok(Table::SDivision),
             "S_INVENTORY" => Ok(Table::SInventory),
+            "S_ITEM" => Ok(Table::SItem),
+            "S_MANAGER" => Ok(Table::SManager),
+            "S_MANUFACTURER" => Ok(Table::SManufacturer),
+            "S_MARKET" => Ok(Table::SMarket),
+            "S_PRODUCT" => Ok(Table::SProduct),
+            "S_PROMOTION" => Ok(Table::SPromotion),
+            "S_PURCHASE" => Ok(Table::SPurchase),
+            "S_PURCHASE_LINEITEM" => Ok(Table::SPurchaseLineitem),
+            "S_REASON" => Ok(Table::SReason),
+            "S_STORE" => Ok(Table::SStore),
+            "S_STORE_PROMOTIONAL_ITEM" => Ok(Table::SStorePromotionalItem),
+            "S_STORE_RETURNS" => Ok(Table::SStoreReturns),
+            "S_SUBCATEGORY" => Ok(Table::SSubcategory),
+            "S_SUBCLASS" => Ok(Table::SSubclass),
+            "S_WAREHOUSE" => Ok(Table::SWarehouse),
+            "S_WEB_ORDER" => Ok(Table::SWebOrder),
+            "S_WEB_ORDER_LINEITEM" => Ok(Table::SWebOrderLineitem),
+            "S_WEB_PAGE" => Ok(Table::SWebPage),
+            "S_WEB_PROMOTIONAL_ITEM" => Ok(Table::SWebPromotionalItem),
+            "S_WEB_RETURNS" => Ok(Table::SWebReturns),
+            "S_WEB_SITE" => Ok(Table::SWebSite),
+            "S_ZIP_TO_GMT" => Ok(Table::SZipToGmt),
+            "DELETE" => Ok(Table::Delete),
+            "INVENTORY_DELETE" => Ok(Table::InventoryDelete),
             _ => Err(TpcdsError::new(&format!("Invalid table name: {}", s))),
         }
     }
@@ -1,4 +1,5 @@
 use crate::distribution::string_values_distribution::StringValuesDistribution;
+use crate::distribution::utils::get_or_load;
 use crate::error::Result;
 use crate::random::stream::RandomNumberStream;
 use std::sync::OnceLock;
@@ -28,19 +29,17 @@ pub fn pick_random_street_name(
     weights: StreetNamesWeights,
     stream: &mut dyn RandomNumberStream,
 ) -> Result<&'static str> {
-    let dist = STREET_NAMES_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&STREET_NAMES_DISTRIBUTION, "street_names.dst", || {
         StringValuesDistribution::build_string_values_distribution("street_names.dst", 1, 2)
-            .expect("Failed to load street names distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, weights as usize, stream)
 }
 
 pub fn pick_random_street_type(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = STREET_TYPES_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&STREET_TYPES_DISTRIBUTION, "street_types.dst", || {
         StringValuesDistribution::build_string_values_distribution("street_types.dst", 1, 1)
-            .expect("Failed to load street types distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
@@ -49,28 +48,25 @@ pub fn pick_random_city(
     weights: CitiesWeights,
     stream: &mut dyn RandomNumberStream,
 ) -> Result<&'static str> {
-    let dist = CITIES_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&CITIES_DISTRIBUTION, "cities.dst", || {
         StringValuesDistribution::build_string_values_distribution("cities.dst", 1, 6)
-            .expect("Failed to load cities distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, weights as usize, stream)
 }
 
 pub fn pick_random_country(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = COUNTRIES_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&COUNTRIES_DISTRIBUTION, "countries.dst", || {
         StringValuesDistribution::build_string_values_distribution("countries.dst", 1, 1)
-            .expect("Failed to load countries distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
 pub fn get_city_at_index(index: usize) -> Result<&'static str> {
-    let dist = CITIES_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&CITIES_DISTRIBUTION, "cities.dst", || {
         StringValuesDistribution::build_string_values_distribution("cities.dst", 1, 6)
-            .expect("Failed to load cities distribution")
-    });
+    })?;
 
     dist.get_value_at_index(0, index)
 }
@@ -1,5 +1,6 @@
 use crate::distribution::file_loader::DistributionFileLoader;
-use crate::distribution::utils::{pick_random_value, WeightsBuilder};
+use crate::distribution::utils::get_or_load;
+use crate::distribution::utils::{get_weight_for_index, pick_random_value, WeightsBuilder};
 use crate::error::Result;
 use crate::random::RandomNumberStream;
 use crate::TpcdsError;
@@ -38,11 +39,13 @@ impl CalendarDistribution {
         [0, 31, 60, 91, 121, 152, 182, 213, 244, 274, 305, 335], // Leap year
     ];
 
-    fn get_instance() -> &'static CalendarDistribution {
+    fn get_instance() -> Result<&'static CalendarDistribution> {
         static DISTRIBUTION: OnceLock<CalendarDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
-            Self::build_calendar_distribution().expect("Failed to load calendar distribution")
-        })
+        get_or_load(
+            &DISTRIBUTION,
+            Self::VALUES_AND_WEIGHTS_FILENAME,
+            Self::build_calendar_distribution,
+        )
     }
 
     fn build_calendar_distribution() -> Result<Self> {
@@ -118,15 +121,15 @@ impl CalendarDistribution {
     }
 
     /// Get the quarter for a given day index (1-based index)
-    pub fn get_quarter_at_index(index: i32) -> i32 {
-        let dist = Self::get_instance();
-        dist.quarters[(index - 1) as usize]
+    pub fn get_quarter_at_index(index: i32) -> Result<i32> {
+        let dist = Self::get_instance()?;
+        Ok(dist.quarters[(index - 1) as usize])
     }
 
     /// Get the holiday flag for a given day index (1-based index)
-    pub fn get_is_holiday_flag_at_index(index: i32) -> i32 {
-        let dist = Self::get_instance();
-        dist.holiday_flags[(index - 1) as usize]
+    pub fn get_is_holiday_flag_at_index(index: i32) -> Result<i32> {
+        let dist = Self::get_instance()?;
+        Ok(dist.holiday_flags[(index - 1) as usize])
     }
 
     /// Pick a random day of year using weighted distribution (CalendarDistribution.pickRandomDayOfYear)
@@ -146,21 +149,31 @@ impl CalendarDistribution {
         weights: CalendarWeights,
         stream: &mut dyn RandomNumberStream,
     ) -> Result<i32> {
-        let dist = Self::get_instance();
+        let dist = Self::get_instance()?;
         let weights_list = &dist.weights_lists[weights as usize];
 
         let value_ref = pick_random_value(&dist.days_of_year, weights_list, stream)?;
         Ok(*value_ref)
     }
+
+    /// Get the weight of a day index (1-based, leap year calendar) in a weight distribution
+    pub fn get_weight_for_day_of_year(weights: CalendarWeights, day_index: i32) -> Result<i32> {
+        let dist = Self::get_instance()?;
+        get_weight_for_index(
+            (day_index - 1) as usize,
+            &dist.weights_lists[weights as usize],
+        )
+    }
 }
 
 #[cfg(test)]
+#[allow(clippy::len_zero, clippy::manual_range_contains)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_calendar_distribution_loading() {
-        let dist = CalendarDistribution::get_instance();
+        let dist = CalendarDistribution::get_instance().unwrap();
         assert!(dist.days_of_year.len() > 0);
         assert_eq!(dist.quarters.len(), dist.days_of_year.len());
         assert_eq!(dist.holiday_flags.len(), dist.days_of_year.len());
@@ -169,7 +182,7 @@ mod tests {
     #[test]
     fn test_get_quarter_at_index() {
         // Day 1 (Jan 1) should be in Q1
-        let quarter = CalendarDistribution::get_quarter_at_index(1);
+        let quarter = CalendarDistribution::get_quarter_at_index(1).unwrap();
         assert_eq!(quarter, 1);
     }
 
@@ -1,4 +1,5 @@
 use crate::distribution::string_values_distribution::StringValuesDistribution;
+use crate::distribution::utils::get_or_load;
 use crate::error::Result;
 use crate::random::RandomNumberStream;
 use std::sync::OnceLock;
@@ -11,52 +12,53 @@ static CALL_CENTER_CLASSES_DISTRIBUTION: OnceLock<StringValuesDistribution> = On
 static CALL_CENTER_HOURS_DISTRIBUTION: OnceLock<StringValuesDistribution> = OnceLock::new();
 
 impl CallCenterDistributions {
-    /// Initialize all distributions (lazy loading)
-    fn ensure_initialized() -> Result<()> {
-        // Initialize call centers distribution
-        if CALL_CENTERS_DISTRIBUTION.get().is_none() {
-            let dist = StringValuesDistribution::build_string_values_distribution(
+    fn get_call_centers_distribution() -> Result<&'static StringValuesDistribution> {
+        get_or_load(&CALL_CENTERS_DISTRIBUTION, "call_centers.dst", || {
+            StringValuesDistribution::build_string_values_distribution(
                 "call_centers.dst",
                 1, // 1 value field: name
                 2, // 2 weight fields: uniform, sales percentage
-            )?;
-            let _ = CALL_CENTERS_DISTRIBUTION.set(dist);
-        }
-
-        // Initialize call center classes distribution
-        if CALL_CENTER_CLASSES_DISTRIBUTION.get().is_none() {
-            let dist = StringValuesDistribution::build_string_values_distribution(
-                "call_center_classes.dst",
-                1, // 1 value field: class
-                1, // 1 weight field: frequency
-            )?;
-            let _ = CALL_CENTER_CLASSES_DISTRIBUTION.set(dist);
-        }
-
-        // Initialize call center hours distribution
-        if CALL_CENTER_HOURS_DISTRIBUTION.get().is_none() {
-            let dist = StringValuesDistribution::build_string_values_distribution(
-                "call_center_hours.dst",
-                1, // 1 value field: hours
-                1, // 1 weight field: frequency
-            )?;
-            let _ = CALL_CENTER_HOURS_DISTRIBUTION.set(dist);
-        }
-
-        Ok(())
+            )
+        })
+    }
+
+    fn get_call_center_classes_distribution() -> Result<&'static StringValuesDistribution> {
+        get_or_load(
+            &CALL_CENTER_CLASSES_DISTRIBUTION,
+            "call_center_classes.dst",
+            || {
+                StringValuesDistribution::build_string_values_distribution(
+                    "call_center_classes.dst",
+                    1, // 1 value field: class
+                    1, // 1 weight field: frequency
+                )
+            },
+        )
+    }
+
+    fn get_call_center_hours_distribution() -> Result<&'static StringValuesDistribution> {
+        get_or_load(
+            &CALL_CENTER_HOURS_DISTRIBUTION,
+            "call_center_hours.dst",
+            || {
+                StringValuesDistribution::build_string_values_distribution(
+                    "call_center_hours.dst",
+                    1, // 1 value field: hours
+                    1, // 1 weight field: frequency
+                )
+            },
+        )
     }
 
     /// Get call center name at specific index
     pub fn get_call_center_at_index(index: usize) -> Result<&'static str> {
-        Self::ensure_initialized()?;
-        let dist = CALL_CENTERS_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_call_centers_distribution()?;
         dist.get_value_at_index(0, index)
     }
 
     /// Get total number of call centers
     pub fn get_number_of_call_centers() -> Result<usize> {
-        Self::ensure_initialized()?;
-        let dist = CALL_CENTERS_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_call_centers_distribution()?;
         Ok(dist.get_size())
     }
 
@@ -64,8 +66,7 @@ impl CallCenterDistributions {
     pub fn pick_random_call_center_class(
         stream: &mut dyn RandomNumberStream,
     ) -> Result<&'static str> {
-        Self::ensure_initialized()?;
-        let dist = CALL_CENTER_CLASSES_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_call_center_classes_distribution()?;
         dist.pick_random_value(0, 0, stream)
     }
 
@@ -73,8 +74,7 @@ impl CallCenterDistributions {
     pub fn pick_random_call_center_hours(
         stream: &mut dyn RandomNumberStream,
     ) -> Result<&'static str> {
-        Self::ensure_initialized()?;
-        let dist = CALL_CENTER_HOURS_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_call_center_hours_distribution()?;
         dist.pick_random_value(0, 0, stream)
     }
 }
@@ -18,6 +18,7 @@
 //! with weighted random selection based on distribution frequency and sales volume.
 
 use crate::distribution::file_loader::DistributionFileLoader;
+use crate::distribution::utils::get_or_load;
 use crate::distribution::utils::{pick_random_value, WeightsBuilder};
 use crate::error::Result;
 use crate::random::RandomNumberStream;
@@ -42,12 +43,13 @@ impl CatalogPageTypesDistribution {
     const NUM_WEIGHT_FIELDS: usize = 2;
     const VALUES_AND_WEIGHTS_FILENAME: &'static str = "catalog_page_types.dst";
 
-    fn get_instance() -> &'static CatalogPageTypesDistribution {
+    fn get_instance() -> Result<&'static CatalogPageTypesDistribution> {
         static DISTRIBUTION: OnceLock<CatalogPageTypesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
-            Self::build_catalog_page_types_distribution()
-                .expect("Failed to load catalog page types distribution")
-        })
+        get_or_load(
+            &DISTRIBUTION,
+            Self::VALUES_AND_WEIGHTS_FILENAME,
+            Self::build_catalog_page_types_distribution,
+        )
     }
 
     fn build_catalog_page_types_distribution() -> Result<Self> {
@@ -118,7 +120,7 @@ impl CatalogPageTypesDistribution {
     ///
     /// A catalog type string ("monthly", "bi-annual", or "quarterly")
     pub fn pick_random_catalog_page_type(stream: &mut dyn RandomNumberStream) -> Result<String> {
-        let dist = Self::get_instance();
+        let dist = Self::get_instance()?;
 
         // Use the second weight list (sales volume, index 1)
         let value_ref = pick_random_value(&dist.values, &dist.weights_list2, stream)?;
@@ -133,7 +135,7 @@ mod tests {
 
     #[test]
     fn test_catalog_page_types_distribution_loading() {
-        let dist = CatalogPageTypesDistribution::get_instance();
+        let dist = CatalogPageTypesDistribution::get_instance().unwrap();
 
         // Should have 3 catalog types: monthly, bi-annual, quarterly
         assert_eq!(dist.values.len(), 3, "Should have 3 catalog types");
@@ -171,7 +173,7 @@ mod tests {
 
     #[test]
     fn test_catalog_type_values() {
-        let dist = CatalogPageTypesDistribution::get_instance();
+        let dist = CatalogPageTypesDistribution::get_instance().unwrap();
 
         // Verify expected catalog types are present
         let types_set: std::collections::HashSet<&String> = dist.values.iter().collect();
@@ -1,4 +1,5 @@
 use crate::distribution::string_values_distribution::StringValuesDistribution as FileBasedStringValuesDistribution;
+use crate::distribution::utils::get_or_load;
 use crate::distribution::{Distribution, IntValuesDistribution};
 use crate::error::Result;
 use std::sync::OnceLock;
@@ -8,206 +9,187 @@ pub struct DemographicsDistributions;
 
 impl DemographicsDistributions {
     /// Lazy-loaded distribution instance for genders.dst (GENDER_DISTRIBUTION)
-    fn get_gender_distribution() -> &'static FileBasedStringValuesDistribution {
+    fn get_gender_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
         static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "genders.dst", || {
             FileBasedStringValuesDistribution::build_string_values_distribution("genders.dst", 1, 1)
-                .expect("Failed to load genders.dst")
         })
     }
 
     /// Lazy-loaded distribution instance for marital_statuses.dst (MARITAL_STATUS_DISTRIBUTION)
-    fn get_marital_status_distribution() -> &'static FileBasedStringValuesDistribution {
+    fn get_marital_status_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
         static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "marital_statuses.dst", || {
             FileBasedStringValuesDistribution::build_string_values_distribution(
                 "marital_statuses.dst",
                 1,
                 1,
             )
-            .expect("Failed to load marital_statuses.dst")
         })
     }
 
     /// Lazy-loaded distribution instance for education.dst (EDUCATION_DISTRIBUTION)
-    fn get_education_distribution() -> &'static FileBasedStringValuesDistribution {
+    fn get_education_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
         static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "education.dst", || {
             FileBasedStringValuesDistribution::build_string_values_distribution(
                 "education.dst",
                 1,
                 4,
             )
-            .expect("Failed to load education.dst")
         })
     }
 
     /// Lazy-loaded distribution instance for purchase_band.dst (PURCHASE_BAND_DISTRIBUTION)
-    fn get_purchase_band_distribution() -> &'static IntValuesDistribution {
+    fn get_purchase_band_distribution() -> Result<&'static IntValuesDistribution> {
         static DISTRIBUTION: OnceLock<IntValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "purchase_band.dst", || {
             IntValuesDistribution::build_int_values_distribution("purchase_band.dst", 1, 1)
-                .expect("Failed to load purchase_band.dst")
         })
     }
 
     /// Lazy-loaded distribution instance for credit_ratings.dst (CREDIT_RATING_DISTRIBUTION)
-    fn get_credit_rating_distribution() -> &'static FileBasedStringValuesDistribution {
+    fn get_credit_rating_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
         static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "credit_ratings.dst", || {
             FileBasedStringValuesDistribution::build_string_values_distribution(
                 "credit_ratings.dst",
                 1,
                 1,
             )
-            .expect("Failed to load credit_ratings.dst")
         })
     }
 
     /// Lazy-loaded distribution instance for income_band.dst
     /// Contains 2 value fields (lower_bound, upper_bound) and 1 weight field
-    fn get_income_band_distribution() -> &'static IntValuesDistribution {
+    fn get_income_band_distribution() -> Result<&'static IntValuesDistribution> {
         static DISTRIBUTION: OnceLock<IntValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "income_band.dst", || {
             IntValuesDistribution::build_int_values_distribution("income_band.dst", 2, 1)
-                .expect("Failed to load income_band.dst")
         })
     }
 
     /// Lazy-loaded distribution instance for buy_potential.dst (BUY_POTENTIAL_DISTRIBUTION)
-    fn get_buy_potential_distribution() -> &'static FileBasedStringValuesDistribution {
+    fn get_buy_potential_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
         static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "buy_potential.dst", || {
             FileBasedStringValuesDistribution::build_string_values_distribution(
                 "buy_potential.dst",
                 1,
                 1,
             )
-            .expect("Failed to load buy_potential.dst")
         })
     }
 
     /// Lazy-loaded distribution instance for dep_count.dst (DEP_COUNT_DISTRIBUTION)
-    fn get_dep_count_distribution() -> &'static IntValuesDistribution {
+    fn get_dep_count_distribution() -> Result<&'static IntValuesDistribution> {
         static DISTRIBUTION: OnceLock<IntValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "dep_count.dst", || {
             IntValuesDistribution::build_int_values_distribution("dep_count.dst", 1, 1)
-                .expect("Failed to load dep_count.dst")
         })
     }
 
     /// Lazy-loaded distribution instance for vehicle_count.dst (VEHICLE_COUNT_DISTRIBUTION)
-    fn get_vehicle_count_distribution() -> &'static IntValuesDistribution {
+    fn get_vehicle_count_distribution() -> Result<&'static IntValuesDistribution> {
         static DISTRIBUTION: OnceLock<IntValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "vehicle_count.dst", || {
             IntValuesDistribution::build_int_values_distribution("vehicle_count.dst", 1, 1)
-                .expect("Failed to load vehicle_count.dst")
         })
     }
 
     /// Get gender for index mod size (getGenderForIndexModSize)
-    pub fn get_gender_for_index_mod_size(index: i64) -> &'static str {
-        Self::get_gender_distribution()
-            .get_value_for_index_mod_size(index, 0)
-            .expect("Failed to get gender value")
+    pub fn get_gender_for_index_mod_size(index: i64) -> Result<&'static str> {
+        Self::get_gender_distribution()?.get_value_for_index_mod_size(index, 0)
     }
 
     /// Get marital status for index mod size (getMaritalStatusForIndexModSize)
-    pub fn get_marital_status_for_index_mod_size(index: i64) -> &'static str {
-        Self::get_marital_status_distribution()
-            .get_value_for_index_mod_size(index, 0)
-            .expect("Failed to get marital status value")
+    pub fn get_marital_status_for_index_mod_size(index: i64) -> Result<&'static str> {
+        Self::get_marital_status_distribution()?.get_value_for_index_mod_size(index, 0)
     }
 
     /// Get education for index mod size (getEducationForIndexModSize)
-    pub fn get_education_for_index_mod_size(index: i64) -> &'static str {
-        Self::get_education_distribution()
-            .get_value_for_index_mod_size(index, 0)
-            .expect("Failed to get education value")
+    pub fn get_education_for_index_mod_size(index: i64) -> Result<&'static str> {
+        Self::get_education_distribution()?.get_value_for_index_mod_size(index, 0)
     }
 
     /// Get purchase band for index mod size (getPurchaseBandForIndexModSize)
-    pub fn get_purchase_band_for_index_mod_size(index: i64) -> i32 {
-        Self::get_purchase_band_distribution().get_value_for_index_mod_size(index, 0)
+    pub fn get_purchase_band_for_index_mod_size(index: i64) -> Result<i32> {
+        Ok(Self::get_purchase_band_distribution()?.get_value_for_index_mod_size(index, 0))
     }
 
     /// Get credit rating for index mod size (getCreditRatingForIndexModSize)
-    pub fn get_credit_rating_for_index_mod_size(index: i64) -> &'static str {
-        Self::get_credit_rating_distribution()
-            .get_value_for_index_mod_size(index, 0)
-            .expect("Failed to get credit rating value")
+    pub fn get_credit_rating_for_index_mod_size(index: i64) -> Result<&'static str> {
+        Self::get_credit_rating_distribution()?.get_value_for_index_mod_size(index, 0)
     }
 
     /// Get gender distribution size
-    pub fn get_gender_size() -> usize {
-        Self::get_gender_distribution().get_size()
+    pub fn get_gender_size() -> Result<usize> {
+        Ok(Self::get_gender_distribution()?.get_size())
     }
 
     /// Get marital status distribution size
-    pub fn get_marital_status_size() -> usize {
-        Self::get_marital_status_distribution().get_size()
+    pub fn get_marital_status_size() -> Result<usize> {
+        Ok(Self::get_marital_status_distribution()?.get_size())
     }
 
     /// Get education distribution size
-    pub fn get_education_size() -> usize {
-        Self::get_education_distribution().get_size()
+    pub fn get_education_size() -> Result<usize> {
+        Ok(Self::get_education_distribution()?.get_size())
     }
 
     /// Get purchase band distribution size
-    pub fn get_purchase_band_size() -> usize {
-        Self::get_purchase_band_distribution().get_size()
+    pub fn get_purchase_band_size() -> Result<usize> {
+        Ok(Self::get_purchase_band_distribution()?.get_size())
     }
 
     /// Get credit rating distribution size
-    pub fn get_credit_rating_size() -> usize {
-        Self::get_credit_rating_distribution().get_size()
+    pub fn get_credit_rating_size() -> Result<usize> {
+        Ok(Self::get_credit_rating_distribution()?.get_size())
     }
 
     /// Get income band lower bound at the specified index (getValueAtIndex)
     pub fn get_income_band_lower_bound_at_index(index: usize) -> Result<i32> {
-        Self::get_income_band_distribution().get_value_at_index(0, index)
+        Self::get_income_band_distribution()?.get_value_at_index(0, index)
     }
 
     /// Get income band upper bound at the specified index (getValueAtIndex)
     pub fn get_income_band_upper_bound_at_index(index: usize) -> Result<i32> {
-        Self::get_income_band_distribution().get_value_at_index(1, index)
+        Self::get_income_band_distribution()?.get_value_at_index(1, index)
     }
 
     /// Get the size of the income band distribution
-    pub fn get_income_band_size() -> usize {
-        Self::get_income_band_distribution().get_value_count(0)
+    pub fn get_income_band_size() -> Result<usize> {
+        Ok(Self::get_income_band_distribution()?.get_value_count(0))
     }
 
     /// Get buy potential for index mod size (getBuyPotentialForIndexModSize)
-    pub fn get_buy_potential_for_index_mod_size(index: i64) -> &'static str {
-        Self::get_buy_potential_distribution()
-            .get_value_for_index_mod_size(index, 0)
-            .expect("Failed to get buy potential value")
+    pub fn get_buy_potential_for_index_mod_size(index: i64) -> Result<&'static str> {
+        Self::get_buy_potential_distribution()?.get_value_for_index_mod_size(index, 0)
     }
 
     /// Get dep count for index mod size (getDepCountForIndexModSize)
-    pub fn get_dep_count_for_index_mod_size(index: i64) -> i32 {
-        Self::get_dep_count_distribution().get_value_for_index_mod_size(index, 0)
+    pub fn get_dep_count_for_index_mod_size(index: i64) -> Result<i32> {
+        Ok(Self::get_dep_count_distribution()?.get_value_for_index_mod_size(index, 0))
     }
 
     /// Get vehicle count for index mod size (getVehicleCountForIndexModSize)
-    pub fn get_vehicle_count_for_index_mod_size(index: i64) -> i32 {
-        Self::get_vehicle_count_distribution().get_value_for_index_mod_size(index, 0)
+    pub fn get_vehicle_count_for_index_mod_size(index: i64) -> Result<i32> {
+        Ok(Self::get_vehicle_count_distribution()?.get_value_for_index_mod_size(index, 0))
     }
 
     /// Get buy potential distribution size
-    pub fn get_buy_potential_size() -> usize {
-        Self::get_buy_potential_distribution().get_size()
+    pub fn get_buy_potential_size() -> Result<usize> {
+        Ok(Self::get_buy_potential_distribution()?.get_size())
     }
 
     /// Get dep count distribution size
-    pub fn get_dep_count_size() -> usize {
-        Self::get_dep_count_distribution().get_size()
+    pub fn get_dep_count_size() -> Result<usize> {
+        Ok(Self::get_dep_count_distribution()?.get_size())
     }
 
     /// Get vehicle count distribution size
-    pub fn get_vehicle_count_size() -> usize {
-        Self::get_vehicle_count_distribution().get_size()
+    pub fn get_vehicle_count_size() -> Result<usize> {
+        Ok(Self::get_vehicle_count_distribution()?.get_size())
     }
 }
 
@@ -218,7 +200,7 @@ mod tests {
     #[test]
     fn test_income_band_distribution() {
         // Test that we can load the distribution
-        let size = DemographicsDistributions::get_income_band_size();
+        let size = DemographicsDistributions::get_income_band_size().unwrap();
         assert!(
             size > 0,
             "Income band distribution should have at least one entry"
@@ -255,7 +237,7 @@ mod tests {
 
     #[test]
     fn test_income_band_out_of_bounds() {
-        let size = DemographicsDistributions::get_income_band_size();
+        let size = DemographicsDistributions::get_income_band_size().unwrap();
         let result = DemographicsDistributions::get_income_band_lower_bound_at_index(size + 100);
         assert!(result.is_err(), "Should fail for out of bounds index");
     }
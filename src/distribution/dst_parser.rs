@@ -0,0 +1,746 @@
+//! Parser for the distribution (.dst) file formats.
+//!
+//! Two formats are supported:
+//! * the flattened format of the files in `data/`, one `values: weights` line per entry
+//! * the dsdgen grammar of the original `tpcds.dst`, which defines any number of distributions:
+//!
+//! ```text
+//! create colors;
+//! set types = (varchar);
+//! set weights = 2;
+//! set names = (name: uniform, skewed);
+//! add ("almond": 1, 5);
+//! ```
+//!
+//! `#include "file"` reads the statements of another file, relative to the including one.
+//!
+//! This module is shared with the build script, which uses it to embed the files in `data/`
+//! into the binary, so it must not depend on anything else in the crate.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Values and weights of one distribution line
+pub type DistributionLine = (Vec<String>, Vec<String>);
+
+/// A syntax error in a distribution file, with the 1-based line it was found on
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A distribution defined with `create` in a dsdgen distribution file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DistributionDefinition {
+    pub name: String,
+    /// Types of the value fields, from `set types`
+    pub types: Vec<String>,
+    /// Number of weight fields, from `set weights`
+    pub weight_sets: usize,
+    /// Names of the value fields, from `set names` (empty if not set)
+    pub value_names: Vec<String>,
+    /// Names of the weight fields, from `set names` (empty if not set)
+    pub weight_names: Vec<String>,
+    pub lines: Vec<DistributionLine>,
+}
+
+/// How deeply `#include`s may nest, which also stops include cycles
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+/// Value types accepted by `set types`
+const VALUE_TYPES: &[&str] = &["char", "date", "decimal", "int", "integer", "varchar"];
+
+/// Decode the contents of a distribution file. The files are ISO-8859-1 (Latin-1) encoded.
+pub fn decode_latin1(bytes: &[u8]) -> String {
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+/// Check whether the contents use the dsdgen grammar: the first statement is `create` or
+/// `#include`
+pub fn is_dsdgen_format(content: &str) -> bool {
+    content
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with("--"))
+        .and_then(|line| line.split_whitespace().next())
+        .is_some_and(|word| word.eq_ignore_ascii_case("create") || word == "#include")
+}
+
+/// Parse the contents of a flattened distribution file into its lines of values and weights.
+///
+/// Values and weights are separated by the first unescaped colon and split on unescaped commas;
+/// `\:`, `\,` and `\\` stand for the escaped character and surrounding whitespace is trimmed.
+pub fn parse_distribution(content: &str) -> Result<Vec<DistributionLine>, ParseError> {
+    let mut parsed_lines = Vec::new();
+
+    for (index, line) in content.lines().enumerate() {
+        let trimmed = line.trim();
+
+        // Skip empty lines and comments
+        if trimmed.is_empty() || trimmed.starts_with("--") {
+            continue;
+        }
+
+        let parts = split_by_unescaped_colon(trimmed);
+        if parts.len() != 2 {
+            return Err(ParseError::new(
+                index + 1,
+                format!(
+                    "Expected line to contain 2 parts but it contains {}: {}",
+                    parts.len(),
+                    trimmed
+                ),
+            ));
+        }
+
+        let values = parse_comma_separated_values(parts[0]);
+        let weights = if parts[1].trim().is_empty() {
+            Vec::new()
+        } else {
+            parse_comma_separated_values(parts[1])
+        };
+
+        parsed_lines.push((values, weights));
+    }
+
+    Ok(parsed_lines)
+}
+
+/// Parse the contents of a dsdgen distribution file into the distributions it defines.
+/// `#include` is only supported by [`parse_definitions_in`].
+pub fn parse_definitions(content: &str) -> Result<Vec<DistributionDefinition>, ParseError> {
+    Parser::new(tokenize(content)?, None, 0).parse_definitions()
+}
+
+/// Parse the contents of a dsdgen distribution file in `directory`, which `#include`d files are
+/// read relative to
+pub fn parse_definitions_in(
+    content: &str,
+    directory: &Path,
+) -> Result<Vec<DistributionDefinition>, ParseError> {
+    Parser::new(tokenize(content)?, Some(directory.to_path_buf()), 0).parse_definitions()
+}
+
+/// Split by colon, but not escaped colon (\\:)
+fn split_by_unescaped_colon(line: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut escaped = false;
+
+    for (position, ch) in line.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if ch == '\\' {
+            escaped = true;
+        } else if ch == ':' {
+            parts.push(line[start..position].trim());
+            start = position + 1;
+        }
+    }
+    parts.push(line[start..].trim());
+
+    parts
+}
+
+/// Parse comma-separated values, handling escaped commas (\\,), colons (\\:) and
+/// backslashes (\\\\). Empty values, including a trailing one, are kept.
+fn parse_comma_separated_values(input: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut chars = input.trim().chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match (ch, chars.peek()) {
+            ('\\', Some(&escaped @ (',' | ':' | '\\'))) => {
+                current.push(escaped);
+                chars.next();
+            }
+            (',', _) => values.push(std::mem::take(&mut current).trim().to_string()),
+            _ => current.push(ch),
+        }
+    }
+    values.push(current.trim().to_string());
+
+    values
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Number(String),
+    String(String),
+    Symbol(char),
+    /// `#include "file"`
+    Include(String),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Word(word) | Token::Number(word) => write!(f, "'{}'", word),
+            Token::String(string) => write!(f, "\"{}\"", string),
+            Token::Symbol(symbol) => write!(f, "'{}'", symbol),
+            Token::Include(file) => write!(f, "#include \"{}\"", file),
+        }
+    }
+}
+
+/// Split dsdgen distribution source into tokens, each with its line number
+fn tokenize(content: &str) -> Result<Vec<(Token, usize)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut chars = content.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\n' => line += 1,
+            c if c.is_whitespace() => {}
+            '-' if chars.peek() == Some(&'-') => while chars.next_if(|&c| c != '\n').is_some() {},
+            '#' => {
+                let directive: String = std::iter::once(ch)
+                    .chain(std::iter::from_fn(|| {
+                        chars.next_if(|c| c.is_ascii_alphabetic())
+                    }))
+                    .collect();
+                if directive != "#include" {
+                    return Err(ParseError::new(
+                        line,
+                        format!("Unsupported preprocessor directive {}", directive),
+                    ));
+                }
+                while chars.next_if(|&c| c == ' ' || c == '\t').is_some() {}
+                let close = match chars.next() {
+                    Some('"') => '"',
+                    Some('<') => '>',
+                    _ => return Err(ParseError::new(line, "Expected a file name after #include")),
+                };
+                let file: String =
+                    std::iter::from_fn(|| chars.next_if(|&c| c != close && c != '\n')).collect();
+                if chars.next() != Some(close) || file.is_empty() {
+                    return Err(ParseError::new(line, "Unterminated #include file name"));
+                }
+                tokens.push((Token::Include(file), line));
+            }
+            '"' => {
+                let start_line = line;
+                let mut string = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(escaped) => string.push(escaped),
+                            None => break,
+                        },
+                        Some('\n') | None => {
+                            return Err(ParseError::new(start_line, "Unterminated string"));
+                        }
+                        Some(c) => string.push(c),
+                    }
+                }
+                tokens.push((Token::String(string), start_line));
+            }
+            '(' | ')' | ',' | ':' | ';' | '=' => tokens.push((Token::Symbol(ch), line)),
+            c if c.is_ascii_digit() || c == '-' || c == '.' => {
+                let number: String = std::iter::once(c)
+                    .chain(std::iter::from_fn(|| {
+                        chars.next_if(|c| c.is_ascii_digit() || *c == '.')
+                    }))
+                    .collect();
+                tokens.push((Token::Number(number), line));
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let word: String = std::iter::once(c)
+                    .chain(std::iter::from_fn(|| {
+                        chars.next_if(|c| c.is_alphanumeric() || *c == '_' || *c == '.')
+                    }))
+                    .collect();
+                tokens.push((Token::Word(word), line));
+            }
+            c => {
+                return Err(ParseError::new(
+                    line,
+                    format!("Unexpected character '{}'", c),
+                ))
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// Recursive descent parser over the tokens of a dsdgen distribution file
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    position: usize,
+    /// Directory `#include`d files are read relative to, if includes are supported
+    directory: Option<PathBuf>,
+    include_depth: usize,
+}
+
+impl Parser {
+    fn new(tokens: Vec<(Token, usize)>, directory: Option<PathBuf>, include_depth: usize) -> Self {
+        Self {
+            tokens,
+            position: 0,
+            directory,
+            include_depth,
+        }
+    }
+
+    /// Line of the next token, or of the last one at the end of the input
+    fn line(&self) -> usize {
+        self.tokens
+            .get(self.position)
+            .or(self.tokens.last())
+            .map_or(1, |(_, line)| *line)
+    }
+
+    fn next(&mut self, expected: &str) -> Result<Token, ParseError> {
+        match self.tokens.get(self.position) {
+            Some((token, _)) => {
+                self.position += 1;
+                Ok(token.clone())
+            }
+            None => Err(ParseError::new(
+                self.line(),
+                format!("Expected {} but found end of file", expected),
+            )),
+        }
+    }
+
+    fn unexpected(&self, token: &Token, expected: &str) -> ParseError {
+        ParseError::new(
+            self.tokens[self.position - 1].1,
+            format!("Expected {} but found {}", expected, token),
+        )
+    }
+
+    fn expect_symbol(&mut self, symbol: char) -> Result<(), ParseError> {
+        let expected = format!("'{}'", symbol);
+        match self.next(&expected)? {
+            Token::Symbol(c) if c == symbol => Ok(()),
+            token => Err(self.unexpected(&token, &expected)),
+        }
+    }
+
+    fn peek_symbol(&self, symbol: char) -> bool {
+        matches!(self.tokens.get(self.position), Some((Token::Symbol(c), _)) if *c == symbol)
+    }
+
+    fn expect_word(&mut self) -> Result<String, ParseError> {
+        match self.next("a name")? {
+            Token::Word(word) => Ok(word),
+            token => Err(self.unexpected(&token, "a name")),
+        }
+    }
+
+    fn expect_keyword(&mut self, keywords: &[&str]) -> Result<String, ParseError> {
+        let expected = keywords.join(" or ");
+        match self.next(&expected)? {
+            Token::Word(word) if keywords.iter().any(|k| word.eq_ignore_ascii_case(k)) => {
+                Ok(word.to_ascii_lowercase())
+            }
+            token => Err(self.unexpected(&token, &expected)),
+        }
+    }
+
+    /// Any literal: a quoted string, a number or a bare word
+    fn expect_value(&mut self) -> Result<String, ParseError> {
+        match self.next("a value")? {
+            Token::String(value) | Token::Number(value) | Token::Word(value) => Ok(value),
+            token => Err(self.unexpected(&token, "a value")),
+        }
+    }
+
+    fn expect_integer(&mut self) -> Result<String, ParseError> {
+        match self.next("an integer")? {
+            Token::Number(number) if number.parse::<i64>().is_ok() => Ok(number),
+            token => Err(self.unexpected(&token, "an integer")),
+        }
+    }
+
+    /// Parse `item (, item)*` up to, but not including, one of the `terminators`
+    fn parse_list(
+        &mut self,
+        terminators: &[char],
+        parse_item: fn(&mut Self) -> Result<String, ParseError>,
+    ) -> Result<Vec<String>, ParseError> {
+        let mut items = vec![parse_item(self)?];
+        while !terminators.iter().any(|&symbol| self.peek_symbol(symbol)) {
+            self.expect_symbol(',')?;
+            items.push(parse_item(self)?);
+        }
+        Ok(items)
+    }
+
+    fn parse_definitions(mut self) -> Result<Vec<DistributionDefinition>, ParseError> {
+        let mut definitions: Vec<DistributionDefinition> = Vec::new();
+
+        while self.position < self.tokens.len() {
+            if let (Token::Include(file), line) = &self.tokens[self.position] {
+                let (file, line) = (file.clone(), *line);
+                self.position += 1;
+                for definition in self.parse_include(&file, line)? {
+                    if definitions.iter().any(|d| d.name == definition.name) {
+                        return Err(ParseError::new(
+                            line,
+                            format!(
+                                "Distribution {} from {} is already defined",
+                                definition.name, file
+                            ),
+                        ));
+                    }
+                    definitions.push(definition);
+                }
+                continue;
+            }
+
+            let keyword = self.expect_keyword(&["create", "set", "add"])?;
+            let line = self.tokens[self.position - 1].1;
+            if keyword != "create" && definitions.is_empty() {
+                return Err(ParseError::new(
+                    line,
+                    format!("'{}' before any 'create'", keyword),
+                ));
+            }
+
+            match keyword.as_str() {
+                "create" => {
+                    let name = self.expect_word()?;
+                    if definitions.iter().any(|definition| definition.name == name) {
+                        return Err(ParseError::new(
+                            line,
+                            format!("Distribution {} is already defined", name),
+                        ));
+                    }
+                    definitions.push(DistributionDefinition {
+                        name,
+                        types: Vec::new(),
+                        weight_sets: 0,
+                        value_names: Vec::new(),
+                        weight_names: Vec::new(),
+                        lines: Vec::new(),
+                    });
+                }
+                "set" => self.parse_set(definitions.last_mut().unwrap())?,
+                _ => self.parse_add(definitions.last_mut().unwrap(), line)?,
+            }
+            self.expect_symbol(';')?;
+        }
+
+        Ok(definitions)
+    }
+
+    /// Parse the distributions of the file included on `line`. Errors in it are reported on
+    /// that line, naming the file and the line of the error.
+    fn parse_include(
+        &self,
+        file: &str,
+        line: usize,
+    ) -> Result<Vec<DistributionDefinition>, ParseError> {
+        let Some(directory) = &self.directory else {
+            return Err(ParseError::new(
+                line,
+                "#include is only supported in files read from disk",
+            ));
+        };
+        if self.include_depth >= MAX_INCLUDE_DEPTH {
+            return Err(ParseError::new(
+                line,
+                format!(
+                    "#include \"{}\" nested more than {} deep",
+                    file, MAX_INCLUDE_DEPTH
+                ),
+            ));
+        }
+
+        let path = directory.join(file);
+        let bytes = std::fs::read(&path).map_err(|e| {
+            ParseError::new(line, format!("Failed to read {}: {}", path.display(), e))
+        })?;
+        let in_file = |e: ParseError| ParseError::new(line, format!("{}: {}", file, e));
+        let tokens = tokenize(&decode_latin1(&bytes)).map_err(in_file)?;
+        let directory = path.parent().map(Path::to_path_buf);
+        Parser::new(tokens, directory, self.include_depth + 1)
+            .parse_definitions()
+            .map_err(in_file)
+    }
+
+    fn parse_set(&mut self, definition: &mut DistributionDefinition) -> Result<(), ParseError> {
+        let setting = self.expect_keyword(&["types", "weights", "names"])?;
+        let line = self.tokens[self.position - 1].1;
+        if !definition.lines.is_empty() {
+            return Err(ParseError::new(
+                line,
+                format!("'set {}' after 'add' in {}", setting, definition.name),
+            ));
+        }
+        self.expect_symbol('=')?;
+
+        match setting.as_str() {
+            "types" => {
+                self.expect_symbol('(')?;
+                let types = self.parse_list(&[')'], Self::expect_word)?;
+                self.expect_symbol(')')?;
+                if let Some(unknown) = types
+                    .iter()
+                    .find(|t| !VALUE_TYPES.contains(&t.to_ascii_lowercase().as_str()))
+                {
+                    return Err(ParseError::new(line, format!("Unknown type {}", unknown)));
+                }
+                definition.types = types.iter().map(|t| t.to_ascii_lowercase()).collect();
+            }
+            "weights" => {
+                definition.weight_sets = self.expect_integer()?.parse().map_err(|_| {
+                    ParseError::new(line, "The number of weights must not be negative")
+                })?;
+            }
+            _ => {
+                self.expect_symbol('(')?;
+                definition.value_names = self.parse_list(&[':', ')'], Self::expect_value)?;
+                if self.peek_symbol(':') {
+                    self.expect_symbol(':')?;
+                    definition.weight_names = self.parse_list(&[')'], Self::expect_value)?;
+                }
+                self.expect_symbol(')')?;
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_add(
+        &mut self,
+        definition: &mut DistributionDefinition,
+        line: usize,
+    ) -> Result<(), ParseError> {
+        if definition.types.is_empty() {
+            return Err(ParseError::new(
+                line,
+                format!("'add' before 'set types' in {}", definition.name),
+            ));
+        }
+
+        self.expect_symbol('(')?;
+        let values = self.parse_list(&[':'], Self::expect_value)?;
+        self.expect_symbol(':')?;
+        let weights = self.parse_list(&[')'], Self::expect_integer)?;
+        self.expect_symbol(')')?;
+
+        if values.len() != definition.types.len() {
+            return Err(ParseError::new(
+                line,
+                format!(
+                    "Expected {} values in {} but found {}",
+                    definition.types.len(),
+                    definition.name,
+                    values.len()
+                ),
+            ));
+        }
+        if weights.len() != definition.weight_sets {
+            return Err(ParseError::new(
+                line,
+                format!(
+                    "Expected {} weights in {} but found {}",
+                    definition.weight_sets,
+                    definition.name,
+                    weights.len()
+                ),
+            ));
+        }
+
+        definition.lines.push((values, weights));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_comma_separated_values() {
+        let result = parse_comma_separated_values("a, b, c");
+        assert_eq!(result, vec!["a", "b", "c"]);
+
+        let result = parse_comma_separated_values("a\\, b, c");
+        assert_eq!(result, vec!["a, b", "c"]);
+
+        let result = parse_comma_separated_values("a\\\\, b");
+        assert_eq!(result, vec!["a\\", "b"]);
+
+        // A trailing empty value is kept
+        let result = parse_comma_separated_values("0, AM, night, ");
+        assert_eq!(result, vec!["0", "AM", "night", ""]);
+    }
+
+    #[test]
+    fn test_split_by_unescaped_colon() {
+        let result = split_by_unescaped_colon("value: 1, 2, 3");
+        assert_eq!(result, vec!["value", "1, 2, 3"]);
+
+        let result = split_by_unescaped_colon("12\\:30 PM: 1");
+        assert_eq!(result, vec!["12\\:30 PM", "1"]);
+    }
+
+    #[test]
+    fn test_parse_distribution() {
+        let error =
+            parse_distribution("-- comment\n\nNew England: 1, 2\n: 3, 4\nbad line\n").unwrap_err();
+        assert_eq!(error.line, 5);
+        assert!(error.to_string().starts_with("line 5: "));
+        assert!(error.message.contains("bad line"));
+
+        let lines = parse_distribution("-- comment\n\nNew England: 1, 2\n: 3, 4\n").unwrap();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].0, vec!["New England"]);
+        assert_eq!(lines[0].1, vec!["1", "2"]);
+        assert_eq!(lines[1].0, vec![""]);
+
+        let lines = parse_distribution("12\\:30 PM\\, noon: 1\n").unwrap();
+        assert_eq!(lines[0].0, vec!["12:30 PM, noon"]);
+    }
+
+    #[test]
+    fn test_parse_definitions() {
+        let content = "\
+-- colors
+create colors;
+set types = (varchar, int);
+set weights = 2;
+set names = (name, rank: uniform, skewed);
+add (\"almond: light, sweet\", 1: 1, 5);
+add (bisque, 2: 1, -1);
+
+create empty;
+set types = (date);
+set weights = 0;
+";
+        assert!(is_dsdgen_format(content));
+        assert!(!is_dsdgen_format("-- create\nNew England: 1\n"));
+
+        let definitions = parse_definitions(content).unwrap();
+        assert_eq!(definitions.len(), 2);
+        let colors = &definitions[0];
+        assert_eq!(colors.name, "colors");
+        assert_eq!(colors.types, vec!["varchar", "int"]);
+        assert_eq!(colors.weight_sets, 2);
+        assert_eq!(colors.value_names, vec!["name", "rank"]);
+        assert_eq!(colors.weight_names, vec!["uniform", "skewed"]);
+        assert_eq!(
+            colors.lines,
+            vec![
+                (
+                    vec!["almond: light, sweet".to_string(), "1".to_string()],
+                    vec!["1".to_string(), "5".to_string()]
+                ),
+                (
+                    vec!["bisque".to_string(), "2".to_string()],
+                    vec!["1".to_string(), "-1".to_string()]
+                ),
+            ]
+        );
+        assert_eq!(definitions[1].name, "empty");
+        assert!(definitions[1].lines.is_empty());
+    }
+
+    #[test]
+    fn test_parse_definitions_errors() {
+        let error = |content: &str| parse_definitions(content).unwrap_err();
+
+        let e =
+            error("create c;\nset types = (varchar);\nset weights = 1;\nadd (\"a\", \"b\": 1);\n");
+        assert_eq!(e.line, 4);
+        assert!(e.message.contains("Expected 1 values"));
+
+        let e = error("create c;\nset types = (varchar);\nset weights = 2;\nadd (\"a\": 1);");
+        assert_eq!(e.line, 4);
+        assert!(e.message.contains("Expected 2 weights"));
+
+        assert_eq!(error("\nadd (\"a\": 1);").line, 2);
+        assert_eq!(error("create c;\nset types = (blob);").line, 2);
+        assert_eq!(error("create c;\n\nset types = (varchar)").line, 3);
+        assert_eq!(
+            error("create c;\nset types = (varchar);\nadd (\"a: 1);").line,
+            3
+        );
+        assert_eq!(error("create c;\ncreate c;").line, 2);
+        assert_eq!(error("#define X 1").line, 1);
+        assert!(error("#include \"tpcds.dsd\"")
+            .message
+            .contains("only supported in files"));
+        assert_eq!(
+            error("create c;\nset types = (varchar);\nset weights = 1;\nadd (\"a\": x);").message,
+            "Expected an integer but found 'x'"
+        );
+    }
+
+    #[test]
+    fn test_parse_definitions_with_includes() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        std::fs::create_dir_all(directory.join("nested")).unwrap();
+        std::fs::write(
+            directory.join("nested/colors.dst"),
+            "create colors;\nset types = (varchar);\nset weights = 1;\nadd (\"red\": 1);\n",
+        )
+        .unwrap();
+        std::fs::write(
+            directory.join("nested/all.dst"),
+            "#include \"colors.dst\"\ncreate sizes;\nset types = (varchar);\nset weights = 1;\n",
+        )
+        .unwrap();
+        let content = "-- main\n#include <nested/all.dst>\nadd (\"small\": 1);\n";
+        assert!(is_dsdgen_format(content));
+
+        // Includes are relative to the including file and their statements continue in place
+        let definitions = parse_definitions_in(content, directory).unwrap();
+        assert_eq!(definitions.len(), 2);
+        assert_eq!(definitions[0].lines[0].0, vec!["red"]);
+        assert_eq!(definitions[1].name, "sizes");
+        assert_eq!(definitions[1].lines[0].0, vec!["small"]);
+
+        // Errors in included files name the file and their line
+        std::fs::write(
+            directory.join("nested/colors.dst"),
+            "create colors;\nadd (x);\n",
+        )
+        .unwrap();
+        let e = parse_definitions_in(content, directory).unwrap_err();
+        assert_eq!(e.line, 2);
+        assert!(e
+            .message
+            .starts_with("nested/all.dst: line 1: colors.dst: line 2:"));
+
+        let e = parse_definitions_in("#include \"missing.dst\"", directory).unwrap_err();
+        assert!(e.message.contains("Failed to read"));
+
+        // A file including itself stops at the depth limit
+        std::fs::write(directory.join("self.dst"), "#include \"self.dst\"\n").unwrap();
+        let e = parse_definitions_in("#include \"self.dst\"", directory).unwrap_err();
+        assert!(e.message.contains("nested more than"));
+    }
+
+    #[test]
+    fn test_decode_latin1() {
+        assert_eq!(decode_latin1(b"caf\xe9"), "café");
+    }
+}
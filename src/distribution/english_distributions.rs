@@ -1,4 +1,5 @@
 use crate::distribution::string_values_distribution::StringValuesDistribution;
+use crate::distribution::utils::get_or_load;
 use crate::error::Result;
 use crate::random::stream::RandomNumberStream;
 use std::sync::OnceLock;
@@ -15,89 +16,79 @@ static TERMINATORS_DISTRIBUTION: OnceLock<StringValuesDistribution> = OnceLock::
 static VERBS_DISTRIBUTION: OnceLock<StringValuesDistribution> = OnceLock::new();
 
 pub fn pick_random_adjective(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = ADJECTIVES_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&ADJECTIVES_DISTRIBUTION, "adjectives.dst", || {
         StringValuesDistribution::build_string_values_distribution("adjectives.dst", 1, 1)
-            .expect("Failed to load adjectives distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
 pub fn pick_random_adverb(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = ADVERBS_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&ADVERBS_DISTRIBUTION, "adverbs.dst", || {
         StringValuesDistribution::build_string_values_distribution("adverbs.dst", 1, 1)
-            .expect("Failed to load adverbs distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
 pub fn pick_random_article(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = ARTICLES_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&ARTICLES_DISTRIBUTION, "articles.dst", || {
         StringValuesDistribution::build_string_values_distribution("articles.dst", 1, 1)
-            .expect("Failed to load articles distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
 pub fn pick_random_auxiliary(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = AUXILIARIES_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&AUXILIARIES_DISTRIBUTION, "auxiliaries.dst", || {
         StringValuesDistribution::build_string_values_distribution("auxiliaries.dst", 1, 1)
-            .expect("Failed to load auxiliaries distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
 pub fn pick_random_preposition(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = PREPOSITIONS_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&PREPOSITIONS_DISTRIBUTION, "prepositions.dst", || {
         StringValuesDistribution::build_string_values_distribution("prepositions.dst", 1, 1)
-            .expect("Failed to load prepositions distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
 pub fn pick_random_noun(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = NOUNS_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&NOUNS_DISTRIBUTION, "nouns.dst", || {
         StringValuesDistribution::build_string_values_distribution("nouns.dst", 1, 1)
-            .expect("Failed to load nouns distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
 pub fn pick_random_sentence(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = SENTENCES_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&SENTENCES_DISTRIBUTION, "sentences.dst", || {
         StringValuesDistribution::build_string_values_distribution("sentences.dst", 1, 1)
-            .expect("Failed to load sentences distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
 pub fn pick_random_terminator(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = TERMINATORS_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&TERMINATORS_DISTRIBUTION, "terminators.dst", || {
         StringValuesDistribution::build_string_values_distribution("terminators.dst", 1, 1)
-            .expect("Failed to load terminators distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
 pub fn pick_random_verb(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-    let dist = VERBS_DISTRIBUTION.get_or_init(|| {
+    let dist = get_or_load(&VERBS_DISTRIBUTION, "verbs.dst", || {
         StringValuesDistribution::build_string_values_distribution("verbs.dst", 1, 1)
-            .expect("Failed to load verbs distribution")
-    });
+    })?;
 
     dist.pick_random_value(0, 0, stream)
 }
 
-pub fn get_syllables_distribution() -> &'static StringValuesDistribution {
-    SYLLABLES_DISTRIBUTION.get_or_init(|| {
+pub fn get_syllables_distribution() -> Result<&'static StringValuesDistribution> {
+    get_or_load(&SYLLABLES_DISTRIBUTION, "syllables.dst", || {
         StringValuesDistribution::build_string_values_distribution("syllables.dst", 1, 1)
-            .expect("Failed to load syllables distribution")
     })
 }
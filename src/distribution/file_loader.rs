@@ -1,132 +1,272 @@
+use crate::distribution::dst_parser::{self, DistributionDefinition, DistributionLine, ParseError};
+use crate::distribution::string_values_distribution::StringValuesDistribution;
 use crate::error::{Result, TpcdsError};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Distribution file name and its pre-parsed lines of values and weights
+type EmbeddedDistribution = (
+    &'static str,
+    &'static [(&'static [&'static str], &'static [&'static str])],
+);
+
+include!(concat!(env!("OUT_DIR"), "/embedded_distributions.rs"));
+
+/// Directory whose files override the embedded distributions, fixed when the first
+/// distribution is loaded
+static OVERRIDE_DIRECTORY: OnceLock<Option<PathBuf>> = OnceLock::new();
 
 /// Loads and parses distribution files (.dst format)
 /// DistributionUtils functionality
 pub struct DistributionFileLoader;
 
 impl DistributionFileLoader {
+    /// Environment variable naming a directory whose .dst files replace the embedded ones, read
+    /// as the default of --distributions-dir
+    pub const OVERRIDE_DIRECTORY_VARIABLE: &'static str = "TPCDSGEN_DISTRIBUTIONS_DIR";
+
     /// Load a distribution file and return parsed lines
-    /// Each line is split by colon into value and weight parts
-    pub fn load_distribution_file(filename: &str) -> Result<Vec<(Vec<String>, Vec<String>)>> {
-        let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
-        let file_path = data_dir.join(filename);
+    /// Each line is split by colon into value and weight parts.
+    /// Files are compiled into the binary; a file of the same name in the override
+    /// directory takes precedence.
+    pub fn load_distribution_file(filename: &str) -> Result<Vec<DistributionLine>> {
+        if let Some(directory) = Self::get_override_directory() {
+            let file_path = directory.join(filename);
+            if file_path.is_file() {
+                return Self::load_distribution_file_from_path(&file_path);
+            }
+        }
 
-        // Read as bytes first then decode as ISO-8859-1 (Latin-1)
-        let bytes = fs::read(&file_path).map_err(|e| {
-            TpcdsError::new(&format!(
-                "Failed to read distribution file {}: {}",
-                filename, e
-            ))
-        })?;
+        let lines = Self::get_embedded_distribution(filename)
+            .ok_or_else(|| TpcdsError::distribution(filename, None, "Unknown distribution file"))?;
+        Ok(lines
+            .iter()
+            .map(|(values, weights)| {
+                (
+                    values.iter().map(|value| value.to_string()).collect(),
+                    weights.iter().map(|weight| weight.to_string()).collect(),
+                )
+            })
+            .collect())
+    }
 
-        // Convert ISO-8859-1 to UTF-8 string
-        let content = bytes.iter().map(|&b| b as char).collect::<String>();
+    /// Read and parse a distribution file from disk.
+    /// Files in the dsdgen grammar are accepted too: the distribution named after the file, or
+    /// the only one it defines, is used.
+    pub fn load_distribution_file_from_path(file_path: &Path) -> Result<Vec<DistributionLine>> {
+        Ok(Self::load_distribution_definition_from_path(file_path)?.lines)
+    }
 
-        let mut parsed_lines = Vec::new();
+    /// Load a distribution file like `load_distribution_file`, with the types of its values and
+    /// the names of its sets
+    pub fn load_distribution_definition(filename: &str) -> Result<DistributionDefinition> {
+        if let Some(directory) = Self::get_override_directory() {
+            let file_path = directory.join(filename);
+            if file_path.is_file() {
+                return Self::load_distribution_definition_from_path(&file_path);
+            }
+        }
 
-        for line in content.lines() {
-            let trimmed = line.trim();
+        let lines = Self::load_distribution_file(filename)?;
+        Ok(Self::flattened_definition(filename, lines))
+    }
+
+    /// Read and parse a distribution file from disk with the types of its values and the names
+    /// of its sets
+    pub fn load_distribution_definition_from_path(
+        file_path: &Path,
+    ) -> Result<DistributionDefinition> {
+        let content = Self::read_distribution_file(file_path)?;
+        let name = file_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        if !dst_parser::is_dsdgen_format(&content) {
+            let lines = dst_parser::parse_distribution(&content)
+                .map_err(|e| Self::parse_error(file_path, e))?;
+            return Ok(Self::flattened_definition(&name, lines));
+        }
 
-            // Skip empty lines and comments
-            if trimmed.is_empty() || trimmed.starts_with("--") {
-                continue;
+        let mut definitions = Self::parse_definitions(file_path, &content)?;
+        let index = match definitions.iter().position(|d| d.name == name) {
+            Some(index) => index,
+            None if definitions.len() == 1 => 0,
+            None => {
+                return Err(TpcdsError::distribution(
+                    &file_path.display().to_string(),
+                    None,
+                    &format!("Does not define a distribution named {}", name),
+                ))
             }
+        };
+        Ok(definitions.swap_remove(index))
+    }
 
-            // Split by colon (not escaped colon)
-            let parts: Vec<&str> = Self::split_by_unescaped_colon(trimmed);
+    /// Describe the lines of a file in the flattened format, which names no sets. A value field
+    /// is `int` when all its values are integers.
+    fn flattened_definition(name: &str, lines: Vec<DistributionLine>) -> DistributionDefinition {
+        let (value_count, weight_sets) = lines
+            .first()
+            .map_or((0, 0), |(values, weights)| (values.len(), weights.len()));
+        let types = (0..value_count)
+            .map(|field| {
+                let is_int = lines.iter().all(|(values, _)| {
+                    values
+                        .get(field)
+                        .is_some_and(|value| value.parse::<i32>().is_ok())
+                });
+                if is_int { "int" } else { "varchar" }.to_string()
+            })
+            .collect();
+        DistributionDefinition {
+            name: name.trim_end_matches(".dst").to_string(),
+            types,
+            weight_sets,
+            value_names: Vec::new(),
+            weight_names: Vec::new(),
+            lines,
+        }
+    }
 
-            if parts.len() != 2 {
-                return Err(TpcdsError::new(&format!(
-                    "Expected line to contain 2 parts but it contains {}: {}",
-                    parts.len(),
-                    trimmed
-                )));
-            }
+    /// Read and parse every distribution defined in a dsdgen distribution file, such as the
+    /// original `tpcds.dst`
+    pub fn load_distribution_definitions(file_path: &Path) -> Result<Vec<DistributionDefinition>> {
+        let content = Self::read_distribution_file(file_path)?;
+        Self::parse_definitions(file_path, &content)
+    }
 
-            let values = if parts[0].is_empty() {
-                vec![String::new()] // Handle empty string case like ": weight1, weight2"
-            } else {
-                Self::parse_comma_separated_values(parts[0])?
-            };
-            let weights = Self::parse_comma_separated_values(parts[1])?;
+    /// Parse a dsdgen distribution file, reading its `#include`s relative to it
+    fn parse_definitions(file_path: &Path, content: &str) -> Result<Vec<DistributionDefinition>> {
+        let directory = file_path.parent().unwrap_or(Path::new("."));
+        dst_parser::parse_definitions_in(content, directory)
+            .map_err(|e| Self::parse_error(file_path, e))
+    }
 
-            parsed_lines.push((values, weights));
-        }
+    fn read_distribution_file(file_path: &Path) -> Result<String> {
+        // Read as bytes first then decode as ISO-8859-1 (Latin-1)
+        let bytes = fs::read(file_path).map_err(|e| {
+            TpcdsError::io(
+                &format!("Failed to read distribution file {}", file_path.display()),
+                e,
+            )
+        })?;
+        Ok(dst_parser::decode_latin1(&bytes))
+    }
 
-        Ok(parsed_lines)
-    }
-
-    /// Split by colon, but not escaped colon (\\:)
-    fn split_by_unescaped_colon(line: &str) -> Vec<&str> {
-        // Simple implementation that splits by colon and trims
-        // In a full implementation, we'd properly handle escaped colons
-        line.split(':').map(str::trim).collect()
-    }
-
-    /// Parse comma-separated values, handling escaped commas (\\,)
-    fn parse_comma_separated_values(input: &str) -> Result<Vec<String>> {
-        let mut values = Vec::new();
-        let mut current = String::new();
-        let mut chars = input.trim().chars().peekable();
-
-        while let Some(ch) = chars.next() {
-            if ch == '\\' && chars.peek() == Some(&',') {
-                // Escaped comma, add the comma to current value
-                current.push(',');
-                chars.next(); // consume the ','
-            } else if ch == '\\' && chars.peek() == Some(&'\\') {
-                // Escaped backslash
-                current.push('\\');
-                chars.next(); // consume the second '\'
-            } else if ch == ',' {
-                // Unescaped comma, split here
-                values.push(current.trim().to_string());
-                current = String::new();
-            } else {
-                current.push(ch);
-            }
+    fn parse_error(file_path: &Path, e: ParseError) -> TpcdsError {
+        TpcdsError::distribution(&file_path.display().to_string(), Some(e.line), &e.message)
+    }
+
+    /// Get the names of the distribution files compiled into the binary
+    pub fn get_embedded_file_names() -> impl Iterator<Item = &'static str> {
+        EMBEDDED_DISTRIBUTIONS.iter().map(|(name, _)| *name)
+    }
+
+    fn get_embedded_distribution(
+        filename: &str,
+    ) -> Option<&'static [(&'static [&'static str], &'static [&'static str])]> {
+        EMBEDDED_DISTRIBUTIONS
+            .binary_search_by(|(name, _)| (*name).cmp(filename))
+            .ok()
+            .map(|index| EMBEDDED_DISTRIBUTIONS[index].1)
+    }
+
+    /// Get the directory whose files override the embedded distributions, if any
+    pub fn get_override_directory() -> Option<&'static Path> {
+        OVERRIDE_DIRECTORY.get_or_init(|| None).as_deref()
+    }
+
+    /// Use the .dst files in `directory` instead of the embedded ones (--distributions-dir or
+    /// `TPCDSGEN_DISTRIBUTIONS_DIR`) and return the names of the files it replaces.
+    /// The directory is validated first; must be called before any distribution is loaded.
+    pub fn set_override_directory(directory: &Path) -> Result<Vec<String>> {
+        let file_names = Self::validate_override_directory(directory)?;
+        let directory = Some(directory.to_path_buf());
+        if OVERRIDE_DIRECTORY.set(directory.clone()).is_err()
+            && OVERRIDE_DIRECTORY.get() != Some(&directory)
+        {
+            return Err(TpcdsError::new(
+                "The distributions directory must be set before any distribution is loaded",
+            ));
         }
+        Ok(file_names)
+    }
 
-        if !current.is_empty() {
-            values.push(current.trim().to_string());
+    /// Get the names of the embedded distribution files replaced by the override directory
+    pub fn get_overridden_file_names() -> Vec<&'static str> {
+        match Self::get_override_directory() {
+            Some(directory) => Self::get_embedded_file_names()
+                .filter(|file_name| directory.join(file_name).is_file())
+                .collect(),
+            None => Vec::new(),
         }
+    }
 
-        // Remove escaping from final values
-        for value in &mut values {
-            *value = value.replace("\\\\", "\\");
+    /// Check that the lines of an override have the shape of the embedded file and build a
+    /// distribution from them
+    fn validate_override(
+        file_name: &str,
+        embedded: &[(&[&str], &[&str])],
+        lines: Vec<DistributionLine>,
+    ) -> Result<()> {
+        if lines.is_empty() {
+            return Err(TpcdsError::distribution(file_name, None, "Empty file"));
         }
 
-        Ok(values)
+        // Every line of the embedded file has the shape of the first one
+        let (values, weights) = embedded[0];
+        StringValuesDistribution::from_distribution_lines(lines, values.len(), weights.len())
+            .map_err(|e| TpcdsError::distribution(file_name, None, &e.to_string()))?;
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Check that every .dst file in `directory` replaces an embedded distribution and has the
+    /// same number of values and weights per line, so that the generators can use it.
+    /// Returns the names of the validated files.
+    pub fn validate_override_directory(directory: &Path) -> Result<Vec<String>> {
+        let entries = fs::read_dir(directory).map_err(|e| {
+            TpcdsError::io(
+                &format!(
+                    "Failed to read distributions directory {}",
+                    directory.display()
+                ),
+                e,
+            )
+        })?;
 
-    #[test]
-    fn test_parse_comma_separated_values() {
-        let result = DistributionFileLoader::parse_comma_separated_values("a, b, c").unwrap();
-        assert_eq!(result, vec!["a", "b", "c"]);
+        let mut file_names = Vec::new();
+        for entry in entries {
+            let file_name = entry?.file_name().to_string_lossy().to_string();
+            if file_name.ends_with(".dst") {
+                file_names.push(file_name);
+            }
+        }
+        file_names.sort();
 
-        let result = DistributionFileLoader::parse_comma_separated_values("a\\, b, c").unwrap();
-        assert_eq!(result, vec!["a, b", "c"]);
+        for file_name in &file_names {
+            let embedded = Self::get_embedded_distribution(file_name).ok_or_else(|| {
+                TpcdsError::distribution(
+                    &directory.join(file_name).display().to_string(),
+                    None,
+                    "Does not replace any distribution file",
+                )
+            })?;
+            let lines = Self::load_distribution_file_from_path(&directory.join(file_name))?;
+            Self::validate_override(file_name, embedded, lines)?;
+        }
 
-        let result = DistributionFileLoader::parse_comma_separated_values("a\\\\, b").unwrap();
-        assert_eq!(result, vec!["a\\", "b"]);
+        Ok(file_names)
     }
+}
 
-    #[test]
-    fn test_split_by_unescaped_colon() {
-        let result = DistributionFileLoader::split_by_unescaped_colon("value: 1, 2, 3");
-        assert_eq!(result, vec!["value", "1, 2, 3"]);
-    }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
     #[test]
     fn test_load_call_centers_distribution() {
-        // This will test against an actual file
+        // This will test against the embedded file
         let result = DistributionFileLoader::load_distribution_file("call_centers.dst");
         assert!(result.is_ok());
 
@@ -137,4 +277,173 @@ mod tests {
         assert_eq!(data[0].0.len(), 1); // 1 value field
         assert_eq!(data[0].1.len(), 2); // 2 weight fields
     }
+
+    #[test]
+    fn test_embedded_distributions_match_data_files() {
+        use sha2::{Digest, Sha256};
+        use std::fmt::Write as _;
+
+        // Checked-in values, so changes to how values are split show up here
+        let expected: Vec<(&str, usize, &str)> =
+            include_str!("../../tests/golden/distributions.tsv")
+                .lines()
+                .filter(|line| !line.starts_with('#'))
+                .map(|line| {
+                    let fields: Vec<&str> = line.split('\t').collect();
+                    (fields[0], fields[1].parse().unwrap(), fields[2])
+                })
+                .collect();
+        let file_names: Vec<&str> = DistributionFileLoader::get_embedded_file_names().collect();
+        assert_eq!(
+            file_names,
+            expected
+                .iter()
+                .map(|(name, _, _)| *name)
+                .collect::<Vec<_>>()
+        );
+
+        for (file_name, rows, sha256) in expected {
+            let lines = DistributionFileLoader::load_distribution_file(file_name).unwrap();
+            let mut serialized = String::new();
+            for (values, weights) in &lines {
+                writeln!(
+                    serialized,
+                    "{}\u{1f}{}\u{1e}{}\u{1f}{}",
+                    values.len(),
+                    values.join("\u{1f}"),
+                    weights.len(),
+                    weights.join("\u{1f}")
+                )
+                .unwrap();
+            }
+            assert_eq!(lines.len(), rows, "{}", file_name);
+            assert_eq!(
+                format!("{:x}", Sha256::digest(serialized.as_bytes())),
+                sha256,
+                "{}",
+                file_name
+            );
+        }
+
+        // The meal of most hours is a trailing empty value
+        let hours = DistributionFileLoader::load_distribution_file("hours.dst").unwrap();
+        assert_eq!(hours[0].0, vec!["0", "AM", "third", "night", ""]);
+    }
+
+    #[test]
+    fn test_data_files_are_valid_overrides() {
+        let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("data");
+        let file_names = DistributionFileLoader::validate_override_directory(&data_dir).unwrap();
+        assert_eq!(
+            file_names.len(),
+            DistributionFileLoader::get_embedded_file_names().count()
+        );
+    }
+
+    #[test]
+    fn test_validate_override_directory() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+
+        fs::write(directory.join("call_centers.dst"), "Nowhere: 1, 1\n").unwrap();
+        fs::write(directory.join("README"), "not a distribution").unwrap();
+        assert_eq!(
+            DistributionFileLoader::validate_override_directory(directory).unwrap(),
+            vec!["call_centers.dst"]
+        );
+
+        // Wrong number of weights
+        fs::write(directory.join("call_centers.dst"), "Nowhere: 1\n").unwrap();
+        assert!(DistributionFileLoader::validate_override_directory(directory).is_err());
+
+        // Weights must be numbers
+        fs::write(directory.join("call_centers.dst"), "Nowhere: 1, x\n").unwrap();
+        assert!(DistributionFileLoader::validate_override_directory(directory).is_err());
+
+        // Files must replace an embedded distribution
+        fs::write(directory.join("call_centers.dst"), "Nowhere: 1, 1\n").unwrap();
+        fs::write(directory.join("planets.dst"), "Mars: 1\n").unwrap();
+        assert!(DistributionFileLoader::validate_override_directory(directory).is_err());
+    }
+
+    #[test]
+    fn test_invalid_override_directory_is_not_set() {
+        let temp = tempfile::tempdir().unwrap();
+        let missing = temp.path().join("missing");
+        assert!(DistributionFileLoader::set_override_directory(&missing).is_err());
+
+        fs::write(temp.path().join("planets.dst"), "Mars: 1\n").unwrap();
+        assert!(DistributionFileLoader::set_override_directory(temp.path()).is_err());
+        assert_ne!(
+            DistributionFileLoader::get_override_directory(),
+            Some(temp.path())
+        );
+    }
+
+    #[test]
+    fn test_load_dsdgen_distribution_file() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        let content = "\
+create call_centers;
+set types = (varchar);
+set weights = 2;
+add (\"Nowhere\": 1, 1);
+create colors;
+set types = (varchar);
+set weights = 1;
+add (\"red\": 1);
+";
+        fs::write(directory.join("call_centers.dst"), content).unwrap();
+        fs::write(directory.join("tpcds.dst"), content).unwrap();
+
+        let lines = DistributionFileLoader::load_distribution_file_from_path(
+            &directory.join("call_centers.dst"),
+        )
+        .unwrap();
+        assert_eq!(
+            lines,
+            vec![(
+                vec!["Nowhere".to_string()],
+                vec!["1".to_string(), "1".to_string()]
+            )]
+        );
+        assert!(DistributionFileLoader::load_distribution_file_from_path(
+            &directory.join("tpcds.dst")
+        )
+        .is_err());
+        let definitions =
+            DistributionFileLoader::load_distribution_definitions(&directory.join("tpcds.dst"))
+                .unwrap();
+        assert_eq!(definitions.len(), 2);
+
+        // Errors name the file and the line
+        fs::write(
+            directory.join("colors.dst"),
+            "create colors;\nset types = (blob);\n",
+        )
+        .unwrap();
+        let error =
+            DistributionFileLoader::load_distribution_file_from_path(&directory.join("colors.dst"))
+                .unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("colors.dst, line 2: Unknown type blob"),
+            "{}",
+            error
+        );
+        assert!(matches!(
+            error,
+            TpcdsError::Distribution { line: Some(2), .. }
+        ));
+    }
+
+    #[test]
+    fn test_unknown_distribution_file() {
+        assert!(matches!(
+            DistributionFileLoader::load_distribution_file("missing.dst"),
+            Err(TpcdsError::Distribution { file, line: None, .. }) if file == "missing.dst"
+        ));
+    }
 }
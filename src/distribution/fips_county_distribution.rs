@@ -1,5 +1,5 @@
 use crate::distribution::file_loader::DistributionFileLoader;
-use crate::distribution::utils::{pick_random_index, WeightsBuilder};
+use crate::distribution::utils::{get_or_load, pick_random_index, WeightsBuilder};
 use crate::error::{Result, TpcdsError};
 use crate::random::RandomNumberStream;
 use std::sync::OnceLock;
@@ -94,23 +94,29 @@ impl FipsCountyDistribution {
         })
     }
 
-    fn get_instance() -> &'static FipsCountyDistribution {
-        FIPS_COUNTY_DISTRIBUTION.get_or_init(|| {
-            Self::build_fips_county_distribution()
-                .expect("Failed to build FIPS county distribution")
-        })
+    fn get_instance() -> Result<&'static FipsCountyDistribution> {
+        get_or_load(
+            &FIPS_COUNTY_DISTRIBUTION,
+            "fips.dst",
+            Self::build_fips_county_distribution,
+        )
     }
 
     pub fn pick_random_index(
         weights: FipsWeights,
         stream: &mut dyn RandomNumberStream,
     ) -> Result<usize> {
-        let instance = Self::get_instance();
+        let instance = Self::get_instance()?;
         pick_random_index(&instance.weights_lists[weights as usize], stream)
     }
 
+    /// Get the number of counties
+    pub fn get_size() -> Result<usize> {
+        Ok(Self::get_instance()?.counties.len())
+    }
+
     pub fn get_county_at_index(index: usize) -> Result<&'static str> {
-        let instance = Self::get_instance();
+        let instance = Self::get_instance()?;
         instance
             .counties
             .get(index)
@@ -119,7 +125,7 @@ impl FipsCountyDistribution {
     }
 
     pub fn get_state_abbreviation_at_index(index: usize) -> Result<&'static str> {
-        let instance = Self::get_instance();
+        let instance = Self::get_instance()?;
         instance
             .state_abbreviations
             .get(index)
@@ -130,7 +136,7 @@ impl FipsCountyDistribution {
     }
 
     pub fn get_zip_prefix_at_index(index: usize) -> Result<i32> {
-        let instance = Self::get_instance();
+        let instance = Self::get_instance()?;
         instance
             .zip_prefixes
             .get(index)
@@ -139,7 +145,7 @@ impl FipsCountyDistribution {
     }
 
     pub fn get_gmt_offset_at_index(index: usize) -> Result<i32> {
-        let instance = Self::get_instance();
+        let instance = Self::get_instance()?;
         instance
             .gmt_offsets
             .get(index)
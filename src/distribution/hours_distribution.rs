@@ -1,4 +1,5 @@
 use crate::distribution::file_loader::DistributionFileLoader;
+use crate::distribution::utils::get_or_load;
 use crate::distribution::utils::{pick_random_value, WeightsBuilder};
 use crate::error::Result;
 use crate::random::RandomNumberStream;
@@ -63,11 +64,13 @@ impl HoursDistribution {
     const NUM_WEIGHT_FIELDS: usize = 3;
     const VALUES_AND_WEIGHTS_FILENAME: &'static str = "hours.dst";
 
-    fn get_instance() -> &'static HoursDistribution {
+    fn get_instance() -> Result<&'static HoursDistribution> {
         static DISTRIBUTION: OnceLock<HoursDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
-            Self::build_hours_distribution().expect("Failed to load hours distribution")
-        })
+        get_or_load(
+            &DISTRIBUTION,
+            Self::VALUES_AND_WEIGHTS_FILENAME,
+            Self::build_hours_distribution,
+        )
     }
 
     fn build_hours_distribution() -> Result<Self> {
@@ -143,14 +146,14 @@ impl HoursDistribution {
     }
 
     /// Get hour information for a specific hour (0-23)
-    pub fn get_hour_info_for_hour(hour: i32) -> HourInfo {
-        let dist = Self::get_instance();
-        HourInfo::new(
+    pub fn get_hour_info_for_hour(hour: i32) -> Result<HourInfo> {
+        let dist = Self::get_instance()?;
+        Ok(HourInfo::new(
             dist.am_pm[hour as usize].clone(),
             dist.shifts[hour as usize].clone(),
             dist.sub_shifts[hour as usize].clone(),
             dist.meals[hour as usize].clone(),
-        )
+        ))
     }
 
     /// Pick a random hour using weighted distribution (HoursDistribution.pickRandomHour)
@@ -170,7 +173,7 @@ impl HoursDistribution {
         weights: HoursWeights,
         stream: &mut dyn RandomNumberStream,
     ) -> Result<i32> {
-        let dist = Self::get_instance();
+        let dist = Self::get_instance()?;
         let weights_list = &dist.weights_lists[weights as usize];
 
         let value_ref = pick_random_value(&dist.hours, weights_list, stream)?;
@@ -179,12 +182,13 @@ impl HoursDistribution {
 }
 
 #[cfg(test)]
+#[allow(clippy::manual_range_contains)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_hours_distribution_loading() {
-        let dist = HoursDistribution::get_instance();
+        let dist = HoursDistribution::get_instance().unwrap();
         assert_eq!(dist.hours.len(), 24); // Should have 24 hours
         assert_eq!(dist.am_pm.len(), 24);
         assert_eq!(dist.shifts.len(), 24);
@@ -192,10 +196,10 @@ mod tests {
 
     #[test]
     fn test_get_hour_info() {
-        let hour_info = HoursDistribution::get_hour_info_for_hour(0);
+        let hour_info = HoursDistribution::get_hour_info_for_hour(0).unwrap();
         assert_eq!(hour_info.get_am_pm(), "AM");
 
-        let hour_info_12 = HoursDistribution::get_hour_info_for_hour(12);
+        let hour_info_12 = HoursDistribution::get_hour_info_for_hour(12).unwrap();
         // Hour 12 should be PM
         assert!(hour_info_12.get_am_pm() == "AM" || hour_info_12.get_am_pm() == "PM");
     }
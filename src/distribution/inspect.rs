@@ -0,0 +1,396 @@
+use crate::distribution::dst_parser::DistributionDefinition;
+use crate::distribution::file_loader::DistributionFileLoader;
+use crate::distribution::int_values::IntValuesDistribution;
+use crate::distribution::string_values_distribution::StringValuesDistribution;
+use crate::distribution::utils::Distribution;
+use crate::error::{Result, TpcdsError};
+use crate::random::{RandomNumberStream, RandomNumberStreamImpl};
+use std::io::Write;
+
+/// Values of an inspected distribution, read the way the generators read them: as integers
+/// when every value field is an integer, as strings otherwise
+enum InspectedValues {
+    Int(IntValuesDistribution),
+    Text(StringValuesDistribution),
+}
+
+/// A distribution file as `tpcdsgen dist` shows it, with the names of its weight sets if the
+/// file declares them
+pub struct InspectedDistribution {
+    values: InspectedValues,
+    weight_names: Vec<String>,
+}
+
+impl InspectedDistribution {
+    /// Check whether the values are read as integers
+    pub fn is_int_valued(&self) -> bool {
+        matches!(self.values, InspectedValues::Int(_))
+    }
+
+    pub fn get_size(&self) -> usize {
+        match &self.values {
+            InspectedValues::Int(distribution) => distribution.get_size(),
+            InspectedValues::Text(distribution) => distribution.get_size(),
+        }
+    }
+
+    pub fn get_value_lists_count(&self) -> usize {
+        match &self.values {
+            InspectedValues::Int(distribution) => distribution.get_value_lists_count(),
+            InspectedValues::Text(distribution) => distribution.get_value_lists_count(),
+        }
+    }
+
+    pub fn get_weight_lists_count(&self) -> usize {
+        match &self.values {
+            InspectedValues::Int(distribution) => distribution.get_weight_lists_count(),
+            InspectedValues::Text(distribution) => distribution.get_weight_lists_count(),
+        }
+    }
+
+    /// Get the value of an entry, formatted for display
+    pub fn get_value_at_index(&self, value_list: usize, index: usize) -> Result<String> {
+        match &self.values {
+            InspectedValues::Int(distribution) => distribution
+                .get_value_at_index(value_list, index)
+                .map(|value| value.to_string()),
+            InspectedValues::Text(distribution) => distribution
+                .get_value_at_index(value_list, index)
+                .map(str::to_string),
+        }
+    }
+
+    pub fn get_weight_for_index(&self, index: usize, weight_list: usize) -> Result<i32> {
+        match &self.values {
+            InspectedValues::Int(distribution) => {
+                distribution.get_weight_for_index(index, weight_list)
+            }
+            InspectedValues::Text(distribution) => {
+                distribution.get_weight_for_index(index, weight_list)
+            }
+        }
+    }
+
+    pub fn pick_random_index(
+        &self,
+        weight_list: usize,
+        stream: &mut dyn RandomNumberStream,
+    ) -> Result<usize> {
+        match &self.values {
+            InspectedValues::Int(distribution) => {
+                distribution.pick_random_index(weight_list, stream)
+            }
+            InspectedValues::Text(distribution) => {
+                distribution.pick_random_index(weight_list, stream)
+            }
+        }
+    }
+
+    /// Get the name of a 0-based weight set, or its 1-based number if the file names none
+    pub fn get_weight_set_name(&self, weight_list: usize) -> String {
+        self.weight_names
+            .get(weight_list)
+            .cloned()
+            .unwrap_or_else(|| (weight_list + 1).to_string())
+    }
+
+    /// Find the 0-based weight set given by its 1-based number or by its name
+    pub fn find_weight_set(&self, weight_set: &str) -> Result<usize> {
+        let count = self.get_weight_lists_count();
+        if let Ok(number) = weight_set.parse::<usize>() {
+            if number < 1 || number > count {
+                return Err(TpcdsError::new(&format!(
+                    "Weight set must be between 1 and {}",
+                    count
+                )));
+            }
+            return Ok(number - 1);
+        }
+        self.weight_names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(weight_set))
+            .ok_or_else(|| {
+                let names = if self.weight_names.is_empty() {
+                    "the distribution does not name its weight sets".to_string()
+                } else {
+                    format!("the weight sets are {}", self.weight_names.join(", "))
+                };
+                TpcdsError::new(&format!("Unknown weight set {}: {}", weight_set, names))
+            })
+    }
+}
+
+/// Prints the contents of distribution files and samples drawn from them (`tpcdsgen dist`)
+pub struct DistributionInspector;
+
+impl DistributionInspector {
+    /// Seed used by `dist sample` unless another one is chosen
+    pub const DEFAULT_SEED: i32 = RandomNumberStreamImpl::DEFAULT_SEED_BASE;
+
+    /// Load a distribution by name, with or without the `.dst` extension. The number of value
+    /// and weight fields is taken from the first line.
+    pub fn load(name: &str) -> Result<StringValuesDistribution> {
+        let file_name = Self::get_file_name(name);
+        let lines = DistributionFileLoader::load_distribution_file(&file_name)?;
+        let (num_value_fields, num_weight_fields) = lines
+            .first()
+            .map(|(values, weights)| (values.len(), weights.len()))
+            .ok_or_else(|| TpcdsError::new(&format!("Distribution {} is empty", name)))?;
+        StringValuesDistribution::from_distribution_lines(
+            lines,
+            num_value_fields,
+            num_weight_fields,
+        )
+    }
+
+    /// Load a distribution by name for inspection: integer-valued distributions, such as
+    /// `dep_count` or `item_manager_id`, are built as an `IntValuesDistribution`
+    pub fn open(name: &str) -> Result<InspectedDistribution> {
+        let definition =
+            DistributionFileLoader::load_distribution_definition(&Self::get_file_name(name))?;
+        Self::from_definition(name, definition)
+    }
+
+    fn from_definition(
+        name: &str,
+        definition: DistributionDefinition,
+    ) -> Result<InspectedDistribution> {
+        if definition.lines.is_empty() {
+            return Err(TpcdsError::new(&format!("Distribution {} is empty", name)));
+        }
+
+        let is_int_valued = definition
+            .types
+            .iter()
+            .all(|value_type| value_type == "int" || value_type == "integer");
+        let num_value_fields = definition.types.len();
+        let values = if is_int_valued {
+            InspectedValues::Int(IntValuesDistribution::from_distribution_lines(
+                definition.lines,
+                num_value_fields,
+                definition.weight_sets,
+            )?)
+        } else {
+            InspectedValues::Text(StringValuesDistribution::from_distribution_lines(
+                definition.lines,
+                num_value_fields,
+                definition.weight_sets,
+            )?)
+        };
+        Ok(InspectedDistribution {
+            values,
+            weight_names: definition.weight_names,
+        })
+    }
+
+    fn get_file_name(name: &str) -> String {
+        if name.ends_with(".dst") {
+            name.to_string()
+        } else {
+            format!("{}.dst", name)
+        }
+    }
+
+    /// Write one line per distribution: name, value type, entries, value fields and weight
+    /// fields
+    pub fn write_list(out: &mut dyn Write) -> Result<()> {
+        writeln!(out, "name\ttype\tentries\tvalues\tweights")?;
+        for file_name in DistributionFileLoader::get_embedded_file_names() {
+            let distribution = Self::open(file_name)?;
+            writeln!(
+                out,
+                "{}\t{}\t{}\t{}\t{}",
+                file_name.trim_end_matches(".dst"),
+                if distribution.is_int_valued() {
+                    "int"
+                } else {
+                    "string"
+                },
+                distribution.get_size(),
+                distribution.get_value_lists_count(),
+                distribution.get_weight_lists_count()
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Write every entry of a distribution with its values, and for each weight set the
+    /// weight and the cumulative weight used by `pick_random_value`. Weight sets are headed by
+    /// their name if the file names them.
+    pub fn write_show(out: &mut dyn Write, name: &str) -> Result<()> {
+        let distribution = Self::open(name)?;
+
+        let mut header = vec!["index".to_string()];
+        header.extend((1..=distribution.get_value_lists_count()).map(|i| format!("value_{}", i)));
+        for weight_list in 0..distribution.get_weight_lists_count() {
+            let set_name = distribution.get_weight_set_name(weight_list);
+            header.push(format!("weight_{}", set_name));
+            header.push(format!("cumulative_{}", set_name));
+        }
+        writeln!(out, "{}", header.join("\t"))?;
+
+        let mut cumulative = vec![0; distribution.get_weight_lists_count()];
+        for index in 0..distribution.get_size() {
+            let mut fields = vec![index.to_string()];
+            for value_list in 0..distribution.get_value_lists_count() {
+                fields.push(distribution.get_value_at_index(value_list, index)?);
+            }
+            for (weight_list, total) in cumulative.iter_mut().enumerate() {
+                let weight = distribution.get_weight_for_index(index, weight_list)?;
+                *total += weight;
+                fields.push(weight.to_string());
+                fields.push(total.to_string());
+            }
+            writeln!(out, "{}", fields.join("\t"))?;
+        }
+        Ok(())
+    }
+
+    /// Draw `count` entries using `weight_set`, given by its 1-based number or its name, and
+    /// write how often each entry was drawn next to the share its weight predicts. Entries are
+    /// drawn with `pick_random_index`, which picks the same entries as `pick_random_value` but
+    /// keeps entries with equal values apart.
+    pub fn write_sample(
+        out: &mut dyn Write,
+        name: &str,
+        weight_set: &str,
+        count: usize,
+        seed: i32,
+    ) -> Result<()> {
+        let distribution = Self::open(name)?;
+        let weight_list = distribution.find_weight_set(weight_set)?;
+
+        let mut stream = RandomNumberStreamImpl::new_with_base(0, seed, 0)?;
+        let mut draws = vec![0usize; distribution.get_size()];
+        for _ in 0..count {
+            draws[distribution.pick_random_index(weight_list, &mut stream)?] += 1;
+        }
+
+        let total_weight: i32 = (0..distribution.get_size())
+            .map(|index| distribution.get_weight_for_index(index, weight_list))
+            .sum::<Result<i32>>()?;
+        writeln!(out, "index\tvalue\tdrawn\tobserved\texpected")?;
+        for (index, &drawn) in draws.iter().enumerate() {
+            let weight = distribution.get_weight_for_index(index, weight_list)?;
+            writeln!(
+                out,
+                "{}\t{}\t{}\t{:.4}\t{:.4}",
+                index,
+                distribution.get_value_at_index(0, index)?,
+                drawn,
+                drawn as f64 / count.max(1) as f64,
+                weight as f64 / total_weight.max(1) as f64
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn output(write: impl FnOnce(&mut dyn Write) -> Result<()>) -> String {
+        let mut out = Vec::new();
+        write(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_list_and_show() {
+        let list = output(DistributionInspector::write_list);
+        assert!(list.contains("\ncall_centers\t"));
+        assert_eq!(
+            list.lines().count(),
+            DistributionFileLoader::get_embedded_file_names().count() + 1
+        );
+
+        let show = output(|out| DistributionInspector::write_show(out, "call_centers.dst"));
+        let lines: Vec<&str> = show.lines().collect();
+        assert_eq!(
+            lines[0],
+            "index\tvalue_1\tweight_1\tcumulative_1\tweight_2\tcumulative_2"
+        );
+        assert_eq!(lines[1], "0\tNew England\t1\t1\t8\t8");
+        assert_eq!(lines[2], "1\tNY Metro\t1\t2\t16\t24");
+
+        let mut out = Vec::new();
+        assert!(DistributionInspector::write_show(&mut out, "missing").is_err());
+    }
+
+    #[test]
+    fn test_sample_is_reproducible() {
+        let sample = |seed| {
+            output(|out| DistributionInspector::write_sample(out, "call_centers", "1", 1000, seed))
+        };
+        let first = sample(DistributionInspector::DEFAULT_SEED);
+        assert_eq!(first, sample(DistributionInspector::DEFAULT_SEED));
+        assert_ne!(first, sample(1));
+
+        let drawn: usize = first
+            .lines()
+            .skip(1)
+            .map(|line| line.split('\t').nth(2).unwrap().parse::<usize>().unwrap())
+            .sum();
+        assert_eq!(drawn, 1000);
+
+        let mut out = Vec::new();
+        assert!(DistributionInspector::write_sample(&mut out, "call_centers", "3", 10, 1).is_err());
+    }
+
+    #[test]
+    fn test_int_valued_distributions() {
+        for name in ["dep_count", "item_manager_id", "vehicle_count"] {
+            assert!(DistributionInspector::open(name).unwrap().is_int_valued());
+        }
+        assert!(!DistributionInspector::open("call_centers")
+            .unwrap()
+            .is_int_valued());
+
+        let list = output(DistributionInspector::write_list);
+        assert!(list.contains("\ndep_count\tint\t"));
+        assert!(list.contains("\ncall_centers\tstring\t"));
+
+        let show = output(|out| DistributionInspector::write_show(out, "dep_count"));
+        assert_eq!(show.lines().nth(1), Some("0\t0\t25\t25"));
+
+        let sample =
+            output(|out| DistributionInspector::write_sample(out, "item_manager_id", "2", 100, 1));
+        assert_eq!(
+            sample.lines().count(),
+            1 + DistributionInspector::open("item_manager_id")
+                .unwrap()
+                .get_size()
+        );
+    }
+
+    #[test]
+    fn test_weight_sets_by_name() {
+        let temp = tempfile::tempdir().unwrap();
+        let file_path = temp.path().join("colors.dst");
+        std::fs::write(
+            &file_path,
+            "create colors;\n\
+             set types = (varchar);\n\
+             set weights = 2;\n\
+             set names = (name: uniform, skewed);\n\
+             add (\"almond\": 1, 5);\n\
+             add (\"beige\": 1, 0);\n",
+        )
+        .unwrap();
+        let definition =
+            DistributionFileLoader::load_distribution_definition_from_path(&file_path).unwrap();
+        let distribution = DistributionInspector::from_definition("colors", definition).unwrap();
+
+        assert_eq!(distribution.find_weight_set("skewed").unwrap(), 1);
+        assert_eq!(distribution.find_weight_set("Uniform").unwrap(), 0);
+        assert_eq!(distribution.find_weight_set("2").unwrap(), 1);
+        assert!(distribution.find_weight_set("3").is_err());
+        assert!(distribution.find_weight_set("sales").is_err());
+        assert_eq!(distribution.get_weight_set_name(1), "skewed");
+
+        let unnamed = DistributionInspector::open("call_centers").unwrap();
+        assert_eq!(unnamed.get_weight_set_name(1), "2");
+        assert!(unnamed.find_weight_set("uniform").is_err());
+    }
+}
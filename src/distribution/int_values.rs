@@ -1,3 +1,5 @@
+use crate::distribution::dst_parser::DistributionLine;
+use crate::distribution::utils::{get_weight_for_index, pick_random_index};
 use crate::distribution::{
     Distribution, DistributionFileLoader, DistributionUtils, WeightsBuilder,
 };
@@ -109,7 +111,16 @@ impl IntValuesDistribution {
         num_weight_fields: usize,
     ) -> Result<Self> {
         let parsed_lines = DistributionFileLoader::load_distribution_file(filename)?;
+        Self::from_distribution_lines(parsed_lines, num_value_fields, num_weight_fields)
+    }
 
+    /// Build an IntValuesDistribution from parsed distribution lines, validating that every
+    /// line has the expected number of values and weights and that the values are integers
+    pub fn from_distribution_lines(
+        parsed_lines: Vec<DistributionLine>,
+        num_value_fields: usize,
+        num_weight_fields: usize,
+    ) -> Result<Self> {
         let mut values_builders: Vec<Vec<i32>> = vec![Vec::new(); num_value_fields];
         let mut weights_builders: Vec<WeightsBuilder> =
             vec![WeightsBuilder::new(); num_weight_fields];
@@ -165,6 +176,35 @@ impl IntValuesDistribution {
         })
     }
 
+    /// Pick a random index from the specified weight list
+    pub fn pick_random_index(
+        &self,
+        weight_list_index: usize,
+        stream: &mut dyn RandomNumberStream,
+    ) -> Result<usize> {
+        let weights = self.get_weights_list(weight_list_index)?;
+        pick_random_index(weights, stream)
+    }
+
+    /// Get the weight for a specific index
+    pub fn get_weight_for_index(&self, index: usize, weight_list_index: usize) -> Result<i32> {
+        let weights = self.get_weights_list(weight_list_index)?;
+        get_weight_for_index(index, weights)
+    }
+
+    fn get_weights_list(&self, weight_list_index: usize) -> Result<&[i32]> {
+        self.weights_lists
+            .get(weight_list_index)
+            .map(Vec::as_slice)
+            .ok_or_else(|| {
+                TpcdsError::new(&format!(
+                    "Weight list index {} out of range, max is {}",
+                    weight_list_index,
+                    self.weights_lists.len() as i64 - 1
+                ))
+            })
+    }
+
     /// Get number of value lists
     pub fn get_value_lists_count(&self) -> usize {
         self.values_lists.len()
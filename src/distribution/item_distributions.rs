@@ -0,0 +1,78 @@
+use crate::distribution::utils::get_or_load;
+use crate::distribution::FileBasedStringValuesDistribution;
+use crate::error::Result;
+use crate::random::RandomNumberStream;
+use std::sync::OnceLock;
+
+/// Item attribute distributions (colors, sizes and units)
+pub struct ItemDistributions;
+
+impl ItemDistributions {
+    fn get_colors_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
+        static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
+        get_or_load(&DISTRIBUTION, "colors.dst", || {
+            FileBasedStringValuesDistribution::build_string_values_distribution(
+                "colors.dst",
+                1, // 1 value field: color
+                5, // 5 weight fields: uniform, skewed, low, medium and high likelihood
+            )
+        })
+    }
+
+    fn get_sizes_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
+        static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
+        get_or_load(&DISTRIBUTION, "sizes.dst", || {
+            FileBasedStringValuesDistribution::build_string_values_distribution(
+                "sizes.dst",
+                1, // 1 value field: size
+                3, // 3 weight fields: uniform, non-sized categories, sized categories
+            )
+        })
+    }
+
+    fn get_units_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
+        static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
+        get_or_load(&DISTRIBUTION, "units.dst", || {
+            FileBasedStringValuesDistribution::build_string_values_distribution(
+                "units.dst",
+                1, // 1 value field: unit name
+                1, // 1 weight field: uniform
+            )
+        })
+    }
+
+    /// Pick a random color with the skewed weights dbgen uses for items
+    pub fn pick_random_color(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
+        Self::get_colors_distribution()?.pick_random_value(0, 1, stream)
+    }
+
+    /// Pick a random size with the weights of sized categories
+    pub fn pick_random_size(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
+        Self::get_sizes_distribution()?.pick_random_value(0, 2, stream)
+    }
+
+    /// Pick a random unit
+    pub fn pick_random_units(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
+        Self::get_units_distribution()?.pick_random_value(0, 0, stream)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random::RandomNumberStreamImpl;
+
+    #[test]
+    fn test_pick_item_attributes() {
+        let mut stream = RandomNumberStreamImpl::new(1).unwrap();
+        assert!(!ItemDistributions::pick_random_color(&mut stream)
+            .unwrap()
+            .is_empty());
+        assert!(!ItemDistributions::pick_random_size(&mut stream)
+            .unwrap()
+            .is_empty());
+        assert!(!ItemDistributions::pick_random_units(&mut stream)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -18,6 +18,7 @@
 //! with weighted random selection.
 
 use crate::distribution::file_loader::DistributionFileLoader;
+use crate::distribution::utils::get_or_load;
 use crate::distribution::utils::{pick_random_value, WeightsBuilder};
 use crate::error::Result;
 use crate::random::RandomNumberStream;
@@ -47,12 +48,13 @@ impl LocationTypesDistribution {
     const NUM_WEIGHT_FIELDS: usize = 2;
     const VALUES_AND_WEIGHTS_FILENAME: &'static str = "location_types.dst";
 
-    fn get_instance() -> &'static LocationTypesDistribution {
+    fn get_instance() -> Result<&'static LocationTypesDistribution> {
         static DISTRIBUTION: OnceLock<LocationTypesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
-            Self::build_location_types_distribution()
-                .expect("Failed to load location types distribution")
-        })
+        get_or_load(
+            &DISTRIBUTION,
+            Self::VALUES_AND_WEIGHTS_FILENAME,
+            Self::build_location_types_distribution,
+        )
     }
 
     fn build_location_types_distribution() -> Result<Self> {
@@ -126,7 +128,7 @@ impl LocationTypesDistribution {
         weights: LocationTypeWeights,
         stream: &mut dyn RandomNumberStream,
     ) -> Result<String> {
-        let dist = Self::get_instance();
+        let dist = Self::get_instance()?;
 
         let weights_list = match weights {
             LocationTypeWeights::Uniform => &dist.weights_list1,
@@ -145,7 +147,7 @@ mod tests {
 
     #[test]
     fn test_location_types_distribution_loading() {
-        let dist = LocationTypesDistribution::get_instance();
+        let dist = LocationTypesDistribution::get_instance().unwrap();
 
         // Should have 3 location types: single family, condo, apartment
         assert_eq!(dist.values.len(), 3, "Should have 3 location types");
@@ -194,7 +196,7 @@ mod tests {
 
     #[test]
     fn test_location_type_values() {
-        let dist = LocationTypesDistribution::get_instance();
+        let dist = LocationTypesDistribution::get_instance().unwrap();
 
         // Verify expected location types are present
         let types_set: std::collections::HashSet<&String> = dist.values.iter().collect();
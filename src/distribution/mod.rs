@@ -3,12 +3,15 @@ pub mod calendar_distribution;
 pub mod call_center_distributions;
 pub mod catalog_page_distributions;
 pub mod demographics_distributions;
+pub mod dst_parser;
 pub mod english;
 pub mod english_distributions;
 pub mod file_loader;
 pub mod fips_county_distribution;
 pub mod hours_distribution;
+pub mod inspect;
 pub mod int_values;
+pub mod item_distributions;
 pub mod location_types_distribution;
 pub mod names_distributions;
 pub mod return_reasons_distribution;
@@ -29,7 +32,9 @@ pub use english_distributions::*;
 pub use file_loader::DistributionFileLoader;
 pub use fips_county_distribution::{FipsCountyDistribution, FipsWeights};
 pub use hours_distribution::{HourInfo, HoursDistribution};
+pub use inspect::DistributionInspector;
 pub use int_values::IntValuesDistribution;
+pub use item_distributions::ItemDistributions;
 pub use location_types_distribution::{LocationTypeWeights, LocationTypesDistribution};
 pub use names_distributions::{FirstNamesWeights, NamesDistributions, SalutationsWeights};
 pub use return_reasons_distribution::ReturnReasonsDistribution;
@@ -1,4 +1,5 @@
 use crate::distribution::string_values_distribution::StringValuesDistribution;
+use crate::distribution::utils::get_or_load;
 use crate::error::Result;
 use crate::random::RandomNumberStream;
 use std::sync::OnceLock;
@@ -27,39 +28,34 @@ static LAST_NAMES_DISTRIBUTION: OnceLock<StringValuesDistribution> = OnceLock::n
 static SALUTATIONS_DISTRIBUTION: OnceLock<StringValuesDistribution> = OnceLock::new();
 
 impl NamesDistributions {
-    /// Initialize all distributions (lazy loading)
-    fn ensure_initialized() -> Result<()> {
-        // Initialize first names distribution
-        if FIRST_NAMES_DISTRIBUTION.get().is_none() {
-            let dist = StringValuesDistribution::build_string_values_distribution(
+    fn get_first_names_distribution() -> Result<&'static StringValuesDistribution> {
+        get_or_load(&FIRST_NAMES_DISTRIBUTION, "first_names.dst", || {
+            StringValuesDistribution::build_string_values_distribution(
                 "first_names.dst",
                 1, // 1 value field: name
                 3, // 3 weight fields: male freq, female freq, general freq
-            )?;
-            let _ = FIRST_NAMES_DISTRIBUTION.set(dist);
-        }
+            )
+        })
+    }
 
-        // Initialize last names distribution
-        if LAST_NAMES_DISTRIBUTION.get().is_none() {
-            let dist = StringValuesDistribution::build_string_values_distribution(
+    fn get_last_names_distribution() -> Result<&'static StringValuesDistribution> {
+        get_or_load(&LAST_NAMES_DISTRIBUTION, "last_names.dst", || {
+            StringValuesDistribution::build_string_values_distribution(
                 "last_names.dst",
                 1, // 1 value field: name
                 1, // 1 weight field: frequency
-            )?;
-            let _ = LAST_NAMES_DISTRIBUTION.set(dist);
-        }
+            )
+        })
+    }
 
-        // Initialize salutations distribution
-        if SALUTATIONS_DISTRIBUTION.get().is_none() {
-            let dist = StringValuesDistribution::build_string_values_distribution(
+    fn get_salutations_distribution() -> Result<&'static StringValuesDistribution> {
+        get_or_load(&SALUTATIONS_DISTRIBUTION, "salutations.dst", || {
+            StringValuesDistribution::build_string_values_distribution(
                 "salutations.dst",
                 1, // 1 value field: salutation
                 3, // 3 weight fields: gender neutral, male, female
-            )?;
-            let _ = SALUTATIONS_DISTRIBUTION.set(dist);
-        }
-
-        Ok(())
+            )
+        })
     }
 
     /// Pick a random first name using the specified weight category
@@ -67,8 +63,7 @@ impl NamesDistributions {
         weights: FirstNamesWeights,
         stream: &mut dyn RandomNumberStream,
     ) -> Result<&'static str> {
-        Self::ensure_initialized()?;
-        let dist = FIRST_NAMES_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_first_names_distribution()?;
         dist.pick_random_value(0, weights as usize, stream)
     }
 
@@ -77,29 +72,25 @@ impl NamesDistributions {
         weights: FirstNamesWeights,
         stream: &mut dyn RandomNumberStream,
     ) -> Result<usize> {
-        Self::ensure_initialized()?;
-        let dist = FIRST_NAMES_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_first_names_distribution()?;
         dist.pick_random_index(weights as usize, stream)
     }
 
     /// Get first name from specific index
     pub fn get_first_name_from_index(index: usize) -> Result<&'static str> {
-        Self::ensure_initialized()?;
-        let dist = FIRST_NAMES_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_first_names_distribution()?;
         dist.get_value_at_index(0, index)
     }
 
     /// Get weight for specific index and weight category
     pub fn get_weight_for_index(index: usize, weights: FirstNamesWeights) -> Result<i32> {
-        Self::ensure_initialized()?;
-        let dist = FIRST_NAMES_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_first_names_distribution()?;
         dist.get_weight_for_index(index, weights as usize)
     }
 
     /// Pick a random last name
     pub fn pick_random_last_name(stream: &mut dyn RandomNumberStream) -> Result<&'static str> {
-        Self::ensure_initialized()?;
-        let dist = LAST_NAMES_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_last_names_distribution()?;
         dist.pick_random_value(0, 0, stream)
     }
 
@@ -108,8 +99,7 @@ impl NamesDistributions {
         weights: SalutationsWeights,
         stream: &mut dyn RandomNumberStream,
     ) -> Result<&'static str> {
-        Self::ensure_initialized()?;
-        let dist = SALUTATIONS_DISTRIBUTION.get().unwrap();
+        let dist = Self::get_salutations_distribution()?;
         dist.pick_random_value(0, weights as usize, stream)
     }
 }
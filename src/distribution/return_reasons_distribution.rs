@@ -1,3 +1,4 @@
+use crate::distribution::utils::get_or_load;
 use crate::distribution::FileBasedStringValuesDistribution;
 use crate::error::Result;
 use std::sync::OnceLock;
@@ -7,26 +8,25 @@ pub struct ReturnReasonsDistribution;
 
 impl ReturnReasonsDistribution {
     /// Lazy-loaded distribution instance
-    fn get_distribution() -> &'static FileBasedStringValuesDistribution {
+    fn get_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
         static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "return_reasons.dst", || {
             FileBasedStringValuesDistribution::build_string_values_distribution(
                 "return_reasons.dst",
                 1,
                 6,
             )
-            .expect("Failed to load return_reasons.dst")
         })
     }
 
     /// Get return reason at the specified index (getValueAtIndex)
     pub fn get_return_reason_at_index(index: usize) -> Result<&'static str> {
-        Self::get_distribution().get_value_at_index(0, index)
+        Self::get_distribution()?.get_value_at_index(0, index)
     }
 
     /// Get the size of the return reasons distribution
-    pub fn get_size() -> usize {
-        Self::get_distribution().get_size()
+    pub fn get_size() -> Result<usize> {
+        Ok(Self::get_distribution()?.get_size())
     }
 }
 
@@ -37,7 +37,7 @@ mod tests {
     #[test]
     fn test_return_reasons_distribution() {
         // Test that we can load the distribution
-        let size = ReturnReasonsDistribution::get_size();
+        let size = ReturnReasonsDistribution::get_size().unwrap();
         assert!(
             size > 0,
             "Return reasons distribution should have at least one entry"
@@ -57,7 +57,7 @@ mod tests {
 
     #[test]
     fn test_return_reasons_out_of_bounds() {
-        let size = ReturnReasonsDistribution::get_size();
+        let size = ReturnReasonsDistribution::get_size().unwrap();
         let result = ReturnReasonsDistribution::get_return_reason_at_index(size + 100);
         assert!(result.is_err(), "Should fail for out of bounds index");
     }
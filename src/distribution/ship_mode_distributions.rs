@@ -1,3 +1,4 @@
+use crate::distribution::utils::get_or_load;
 use crate::distribution::FileBasedStringValuesDistribution;
 use crate::error::Result;
 use std::sync::OnceLock;
@@ -6,55 +7,52 @@ use std::sync::OnceLock;
 pub struct ShipModeDistributions;
 
 impl ShipModeDistributions {
-    fn get_ship_mode_carrier_distribution() -> &'static FileBasedStringValuesDistribution {
+    fn get_ship_mode_carrier_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
         static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "ship_mode_carrier.dst", || {
             FileBasedStringValuesDistribution::build_string_values_distribution(
                 "ship_mode_carrier.dst",
                 1,
                 1,
             )
-            .expect("Failed to load ship_mode_carrier.dst")
         })
     }
 
-    fn get_ship_mode_code_distribution() -> &'static FileBasedStringValuesDistribution {
+    fn get_ship_mode_code_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
         static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "ship_mode_code.dst", || {
             FileBasedStringValuesDistribution::build_string_values_distribution(
                 "ship_mode_code.dst",
                 1,
                 1,
             )
-            .expect("Failed to load ship_mode_code.dst")
         })
     }
 
-    fn get_ship_mode_type_distribution() -> &'static FileBasedStringValuesDistribution {
+    fn get_ship_mode_type_distribution() -> Result<&'static FileBasedStringValuesDistribution> {
         static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
+        get_or_load(&DISTRIBUTION, "ship_mode_type.dst", || {
             FileBasedStringValuesDistribution::build_string_values_distribution(
                 "ship_mode_type.dst",
                 1,
                 1,
             )
-            .expect("Failed to load ship_mode_type.dst")
         })
     }
 
     pub fn get_ship_mode_carrier_at_index(index: usize) -> Result<&'static str> {
-        Self::get_ship_mode_carrier_distribution().get_value_at_index(0, index)
+        Self::get_ship_mode_carrier_distribution()?.get_value_at_index(0, index)
     }
 
     pub fn get_ship_mode_code_for_index_mod_size(index: i64) -> Result<&'static str> {
-        Self::get_ship_mode_code_distribution().get_value_for_index_mod_size(index, 0)
+        Self::get_ship_mode_code_distribution()?.get_value_for_index_mod_size(index, 0)
     }
 
     pub fn get_ship_mode_type_for_index_mod_size(index: i64) -> Result<&'static str> {
-        Self::get_ship_mode_type_distribution().get_value_for_index_mod_size(index, 0)
+        Self::get_ship_mode_type_distribution()?.get_value_for_index_mod_size(index, 0)
     }
 
-    pub fn get_ship_mode_type_size() -> usize {
-        Self::get_ship_mode_type_distribution().get_size()
+    pub fn get_ship_mode_type_size() -> Result<usize> {
+        Ok(Self::get_ship_mode_type_distribution()?.get_size())
     }
 }
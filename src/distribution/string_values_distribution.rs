@@ -1,3 +1,4 @@
+use crate::distribution::dst_parser::DistributionLine;
 use crate::distribution::file_loader::DistributionFileLoader;
 use crate::distribution::utils::{
     get_value_for_index_mod_size, get_weight_for_index, pick_random_index, pick_random_value,
@@ -27,7 +28,16 @@ impl StringValuesDistribution {
         num_weight_fields: usize,
     ) -> Result<Self> {
         let parsed_lines = DistributionFileLoader::load_distribution_file(filename)?;
+        Self::from_distribution_lines(parsed_lines, num_value_fields, num_weight_fields)
+    }
 
+    /// Build a StringValuesDistribution from parsed distribution lines, validating that every
+    /// line has the expected number of values and weights
+    pub fn from_distribution_lines(
+        parsed_lines: Vec<DistributionLine>,
+        num_value_fields: usize,
+        num_weight_fields: usize,
+    ) -> Result<Self> {
         let mut values_builders: Vec<Vec<String>> = vec![Vec::new(); num_value_fields];
         let mut weights_builders: Vec<WeightsBuilder> =
             vec![WeightsBuilder::new(); num_weight_fields];
@@ -157,6 +167,16 @@ impl StringValuesDistribution {
         get_weight_for_index(index, &self.weights_lists[weight_list_index])
     }
 
+    /// Get number of value lists
+    pub fn get_value_lists_count(&self) -> usize {
+        self.values_lists.len()
+    }
+
+    /// Get number of weight lists
+    pub fn get_weight_lists_count(&self) -> usize {
+        self.weights_lists.len()
+    }
+
     /// Get the size of the distribution (number of entries)
     pub fn get_size(&self) -> usize {
         if self.values_lists.is_empty() {
@@ -18,6 +18,7 @@
 //! with uniform weighted random selection.
 
 use crate::distribution::file_loader::DistributionFileLoader;
+use crate::distribution::utils::get_or_load;
 use crate::distribution::utils::{pick_random_value, WeightsBuilder};
 use crate::error::Result;
 use crate::random::RandomNumberStream;
@@ -39,11 +40,13 @@ impl TopDomainsDistribution {
     const NUM_WEIGHT_FIELDS: usize = 1;
     const VALUES_AND_WEIGHTS_FILENAME: &'static str = "top_domains.dst";
 
-    fn get_instance() -> &'static TopDomainsDistribution {
+    fn get_instance() -> Result<&'static TopDomainsDistribution> {
         static DISTRIBUTION: OnceLock<TopDomainsDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
-            Self::build_top_domains_distribution().expect("Failed to load top domains distribution")
-        })
+        get_or_load(
+            &DISTRIBUTION,
+            Self::VALUES_AND_WEIGHTS_FILENAME,
+            Self::build_top_domains_distribution,
+        )
     }
 
     fn build_top_domains_distribution() -> Result<Self> {
@@ -103,7 +106,7 @@ impl TopDomainsDistribution {
     ///
     /// A domain suffix string (e.g., "com", "org", "edu")
     pub fn pick_random_top_domain(stream: &mut dyn RandomNumberStream) -> Result<String> {
-        let dist = Self::get_instance();
+        let dist = Self::get_instance()?;
         let value_ref = pick_random_value(&dist.values, &dist.weights_list, stream)?;
         Ok(value_ref.clone())
     }
@@ -116,7 +119,7 @@ mod tests {
 
     #[test]
     fn test_top_domains_distribution_loading() {
-        let dist = TopDomainsDistribution::get_instance();
+        let dist = TopDomainsDistribution::get_instance().unwrap();
 
         // Should have at least 3 domain types: com, org, edu
         assert!(dist.values.len() >= 3, "Should have at least 3 top domains");
@@ -153,7 +156,7 @@ mod tests {
 
     #[test]
     fn test_domain_values() {
-        let dist = TopDomainsDistribution::get_instance();
+        let dist = TopDomainsDistribution::get_instance().unwrap();
 
         // Verify expected domains are present
         let domains_set: std::collections::HashSet<&String> = dist.values.iter().collect();
@@ -1,5 +1,6 @@
 use crate::random::RandomNumberStream;
 use crate::{check_argument, error::Result, TpcdsError};
+use std::sync::OnceLock;
 
 /// Core trait for weighted distributions
 pub trait Distribution<T> {
@@ -96,6 +97,23 @@ fn get_value_for_weight<'a, T>(weight: i32, values: &'a [T], weights: &[i32]) ->
     Err(TpcdsError::new("Random weight was greater than max weight"))
 }
 
+/// Get the distribution held by `cell`, loading it with `load` on first use.
+/// A load failure is reported as an error in `filename`, and the next call tries again.
+pub fn get_or_load<T>(
+    cell: &'static OnceLock<T>,
+    filename: &str,
+    load: impl FnOnce() -> Result<T>,
+) -> Result<&'static T> {
+    if let Some(distribution) = cell.get() {
+        return Ok(distribution);
+    }
+    let distribution = load().map_err(|e| match e {
+        TpcdsError::Distribution { .. } | TpcdsError::Io { .. } => e,
+        e => TpcdsError::distribution(filename, None, &e.to_string()),
+    })?;
+    Ok(cell.get_or_init(|| distribution))
+}
+
 /// Get value for index modulo size (DistributionUtils.getValueForIndexModSize)
 pub fn get_value_for_index_mod_size<T>(index: i64, values: &[T]) -> &T {
     let size = values.len() as i64;
@@ -346,4 +364,32 @@ mod tests {
 
         assert_eq!(index1, index2); // Should be deterministic
     }
+
+    #[test]
+    fn test_get_or_load_reports_malformed_distribution() {
+        use crate::distribution::FileBasedStringValuesDistribution;
+
+        static DISTRIBUTION: OnceLock<FileBasedStringValuesDistribution> = OnceLock::new();
+        let lines = || {
+            vec![(
+                vec!["M".to_string()],
+                vec!["1".to_string(), "2".to_string()],
+            )]
+        };
+        let error = get_or_load(&DISTRIBUTION, "genders.dst", || {
+            FileBasedStringValuesDistribution::from_distribution_lines(lines(), 1, 1)
+        })
+        .unwrap_err();
+        assert!(matches!(
+            error,
+            TpcdsError::Distribution { ref file, .. } if file == "genders.dst"
+        ));
+        assert!(DISTRIBUTION.get().is_none());
+
+        let distribution = get_or_load(&DISTRIBUTION, "genders.dst", || {
+            FileBasedStringValuesDistribution::from_distribution_lines(lines(), 1, 2)
+        })
+        .unwrap();
+        assert_eq!(distribution.get_size(), 1);
+    }
 }
@@ -18,6 +18,7 @@
 //! with uniform weighted random selection.
 
 use crate::distribution::file_loader::DistributionFileLoader;
+use crate::distribution::utils::get_or_load;
 use crate::distribution::utils::{pick_random_value, WeightsBuilder};
 use crate::error::Result;
 use crate::random::RandomNumberStream;
@@ -39,12 +40,13 @@ impl WebPageUseDistribution {
     const NUM_WEIGHT_FIELDS: usize = 1;
     const VALUES_AND_WEIGHTS_FILENAME: &'static str = "web_page_use.dst";
 
-    fn get_instance() -> &'static WebPageUseDistribution {
+    fn get_instance() -> Result<&'static WebPageUseDistribution> {
         static DISTRIBUTION: OnceLock<WebPageUseDistribution> = OnceLock::new();
-        DISTRIBUTION.get_or_init(|| {
-            Self::build_web_page_use_distribution()
-                .expect("Failed to load web page use distribution")
-        })
+        get_or_load(
+            &DISTRIBUTION,
+            Self::VALUES_AND_WEIGHTS_FILENAME,
+            Self::build_web_page_use_distribution,
+        )
     }
 
     fn build_web_page_use_distribution() -> Result<Self> {
@@ -104,7 +106,7 @@ impl WebPageUseDistribution {
     ///
     /// A web page use type string (e.g., "general", "order", "welcome", "ad", "feedback", "protected", "dynamic")
     pub fn pick_random_web_page_use_type(stream: &mut dyn RandomNumberStream) -> Result<String> {
-        let dist = Self::get_instance();
+        let dist = Self::get_instance()?;
         let value_ref = pick_random_value(&dist.values, &dist.weights_list, stream)?;
         Ok(value_ref.clone())
     }
@@ -117,7 +119,7 @@ mod tests {
 
     #[test]
     fn test_web_page_use_distribution_loading() {
-        let dist = WebPageUseDistribution::get_instance();
+        let dist = WebPageUseDistribution::get_instance().unwrap();
 
         // Should have 7 page use types: general, order, welcome, ad, feedback, protected, dynamic
         assert_eq!(dist.values.len(), 7, "Should have 7 web page use types");
@@ -169,7 +171,7 @@ mod tests {
 
     #[test]
     fn test_page_use_values() {
-        let dist = WebPageUseDistribution::get_instance();
+        let dist = WebPageUseDistribution::get_instance().unwrap();
 
         // Verify expected page use types are present
         let types_set: std::collections::HashSet<&String> = dist.values.iter().collect();
@@ -1,27 +1,121 @@
-#[derive(Debug, Clone, PartialEq)]
-pub struct TpcdsError {
-    message: String,
+use crate::table::Table;
+
+/// Errors of the generator, by kind, so that callers can tell them apart without matching on the
+/// message
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum TpcdsError {
+    /// A command-line option has an invalid value
+    InvalidOption(InvalidOptionError),
+    /// A distribution file is unknown or malformed. `line` is the 1-based line of the file the
+    /// error is about, if any.
+    Distribution {
+        file: String,
+        line: Option<usize>,
+        message: String,
+    },
+    /// Reading or writing a file failed
+    Io {
+        context: String,
+        source: std::io::Error,
+    },
+    /// A function was called with an invalid argument (`check_argument!`)
+    InvalidArgument(String),
+    /// An operation was attempted in an invalid state (`check_state!`)
+    InvalidState(String),
+    /// Generating a row of a table failed
+    Generation {
+        table: Table,
+        row: i64,
+        source: Box<TpcdsError>,
+    },
 }
 
 impl TpcdsError {
+    /// Invalid argument error, the kind of most errors of the generator
     pub fn new(message: &str) -> Self {
-        Self {
+        Self::InvalidArgument(message.to_string())
+    }
+
+    pub fn invalid_state(message: &str) -> Self {
+        Self::InvalidState(message.to_string())
+    }
+
+    pub fn distribution(file: &str, line: Option<usize>, message: &str) -> Self {
+        Self::Distribution {
+            file: file.to_string(),
+            line,
             message: message.to_string(),
         }
     }
 
-    pub fn message(&self) -> &str {
-        &self.message
+    pub fn io(context: &str, source: std::io::Error) -> Self {
+        Self::Io {
+            context: context.to_string(),
+            source,
+        }
+    }
+
+    pub fn generation(table: Table, row: i64, source: TpcdsError) -> Self {
+        Self::Generation {
+            table,
+            row,
+            source: Box::new(source),
+        }
+    }
+
+    /// Check if writing failed because the reader closed the pipe, as `head` does once it has
+    /// its lines
+    pub fn is_broken_pipe(&self) -> bool {
+        match self {
+            Self::Io { source, .. } => source.kind() == std::io::ErrorKind::BrokenPipe,
+            Self::Generation { source, .. } => source.is_broken_pipe(),
+            _ => false,
+        }
     }
 }
 
 impl std::fmt::Display for TpcdsError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.message)
+        match self {
+            Self::InvalidOption(err) => write!(f, "{}", err),
+            Self::Distribution {
+                file,
+                line: Some(line),
+                message,
+            } => write!(f, "Distribution file {}, line {}: {}", file, line, message),
+            Self::Distribution {
+                file,
+                line: None,
+                message,
+            } => write!(f, "Distribution file {}: {}", file, message),
+            Self::Io { context, source } => write!(f, "{}: {}", context, source),
+            Self::InvalidArgument(message) | Self::InvalidState(message) => {
+                write!(f, "{}", message)
+            }
+            Self::Generation { table, row, source } => {
+                write!(f, "Failed to generate row {} of {}: {}", row, table, source)
+            }
+        }
     }
 }
 
-impl std::error::Error for TpcdsError {}
+impl std::error::Error for TpcdsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidOption(err) => Some(err),
+            Self::Io { source, .. } => Some(source),
+            Self::Generation { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for TpcdsError {
+    fn from(err: std::io::Error) -> Self {
+        TpcdsError::io("I/O error", err)
+    }
+}
 
 pub type Result<T> = std::result::Result<T, TpcdsError>;
 
@@ -73,7 +167,7 @@ impl std::error::Error for InvalidOptionError {}
 
 impl From<InvalidOptionError> for TpcdsError {
     fn from(err: InvalidOptionError) -> Self {
-        TpcdsError::new(&err.to_string())
+        TpcdsError::InvalidOption(err)
     }
 }
 
@@ -83,7 +177,7 @@ macro_rules! check_argument {
     ($condition:expr, $message:expr) => {
         #[allow(clippy::neg_cmp_op_on_partial_ord)]
         if !$condition {
-            return Err(TpcdsError::new($message));
+            return Err($crate::TpcdsError::InvalidArgument($message.to_string()));
         }
     };
 }
@@ -92,7 +186,59 @@ macro_rules! check_argument {
 macro_rules! check_state {
     ($condition:expr, $message:expr) => {
         if !$condition {
-            return Err(TpcdsError::new($message));
+            return Err($crate::TpcdsError::InvalidState($message.to_string()));
         }
     };
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    fn check(value: i32) -> Result<i32> {
+        check_argument!(value >= 0, "value must be >= 0");
+        check_state!(value < 10, "value must be < 10");
+        Ok(value)
+    }
+
+    #[test]
+    fn test_check_macros_set_the_kind() {
+        assert!(matches!(check(-1), Err(TpcdsError::InvalidArgument(_))));
+        assert!(matches!(check(10), Err(TpcdsError::InvalidState(_))));
+        assert_eq!(check(-1).unwrap_err().to_string(), "value must be >= 0");
+    }
+
+    #[test]
+    fn test_source_chain() {
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+        let error = TpcdsError::generation(Table::Reason, 7, TpcdsError::io("Failed to read", io));
+        assert_eq!(
+            error.to_string(),
+            "Failed to generate row 7 of reason: Failed to read: no such file"
+        );
+
+        let source = error.source().unwrap();
+        assert!(matches!(
+            source.downcast_ref::<TpcdsError>(),
+            Some(TpcdsError::Io { .. })
+        ));
+        let io = source.source().unwrap();
+        assert_eq!(io.to_string(), "no such file");
+        assert!(io.source().is_none());
+
+        let option: TpcdsError = InvalidOptionError::new("--scale", "x").into();
+        assert!(option.source().is_some());
+        assert!(TpcdsError::new("invalid").source().is_none());
+    }
+
+    #[test]
+    fn test_is_broken_pipe() {
+        let pipe = || std::io::Error::new(std::io::ErrorKind::BrokenPipe, "broken pipe");
+        assert!(TpcdsError::from(pipe()).is_broken_pipe());
+        assert!(TpcdsError::generation(Table::Reason, 1, pipe().into()).is_broken_pipe());
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+        assert!(!TpcdsError::from(io).is_broken_pipe());
+        assert!(!TpcdsError::new("broken pipe").is_broken_pipe());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// Delete generator columns (DeleteGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeleteGeneratorColumn {
+    Date1,
+    Date2,
+}
+
+impl DeleteGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [DeleteGeneratorColumn] {
+        use DeleteGeneratorColumn::*;
+        static VALUES: &[DeleteGeneratorColumn] = &[Date1, Date2];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    /// The date ranges come from the refresh's update dates and draw no random numbers
+    fn get_column_info(&self) -> (i32, i32) {
+        use DeleteGeneratorColumn::*;
+        match self {
+            Date1 => (413, 0),
+            Date2 => (414, 0),
+        }
+    }
+}
+
+impl GeneratorColumn for DeleteGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::Delete
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
+
+/// Inventory_delete generator columns (InventoryDeleteGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InventoryDeleteGeneratorColumn {
+    Date1,
+    Date2,
+}
+
+impl InventoryDeleteGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [InventoryDeleteGeneratorColumn] {
+        use InventoryDeleteGeneratorColumn::*;
+        static VALUES: &[InventoryDeleteGeneratorColumn] = &[Date1, Date2];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    /// The date ranges come from the refresh's update dates and draw no random numbers
+    fn get_column_info(&self) -> (i32, i32) {
+        use InventoryDeleteGeneratorColumn::*;
+        match self {
+            Date1 => (415, 0),
+            Date2 => (416, 0),
+        }
+    }
+}
+
+impl GeneratorColumn for InventoryDeleteGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::InventoryDelete
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
@@ -6,7 +6,7 @@ use crate::column::Table;
 /// (because those get derived from other columns).
 ///
 /// GeneratorColumn
-pub trait GeneratorColumn: Send + Sync {
+pub trait GeneratorColumn: Send + Sync + std::fmt::Debug {
     /// Get the table this generator column belongs to
     fn get_table(&self) -> Table;
 
@@ -22,6 +22,7 @@ mod tests {
     use super::*;
 
     // Create a simple test implementation
+    #[derive(Debug)]
     struct TestGeneratorColumn {
         table: Table,
         global_column_number: i32,
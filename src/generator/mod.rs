@@ -1,11 +1,25 @@
 pub mod call_center_generator_column;
 pub mod customer_demographics_generator_column;
 pub mod date_dim_generator_column;
+pub mod delete_generator_column;
 pub mod generator_column;
 pub mod household_demographics_generator_column;
 pub mod income_band_generator_column;
 pub mod promotion_generator_column;
 pub mod reason_generator_column;
+pub mod s_call_center_generator_column;
+pub mod s_catalog_order_generator_column;
+pub mod s_catalog_returns_generator_column;
+pub mod s_inventory_generator_column;
+pub mod s_item_generator_column;
+pub mod s_promotion_generator_column;
+pub mod s_purchase_generator_column;
+pub mod s_store_returns_generator_column;
+pub mod s_warehouse_generator_column;
+pub mod s_web_order_generator_column;
+pub mod s_web_page_generator_column;
+pub mod s_web_returns_generator_column;
+pub mod s_zip_to_gmt_generator_column;
 pub mod ship_mode_generator_column;
 pub mod time_dim_generator_column;
 pub mod warehouse_generator_column;
@@ -15,11 +29,29 @@ pub mod web_site_generator_column;
 pub use call_center_generator_column::CallCenterGeneratorColumn;
 pub use customer_demographics_generator_column::CustomerDemographicsGeneratorColumn;
 pub use date_dim_generator_column::DateDimGeneratorColumn;
+pub use delete_generator_column::{DeleteGeneratorColumn, InventoryDeleteGeneratorColumn};
 pub use generator_column::GeneratorColumn;
 pub use household_demographics_generator_column::HouseholdDemographicsGeneratorColumn;
 pub use income_band_generator_column::IncomeBandGeneratorColumn;
 pub use promotion_generator_column::PromotionGeneratorColumn;
 pub use reason_generator_column::ReasonGeneratorColumn;
+pub use s_call_center_generator_column::SCallCenterGeneratorColumn;
+pub use s_catalog_order_generator_column::{
+    SCatalogOrderGeneratorColumn, SCatalogOrderLineitemGeneratorColumn,
+};
+pub use s_catalog_returns_generator_column::SCatalogReturnsGeneratorColumn;
+pub use s_inventory_generator_column::SInventoryGeneratorColumn;
+pub use s_item_generator_column::SItemGeneratorColumn;
+pub use s_promotion_generator_column::SPromotionGeneratorColumn;
+pub use s_purchase_generator_column::{SPurchaseGeneratorColumn, SPurchaseLineitemGeneratorColumn};
+pub use s_store_returns_generator_column::SStoreReturnsGeneratorColumn;
+pub use s_warehouse_generator_column::SWarehouseGeneratorColumn;
+pub use s_web_order_generator_column::{
+    SWebOrderGeneratorColumn, SWebOrderLineitemGeneratorColumn,
+};
+pub use s_web_page_generator_column::SWebPageGeneratorColumn;
+pub use s_web_returns_generator_column::SWebReturnsGeneratorColumn;
+pub use s_zip_to_gmt_generator_column::SZipToGmtGeneratorColumn;
 pub use ship_mode_generator_column::ShipModeGeneratorColumn;
 pub use time_dim_generator_column::TimeDimGeneratorColumn;
 pub use warehouse_generator_column::WarehouseGeneratorColumn;
@@ -0,0 +1,66 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_call_center generator columns (SCallCenterGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SCallCenterGeneratorColumn {
+    SccCallCenterId,
+    SccOpenDate,
+    SccClass,
+    SccEmployees,
+    SccSqFt,
+    SccHours,
+    SccManager,
+    SccTaxPercentage,
+    SccNulls,
+}
+
+impl SCallCenterGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SCallCenterGeneratorColumn] {
+        use SCallCenterGeneratorColumn::*;
+        static VALUES: &[SCallCenterGeneratorColumn] = &[
+            SccCallCenterId,
+            SccOpenDate,
+            SccClass,
+            SccEmployees,
+            SccSqFt,
+            SccHours,
+            SccManager,
+            SccTaxPercentage,
+            SccNulls,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    /// Refresh tables are not part of the Java implementation; their columns start at 401
+    fn get_column_info(&self) -> (i32, i32) {
+        use SCallCenterGeneratorColumn::*;
+        match self {
+            SccCallCenterId => (401, 1),
+            SccOpenDate => (402, 1),
+            SccClass => (403, 2),
+            SccEmployees => (404, 1),
+            SccSqFt => (405, 1),
+            SccHours => (406, 1),
+            SccManager => (407, 2),
+            SccTaxPercentage => (408, 1),
+            SccNulls => (409, 2),
+        }
+    }
+}
+
+impl GeneratorColumn for SCallCenterGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SCallCenter
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
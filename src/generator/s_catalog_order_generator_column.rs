@@ -0,0 +1,127 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_catalog_order generator columns (SCatalogOrderGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SCatalogOrderGeneratorColumn {
+    CordBillCustomerId,
+    CordShipCustomerId,
+    CordOrderDate,
+    CordOrderTime,
+    CordShipModeId,
+    CordCallCenterId,
+    CordOrderComments,
+}
+
+impl SCatalogOrderGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SCatalogOrderGeneratorColumn] {
+        use SCatalogOrderGeneratorColumn::*;
+        static VALUES: &[SCatalogOrderGeneratorColumn] = &[
+            CordBillCustomerId,
+            CordShipCustomerId,
+            CordOrderDate,
+            CordOrderTime,
+            CordShipModeId,
+            CordCallCenterId,
+            CordOrderComments,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SCatalogOrderGeneratorColumn::*;
+        match self {
+            CordBillCustomerId => (440, 1),
+            CordShipCustomerId => (441, 1),
+            CordOrderDate => (442, 2),
+            CordOrderTime => (443, 1),
+            CordShipModeId => (444, 1),
+            CordCallCenterId => (445, 1),
+            CordOrderComments => (446, 100),
+        }
+    }
+}
+
+impl GeneratorColumn for SCatalogOrderGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SCatalogOrder
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
+
+/// S_catalog_order_lineitem generator columns (SCatalogOrderLineitemGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SCatalogOrderLineitemGeneratorColumn {
+    ClinLineCount,
+    ClinItemId,
+    ClinPromotionId,
+    ClinQuantity,
+    ClinSalesPrice,
+    ClinCouponAmt,
+    ClinWarehouseId,
+    ClinShipDate,
+    ClinCatalogPage,
+    ClinShipCost,
+}
+
+impl SCatalogOrderLineitemGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SCatalogOrderLineitemGeneratorColumn] {
+        use SCatalogOrderLineitemGeneratorColumn::*;
+        static VALUES: &[SCatalogOrderLineitemGeneratorColumn] = &[
+            ClinLineCount,
+            ClinItemId,
+            ClinPromotionId,
+            ClinQuantity,
+            ClinSalesPrice,
+            ClinCouponAmt,
+            ClinWarehouseId,
+            ClinShipDate,
+            ClinCatalogPage,
+            ClinShipCost,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    /// A row is an order: columns drawn for every line have a seed for each of the 14 lines
+    /// of the largest order
+    fn get_column_info(&self) -> (i32, i32) {
+        use SCatalogOrderLineitemGeneratorColumn::*;
+        match self {
+            ClinLineCount => (447, 1),
+            ClinItemId => (448, 1),
+            ClinPromotionId => (449, 14),
+            ClinQuantity => (450, 14),
+            ClinSalesPrice => (451, 14),
+            ClinCouponAmt => (452, 14),
+            ClinWarehouseId => (453, 14),
+            ClinShipDate => (454, 14),
+            ClinCatalogPage => (455, 14),
+            ClinShipCost => (456, 14),
+        }
+    }
+}
+
+impl GeneratorColumn for SCatalogOrderLineitemGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SCatalogOrderLineitem
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
@@ -0,0 +1,77 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_catalog_returns generator columns (SCatalogReturnsGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SCatalogReturnsGeneratorColumn {
+    CretCallCenterId,
+    CretOrderId,
+    CretLineNumber,
+    CretReturnCustomerId,
+    CretRefundCustomerId,
+    CretReturnDate,
+    CretReturnTime,
+    CretReturnQty,
+    CretAmounts,
+    CretReasonId,
+    CretShipModeId,
+    CretCatalogPageId,
+    CretWarehouseId,
+}
+
+impl SCatalogReturnsGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SCatalogReturnsGeneratorColumn] {
+        use SCatalogReturnsGeneratorColumn::*;
+        static VALUES: &[SCatalogReturnsGeneratorColumn] = &[
+            CretCallCenterId,
+            CretOrderId,
+            CretLineNumber,
+            CretReturnCustomerId,
+            CretRefundCustomerId,
+            CretReturnDate,
+            CretReturnTime,
+            CretReturnQty,
+            CretAmounts,
+            CretReasonId,
+            CretShipModeId,
+            CretCatalogPageId,
+            CretWarehouseId,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SCatalogReturnsGeneratorColumn::*;
+        match self {
+            CretCallCenterId => (457, 1),
+            CretOrderId => (458, 0),
+            CretLineNumber => (459, 1),
+            CretReturnCustomerId => (460, 1),
+            CretRefundCustomerId => (461, 1),
+            CretReturnDate => (462, 1),
+            CretReturnTime => (463, 1),
+            CretReturnQty => (464, 1),
+            CretAmounts => (465, 5),
+            CretReasonId => (466, 1),
+            CretShipModeId => (467, 1),
+            CretCatalogPageId => (468, 1),
+            CretWarehouseId => (469, 1),
+        }
+    }
+}
+
+impl GeneratorColumn for SCatalogReturnsGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SCatalogReturns
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
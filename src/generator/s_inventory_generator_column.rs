@@ -0,0 +1,44 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_inventory generator columns (SInventoryGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SInventoryGeneratorColumn {
+    InvnDate,
+    InvnQtyOnHand,
+    InvnNulls,
+}
+
+impl SInventoryGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SInventoryGeneratorColumn] {
+        use SInventoryGeneratorColumn::*;
+        static VALUES: &[SInventoryGeneratorColumn] = &[InvnDate, InvnQtyOnHand, InvnNulls];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    /// Refresh tables are not part of the Java implementation; their columns start at 401
+    fn get_column_info(&self) -> (i32, i32) {
+        use SInventoryGeneratorColumn::*;
+        match self {
+            InvnDate => (410, 1),
+            InvnQtyOnHand => (411, 1),
+            InvnNulls => (412, 2),
+        }
+    }
+}
+
+impl GeneratorColumn for SInventoryGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SInventory
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
@@ -0,0 +1,68 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_item generator columns (SItemGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SItemGeneratorColumn {
+    SItemItemId,
+    SItemDescription,
+    SItemListPrice,
+    SItemWholesaleCost,
+    SItemSize,
+    SItemFormulation,
+    SItemColor,
+    SItemUnits,
+    SItemManagerId,
+    SItemNulls,
+}
+
+impl SItemGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SItemGeneratorColumn] {
+        use SItemGeneratorColumn::*;
+        static VALUES: &[SItemGeneratorColumn] = &[
+            SItemItemId,
+            SItemDescription,
+            SItemListPrice,
+            SItemWholesaleCost,
+            SItemSize,
+            SItemFormulation,
+            SItemColor,
+            SItemUnits,
+            SItemManagerId,
+            SItemNulls,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SItemGeneratorColumn::*;
+        match self {
+            SItemItemId => (497, 1),
+            SItemDescription => (498, 200),
+            SItemListPrice => (499, 1),
+            SItemWholesaleCost => (500, 1),
+            SItemSize => (501, 1),
+            SItemFormulation => (502, 20),
+            SItemColor => (503, 1),
+            SItemUnits => (504, 1),
+            SItemManagerId => (505, 1),
+            SItemNulls => (506, 2),
+        }
+    }
+}
+
+impl GeneratorColumn for SItemGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SItem
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
@@ -0,0 +1,56 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_promotion generator columns (SPromotionGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SPromotionGeneratorColumn {
+    SPromPromotionId,
+    SPromStartDate,
+    SPromEndDate,
+    SPromChannels,
+    SPromChannelDetails,
+    SPromNulls,
+}
+
+impl SPromotionGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SPromotionGeneratorColumn] {
+        use SPromotionGeneratorColumn::*;
+        static VALUES: &[SPromotionGeneratorColumn] = &[
+            SPromPromotionId,
+            SPromStartDate,
+            SPromEndDate,
+            SPromChannels,
+            SPromChannelDetails,
+            SPromNulls,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SPromotionGeneratorColumn::*;
+        match self {
+            SPromPromotionId => (507, 1),
+            SPromStartDate => (508, 1),
+            SPromEndDate => (509, 1),
+            SPromChannels => (510, 1),
+            SPromChannelDetails => (511, 100),
+            SPromNulls => (512, 2),
+        }
+    }
+}
+
+impl GeneratorColumn for SPromotionGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SPromotion
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
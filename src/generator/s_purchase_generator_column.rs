@@ -0,0 +1,118 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_purchase generator columns (SPurchaseGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SPurchaseGeneratorColumn {
+    PurcStoreId,
+    PurcCustomerId,
+    PurcPurchaseDate,
+    PurcPurchaseTime,
+    PurcRegisterId,
+    PurcClerkId,
+    PurcComment,
+}
+
+impl SPurchaseGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SPurchaseGeneratorColumn] {
+        use SPurchaseGeneratorColumn::*;
+        static VALUES: &[SPurchaseGeneratorColumn] = &[
+            PurcStoreId,
+            PurcCustomerId,
+            PurcPurchaseDate,
+            PurcPurchaseTime,
+            PurcRegisterId,
+            PurcClerkId,
+            PurcComment,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SPurchaseGeneratorColumn::*;
+        match self {
+            PurcStoreId => (417, 1),
+            PurcCustomerId => (418, 1),
+            PurcPurchaseDate => (419, 2),
+            PurcPurchaseTime => (420, 1),
+            PurcRegisterId => (421, 1),
+            PurcClerkId => (422, 1),
+            PurcComment => (423, 100),
+        }
+    }
+}
+
+impl GeneratorColumn for SPurchaseGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SPurchase
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
+
+/// S_purchase_lineitem generator columns (SPurchaseLineitemGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SPurchaseLineitemGeneratorColumn {
+    PlinLineCount,
+    PlinItemId,
+    PlinPromotionId,
+    PlinQuantity,
+    PlinSalePrice,
+    PlinCouponAmt,
+    PlinComment,
+}
+
+impl SPurchaseLineitemGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SPurchaseLineitemGeneratorColumn] {
+        use SPurchaseLineitemGeneratorColumn::*;
+        static VALUES: &[SPurchaseLineitemGeneratorColumn] = &[
+            PlinLineCount,
+            PlinItemId,
+            PlinPromotionId,
+            PlinQuantity,
+            PlinSalePrice,
+            PlinCouponAmt,
+            PlinComment,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    /// A row is an order: columns drawn for every line have a seed for each of the 16 lines
+    /// of the largest order
+    fn get_column_info(&self) -> (i32, i32) {
+        use SPurchaseLineitemGeneratorColumn::*;
+        match self {
+            PlinLineCount => (424, 1),
+            PlinItemId => (425, 1),
+            PlinPromotionId => (426, 16),
+            PlinQuantity => (427, 16),
+            PlinSalePrice => (428, 16),
+            PlinCouponAmt => (429, 16),
+            PlinComment => (430, 1600),
+        }
+    }
+}
+
+impl GeneratorColumn for SPurchaseLineitemGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SPurchaseLineitem
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
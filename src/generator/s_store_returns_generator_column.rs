@@ -0,0 +1,65 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_store_returns generator columns (SStoreReturnsGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SStoreReturnsGeneratorColumn {
+    SretStoreId,
+    SretPurchaseId,
+    SretLineNumber,
+    SretCustomerId,
+    SretReturnDate,
+    SretReturnTime,
+    SretReturnQty,
+    SretAmounts,
+    SretReasonId,
+}
+
+impl SStoreReturnsGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SStoreReturnsGeneratorColumn] {
+        use SStoreReturnsGeneratorColumn::*;
+        static VALUES: &[SStoreReturnsGeneratorColumn] = &[
+            SretStoreId,
+            SretPurchaseId,
+            SretLineNumber,
+            SretCustomerId,
+            SretReturnDate,
+            SretReturnTime,
+            SretReturnQty,
+            SretAmounts,
+            SretReasonId,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SStoreReturnsGeneratorColumn::*;
+        match self {
+            SretStoreId => (431, 1),
+            SretPurchaseId => (432, 0),
+            SretLineNumber => (433, 1),
+            SretCustomerId => (434, 1),
+            SretReturnDate => (435, 1),
+            SretReturnTime => (436, 1),
+            SretReturnQty => (437, 1),
+            SretAmounts => (438, 5),
+            SretReasonId => (439, 1),
+        }
+    }
+}
+
+impl GeneratorColumn for SStoreReturnsGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SStoreReturns
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
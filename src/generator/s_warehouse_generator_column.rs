@@ -0,0 +1,50 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_warehouse generator columns (SWarehouseGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWarehouseGeneratorColumn {
+    SWrhsWarehouseId,
+    SWrhsWarehouseDesc,
+    SWrhsWarehouseSqFt,
+    SWrhsNulls,
+}
+
+impl SWarehouseGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SWarehouseGeneratorColumn] {
+        use SWarehouseGeneratorColumn::*;
+        static VALUES: &[SWarehouseGeneratorColumn] = &[
+            SWrhsWarehouseId,
+            SWrhsWarehouseDesc,
+            SWrhsWarehouseSqFt,
+            SWrhsNulls,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SWarehouseGeneratorColumn::*;
+        match self {
+            SWrhsWarehouseId => (513, 1),
+            SWrhsWarehouseDesc => (514, 80),
+            SWrhsWarehouseSqFt => (515, 1),
+            SWrhsNulls => (516, 2),
+        }
+    }
+}
+
+impl GeneratorColumn for SWarehouseGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SWarehouse
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
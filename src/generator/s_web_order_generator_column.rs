@@ -0,0 +1,127 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_web_order generator columns (SWebOrderGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWebOrderGeneratorColumn {
+    WordBillCustomerId,
+    WordShipCustomerId,
+    WordOrderDate,
+    WordOrderTime,
+    WordShipModeId,
+    WordWebSiteId,
+    WordOrderComments,
+}
+
+impl SWebOrderGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SWebOrderGeneratorColumn] {
+        use SWebOrderGeneratorColumn::*;
+        static VALUES: &[SWebOrderGeneratorColumn] = &[
+            WordBillCustomerId,
+            WordShipCustomerId,
+            WordOrderDate,
+            WordOrderTime,
+            WordShipModeId,
+            WordWebSiteId,
+            WordOrderComments,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SWebOrderGeneratorColumn::*;
+        match self {
+            WordBillCustomerId => (470, 1),
+            WordShipCustomerId => (471, 1),
+            WordOrderDate => (472, 2),
+            WordOrderTime => (473, 1),
+            WordShipModeId => (474, 1),
+            WordWebSiteId => (475, 1),
+            WordOrderComments => (476, 100),
+        }
+    }
+}
+
+impl GeneratorColumn for SWebOrderGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SWebOrder
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
+
+/// S_web_order_lineitem generator columns (SWebOrderLineitemGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWebOrderLineitemGeneratorColumn {
+    WlinLineCount,
+    WlinItemId,
+    WlinPromotionId,
+    WlinQuantity,
+    WlinSalesPrice,
+    WlinCouponAmt,
+    WlinWarehouseId,
+    WlinShipDate,
+    WlinShipCost,
+    WlinWebPageId,
+}
+
+impl SWebOrderLineitemGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SWebOrderLineitemGeneratorColumn] {
+        use SWebOrderLineitemGeneratorColumn::*;
+        static VALUES: &[SWebOrderLineitemGeneratorColumn] = &[
+            WlinLineCount,
+            WlinItemId,
+            WlinPromotionId,
+            WlinQuantity,
+            WlinSalesPrice,
+            WlinCouponAmt,
+            WlinWarehouseId,
+            WlinShipDate,
+            WlinShipCost,
+            WlinWebPageId,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    /// A row is an order: columns drawn for every line have a seed for each of the 16 lines
+    /// of the largest order
+    fn get_column_info(&self) -> (i32, i32) {
+        use SWebOrderLineitemGeneratorColumn::*;
+        match self {
+            WlinLineCount => (477, 1),
+            WlinItemId => (478, 1),
+            WlinPromotionId => (479, 16),
+            WlinQuantity => (480, 16),
+            WlinSalesPrice => (481, 16),
+            WlinCouponAmt => (482, 16),
+            WlinWarehouseId => (483, 16),
+            WlinShipDate => (484, 16),
+            WlinShipCost => (485, 16),
+            WlinWebPageId => (486, 16),
+        }
+    }
+}
+
+impl GeneratorColumn for SWebOrderLineitemGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SWebOrderLineitem
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
@@ -0,0 +1,71 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_web_page generator columns (SWebPageGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWebPageGeneratorColumn {
+    SWpagWebPageId,
+    SWpagCreateDate,
+    SWpagAccessDate,
+    SWpagAutogenFlag,
+    SWpagUrl,
+    SWpagType,
+    SWpagCharCount,
+    SWpagLinkCount,
+    SWpagImageCount,
+    SWpagMaxAdCount,
+    SWpagNulls,
+}
+
+impl SWebPageGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SWebPageGeneratorColumn] {
+        use SWebPageGeneratorColumn::*;
+        static VALUES: &[SWebPageGeneratorColumn] = &[
+            SWpagWebPageId,
+            SWpagCreateDate,
+            SWpagAccessDate,
+            SWpagAutogenFlag,
+            SWpagUrl,
+            SWpagType,
+            SWpagCharCount,
+            SWpagLinkCount,
+            SWpagImageCount,
+            SWpagMaxAdCount,
+            SWpagNulls,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SWebPageGeneratorColumn::*;
+        match self {
+            SWpagWebPageId => (517, 1),
+            SWpagCreateDate => (518, 1),
+            SWpagAccessDate => (519, 1),
+            SWpagAutogenFlag => (520, 1),
+            SWpagUrl => (521, 1),
+            SWpagType => (522, 1),
+            SWpagCharCount => (523, 1),
+            SWpagLinkCount => (524, 1),
+            SWpagImageCount => (525, 1),
+            SWpagMaxAdCount => (526, 1),
+            SWpagNulls => (527, 2),
+        }
+    }
+}
+
+impl GeneratorColumn for SWebPageGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SWebPage
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
@@ -0,0 +1,68 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_web_returns generator columns (SWebReturnsGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SWebReturnsGeneratorColumn {
+    WretWebPageId,
+    WretOrderId,
+    WretLineNumber,
+    WretReturnCustomerId,
+    WretRefundCustomerId,
+    WretReturnDate,
+    WretReturnTime,
+    WretReturnQty,
+    WretAmounts,
+    WretReasonId,
+}
+
+impl SWebReturnsGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SWebReturnsGeneratorColumn] {
+        use SWebReturnsGeneratorColumn::*;
+        static VALUES: &[SWebReturnsGeneratorColumn] = &[
+            WretWebPageId,
+            WretOrderId,
+            WretLineNumber,
+            WretReturnCustomerId,
+            WretRefundCustomerId,
+            WretReturnDate,
+            WretReturnTime,
+            WretReturnQty,
+            WretAmounts,
+            WretReasonId,
+        ];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SWebReturnsGeneratorColumn::*;
+        match self {
+            WretWebPageId => (487, 1),
+            WretOrderId => (488, 0),
+            WretLineNumber => (489, 1),
+            WretReturnCustomerId => (490, 1),
+            WretRefundCustomerId => (491, 1),
+            WretReturnDate => (492, 1),
+            WretReturnTime => (493, 1),
+            WretReturnQty => (494, 1),
+            WretAmounts => (495, 5),
+            WretReasonId => (496, 1),
+        }
+    }
+}
+
+impl GeneratorColumn for SWebReturnsGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SWebReturns
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
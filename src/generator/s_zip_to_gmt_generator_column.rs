@@ -0,0 +1,41 @@
+use crate::column::Table;
+use crate::generator::GeneratorColumn;
+
+/// S_zip_to_gmt generator columns (SZipToGmtGeneratorColumn enum)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SZipToGmtGeneratorColumn {
+    SZipgGmtOffset,
+    SZipgNulls,
+}
+
+impl SZipToGmtGeneratorColumn {
+    /// Get all generator columns in order
+    pub fn values() -> &'static [SZipToGmtGeneratorColumn] {
+        use SZipToGmtGeneratorColumn::*;
+        static VALUES: &[SZipToGmtGeneratorColumn] = &[SZipgGmtOffset, SZipgNulls];
+        VALUES
+    }
+
+    /// Get the global column number and seeds per row for this generator column
+    fn get_column_info(&self) -> (i32, i32) {
+        use SZipToGmtGeneratorColumn::*;
+        match self {
+            SZipgGmtOffset => (528, 1),
+            SZipgNulls => (529, 2),
+        }
+    }
+}
+
+impl GeneratorColumn for SZipToGmtGeneratorColumn {
+    fn get_table(&self) -> Table {
+        Table::SZipToGmt
+    }
+
+    fn get_global_column_number(&self) -> i32 {
+        self.get_column_info().0
+    }
+
+    fn get_seeds_per_row(&self) -> i32 {
+        self.get_column_info().1
+    }
+}
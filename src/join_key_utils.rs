@@ -265,7 +265,7 @@ fn generate_scd_join_key(
         julian_date,
         to_table,
         scaling,
-    );
+    )?;
 
     Ok(if key > scaling.get_row_count(to_table) {
         -1
@@ -292,7 +292,7 @@ fn generate_web_join_key(
         // so it occurs in the gap between site creation and the site's actual activity. For sites that are replaced
         // in the time span of the data set, this will depend on whether they are the first version or the second
         let site = (join_key / WEB_PAGES_PER_SITE as i64 + 1) as i32;
-        let web_site_duration = get_web_site_duration(scaling);
+        let web_site_duration = get_web_site_duration(scaling)?;
         let min_result = Date::JULIAN_DATE_MINIMUM as i64
             - ((site as i64 * WEB_DATE_STAGGER) % web_site_duration / 2);
         return Ok(RandomValueGenerator::generate_uniform_random_int(
@@ -304,14 +304,14 @@ fn generate_web_join_key(
 
     // WEB_OPEN_DATE for WebPage (global column 340) or WebSite (global column 452)
     if global_column_number == 340 || global_column_number == 452 {
-        let web_site_duration = get_web_site_duration(scaling);
+        let web_site_duration = get_web_site_duration(scaling)?;
         return Ok(Date::JULIAN_DATE_MINIMUM as i64
             - ((join_key * WEB_DATE_STAGGER) % web_site_duration / 2));
     }
 
     // WEB_CLOSE_DATE for WebPage (global column 341) or WebSite (global column 453)
     if global_column_number == 341 || global_column_number == 453 {
-        let web_site_duration = get_web_site_duration(scaling);
+        let web_site_duration = get_web_site_duration(scaling)?;
         let mut result = Date::JULIAN_DATE_MINIMUM as i64
             - ((join_key * WEB_DATE_STAGGER) % web_site_duration / 2);
         result += -web_site_duration; // the -1 here and below are due to undefined values in the C code
@@ -332,13 +332,11 @@ fn generate_web_join_key(
 
 /// Calculates the duration of a web site based on concurrent sites.
 /// Based on JoinKeyUtils.java:getWebSiteDuration (lines 177-180)
-fn get_web_site_duration(scaling: &Scaling) -> i64 {
-    let concurrent_web_sites = PseudoTableScalingInfos::get_concurrent_web_sites();
-    let row_count = concurrent_web_sites
-        .get_row_count_for_scale(scaling.get_scale())
-        .expect("Failed to get row count for concurrent web sites");
+fn get_web_site_duration(scaling: &Scaling) -> Result<i64> {
+    let concurrent_web_sites = PseudoTableScalingInfos::get_concurrent_web_sites()?;
+    let row_count = concurrent_web_sites.get_row_count_for_scale(scaling.get_scale())?;
 
-    (Date::JULIAN_DATE_MAXIMUM as i64 - Date::JULIAN_DATE_MINIMUM as i64) * row_count
+    Ok((Date::JULIAN_DATE_MAXIMUM as i64 - Date::JULIAN_DATE_MINIMUM as i64) * row_count)
 }
 
 /// Checks if a web site is replaced (has an even join key).
@@ -370,6 +368,7 @@ fn is_replacement(join_key: i64) -> bool {
 // }
 
 #[cfg(test)]
+#[allow(clippy::manual_range_contains)]
 mod tests {
     use super::*;
     use crate::random::RandomNumberStreamImpl;
@@ -5,15 +5,25 @@ pub mod distribution;
 pub mod error;
 pub mod generator;
 pub mod join_key_utils;
+pub mod manifest;
 pub mod nulls;
+pub mod output;
+pub mod parallel;
 pub mod permutations;
+pub mod progress;
 pub mod pseudo_table_scaling_infos;
+pub mod query;
 pub mod random;
+pub mod random_access;
 pub mod row;
 pub mod scaling_info;
 pub mod slowly_changing_dimension_utils;
 pub mod table;
 pub mod table_flags;
+pub mod table_generator;
 pub mod types;
+pub mod update;
+pub mod verify_ri;
 
 pub use error::TpcdsError;
+pub use random_access::row_at;
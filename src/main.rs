@@ -1,70 +1,308 @@
 use clap::Parser;
-use tpcdsgen::config::{Options, Table};
-use tpcdsgen::distribution::EnglishDistributions;
-use tpcdsgen::random::RandomNumberStreamImpl;
+use std::io::Write;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+use tpcdsgen::config::{Command, DistCommand, Options, Session};
+use tpcdsgen::distribution::{DistributionFileLoader, DistributionInspector};
+use tpcdsgen::error::Result;
+use tpcdsgen::manifest::{ChunkMarker, Manifest, ManifestFile};
+use tpcdsgen::progress::{ProgressMode, ProgressReporter, Summary};
+use tpcdsgen::query::{load_templates, Dialect, Qualification, QueryGenerator};
+use tpcdsgen::table::Table;
+use tpcdsgen::table_generator::TableGenerator;
+use tpcdsgen::verify_ri::ReferentialIntegrityChecker;
+use tpcdsgen::TpcdsError;
 
 fn main() {
     let options = Options::parse();
 
+    if let Some(command) = &options.command {
+        match run_command(&options, command) {
+            // Output piped into `head` ends early, which is not an error
+            Err(e) if !e.is_broken_pipe() => {
+                eprintln!("Error: {}", e);
+                std::process::exit(1);
+            }
+            _ => return,
+        }
+    }
+
     match options.to_session() {
         Ok(session) => {
-            println!("TPC-DS Data Generator (Rust implementation)");
-            println!("Scale factor: {}", session.get_scaling().get_scale());
-            println!("Target directory: {}", session.get_target_directory());
-            println!("File suffix: {}", session.get_suffix());
-            println!("Column separator: '{}'", session.get_separator());
-            println!("Parallelism: {}", session.get_parallelism());
-
-            if session.generate_only_one_table() {
-                let table = session.get_only_table_to_generate();
-                println!("Generating table: {} ({})", table.get_name(), table);
-                println!(
-                    "Estimated row count: {}",
-                    session.get_scaling().get_row_count(table)
+            if session.get_progress() == ProgressMode::Text {
+                print_settings(&session);
+            }
+
+            match run(&session) {
+                Err(e) if !(session.write_to_stdout() && e.is_broken_pipe()) => {
+                    eprintln!("Error: {}", e);
+                    std::process::exit(1);
+                }
+                _ => {}
+            }
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print the settings of the run on stderr
+fn print_settings(session: &Session) {
+    eprintln!("TPC-DS Data Generator (Rust implementation)");
+    eprintln!("Scale factor: {}", session.get_scaling().get_scale());
+    eprintln!("Target directory: {}", session.get_target_directory());
+    eprintln!("File suffix: {}", session.get_suffix());
+    eprintln!("Column separator: '{}'", session.get_separator());
+    eprintln!("Format: {}", session.get_format());
+    eprintln!("Compression: {}", session.get_compression());
+    eprintln!("Parallelism: {}", session.get_parallelism());
+    if session.get_update() > 0 {
+        eprintln!("Refresh data set: {}", session.get_update());
+    }
+
+    if !session.get_command_line_arguments().is_empty() {
+        eprintln!(
+            "Equivalent command line: tpcdsgen {}",
+            session.get_command_line_arguments()
+        );
+    }
+}
+
+/// Run a tool subcommand instead of generating data
+fn run_command(options: &Options, command: &Command) -> Result<()> {
+    if let Some(directory) = &options.distributions_dir {
+        use_distribution_overrides(Path::new(directory))?;
+    }
+
+    let stdout = std::io::stdout();
+    let mut out = stdout.lock();
+    match command {
+        Command::Dist { command } => match command {
+            DistCommand::List => DistributionInspector::write_list(&mut out),
+            DistCommand::Show { name } => DistributionInspector::write_show(&mut out, name),
+            DistCommand::Sample {
+                name,
+                weights,
+                count,
+                seed,
+            } => DistributionInspector::write_sample(&mut out, name, weights, *count, *seed),
+        },
+        Command::VerifyRi { directory } => {
+            let session = options.to_session()?;
+            let results = ReferentialIntegrityChecker::new(
+                Path::new(directory),
+                session.get_separator(),
+                session.get_suffix(),
+            )
+            .check()?;
+            if !ReferentialIntegrityChecker::write_report(&mut out, &results)? {
+                return Err(TpcdsError::new("Referential integrity violations found"));
+            }
+            Ok(())
+        }
+        Command::Query {
+            templates,
+            input,
+            streams,
+            seed,
+            output,
+            distributions,
+            dialect,
+            substitution_values,
+        } => {
+            let session = options.to_session()?;
+            if substitution_values.is_some() && *streams != 1 {
+                return Err(TpcdsError::new(
+                    "--substitution-values generates a single stream, --streams must be 1",
+                ));
+            }
+            let templates = load_templates(Path::new(templates), input.as_deref().map(Path::new))?;
+            let mut generator =
+                QueryGenerator::new(templates, session.get_scaling().clone(), *seed)
+                    .with_dialect(Dialect::get(dialect)?);
+            if let Some(file) = distributions {
+                generator = generator.with_distribution_definitions(
+                    DistributionFileLoader::load_distribution_definitions(Path::new(file))?,
                 );
-            } else {
-                println!("Generating all tables");
-                let main_tables = Table::main_tables();
-                println!("Main tables to generate: {}", main_tables.len());
-
-                for table in main_tables.iter().take(5) {
-                    println!(
-                        "  {}: ~{} rows",
-                        table.get_name(),
-                        session.get_scaling().get_row_count(*table)
+            }
+            if let Some(file) = substitution_values {
+                if session.get_scaling().get_scale() != 1.0 {
+                    eprintln!(
+                        "Warning: qualification values are defined for scale factor 1, not {}",
+                        session.get_scaling().get_scale()
                     );
                 }
-                if main_tables.len() > 5 {
-                    println!("  ... and {} more tables", main_tables.len() - 5);
+                generator = generator.with_qualification(Qualification::open(file)?);
+            }
+            for stream in 0..*streams {
+                if output == Options::STDOUT_DIRECTORY {
+                    generator.write_stream(&mut out, stream)?;
+                } else {
+                    let path = Path::new(output).join(format!("query_{}.sql", stream));
+                    let mut file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+                    generator.write_stream(&mut file, stream)?;
+                    file.flush()?;
+                    eprintln!("Wrote {}", path.display());
                 }
             }
+            Ok(())
+        }
+    }
+}
 
-            if !session.get_command_line_arguments().is_empty() {
-                println!(
-                    "Equivalent command line: tpcdsgen {}",
-                    session.get_command_line_arguments()
-                );
-            }
+/// Resolve the tables selected by the session to the tables that have row generators.
+/// With `--update` these are the tables of the refresh data set instead of the base tables.
+fn tables_to_generate(session: &Session) -> Result<Vec<Table>> {
+    let is_update = session.get_update() > 0;
+    if session.generate_only_one_table() {
+        let name = session.get_only_table_to_generate().get_name();
+        let table = Table::get_table(name)
+            .ok()
+            .filter(|table| table.has_row_generator())
+            .ok_or_else(|| {
+                if session.get_only_table_to_generate().is_main_table() {
+                    TpcdsError::new(&format!("Table {} has not been ported yet", name))
+                } else {
+                    TpcdsError::new(&format!(
+                        "Table {} is not part of the refresh data generated with --update",
+                        name
+                    ))
+                }
+            })?;
+        if table.is_refresh_table() != is_update {
+            return Err(TpcdsError::new(&format!(
+                "Table {} is {}generated with --update",
+                name,
+                if is_update { "not " } else { "only " }
+            )));
+        }
+        Ok(vec![table])
+    } else if is_update {
+        Ok(Table::get_refresh_tables())
+    } else {
+        Ok(Table::get_base_tables()
+            .into_iter()
+            .filter(|table| table.has_row_generator())
+            .collect())
+    }
+}
 
-            // Demo the distribution system
-            println!("\n--- Distribution System Demo ---");
-            let mut stream = RandomNumberStreamImpl::new(1).unwrap();
+/// Generate every chunk on its own thread (Driver). Each worker generates, formats and
+/// compresses its chunk of every table, so all the work scales with `--parallelism`.
+/// Progress is reported on stderr and the per-table statistics are written to `summary.json`.
+fn run(session: &Session) -> Result<()> {
+    let started = Instant::now();
+    let tables = tables_to_generate(session)?;
+    if let Some(directory) = session.get_distributions_directory() {
+        use_distribution_overrides(Path::new(directory))?;
+    }
+    if session.get_update() > 0 {
+        eprintln!("WARNING: refresh data only approximates dsdgen's and is NOT TPC-DS compliant");
+    }
+    let directory = Path::new(session.get_target_directory());
+    // Resuming needs the previous manifest; otherwise it only provides the files of the tables
+    // this run does not generate
+    let previous_manifest = if !directory.join(Manifest::FILE_NAME).exists() {
+        None
+    } else if session.should_resume() {
+        Some(Arc::new(Manifest::read(directory)?))
+    } else {
+        Manifest::read(directory).ok().map(Arc::new)
+    };
+    let progress = Arc::new(ProgressReporter::new(session.get_progress()));
+    if progress.get_mode() == ProgressMode::Text {
+        eprintln!("Generating {} table(s)", tables.len());
+    }
 
-            println!("Random English words:");
-            for i in 0..5 {
-                let adjective = EnglishDistributions::pick_random_adjective(&mut stream).unwrap();
-                let noun = EnglishDistributions::pick_random_noun(&mut stream).unwrap();
-                println!("  {}. {} {}", i + 1, adjective, noun);
-            }
+    let chunk_results: Vec<Result<Vec<ManifestFile>>> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (1..=session.get_parallelism())
+            .map(|chunk_number| {
+                let mut generator = TableGenerator::new(session.with_chunk_number(chunk_number))
+                    .with_progress(Arc::clone(&progress));
+                if let Some(manifest) = previous_manifest
+                    .as_ref()
+                    .filter(|_| session.should_resume())
+                {
+                    generator = generator.with_previous_manifest(Arc::clone(manifest));
+                }
+                let tables = &tables;
+                scope.spawn(move || {
+                    let mut files = Vec::new();
+                    for &table in tables {
+                        files.extend(generator.generate_table(table)?);
+                    }
+                    Ok(files)
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| {
+                worker.join().unwrap_or_else(|panic| {
+                    let message = panic
+                        .downcast_ref::<&str>()
+                        .map(|message| message.to_string())
+                        .or_else(|| panic.downcast_ref::<String>().cloned())
+                        .unwrap_or_default();
+                    Err(TpcdsError::invalid_state(&format!(
+                        "Generation worker panicked: {}",
+                        message
+                    )))
+                })
+            })
+            .collect()
+    });
+
+    let mut files = Vec::new();
+    for chunk_result in chunk_results {
+        files.extend(chunk_result?);
+    }
+    // List the files by table, in generation order, then by chunk
+    files.sort_by_key(|file| {
+        let table = tables
+            .iter()
+            .position(|table| table.get_name() == file.table);
+        (table, file.chunk)
+    });
 
-            let phrase = EnglishDistributions::generate_random_phrase(&mut stream, 4).unwrap();
-            println!("Random phrase: {}", phrase);
+    let summary = Summary::new(
+        tables
+            .iter()
+            .filter_map(|&table| progress.get_table_stats(table))
+            .collect(),
+        started.elapsed(),
+    );
+    progress.finish_run(&summary);
 
-            println!("\nImplementation in progress...");
+    if !session.write_to_stdout() {
+        // The manifest now records the complete chunks, their markers are not needed anymore
+        let mut manifest = Manifest::new(session).with_files(files);
+        if let Some(previous) = &previous_manifest {
+            manifest = manifest.merge_previous(previous, &tables);
         }
-        Err(e) => {
-            eprintln!("Error: {}", e);
-            std::process::exit(1);
+        manifest.write(directory)?;
+        for file in &manifest.files {
+            ChunkMarker::remove(&directory.join(&file.file))?;
         }
+        summary.write(directory)?;
     }
+
+    Ok(())
+}
+
+/// Validate the files of `--distributions-dir` (or `TPCDSGEN_DISTRIBUTIONS_DIR`) and load them
+/// instead of the built-in ones
+fn use_distribution_overrides(directory: &Path) -> Result<()> {
+    let file_names = DistributionFileLoader::set_override_directory(directory)?;
+    eprintln!(
+        "WARNING: distributions overridden from {}: {}",
+        directory.display(),
+        file_names.join(", ")
+    );
+    eprintln!("WARNING: the generated data is NOT TPC-DS compliant");
+    Ok(())
 }
@@ -0,0 +1,374 @@
+use crate::config::Session;
+use crate::distribution::DistributionFileLoader;
+use crate::error::Result;
+use crate::output::FileDigest;
+use crate::parallel::ChunkBoundaries;
+use crate::random::RandomNumberStreamImpl;
+use crate::table::Table;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Description of a generation run, written next to the data as `manifest.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub tool: String,
+    pub version: String,
+    pub scale: f64,
+    /// Base seed of the random number streams
+    pub seed: i32,
+    /// Arguments that reproduce the run
+    pub command_line: String,
+    /// False when the data was generated from modified distributions, or is refresh data
+    /// (`--update`), which only approximates dsdgen's
+    pub tpcds_compliant: bool,
+    /// Distribution files replaced by the distributions directory
+    pub distribution_overrides: Vec<String>,
+    /// Options needed to read the data files
+    pub options: ManifestOptions,
+    /// Data files written, by table and chunk
+    pub files: Vec<ManifestFile>,
+}
+
+/// Session options that determine the layout of the data files
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestOptions {
+    pub separator: String,
+    pub null_string: String,
+    /// True when every row ends with a separator
+    pub terminate_rows: bool,
+    pub suffix: String,
+    pub format: String,
+    pub compression: String,
+    pub parallelism: i32,
+    /// Refresh data set, 0 for the base data
+    pub update: i32,
+}
+
+/// A data file written for one chunk of a table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestFile {
+    pub table: String,
+    pub chunk: i32,
+    /// File name in the data directory
+    pub file: String,
+    /// First and last row numbers of the chunk
+    pub start_row: i64,
+    pub end_row: i64,
+    /// Rows in the file; lineitem tables have several rows per row number
+    pub rows: i64,
+    pub bytes: u64,
+    pub sha256: String,
+}
+
+impl ManifestFile {
+    pub fn new(
+        table: Table,
+        session: &Session,
+        path: &Path,
+        boundaries: ChunkBoundaries,
+        rows: i64,
+        digest: FileDigest,
+    ) -> Self {
+        Self {
+            table: table.get_name().to_string(),
+            chunk: session.get_chunk_number(),
+            file: path
+                .file_name()
+                .map_or_else(|| path.to_string_lossy(), |name| name.to_string_lossy())
+                .to_string(),
+            start_row: boundaries.get_start_row(),
+            end_row: boundaries.get_end_row(),
+            rows,
+            bytes: digest.bytes,
+            sha256: digest.sha256,
+        }
+    }
+}
+
+/// Marker written next to a data file once its chunk is complete, e.g. `date_dim_1_4.dat.done`.
+/// `--resume` keeps the chunks whose marker matches the run; markers are removed once the
+/// manifest listing all files is written.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkMarker {
+    pub version: String,
+    pub scale: f64,
+    pub distribution_overrides: Vec<String>,
+    pub options: ManifestOptions,
+    pub file: ManifestFile,
+}
+
+impl ChunkMarker {
+    pub const SUFFIX: &'static str = ".done";
+
+    pub fn new(session: &Session, file: ManifestFile) -> Self {
+        let manifest = Manifest::new(session);
+        Self {
+            version: manifest.version,
+            scale: manifest.scale,
+            distribution_overrides: manifest.distribution_overrides,
+            options: manifest.options,
+            file,
+        }
+    }
+
+    /// Get the marker path of the data file at `path`
+    pub fn get_path(path: &Path) -> PathBuf {
+        let mut marker_path = path.as_os_str().to_owned();
+        marker_path.push(Self::SUFFIX);
+        PathBuf::from(marker_path)
+    }
+
+    /// Write the marker of the data file at `path`
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string(self)
+            .map_err(|e| crate::TpcdsError::new(&format!("Failed to encode marker: {}", e)))?;
+        write_atomically(&Self::get_path(path), json + "\n")
+    }
+
+    /// Read the marker of the data file at `path`. Missing or unreadable markers are `None`,
+    /// their chunk is generated again.
+    pub fn read(path: &Path) -> Option<Self> {
+        let json = fs::read_to_string(Self::get_path(path)).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    /// Remove the marker of the data file at `path`, if any
+    pub fn remove(path: &Path) -> Result<()> {
+        match fs::remove_file(Self::get_path(path)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(crate::TpcdsError::io(
+                &format!("Failed to remove {}", Self::get_path(path).display()),
+                e,
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check if the marker was written by a run of `session`
+    pub fn matches(&self, session: &Session) -> bool {
+        let manifest = Manifest::new(session);
+        self.version == manifest.version
+            && self.scale == manifest.scale
+            && self.distribution_overrides == manifest.distribution_overrides
+            && self.options == manifest.options
+    }
+}
+
+impl Manifest {
+    pub const FILE_NAME: &'static str = "manifest.json";
+
+    /// Describe a run of `session` with the distributions currently in effect
+    pub fn new(session: &Session) -> Self {
+        let distribution_overrides: Vec<String> =
+            DistributionFileLoader::get_overridden_file_names()
+                .into_iter()
+                .map(str::to_string)
+                .collect();
+        Self {
+            tool: env!("CARGO_PKG_NAME").to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            scale: session.get_scaling().get_scale(),
+            seed: RandomNumberStreamImpl::DEFAULT_SEED_BASE,
+            command_line: session.get_command_line_arguments(),
+            tpcds_compliant: distribution_overrides.is_empty() && session.get_update() == 0,
+            distribution_overrides,
+            options: ManifestOptions {
+                separator: session.get_separator().to_string(),
+                null_string: session.get_null_string().to_string(),
+                terminate_rows: session.terminate_rows_with_separator(),
+                suffix: session.get_suffix().to_string(),
+                format: session.get_format().to_string(),
+                compression: session.get_compression().to_string(),
+                parallelism: session.get_parallelism(),
+                update: session.get_update(),
+            },
+            files: Vec::new(),
+        }
+    }
+
+    /// Set the data files of the run
+    pub fn with_files(self, files: Vec<ManifestFile>) -> Self {
+        Self { files, ..self }
+    }
+
+    /// Check if the data files of the manifest were generated like the files of `session`
+    pub fn matches(&self, session: &Session) -> bool {
+        self.generated_like(&Manifest::new(session))
+    }
+
+    fn generated_like(&self, other: &Manifest) -> bool {
+        self.tool == other.tool
+            && self.version == other.version
+            && self.scale == other.scale
+            && self.seed == other.seed
+            && self.distribution_overrides == other.distribution_overrides
+            && self.options == other.options
+    }
+
+    /// Keep the files of `previous` for the tables other than `tables`, which this run did not
+    /// generate, if they were generated like the files of this run. The kept files are listed
+    /// first.
+    pub fn merge_previous(self, previous: &Manifest, tables: &[Table]) -> Self {
+        if !self.generated_like(previous) {
+            return self;
+        }
+        let mut files: Vec<ManifestFile> = previous
+            .files
+            .iter()
+            .filter(|file| !tables.iter().any(|table| table.get_name() == file.table))
+            .cloned()
+            .collect();
+        files.extend(self.files);
+        Self { files, ..self }
+    }
+
+    /// Find the file of chunk `chunk` of `table`
+    pub fn find_file(&self, table: Table, chunk: i32) -> Option<&ManifestFile> {
+        self.files
+            .iter()
+            .find(|file| file.table == table.get_name() && file.chunk == chunk)
+    }
+
+    /// Write the manifest into `directory`, replacing any previous one
+    pub fn write(&self, directory: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| crate::TpcdsError::new(&format!("Failed to encode manifest: {}", e)))?;
+        write_atomically(&directory.join(Self::FILE_NAME), json + "\n")
+    }
+
+    /// Read the manifest from `directory`
+    pub fn read(directory: &Path) -> Result<Self> {
+        let json = fs::read_to_string(directory.join(Self::FILE_NAME))?;
+        serde_json::from_str(&json)
+            .map_err(|e| crate::TpcdsError::new(&format!("Invalid manifest: {}", e)))
+    }
+}
+
+/// Write `contents` to a temporary file renamed to `path`, so that `path` is never truncated
+fn write_atomically(path: &Path, contents: String) -> Result<()> {
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(".tmp");
+    fs::write(&temp_path, contents)?;
+    fs::rename(&temp_path, path)
+        .map_err(|e| crate::TpcdsError::io(&format!("Failed to write {}", path.display()), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table_generator::TableGenerator;
+
+    #[test]
+    fn test_manifest_round_trip() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+
+        let session = Session::get_default_session().with_scale(10.0);
+        let manifest = Manifest::new(&session);
+        assert_eq!(manifest.scale, 10.0);
+        assert_eq!(manifest.seed, 19620718);
+        assert_eq!(manifest.command_line, "--scale 10");
+        assert_eq!(manifest.options.separator, "|");
+        assert!(manifest.options.terminate_rows);
+        assert!(manifest.tpcds_compliant);
+        assert!(!Manifest::new(&session.with_update(1)).tpcds_compliant);
+
+        manifest.write(directory).unwrap();
+        assert_eq!(Manifest::read(directory).unwrap(), manifest);
+    }
+
+    #[test]
+    fn test_chunk_marker_matches_options() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        let path = directory.join("reason.dat");
+        assert!(ChunkMarker::read(&path).is_none());
+
+        let session = Session::get_default_session();
+        let file = ManifestFile {
+            table: "reason".to_string(),
+            chunk: 1,
+            file: "reason.dat".to_string(),
+            start_row: 1,
+            end_row: 35,
+            rows: 35,
+            bytes: 1000,
+            sha256: String::new(),
+        };
+        ChunkMarker::new(&session, file).write(&path).unwrap();
+        let marker = ChunkMarker::read(&path).unwrap();
+        assert!(marker.matches(&session));
+        assert!(!marker.matches(&session.with_scale(10.0)));
+        assert!(!marker.matches(&session.with_compression(crate::output::Compression::Gzip)));
+        let other_version = ChunkMarker {
+            version: "0.0.0".to_string(),
+            ..marker.clone()
+        };
+        assert!(!other_version.matches(&session));
+
+        ChunkMarker::remove(&path).unwrap();
+        assert!(ChunkMarker::read(&path).is_none());
+        ChunkMarker::remove(&path).unwrap();
+    }
+
+    #[test]
+    fn test_files_describe_generated_chunks() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        let mut options = crate::config::Options::new();
+        options.directory = directory.to_string_lossy().to_string();
+        options.overwrite = true;
+        options.parallelism = 2;
+        let session = options.to_session().unwrap();
+
+        let files: Vec<ManifestFile> = (1..=2)
+            .filter_map(|chunk| {
+                TableGenerator::new(session.with_chunk_number(chunk))
+                    .generate_table(Table::Promotion)
+                    .unwrap()
+            })
+            .collect();
+
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[1].file, "promotion_2_2.dat");
+        assert_eq!((files[1].start_row, files[1].end_row), (151, 300));
+        assert_eq!(files[1].rows, 150);
+        let contents = fs::read(directory.join(&files[1].file)).unwrap();
+        assert_eq!(files[1].bytes, contents.len() as u64);
+        assert_eq!(
+            files[1].sha256,
+            format!("{:x}", <sha2::Sha256 as sha2::Digest>::digest(&contents))
+        );
+    }
+
+    #[test]
+    fn test_merge_previous_keeps_other_tables() {
+        let file = |table: &str, sha256: &str| ManifestFile {
+            table: table.to_string(),
+            chunk: 1,
+            file: format!("{}.dat", table),
+            start_row: 1,
+            end_row: 1,
+            rows: 1,
+            bytes: 1,
+            sha256: sha256.to_string(),
+        };
+        let session = Session::get_default_session();
+        let previous = Manifest::new(&session)
+            .with_files(vec![file("reason", "old"), file("ship_mode", "old")]);
+
+        let manifest = Manifest::new(&session)
+            .with_files(vec![file("reason", "new")])
+            .merge_previous(&previous, &[Table::Reason]);
+        assert_eq!(
+            manifest.files,
+            vec![file("ship_mode", "old"), file("reason", "new")]
+        );
+
+        // Files generated with other options are not kept
+        let manifest = Manifest::new(&session.with_scale(10.0))
+            .with_files(vec![file("reason", "new")])
+            .merge_previous(&previous, &[Table::Reason]);
+        assert_eq!(manifest.files, vec![file("reason", "new")]);
+    }
+}
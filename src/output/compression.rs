@@ -0,0 +1,179 @@
+use crate::error::Result;
+use crate::TpcdsError;
+use flate2::write::GzEncoder;
+use lz4_flex::frame::FrameEncoder;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// Compression codec applied to generated data files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Compression {
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+    Lz4,
+}
+
+impl Compression {
+    /// Default zstd level (same as the zstd command line tool)
+    const ZSTD_LEVEL: i32 = 3;
+
+    /// Get the codec name as used on the command line
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Compression::None => "none",
+            Compression::Gzip => "gzip",
+            Compression::Zstd => "zstd",
+            Compression::Lz4 => "lz4",
+        }
+    }
+
+    /// Get the suffix appended to the data file name (e.g. `.dat` becomes `.dat.gz`)
+    pub fn get_suffix(&self) -> &'static str {
+        match self {
+            Compression::None => "",
+            Compression::Gzip => ".gz",
+            Compression::Zstd => ".zst",
+            Compression::Lz4 => ".lz4",
+        }
+    }
+
+    /// Wrap a writer so that everything written to it is compressed with this codec
+    pub fn wrap<W: Write>(&self, writer: W) -> Result<CompressedWriter<W>> {
+        Ok(match self {
+            Compression::None => CompressedWriter::Uncompressed(writer),
+            Compression::Gzip => {
+                CompressedWriter::Gzip(GzEncoder::new(writer, flate2::Compression::default()))
+            }
+            Compression::Zstd => {
+                CompressedWriter::Zstd(zstd::Encoder::new(writer, Self::ZSTD_LEVEL)?)
+            }
+            Compression::Lz4 => CompressedWriter::Lz4(FrameEncoder::new(writer)),
+        })
+    }
+}
+
+impl FromStr for Compression {
+    type Err = TpcdsError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(Compression::None),
+            "gzip" => Ok(Compression::Gzip),
+            "zstd" => Ok(Compression::Zstd),
+            "lz4" => Ok(Compression::Lz4),
+            _ => Err(TpcdsError::new(&format!("Invalid compression: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// Writer that compresses its output with one of the supported codecs.
+/// `finish` must be called to write the codec trailer.
+pub enum CompressedWriter<W: Write> {
+    Uncompressed(W),
+    Gzip(GzEncoder<W>),
+    Zstd(zstd::Encoder<'static, W>),
+    Lz4(FrameEncoder<W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    /// Flush any buffered data, write the codec trailer and return the inner writer
+    pub fn finish(self) -> io::Result<W> {
+        let mut writer = match self {
+            CompressedWriter::Uncompressed(writer) => writer,
+            CompressedWriter::Gzip(encoder) => encoder.finish()?,
+            CompressedWriter::Zstd(encoder) => encoder.finish()?,
+            CompressedWriter::Lz4(encoder) => encoder.finish().map_err(io::Error::other)?,
+        };
+        writer.flush()?;
+        Ok(writer)
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            CompressedWriter::Uncompressed(writer) => writer.write(buf),
+            CompressedWriter::Gzip(encoder) => encoder.write(buf),
+            CompressedWriter::Zstd(encoder) => encoder.write(buf),
+            CompressedWriter::Lz4(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            CompressedWriter::Uncompressed(writer) => writer.flush(),
+            CompressedWriter::Gzip(encoder) => encoder.flush(),
+            CompressedWriter::Zstd(encoder) => encoder.flush(),
+            CompressedWriter::Lz4(encoder) => encoder.flush(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    const DATA: &[u8] = b"1|AAAAAAAABAAAAAAA|1998-01-01||\n2|AAAAAAAACAAAAAAA|1998-01-01||\n";
+
+    fn compress(compression: Compression) -> Vec<u8> {
+        let mut writer = compression.wrap(Vec::new()).unwrap();
+        writer.write_all(DATA).unwrap();
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("none".parse::<Compression>().unwrap(), Compression::None);
+        assert_eq!("GZIP".parse::<Compression>().unwrap(), Compression::Gzip);
+        assert_eq!("zstd".parse::<Compression>().unwrap(), Compression::Zstd);
+        assert_eq!("lz4".parse::<Compression>().unwrap(), Compression::Lz4);
+        assert!("bzip2".parse::<Compression>().is_err());
+    }
+
+    #[test]
+    fn test_suffixes() {
+        assert_eq!(Compression::None.get_suffix(), "");
+        assert_eq!(Compression::Gzip.get_suffix(), ".gz");
+        assert_eq!(Compression::Zstd.get_suffix(), ".zst");
+        assert_eq!(Compression::Lz4.get_suffix(), ".lz4");
+    }
+
+    #[test]
+    fn test_uncompressed_round_trip() {
+        assert_eq!(compress(Compression::None), DATA);
+    }
+
+    #[test]
+    fn test_gzip_round_trip() {
+        let mut decoded = Vec::new();
+        flate2::read::GzDecoder::new(compress(Compression::Gzip).as_slice())
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, DATA);
+    }
+
+    #[test]
+    fn test_zstd_round_trip() {
+        let decoded = zstd::decode_all(compress(Compression::Zstd).as_slice()).unwrap();
+        assert_eq!(decoded, DATA);
+    }
+
+    #[test]
+    fn test_lz4_round_trip() {
+        let mut decoded = Vec::new();
+        lz4_flex::frame::FrameDecoder::new(compress(Compression::Lz4).as_slice())
+            .read_to_end(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, DATA);
+    }
+}
@@ -0,0 +1,152 @@
+use crate::config::Session;
+use crate::error::Result;
+use crate::output::{JsonlRowFormatter, PgCopyRowFormatter, SqlRowFormatter, TextRowFormatter};
+use crate::table::Table;
+use crate::TpcdsError;
+use std::fmt;
+use std::io::{self, Write};
+use std::str::FromStr;
+
+/// Layout of the rows written to the data files
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OutputFormat {
+    /// Separator delimited flat files, byte-for-byte compatible with dsdgen
+    #[default]
+    Text,
+    /// Batched `INSERT INTO` statements
+    Sql,
+    /// One JSON object per row (JSON Lines / NDJSON)
+    Jsonl,
+    /// PostgreSQL binary COPY format
+    PgCopy,
+}
+
+impl OutputFormat {
+    /// Get the format name as used on the command line
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => "text",
+            OutputFormat::Sql => "sql",
+            OutputFormat::Jsonl => "jsonl",
+            OutputFormat::PgCopy => "pgcopy",
+        }
+    }
+
+    /// Get the file suffix used when `--suffix` is not given
+    pub fn get_suffix(&self) -> &'static str {
+        match self {
+            OutputFormat::Text => ".dat",
+            OutputFormat::Sql => ".sql",
+            OutputFormat::Jsonl => ".jsonl",
+            OutputFormat::PgCopy => ".pgcopy",
+        }
+    }
+
+    /// Create the formatter for the rows of `table`
+    pub fn create_formatter(&self, table: Table, session: &Session) -> Box<dyn RowFormatter> {
+        match self {
+            OutputFormat::Text => Box::new(TextRowFormatter::new(
+                session.get_separator(),
+                session.terminate_rows_with_separator(),
+                session.get_null_string(),
+            )),
+            OutputFormat::Sql => Box::new(SqlRowFormatter::new(
+                table,
+                session.get_sql_batch_size() as usize,
+                session.get_null_string(),
+            )),
+            OutputFormat::Jsonl => Box::new(JsonlRowFormatter::new(
+                table,
+                session.json_decimals_as_strings(),
+            )),
+            OutputFormat::PgCopy => Box::new(PgCopyRowFormatter::new(table)),
+        }
+    }
+}
+
+impl FromStr for OutputFormat {
+    type Err = TpcdsError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(OutputFormat::Text),
+            "sql" => Ok(OutputFormat::Sql),
+            "jsonl" | "ndjson" => Ok(OutputFormat::Jsonl),
+            "pgcopy" => Ok(OutputFormat::PgCopy),
+            _ => Err(TpcdsError::new(&format!("Invalid format: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for OutputFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// Formats the rows of one table chunk into an output stream
+pub trait RowFormatter: Send {
+    /// Write whatever has to precede the first row
+    fn start(&mut self, _out: &mut dyn Write) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Write a single row. Values are formatted as for the flat files, NULL being an empty value.
+    /// Text and SQL output write NULL as the session's null string; JSON Lines and the binary
+    /// COPY format have their own NULL encoding.
+    fn write_row(&mut self, out: &mut dyn Write, values: &[String]) -> Result<()>;
+
+    /// Write whatever has to follow the last row
+    fn finish(&mut self, _out: &mut dyn Write) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Check that a row has one value per column of its table. Formats that name or type their
+/// fields by column cannot write any other row.
+pub fn check_value_count(values: &[String], column_count: usize) -> Result<()> {
+    if values.len() != column_count {
+        return Err(TpcdsError::invalid_state(&format!(
+            "Row has {} values, its table has {} columns",
+            values.len(),
+            column_count
+        )));
+    }
+    Ok(())
+}
+
+/// Check if a generated value is NULL. Like the flat files, rows represent NULL as an empty
+/// value; no TPC-DS column holds an empty string that is not NULL.
+pub fn is_null(value: &str) -> bool {
+    value.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_parsing() {
+        assert_eq!("text".parse::<OutputFormat>().unwrap(), OutputFormat::Text);
+        assert_eq!("SQL".parse::<OutputFormat>().unwrap(), OutputFormat::Sql);
+        assert_eq!(
+            "ndjson".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Jsonl
+        );
+        assert_eq!(
+            "pgcopy".parse::<OutputFormat>().unwrap(),
+            OutputFormat::PgCopy
+        );
+        assert!("csv".parse::<OutputFormat>().is_err());
+        assert_eq!(OutputFormat::default(), OutputFormat::Text);
+        assert_eq!(OutputFormat::Sql.to_string(), "sql");
+    }
+
+    #[test]
+    fn test_format_suffix() {
+        assert_eq!(OutputFormat::Text.get_suffix(), ".dat");
+        assert_eq!(OutputFormat::Sql.get_suffix(), ".sql");
+        assert_eq!(OutputFormat::Jsonl.get_suffix(), ".jsonl");
+        assert_eq!(OutputFormat::PgCopy.get_suffix(), ".pgcopy");
+    }
+}
@@ -0,0 +1,159 @@
+use crate::column::{ColumnType, ColumnTypeBase};
+use crate::error::Result;
+use crate::output::{check_value_count, is_null, RowFormatter};
+use crate::table::Table;
+use std::fmt::Write as _;
+use std::io::Write;
+
+/// Writes one JSON object per line, keyed by column name.
+/// Numbers are JSON numbers, NULL is `null` and every other type is a string.
+pub struct JsonlRowFormatter {
+    keys: Vec<String>,
+    column_types: Vec<&'static ColumnType>,
+    decimals_as_strings: bool,
+    line: String,
+}
+
+impl JsonlRowFormatter {
+    pub fn new(table: Table, decimals_as_strings: bool) -> Self {
+        let columns = table.get_columns();
+        Self {
+            keys: columns
+                .iter()
+                .map(|column| {
+                    let mut key = String::new();
+                    Self::push_string(&mut key, column.get_name());
+                    key
+                })
+                .collect(),
+            column_types: columns.iter().map(|column| column.get_type()).collect(),
+            decimals_as_strings,
+            line: String::new(),
+        }
+    }
+
+    /// Append `value` as the JSON value for a column of type `column_type`
+    fn push_value(
+        line: &mut String,
+        value: &str,
+        column_type: &ColumnType,
+        decimals_as_strings: bool,
+    ) {
+        if is_null(value) {
+            line.push_str("null");
+            return;
+        }
+        match column_type.get_base() {
+            ColumnTypeBase::Integer | ColumnTypeBase::Identifier => line.push_str(value),
+            ColumnTypeBase::Decimal if !decimals_as_strings => line.push_str(value),
+            _ => Self::push_string(line, value),
+        }
+    }
+
+    /// Append `value` as a JSON string literal
+    fn push_string(line: &mut String, value: &str) {
+        line.push('"');
+        for c in value.chars() {
+            match c {
+                '"' => line.push_str("\\\""),
+                '\\' => line.push_str("\\\\"),
+                '\n' => line.push_str("\\n"),
+                '\r' => line.push_str("\\r"),
+                '\t' => line.push_str("\\t"),
+                c if c.is_control() => {
+                    let _ = write!(line, "\\u{:04x}", c as u32);
+                }
+                c => line.push(c),
+            }
+        }
+        line.push('"');
+    }
+}
+
+impl RowFormatter for JsonlRowFormatter {
+    fn write_row(&mut self, out: &mut dyn Write, values: &[String]) -> Result<()> {
+        check_value_count(values, self.column_types.len())?;
+        self.line.clear();
+        self.line.push('{');
+        let columns = self.keys.iter().zip(&self.column_types);
+        for (i, (value, (key, column_type))) in values.iter().zip(columns).enumerate() {
+            if i > 0 {
+                self.line.push(',');
+            }
+            self.line.push_str(key);
+            self.line.push(':');
+            Self::push_value(&mut self.line, value, column_type, self.decimals_as_strings);
+        }
+        self.line.push_str("}\n");
+        out.write_all(self.line.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format_row(table: Table, decimals_as_strings: bool, values: &[&str]) -> String {
+        let mut formatter = JsonlRowFormatter::new(table, decimals_as_strings);
+        let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        let mut out = Vec::new();
+        formatter.write_row(&mut out, &values).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_values_follow_column_types() {
+        assert_eq!(
+            format_row(
+                Table::Reason,
+                false,
+                &["1", "AAAAAAAABAAAAAAA", "say \"hi\"\\"]
+            ),
+            "{\"r_reason_sk\":1,\"r_reason_id\":\"AAAAAAAABAAAAAAA\",\
+             \"r_reason_desc\":\"say \\\"hi\\\"\\\\\"}\n"
+        );
+        assert_eq!(
+            format_row(Table::IncomeBand, false, &["1", "", "10000"]),
+            "{\"ib_income_band_sk\":1,\"ib_lower_bound\":null,\"ib_upper_bound\":10000}\n"
+        );
+    }
+
+    #[test]
+    fn test_decimals() {
+        let values = [
+            "1",
+            "AAAAAAAABAAAAAAA",
+            "Conventional childr",
+            "977787",
+            "651",
+            "6th ",
+            "Parkway",
+            "Suite 470",
+            "Fairview",
+            "Williamson County",
+            "TN",
+            "35709",
+            "United States",
+            "-5.00",
+        ];
+        assert!(
+            format_row(Table::Warehouse, false, &values).ends_with(",\"w_gmt_offset\":-5.00}\n")
+        );
+        assert!(
+            format_row(Table::Warehouse, true, &values).ends_with(",\"w_gmt_offset\":\"-5.00\"}\n")
+        );
+    }
+
+    #[test]
+    fn test_value_count_must_match_columns() {
+        let mut formatter = JsonlRowFormatter::new(Table::Reason, false);
+        for values in [vec!["1", "AAAAAAAABAAAAAAA"], vec!["1", "A", "x", "extra"]] {
+            let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+            let mut out = Vec::new();
+            let error = formatter.write_row(&mut out, &values).unwrap_err();
+            assert!(matches!(error, crate::TpcdsError::InvalidState(_)));
+            assert!(out.is_empty());
+        }
+    }
+}
@@ -0,0 +1,15 @@
+pub mod compression;
+pub mod format;
+pub mod jsonl;
+pub mod pgcopy;
+pub mod sql;
+pub mod table_writer;
+pub mod text;
+
+pub use compression::{CompressedWriter, Compression};
+pub use format::{check_value_count, is_null, OutputFormat, RowFormatter};
+pub use jsonl::JsonlRowFormatter;
+pub use pgcopy::PgCopyRowFormatter;
+pub use sql::SqlRowFormatter;
+pub use table_writer::{FileDigest, TableWriter};
+pub use text::TextRowFormatter;
@@ -0,0 +1,276 @@
+use crate::column::{ColumnType, ColumnTypeBase};
+use crate::error::Result;
+use crate::output::{check_value_count, is_null, RowFormatter};
+use crate::table::Table;
+use crate::types::Date;
+use std::io::{self, Write};
+
+/// Writes rows in the PostgreSQL binary COPY format (`COPY ... FROM ... WITH (FORMAT binary)`).
+/// Field encodings follow the column types: identifiers are `bigint`, integers are `integer`,
+/// decimals are `numeric`, dates are `date`, times are `time` and strings are `text`.
+pub struct PgCopyRowFormatter {
+    column_types: Vec<&'static ColumnType>,
+    buffer: Vec<u8>,
+}
+
+impl PgCopyRowFormatter {
+    /// File signature followed by the flags field and the header extension length
+    const HEADER: &'static [u8] = b"PGCOPY\n\xff\r\n\0\0\0\0\0\0\0\0\0";
+    /// Field count that marks the end of the data
+    const TRAILER: i16 = -1;
+    /// Julian day of 2000-01-01, the PostgreSQL date epoch
+    const POSTGRES_EPOCH_JULIAN_DAYS: i32 = 2451545;
+    const NUMERIC_POSITIVE: i16 = 0x0000;
+    const NUMERIC_NEGATIVE: i16 = 0x4000;
+    const NUMERIC_DIGITS_PER_GROUP: usize = 4;
+
+    pub fn new(table: Table) -> Self {
+        Self {
+            column_types: table
+                .get_columns()
+                .iter()
+                .map(|column| column.get_type())
+                .collect(),
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Append the length prefixed binary encoding of `value`
+    fn push_field(buffer: &mut Vec<u8>, value: &str, column_type: &ColumnType) -> io::Result<()> {
+        if is_null(value) {
+            buffer.extend_from_slice(&(-1i32).to_be_bytes());
+            return Ok(());
+        }
+
+        let length_position = buffer.len();
+        buffer.extend_from_slice(&0i32.to_be_bytes());
+        match column_type.get_base() {
+            ColumnTypeBase::Identifier => {
+                buffer.extend_from_slice(&parse::<i64>(value)?.to_be_bytes())
+            }
+            ColumnTypeBase::Integer => {
+                buffer.extend_from_slice(&parse::<i32>(value)?.to_be_bytes())
+            }
+            ColumnTypeBase::Decimal => Self::push_numeric(buffer, value)?,
+            ColumnTypeBase::Date => {
+                let days = parse_date(value)?.to_julian_days() - Self::POSTGRES_EPOCH_JULIAN_DAYS;
+                buffer.extend_from_slice(&days.to_be_bytes());
+            }
+            ColumnTypeBase::Time => {
+                buffer.extend_from_slice(&parse_time_micros(value)?.to_be_bytes())
+            }
+            ColumnTypeBase::Varchar | ColumnTypeBase::Char => {
+                buffer.extend_from_slice(value.as_bytes())
+            }
+        }
+
+        let length = (buffer.len() - length_position - 4) as i32;
+        buffer[length_position..length_position + 4].copy_from_slice(&length.to_be_bytes());
+        Ok(())
+    }
+
+    /// Append a decimal string as a PostgreSQL `numeric`: digit count, weight, sign and
+    /// display scale, followed by the base 10000 digits
+    fn push_numeric(buffer: &mut Vec<u8>, value: &str) -> io::Result<()> {
+        let (sign, unsigned) = match value.strip_prefix('-') {
+            Some(unsigned) => (Self::NUMERIC_NEGATIVE, unsigned),
+            None => (Self::NUMERIC_POSITIVE, value),
+        };
+        let (integer_part, fraction_part) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if integer_part.is_empty() && fraction_part.is_empty()
+            || !integer_part
+                .chars()
+                .chain(fraction_part.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(invalid_value("numeric", value));
+        }
+
+        // Group the integer digits from the decimal point to the left and the fraction
+        // digits from the decimal point to the right
+        let group = |digits: &str| digits.parse::<i16>().unwrap_or(0);
+        let integer_part = integer_part.trim_start_matches('0');
+        let leading = integer_part.len() % Self::NUMERIC_DIGITS_PER_GROUP;
+        let mut groups = Vec::new();
+        if leading > 0 {
+            groups.push(group(&integer_part[..leading]));
+        }
+        for start in (leading..integer_part.len()).step_by(Self::NUMERIC_DIGITS_PER_GROUP) {
+            groups.push(group(
+                &integer_part[start..start + Self::NUMERIC_DIGITS_PER_GROUP],
+            ));
+        }
+        let mut weight = groups.len() as i16 - 1;
+        for start in (0..fraction_part.len()).step_by(Self::NUMERIC_DIGITS_PER_GROUP) {
+            let end = (start + Self::NUMERIC_DIGITS_PER_GROUP).min(fraction_part.len());
+            let padding = Self::NUMERIC_DIGITS_PER_GROUP - (end - start);
+            groups.push(group(&fraction_part[start..end]) * 10i16.pow(padding as u32));
+        }
+
+        // Leading and trailing zero groups are implied by the weight and the digit count
+        let leading_zeros = groups.iter().take_while(|&&digit| digit == 0).count();
+        groups.drain(..leading_zeros);
+        weight -= leading_zeros as i16;
+        while groups.last() == Some(&0) {
+            groups.pop();
+        }
+        let (sign, weight) = if groups.is_empty() {
+            (Self::NUMERIC_POSITIVE, 0)
+        } else {
+            (sign, weight)
+        };
+
+        buffer.extend_from_slice(&(groups.len() as i16).to_be_bytes());
+        buffer.extend_from_slice(&weight.to_be_bytes());
+        buffer.extend_from_slice(&sign.to_be_bytes());
+        buffer.extend_from_slice(&(fraction_part.len() as i16).to_be_bytes());
+        for digit in groups {
+            buffer.extend_from_slice(&digit.to_be_bytes());
+        }
+        Ok(())
+    }
+}
+
+impl RowFormatter for PgCopyRowFormatter {
+    fn start(&mut self, out: &mut dyn Write) -> io::Result<()> {
+        out.write_all(Self::HEADER)
+    }
+
+    fn write_row(&mut self, out: &mut dyn Write, values: &[String]) -> Result<()> {
+        check_value_count(values, self.column_types.len())?;
+        self.buffer.clear();
+        self.buffer
+            .extend_from_slice(&(values.len() as i16).to_be_bytes());
+        for (value, column_type) in values.iter().zip(&self.column_types) {
+            Self::push_field(&mut self.buffer, value, column_type)?;
+        }
+        out.write_all(&self.buffer)?;
+        Ok(())
+    }
+
+    fn finish(&mut self, out: &mut dyn Write) -> io::Result<()> {
+        out.write_all(&Self::TRAILER.to_be_bytes())
+    }
+}
+
+fn invalid_value(type_name: &str, value: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("Invalid {} value for binary COPY: '{}'", type_name, value),
+    )
+}
+
+fn parse<T: std::str::FromStr>(value: &str) -> io::Result<T> {
+    value
+        .parse()
+        .map_err(|_| invalid_value(std::any::type_name::<T>(), value))
+}
+
+/// Parse a `YYYY-MM-DD` date as formatted by `Date`
+fn parse_date(value: &str) -> io::Result<Date> {
+    let parts: Vec<&str> = value.split('-').collect();
+    if parts.len() != 3 {
+        return Err(invalid_value("date", value));
+    }
+    Date::new_validated(parse(parts[0])?, parse(parts[1])?, parse(parts[2])?)
+        .map_err(|_| invalid_value("date", value))
+}
+
+/// Parse a `HH:MM:SS` time into microseconds since midnight
+fn parse_time_micros(value: &str) -> io::Result<i64> {
+    let parts: Vec<&str> = value.split(':').collect();
+    if parts.len() != 3 {
+        return Err(invalid_value("time", value));
+    }
+    let seconds =
+        parse::<i64>(parts[0])? * 3600 + parse::<i64>(parts[1])? * 60 + parse::<i64>(parts[2])?;
+    Ok(seconds * 1_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn numeric(value: &str) -> Vec<i16> {
+        let mut buffer = Vec::new();
+        PgCopyRowFormatter::push_numeric(&mut buffer, value).unwrap();
+        buffer
+            .chunks(2)
+            .map(|pair| i16::from_be_bytes([pair[0], pair[1]]))
+            .collect()
+    }
+
+    #[test]
+    fn test_numeric_encoding() {
+        // ndigits, weight, sign, dscale, digits...
+        assert_eq!(numeric("0.11"), vec![1, -1, 0x0000, 2, 1100]);
+        assert_eq!(numeric("-5"), vec![1, 0, 0x4000, 0, 5]);
+        assert_eq!(numeric("-5.00"), vec![1, 0, 0x4000, 2, 5]);
+        assert_eq!(numeric("123456.7"), vec![3, 1, 0x0000, 1, 12, 3456, 7000]);
+        assert_eq!(numeric("10000"), vec![1, 1, 0x0000, 0, 1]);
+        assert_eq!(numeric("0.00001"), vec![1, -2, 0x0000, 5, 1000]);
+        assert_eq!(numeric("0.00"), vec![0, 0, 0x0000, 2]);
+
+        let mut buffer = Vec::new();
+        assert!(PgCopyRowFormatter::push_numeric(&mut buffer, "1e5").is_err());
+    }
+
+    #[test]
+    fn test_binary_copy_layout() {
+        let mut formatter = PgCopyRowFormatter::new(Table::WebPage);
+        let values: Vec<String> = [
+            "1",
+            "AAAAAAAABAAAAAAA",
+            "2000-01-02",
+            "",
+            "2450810",
+            "2452620",
+            "Y",
+            "",
+            "http://www.foo.com",
+            "welcome",
+            "2531",
+            "8",
+            "3",
+            "4",
+        ]
+        .iter()
+        .map(|value| value.to_string())
+        .collect();
+
+        let mut out = Vec::new();
+        formatter.start(&mut out).unwrap();
+        formatter.write_row(&mut out, &values).unwrap();
+        formatter.finish(&mut out).unwrap();
+
+        assert_eq!(&out[..11], b"PGCOPY\n\xff\r\n\0");
+        let tuple = &out[19..];
+        assert_eq!(&tuple[..2], &14i16.to_be_bytes());
+        // wp_web_page_sk: bigint
+        assert_eq!(&tuple[2..6], &8i32.to_be_bytes());
+        assert_eq!(&tuple[6..14], &1i64.to_be_bytes());
+        // wp_web_page_id: text
+        assert_eq!(&tuple[14..18], &16i32.to_be_bytes());
+        assert_eq!(&tuple[18..34], b"AAAAAAAABAAAAAAA");
+        // wp_rec_start_date: one day after the PostgreSQL epoch
+        assert_eq!(&tuple[34..38], &4i32.to_be_bytes());
+        assert_eq!(&tuple[38..42], &1i32.to_be_bytes());
+        // wp_rec_end_date: NULL
+        assert_eq!(&tuple[42..46], &(-1i32).to_be_bytes());
+        assert_eq!(&out[out.len() - 2..], &(-1i16).to_be_bytes());
+    }
+
+    #[test]
+    fn test_invalid_values_are_errors() {
+        let mut formatter = PgCopyRowFormatter::new(Table::IncomeBand);
+        let values = vec!["1".to_string(), "x".to_string(), "2".to_string()];
+        assert!(formatter.write_row(&mut Vec::new(), &values).is_err());
+
+        // The field count of a row must match the table
+        let values = vec!["1".to_string(), "2".to_string()];
+        assert!(matches!(
+            formatter.write_row(&mut Vec::new(), &values),
+            Err(crate::TpcdsError::InvalidState(_))
+        ));
+    }
+}
@@ -0,0 +1,213 @@
+use crate::column::{ColumnType, ColumnTypeBase};
+use crate::error::Result;
+use crate::output::{check_value_count, is_null, RowFormatter};
+use crate::table::Table;
+use std::io::{self, Write};
+
+/// Writes rows as batched `INSERT INTO <table> (...) VALUES (...), ...;` statements.
+/// Literals are quoted according to the column types.
+pub struct SqlRowFormatter {
+    insert_prefix: String,
+    column_types: Vec<&'static ColumnType>,
+    batch_size: usize,
+    rows_in_batch: usize,
+    /// Written in place of NULL values, `NULL` unless `--null` is given
+    null_literal: String,
+    line: String,
+}
+
+impl SqlRowFormatter {
+    /// Literal written for NULL values when the session has no null string
+    pub const DEFAULT_NULL_LITERAL: &'static str = "NULL";
+
+    pub fn new(table: Table, batch_size: usize, null_string: &str) -> Self {
+        let columns = table.get_columns();
+        let column_names: Vec<&str> = columns.iter().map(|column| column.get_name()).collect();
+        Self {
+            insert_prefix: format!(
+                "INSERT INTO {} ({}) VALUES\n",
+                table.get_name(),
+                column_names.join(", ")
+            ),
+            column_types: columns.iter().map(|column| column.get_type()).collect(),
+            batch_size: batch_size.max(1),
+            rows_in_batch: 0,
+            null_literal: if null_string.is_empty() {
+                Self::DEFAULT_NULL_LITERAL.to_string()
+            } else {
+                null_string.to_string()
+            },
+            line: String::new(),
+        }
+    }
+
+    /// Append `value` as a SQL literal of type `column_type`
+    fn push_literal(line: &mut String, value: &str, column_type: &ColumnType, null_literal: &str) {
+        if is_null(value) {
+            line.push_str(null_literal);
+            return;
+        }
+        match column_type.get_base() {
+            ColumnTypeBase::Integer | ColumnTypeBase::Identifier | ColumnTypeBase::Decimal => {
+                line.push_str(value)
+            }
+            ColumnTypeBase::Varchar | ColumnTypeBase::Char => Self::push_quoted(line, value),
+            ColumnTypeBase::Date => {
+                line.push_str("DATE ");
+                Self::push_quoted(line, value);
+            }
+            ColumnTypeBase::Time => {
+                line.push_str("TIME ");
+                Self::push_quoted(line, value);
+            }
+        }
+    }
+
+    /// Append `value` as a string literal, doubling embedded quotes
+    fn push_quoted(line: &mut String, value: &str) {
+        line.push('\'');
+        for c in value.chars() {
+            if c == '\'' {
+                line.push('\'');
+            }
+            line.push(c);
+        }
+        line.push('\'');
+    }
+}
+
+impl RowFormatter for SqlRowFormatter {
+    fn write_row(&mut self, out: &mut dyn Write, values: &[String]) -> Result<()> {
+        check_value_count(values, self.column_types.len())?;
+        self.line.clear();
+        if self.rows_in_batch == 0 {
+            self.line.push_str(&self.insert_prefix);
+        } else {
+            self.line.push_str(",\n");
+        }
+
+        self.line.push('(');
+        for (i, (value, column_type)) in values.iter().zip(&self.column_types).enumerate() {
+            if i > 0 {
+                self.line.push_str(", ");
+            }
+            Self::push_literal(&mut self.line, value, column_type, &self.null_literal);
+        }
+        self.line.push(')');
+
+        self.rows_in_batch += 1;
+        if self.rows_in_batch == self.batch_size {
+            self.line.push_str(";\n");
+            self.rows_in_batch = 0;
+        }
+        out.write_all(self.line.as_bytes())?;
+        Ok(())
+    }
+
+    fn finish(&mut self, out: &mut dyn Write) -> io::Result<()> {
+        if self.rows_in_batch > 0 {
+            self.rows_in_batch = 0;
+            out.write_all(b";\n")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format_rows(table: Table, batch_size: usize, rows: &[&[&str]]) -> String {
+        format_rows_with_null(table, batch_size, "", rows)
+    }
+
+    fn format_rows_with_null(
+        table: Table,
+        batch_size: usize,
+        null_string: &str,
+        rows: &[&[&str]],
+    ) -> String {
+        let mut formatter = SqlRowFormatter::new(table, batch_size, null_string);
+        let mut out = Vec::new();
+        for row in rows {
+            let values: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+            formatter.write_row(&mut out, &values).unwrap();
+        }
+        formatter.finish(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_literals_follow_column_types() {
+        let sql = format_rows(
+            Table::WebPage,
+            10,
+            &[&[
+                "1",
+                "AAAAAAAABAAAAAAA",
+                "1997-09-03",
+                "",
+                "2450810",
+                "2452620",
+                "Y",
+                "",
+                "http://www.foo.com",
+                "it's",
+                "2531",
+                "8",
+                "3",
+                "4",
+            ]],
+        );
+        assert_eq!(
+            sql,
+            "INSERT INTO web_page (wp_web_page_sk, wp_web_page_id, wp_rec_start_date, \
+             wp_rec_end_date, wp_creation_date_sk, wp_access_date_sk, wp_autogen_flag, \
+             wp_customer_sk, wp_url, wp_type, wp_char_count, wp_link_count, wp_image_count, \
+             wp_max_ad_count) VALUES\n\
+             (1, 'AAAAAAAABAAAAAAA', DATE '1997-09-03', NULL, 2450810, 2452620, 'Y', NULL, \
+             'http://www.foo.com', 'it''s', 2531, 8, 3, 4);\n"
+        );
+    }
+
+    #[test]
+    fn test_batching() {
+        let rows: &[&[&str]] = &[
+            &["1", "0", "10000"],
+            &["2", "10001", "20000"],
+            &["3", "", ""],
+        ];
+        let sql = format_rows(Table::IncomeBand, 2, rows);
+        assert_eq!(
+            sql,
+            "INSERT INTO income_band (ib_income_band_sk, ib_lower_bound, ib_upper_bound) VALUES\n\
+             (1, 0, 10000),\n\
+             (2, 10001, 20000);\n\
+             INSERT INTO income_band (ib_income_band_sk, ib_lower_bound, ib_upper_bound) VALUES\n\
+             (3, NULL, NULL);\n"
+        );
+    }
+
+    #[test]
+    fn test_null_string() {
+        let sql = format_rows_with_null(Table::IncomeBand, 10, "DEFAULT", &[&["1", "", "10000"]]);
+        assert!(sql.ends_with("(1, DEFAULT, 10000);\n"));
+    }
+
+    #[test]
+    fn test_value_count_must_match_columns() {
+        let mut formatter = SqlRowFormatter::new(Table::IncomeBand, 10, "");
+        let mut out = Vec::new();
+        let values = vec!["1".to_string(), "2".to_string()];
+        assert!(matches!(
+            formatter.write_row(&mut out, &values),
+            Err(crate::TpcdsError::InvalidState(_))
+        ));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_empty_chunk_writes_nothing() {
+        assert_eq!(format_rows(Table::Reason, 100, &[]), "");
+    }
+}
@@ -0,0 +1,324 @@
+use crate::config::Session;
+use crate::error::Result;
+use crate::output::{CompressedWriter, RowFormatter};
+use crate::table::Table;
+use crate::TpcdsError;
+use sha2::{Digest, Sha256};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Writes the rows of one table chunk to its data file (TableGenerator file writer).
+/// Files are written under a temporary name and renamed when finished, so that an interrupted
+/// run never leaves a truncated file under the name of a complete one.
+pub struct TableWriter {
+    writer: CompressedWriter<BufWriter<DigestWriter>>,
+    path: PathBuf,
+    /// File being written until `finish` renames it to `path`, if any
+    temp_path: Option<PathBuf>,
+    formatter: Box<dyn RowFormatter>,
+    /// Bytes written to the file so far, after compression
+    bytes_written: Arc<AtomicU64>,
+}
+
+impl TableWriter {
+    /// Create the data file for `table` in the session's target directory, or write to
+    /// stdout when the target directory is `-`.
+    /// Fails if the file already exists and the session does not allow overwriting.
+    pub fn create(table: Table, session: &Session) -> Result<Self> {
+        let (output, path, temp_path): (Box<dyn Write + Send>, PathBuf, Option<PathBuf>) =
+            if session.write_to_stdout() {
+                (Box::new(io::stdout()), PathBuf::from("-"), None)
+            } else {
+                let path = Self::get_path(table, session);
+                if is_fifo(&path) {
+                    let fifo = OpenOptions::new().write(true).open(&path)?;
+                    (Box::new(fifo), path, None)
+                } else {
+                    let temp_path = Self::get_temp_path(&path);
+                    let file = Self::create_file(&path, &temp_path, session)?;
+                    (Box::new(file), path, Some(temp_path))
+                }
+            };
+
+        let bytes_written = Arc::new(AtomicU64::new(0));
+        let output = DigestWriter {
+            inner: output,
+            count: Arc::clone(&bytes_written),
+            hasher: Sha256::new(),
+        };
+        let mut writer = session.get_compression().wrap(BufWriter::new(output))?;
+        let mut formatter = session.get_format().create_formatter(table, session);
+        formatter.start(&mut writer)?;
+
+        Ok(Self {
+            writer,
+            path,
+            temp_path,
+            formatter,
+            bytes_written,
+        })
+    }
+
+    /// Get the data file path for `table` (getPath).
+    /// With parallelism > 1 the chunk number and chunk count are part of the file name.
+    /// Refresh tables are named after their refresh, e.g. `s_call_center_1.dat`.
+    pub fn get_path(table: Table, session: &Session) -> PathBuf {
+        let name = if table.is_refresh_table() {
+            format!("{}_{}", table.get_name(), session.get_update())
+        } else {
+            table.get_name().to_string()
+        };
+        let file_name = if session.get_parallelism() > 1 {
+            format!(
+                "{}_{}_{}{}{}",
+                name,
+                session.get_chunk_number(),
+                session.get_parallelism(),
+                session.get_suffix(),
+                session.get_compression().get_suffix()
+            )
+        } else {
+            format!(
+                "{}{}{}",
+                name,
+                session.get_suffix(),
+                session.get_compression().get_suffix()
+            )
+        };
+        PathBuf::from(session.get_target_directory()).join(file_name)
+    }
+
+    /// Get the temporary path a data file is written to, e.g. `date_dim.dat.tmp`.
+    /// A temporary file left behind by an interrupted run is overwritten by the next one.
+    pub fn get_temp_path(path: &Path) -> PathBuf {
+        let mut temp_path = path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        PathBuf::from(temp_path)
+    }
+
+    /// Create the temporary file of the data file at `path`.
+    /// An existing data file is only replaced with `--overwrite`, or with `--resume` which
+    /// regenerates the chunks it could not verify as complete.
+    fn create_file(path: &Path, temp_path: &Path, session: &Session) -> Result<File> {
+        if path.exists() && !session.should_overwrite() && !session.should_resume() {
+            return Err(TpcdsError::new(&format!(
+                "File {} exists.  Remove it or run with the '--overwrite' option",
+                path.display()
+            )));
+        }
+        File::create(temp_path)
+            .map_err(|e| TpcdsError::io(&format!("Failed to create {}", temp_path.display()), e))
+    }
+
+    /// Get the path of the file being written (`-` for stdout)
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    /// Format and write a single row in the session's output format
+    pub fn write_row(&mut self, values: &[String]) -> Result<()> {
+        self.formatter.write_row(&mut self.writer, values)?;
+        Ok(())
+    }
+
+    /// Get the number of bytes written to the file so far.
+    /// Buffered and compressed data is only counted once it reaches the file.
+    pub fn get_bytes_written(&self) -> u64 {
+        self.bytes_written.load(Ordering::Relaxed)
+    }
+
+    /// Write the end of the output format, finish the compressed stream and move the file to
+    /// its final name. Returns the size and checksum of the file.
+    pub fn finish(mut self) -> Result<FileDigest> {
+        self.formatter.finish(&mut self.writer)?;
+        let output = self
+            .writer
+            .finish()?
+            .into_inner()
+            .map_err(|e| e.into_error())?;
+        let digest = FileDigest {
+            bytes: self.bytes_written.load(Ordering::Relaxed),
+            sha256: format!("{:x}", output.hasher.finalize()),
+        };
+        drop(output.inner);
+
+        if let Some(temp_path) = &self.temp_path {
+            fs::rename(temp_path, &self.path).map_err(|e| {
+                TpcdsError::io(
+                    &format!(
+                        "Failed to rename {} to {}",
+                        temp_path.display(),
+                        self.path.display()
+                    ),
+                    e,
+                )
+            })?;
+        }
+        Ok(digest)
+    }
+}
+
+/// Size and SHA-256 checksum of a written data file, as stored in the manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileDigest {
+    pub bytes: u64,
+    pub sha256: String,
+}
+
+impl FileDigest {
+    /// Read the file at `path` to get its size and checksum
+    pub fn of_file(path: &Path) -> io::Result<Self> {
+        let mut writer = DigestWriter {
+            inner: Box::new(io::sink()),
+            count: Arc::new(AtomicU64::new(0)),
+            hasher: Sha256::new(),
+        };
+        io::copy(&mut File::open(path)?, &mut writer)?;
+        Ok(Self {
+            bytes: writer.count.load(Ordering::Relaxed),
+            sha256: format!("{:x}", writer.hasher.finalize()),
+        })
+    }
+}
+
+/// Counts and hashes the bytes written through it
+struct DigestWriter {
+    inner: Box<dyn Write + Send>,
+    count: Arc<AtomicU64>,
+    hasher: Sha256,
+}
+
+impl Write for DigestWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.count.fetch_add(written as u64, Ordering::Relaxed);
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(unix)]
+fn is_fifo(path: &Path) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    std::fs::metadata(path)
+        .map(|metadata| metadata.file_type().is_fifo())
+        .unwrap_or(false)
+}
+
+#[cfg(not(unix))]
+fn is_fifo(_path: &Path) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Compression;
+
+    #[test]
+    fn test_path_single_chunk() {
+        let session = Session::get_default_session();
+        assert_eq!(
+            TableWriter::get_path(Table::CallCenter, &session),
+            PathBuf::from("./call_center.dat")
+        );
+    }
+
+    #[test]
+    fn test_path_with_parallelism_and_compression() {
+        let session = Session::get_default_session()
+            .with_parallelism(4)
+            .with_chunk_number(2)
+            .with_compression(Compression::Zstd);
+        assert_eq!(
+            TableWriter::get_path(Table::DateDim, &session),
+            PathBuf::from("./date_dim_2_4.dat.zst")
+        );
+    }
+
+    #[test]
+    fn test_file_is_renamed_when_finished() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let mut options = crate::config::Options::new();
+        options.directory = dir.to_string_lossy().to_string();
+        let session = options.to_session().unwrap();
+
+        let mut writer = TableWriter::create(Table::Reason, &session).unwrap();
+        writer
+            .write_row(&["1".to_string(), "AAAAAAAABAAAAAAA".to_string()])
+            .unwrap();
+        let path = dir.join("reason.dat");
+        assert!(!path.exists());
+        assert!(TableWriter::get_temp_path(&path).exists());
+
+        writer.finish().unwrap();
+        assert!(!TableWriter::get_temp_path(&path).exists());
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "1|AAAAAAAABAAAAAAA|\n"
+        );
+    }
+
+    #[test]
+    fn test_path_of_refresh_table() {
+        let session = Session::get_default_session().with_update(3);
+        assert_eq!(
+            TableWriter::get_path(Table::SInventory, &session),
+            PathBuf::from("./s_inventory_3.dat")
+        );
+        assert_eq!(
+            TableWriter::get_path(Table::SInventory, &session.with_parallelism(2)),
+            PathBuf::from("./s_inventory_3_1_2.dat")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_to_existing_fifo() {
+        use std::io::Read;
+
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let fifo = dir.join("reason.dat");
+        let status = std::process::Command::new("mkfifo")
+            .arg(&fifo)
+            .status()
+            .unwrap();
+        assert!(status.success());
+
+        let reader_path = fifo.clone();
+        let reader = std::thread::spawn(move || {
+            let mut content = String::new();
+            File::open(reader_path)
+                .unwrap()
+                .read_to_string(&mut content)
+                .unwrap();
+            content
+        });
+
+        // No --overwrite needed: the FIFO is a pre-created destination, not stale output
+        let mut options = crate::config::Options::new();
+        options.directory = dir.to_string_lossy().to_string();
+        let session = options.to_session().unwrap();
+        let mut writer = TableWriter::create(Table::Reason, &session).unwrap();
+        writer
+            .write_row(&["1".to_string(), "AAAAAAAABAAAAAAA".to_string()])
+            .unwrap();
+        let digest = writer.finish().unwrap();
+        assert_eq!(digest.bytes, 20);
+        assert_eq!(
+            digest.sha256,
+            format!("{:x}", Sha256::digest(b"1|AAAAAAAABAAAAAAA|\n"))
+        );
+
+        assert_eq!(reader.join().unwrap(), "1|AAAAAAAABAAAAAAA|\n");
+    }
+}
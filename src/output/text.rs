@@ -0,0 +1,72 @@
+use crate::error::Result;
+use crate::output::{is_null, RowFormatter};
+use std::io::Write;
+
+/// Writes rows as separator delimited lines (TableGenerator.formatRow)
+pub struct TextRowFormatter {
+    separator: char,
+    terminate_rows_with_separator: bool,
+    /// Written in place of NULL values (`--null`)
+    null_string: String,
+    line: String,
+}
+
+impl TextRowFormatter {
+    pub fn new(separator: char, terminate_rows_with_separator: bool, null_string: &str) -> Self {
+        Self {
+            separator,
+            terminate_rows_with_separator,
+            null_string: null_string.to_string(),
+            line: String::new(),
+        }
+    }
+}
+
+impl RowFormatter for TextRowFormatter {
+    fn write_row(&mut self, out: &mut dyn Write, values: &[String]) -> Result<()> {
+        self.line.clear();
+        for (i, value) in values.iter().enumerate() {
+            if is_null(value) {
+                self.line.push_str(&self.null_string);
+            } else {
+                self.line.push_str(value);
+            }
+            if self.terminate_rows_with_separator || i < values.len() - 1 {
+                self.line.push(self.separator);
+            }
+        }
+        self.line.push('\n');
+        out.write_all(self.line.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(formatter: &mut TextRowFormatter, values: &[&str]) -> String {
+        let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        let mut out = Vec::new();
+        formatter.write_row(&mut out, &values).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_terminated_rows() {
+        let mut formatter = TextRowFormatter::new('|', true, "");
+        assert_eq!(format(&mut formatter, &["1", "", "x"]), "1||x|\n");
+    }
+
+    #[test]
+    fn test_unterminated_rows() {
+        let mut formatter = TextRowFormatter::new(',', false, "");
+        assert_eq!(format(&mut formatter, &["1", "", "x"]), "1,,x\n");
+    }
+
+    #[test]
+    fn test_null_string() {
+        let mut formatter = TextRowFormatter::new('|', true, "NULL");
+        assert_eq!(format(&mut formatter, &["1", "", "x"]), "1|NULL|x|\n");
+    }
+}
@@ -0,0 +1,132 @@
+use crate::config::Session;
+use crate::error::Result;
+use crate::table::Table;
+use crate::update;
+
+/// Inclusive range of row numbers generated by one chunk (ChunkBoundaries)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkBoundaries {
+    start_row: i64,
+    end_row: i64,
+}
+
+impl ChunkBoundaries {
+    pub fn new(start_row: i64, end_row: i64) -> Self {
+        Self { start_row, end_row }
+    }
+
+    pub fn get_start_row(&self) -> i64 {
+        self.start_row
+    }
+
+    pub fn get_end_row(&self) -> i64 {
+        self.end_row
+    }
+
+    /// Number of rows in this chunk
+    pub fn get_row_count(&self) -> i64 {
+        (self.end_row - self.start_row + 1).max(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.get_row_count() == 0
+    }
+}
+
+/// Compute the rows the session's chunk is responsible for (Parallel.splitWork).
+/// Small tables are never split: chunk 1 generates all of them and the other chunks get nothing.
+/// Otherwise rows are divided evenly and the last chunk also takes the remainder.
+/// The rows of a refresh table follow the rows of the session's previous refreshes.
+pub fn split_work(table: Table, session: &Session) -> Result<ChunkBoundaries> {
+    let (row_offset, row_count) = if table.is_refresh_table() {
+        let scaling = session.get_scaling();
+        (
+            update::get_row_offset(table, scaling, session.get_update())?,
+            update::get_row_count(table, scaling)?,
+        )
+    } else {
+        (0, session.get_scaling().get_row_count(table.into()))
+    };
+    let parallelism = session.get_parallelism() as i64;
+    let chunk_number = session.get_chunk_number() as i64;
+
+    if table.is_small() || parallelism == 1 {
+        return Ok(if chunk_number == 1 {
+            ChunkBoundaries::new(row_offset + 1, row_offset + row_count)
+        } else {
+            ChunkBoundaries::new(1, 0)
+        });
+    }
+
+    let rows_per_chunk = row_count / parallelism;
+    let start_row = (chunk_number - 1) * rows_per_chunk + 1;
+    let end_row = if chunk_number == parallelism {
+        row_count
+    } else {
+        start_row + rows_per_chunk - 1
+    };
+    Ok(ChunkBoundaries::new(
+        row_offset + start_row,
+        row_offset + end_row,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_chunk_covers_table() {
+        let session = Session::get_default_session();
+        let boundaries = split_work(Table::DateDim, &session).unwrap();
+        assert_eq!(boundaries, ChunkBoundaries::new(1, 73049));
+    }
+
+    #[test]
+    fn test_chunks_are_contiguous() {
+        let session = Session::get_default_session().with_parallelism(3);
+        let mut next_row = 1;
+        for chunk in 1..=3 {
+            let boundaries = split_work(Table::TimeDim, &session.with_chunk_number(chunk)).unwrap();
+            assert_eq!(boundaries.get_start_row(), next_row);
+            next_row = boundaries.get_end_row() + 1;
+        }
+        assert_eq!(next_row, 86401);
+    }
+
+    #[test]
+    fn test_small_table_only_in_first_chunk() {
+        let session = Session::get_default_session().with_parallelism(4);
+        assert_eq!(
+            split_work(Table::CallCenter, &session.with_chunk_number(1))
+                .unwrap()
+                .get_row_count(),
+            6
+        );
+        assert!(split_work(Table::CallCenter, &session.with_chunk_number(2))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_refresh_rows_follow_previous_refreshes() {
+        let session = Session::get_default_session().with_update(2);
+        assert_eq!(
+            split_work(Table::SCallCenter, &session).unwrap(),
+            ChunkBoundaries::new(4, 6)
+        );
+
+        let chunked = session.with_parallelism(2);
+        assert_eq!(
+            split_work(Table::SInventory, &chunked.with_chunk_number(1)).unwrap(),
+            ChunkBoundaries::new(45001, 67500)
+        );
+        assert_eq!(
+            split_work(Table::SInventory, &chunked.with_chunk_number(2)).unwrap(),
+            ChunkBoundaries::new(67501, 90000)
+        );
+
+        let base = Session::get_default_session();
+        assert!(split_work(Table::SCallCenter, &base).is_err());
+    }
+}
@@ -0,0 +1,463 @@
+use crate::error::Result;
+use crate::table::Table;
+use crate::TpcdsError;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// How generation progress is reported on stderr (`--progress`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ProgressMode {
+    /// No progress lines, only errors
+    None,
+    /// Human readable lines
+    #[default]
+    Text,
+    /// One JSON object per line, for orchestration tools
+    Json,
+}
+
+impl ProgressMode {
+    /// Get the mode name as used on the command line
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            ProgressMode::None => "none",
+            ProgressMode::Text => "text",
+            ProgressMode::Json => "json",
+        }
+    }
+}
+
+impl FromStr for ProgressMode {
+    type Err = TpcdsError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "none" => Ok(ProgressMode::None),
+            "text" => Ok(ProgressMode::Text),
+            "json" => Ok(ProgressMode::Json),
+            _ => Err(TpcdsError::new(&format!("Invalid progress mode: {}", s))),
+        }
+    }
+}
+
+impl fmt::Display for ProgressMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
+
+/// Progress of one chunk of a table, updated by the worker generating it
+#[derive(Debug, Clone)]
+pub struct ChunkProgress {
+    table: Table,
+    chunk_number: i32,
+    parallelism: i32,
+    /// Row numbers of the chunk; lineitem tables write several rows per row number
+    row_numbers: i64,
+    row_numbers_done: i64,
+    rows: i64,
+    bytes: u64,
+    started: Instant,
+    last_report: Instant,
+}
+
+impl ChunkProgress {
+    pub fn new(table: Table, chunk_number: i32, parallelism: i32, row_numbers: i64) -> Self {
+        let now = Instant::now();
+        Self {
+            table,
+            chunk_number,
+            parallelism,
+            row_numbers,
+            row_numbers_done: 0,
+            rows: 0,
+            bytes: 0,
+            started: now,
+            last_report: now,
+        }
+    }
+
+    /// Record the rows and bytes written so far
+    pub fn update(&mut self, row_numbers_done: i64, rows: i64, bytes: u64) {
+        self.row_numbers_done = row_numbers_done;
+        self.rows = rows;
+        self.bytes = bytes;
+    }
+
+    pub fn get_rows(&self) -> i64 {
+        self.rows
+    }
+
+    pub fn get_bytes(&self) -> u64 {
+        self.bytes
+    }
+
+    pub fn get_elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Fraction of the chunk's row numbers generated so far
+    pub fn get_fraction_done(&self) -> f64 {
+        if self.row_numbers == 0 {
+            1.0
+        } else {
+            self.row_numbers_done as f64 / self.row_numbers as f64
+        }
+    }
+
+    pub fn get_rows_per_second(&self) -> f64 {
+        rate(self.rows, self.get_elapsed())
+    }
+
+    /// Estimated time left, extrapolated from the row numbers generated so far
+    pub fn get_eta(&self) -> Option<Duration> {
+        if self.row_numbers_done == 0 {
+            return None;
+        }
+        let remaining = (self.row_numbers - self.row_numbers_done).max(0) as f64;
+        Some(
+            self.get_elapsed()
+                .mul_f64(remaining / self.row_numbers_done as f64),
+        )
+    }
+}
+
+/// Final statistics of a table over all its chunks, as written to `summary.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableStats {
+    pub table: String,
+    pub rows: i64,
+    pub bytes: u64,
+    /// Seconds from the start of the first chunk to the end of the last one
+    pub elapsed_seconds: f64,
+    pub rows_per_second: f64,
+}
+
+/// Chunks of a table reported so far
+#[derive(Debug)]
+struct TableProgress {
+    chunks_done: i32,
+    rows: i64,
+    bytes: u64,
+    started: Instant,
+    finished: Instant,
+}
+
+/// Reports the progress of the chunk workers on stderr and collects the per-table statistics.
+/// Shared by all the workers of a run.
+#[derive(Debug)]
+pub struct ProgressReporter {
+    mode: ProgressMode,
+    interval: Duration,
+    tables: Mutex<HashMap<Table, TableProgress>>,
+}
+
+impl ProgressReporter {
+    /// Default time between two progress lines of a chunk
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+    pub fn new(mode: ProgressMode) -> Self {
+        Self {
+            mode,
+            interval: Self::DEFAULT_INTERVAL,
+            tables: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+
+    pub fn get_mode(&self) -> ProgressMode {
+        self.mode
+    }
+
+    /// Report the progress of a chunk if the reporting interval has passed since its last report
+    pub fn report(&self, chunk: &mut ChunkProgress) {
+        if self.mode == ProgressMode::None || chunk.last_report.elapsed() < self.interval {
+            return;
+        }
+        chunk.last_report = Instant::now();
+        match self.mode {
+            ProgressMode::None => {}
+            ProgressMode::Text => eprintln!(
+                "  {} chunk {}/{}: {} rows, {}, {:.1}%, {:.0} rows/s, ETA {}",
+                chunk.table.get_name(),
+                chunk.chunk_number,
+                chunk.parallelism,
+                chunk.rows,
+                format_bytes(chunk.bytes),
+                chunk.get_fraction_done() * 100.0,
+                chunk.get_rows_per_second(),
+                chunk
+                    .get_eta()
+                    .map_or_else(|| "unknown".to_string(), format_duration)
+            ),
+            ProgressMode::Json => eprintln!(
+                "{}",
+                json!({
+                    "event": "chunk_progress",
+                    "table": chunk.table.get_name(),
+                    "chunk": chunk.chunk_number,
+                    "parallelism": chunk.parallelism,
+                    "rows": chunk.rows,
+                    "bytes": chunk.bytes,
+                    "fraction_done": chunk.get_fraction_done(),
+                    "elapsed_seconds": chunk.get_elapsed().as_secs_f64(),
+                    "rows_per_second": chunk.get_rows_per_second(),
+                    "eta_seconds": chunk.get_eta().map(|eta| eta.as_secs_f64()),
+                })
+            ),
+        }
+    }
+
+    /// Record a chunk completed by a previous run, kept with `--resume`
+    pub fn resume_chunk(&self, chunk: &ChunkProgress) {
+        match self.mode {
+            ProgressMode::None => {}
+            ProgressMode::Text => eprintln!(
+                "  {} chunk {}/{}: already complete, {} rows kept",
+                chunk.table.get_name(),
+                chunk.chunk_number,
+                chunk.parallelism,
+                chunk.rows
+            ),
+            ProgressMode::Json => eprintln!(
+                "{}",
+                json!({
+                    "event": "chunk_resumed",
+                    "table": chunk.table.get_name(),
+                    "chunk": chunk.chunk_number,
+                    "parallelism": chunk.parallelism,
+                    "rows": chunk.rows,
+                    "bytes": chunk.bytes,
+                })
+            ),
+        }
+        self.finish_chunk(chunk);
+    }
+
+    /// Record a finished chunk. Once all chunks of its table are finished the table is reported.
+    pub fn finish_chunk(&self, chunk: &ChunkProgress) {
+        let now = Instant::now();
+        let mut tables = self.tables.lock().unwrap_or_else(|e| e.into_inner());
+        let progress = tables.entry(chunk.table).or_insert(TableProgress {
+            chunks_done: 0,
+            rows: 0,
+            bytes: 0,
+            started: chunk.started,
+            finished: now,
+        });
+        progress.chunks_done += 1;
+        progress.rows += chunk.rows;
+        progress.bytes += chunk.bytes;
+        progress.started = progress.started.min(chunk.started);
+        progress.finished = progress.finished.max(now);
+
+        if self.mode == ProgressMode::Json {
+            eprintln!(
+                "{}",
+                json!({
+                    "event": "chunk_done",
+                    "table": chunk.table.get_name(),
+                    "chunk": chunk.chunk_number,
+                    "parallelism": chunk.parallelism,
+                    "rows": chunk.rows,
+                    "bytes": chunk.bytes,
+                    "elapsed_seconds": chunk.get_elapsed().as_secs_f64(),
+                    "rows_per_second": chunk.get_rows_per_second(),
+                })
+            );
+        }
+        if progress.chunks_done == chunk.parallelism {
+            let stats = Self::to_stats(chunk.table, progress);
+            match self.mode {
+                ProgressMode::None => {}
+                ProgressMode::Text => eprintln!(
+                    "  {}: {} rows, {} in {} ({:.0} rows/s)",
+                    stats.table,
+                    stats.rows,
+                    format_bytes(stats.bytes),
+                    format_duration(Duration::from_secs_f64(stats.elapsed_seconds)),
+                    stats.rows_per_second
+                ),
+                ProgressMode::Json => eprintln!(
+                    "{}",
+                    json!({
+                        "event": "table_done",
+                        "table": stats.table,
+                        "rows": stats.rows,
+                        "bytes": stats.bytes,
+                        "elapsed_seconds": stats.elapsed_seconds,
+                        "rows_per_second": stats.rows_per_second,
+                    })
+                ),
+            }
+        }
+    }
+
+    /// Report the end of the run
+    pub fn finish_run(&self, summary: &Summary) {
+        match self.mode {
+            ProgressMode::None => {}
+            ProgressMode::Text => eprintln!(
+                "Generated {} rows, {} in {}",
+                summary.rows,
+                format_bytes(summary.bytes),
+                format_duration(Duration::from_secs_f64(summary.elapsed_seconds))
+            ),
+            ProgressMode::Json => eprintln!(
+                "{}",
+                json!({
+                    "event": "run_done",
+                    "rows": summary.rows,
+                    "bytes": summary.bytes,
+                    "elapsed_seconds": summary.elapsed_seconds,
+                })
+            ),
+        }
+    }
+
+    /// Get the statistics of `table` over the chunks finished so far
+    pub fn get_table_stats(&self, table: Table) -> Option<TableStats> {
+        let tables = self.tables.lock().unwrap_or_else(|e| e.into_inner());
+        tables
+            .get(&table)
+            .map(|progress| Self::to_stats(table, progress))
+    }
+
+    fn to_stats(table: Table, progress: &TableProgress) -> TableStats {
+        let elapsed = progress.finished.duration_since(progress.started);
+        TableStats {
+            table: table.get_name().to_string(),
+            rows: progress.rows,
+            bytes: progress.bytes,
+            elapsed_seconds: elapsed.as_secs_f64(),
+            rows_per_second: rate(progress.rows, elapsed),
+        }
+    }
+}
+
+/// Per-table statistics of a run, written next to the data as `summary.json`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Summary {
+    pub tables: Vec<TableStats>,
+    pub rows: i64,
+    pub bytes: u64,
+    pub elapsed_seconds: f64,
+}
+
+impl Summary {
+    pub const FILE_NAME: &'static str = "summary.json";
+
+    pub fn new(tables: Vec<TableStats>, elapsed: Duration) -> Self {
+        Self {
+            rows: tables.iter().map(|stats| stats.rows).sum(),
+            bytes: tables.iter().map(|stats| stats.bytes).sum(),
+            tables,
+            elapsed_seconds: elapsed.as_secs_f64(),
+        }
+    }
+
+    /// Write the summary into `directory`, replacing any previous one
+    pub fn write(&self, directory: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| TpcdsError::new(&format!("Failed to encode summary: {}", e)))?;
+        std::fs::write(directory.join(Self::FILE_NAME), json + "\n")
+            .map_err(|e| TpcdsError::io(&format!("Failed to write {}", Self::FILE_NAME), e))
+    }
+}
+
+fn rate(rows: i64, elapsed: Duration) -> f64 {
+    let seconds = elapsed.as_secs_f64();
+    if seconds > 0.0 {
+        rows as f64 / seconds
+    } else {
+        0.0
+    }
+}
+
+/// Format a byte count with a binary unit, e.g. `1.5 GiB`
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+/// Format a duration as `1h02m03s`, `2m03s` or `3.4s`
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds >= 3600 {
+        format!(
+            "{}h{:02}m{:02}s",
+            seconds / 3600,
+            seconds % 3600 / 60,
+            seconds % 60
+        )
+    } else if seconds >= 60 {
+        format!("{}m{:02}s", seconds / 60, seconds % 60)
+    } else {
+        format!("{:.1}s", duration.as_secs_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_mode_parsing() {
+        assert_eq!("JSON".parse::<ProgressMode>().unwrap(), ProgressMode::Json);
+        assert_eq!(ProgressMode::default().to_string(), "text");
+        assert!("verbose".parse::<ProgressMode>().is_err());
+    }
+
+    #[test]
+    fn test_chunk_eta() {
+        let mut chunk = ChunkProgress::new(Table::SPurchaseLineitem, 1, 2, 1000);
+        assert_eq!(chunk.get_eta(), None);
+        chunk.update(250, 2500, 100_000);
+        assert_eq!(chunk.get_fraction_done(), 0.25);
+        // Three times the time taken so far, which only grows between the two calls
+        let elapsed = chunk.get_elapsed();
+        assert!(chunk.get_eta().unwrap() >= elapsed * 3);
+    }
+
+    #[test]
+    fn test_table_stats_add_up_chunks() {
+        let reporter = ProgressReporter::new(ProgressMode::None);
+        for chunk_number in 1..=2 {
+            let mut chunk = ChunkProgress::new(Table::Promotion, chunk_number, 2, 150);
+            chunk.update(150, 150, 1000);
+            reporter.finish_chunk(&chunk);
+        }
+        let stats = reporter.get_table_stats(Table::Promotion).unwrap();
+        assert_eq!(stats.rows, 300);
+        assert_eq!(stats.bytes, 2000);
+        assert!(reporter.get_table_stats(Table::Reason).is_none());
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(3 * 1024 * 1024 / 2), "1.5 MiB");
+        assert_eq!(format_duration(Duration::from_secs(3723)), "1h02m03s");
+        assert_eq!(format_duration(Duration::from_secs(123)), "2m03s");
+        assert_eq!(format_duration(Duration::from_millis(3400)), "3.4s");
+    }
+}
@@ -0,0 +1,323 @@
+use crate::error::Result;
+use crate::query::template::QueryTemplate;
+use crate::TpcdsError;
+use std::path::Path;
+
+/// Built-in dialect profiles: name and dsqgen dialect template
+const PROFILES: &[(&str, &str)] = &[
+    ("ansi", include_str!("dialects/ansi.tpl")),
+    ("duckdb", include_str!("dialects/duckdb.tpl")),
+    ("postgres", include_str!("dialects/postgres.tpl")),
+    ("spark", include_str!("dialects/spark.tpl")),
+    ("trino", include_str!("dialects/trino.tpl")),
+];
+
+/// SQL dialect of the generated queries: a template of defines that are evaluated before the
+/// defines of every query template (the dsqgen `-dialect` template).
+///
+/// - `__LIMITA`, `__LIMITB` and `__LIMITC` make `[_LIMITA]`, `[_LIMITB]` and `[_LIMITC]`, with
+///   `%d` replaced by the query's `_LIMIT`
+/// - `_BEGIN` and `_END` are written before and after every query
+/// - `__DAYS` rewrites day counts of date arithmetic (`+ 30 days`), with `%d` replaced by the
+///   number of days
+/// - `__CONCAT` rewrites `a || b` to a call of the function it names, `concat(a, b)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dialect {
+    template: QueryTemplate,
+}
+
+impl Dialect {
+    pub const DEFAULT_NAME: &'static str = "ansi";
+
+    /// Names of the built-in profiles
+    pub fn get_names() -> impl Iterator<Item = &'static str> {
+        PROFILES.iter().map(|(name, _)| *name)
+    }
+
+    /// A built-in profile, or the dialect template at a path ending in `.tpl`
+    pub fn get(name: &str) -> Result<Self> {
+        if name.ends_with(".tpl") {
+            return Self::load(Path::new(name));
+        }
+        let (name, content) = PROFILES
+            .iter()
+            .find(|(profile, _)| profile.eq_ignore_ascii_case(name))
+            .ok_or_else(|| {
+                TpcdsError::new(&format!(
+                    "Unknown dialect {}, expected one of {} or a .tpl file",
+                    name,
+                    Self::get_names().collect::<Vec<_>>().join(", ")
+                ))
+            })?;
+        Self::parse(&format!("{}.tpl", name), content)
+    }
+
+    /// Read a dialect template
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::from_template(QueryTemplate::load(path)?)
+    }
+
+    fn parse(name: &str, content: &str) -> Result<Self> {
+        Self::from_template(QueryTemplate::parse(name, content)?)
+    }
+
+    fn from_template(template: QueryTemplate) -> Result<Self> {
+        if !template.segments.is_empty() {
+            return Err(TpcdsError::new(&format!(
+                "Dialect template {} may only contain defines",
+                template.name
+            )));
+        }
+        Ok(Self { template })
+    }
+
+    pub fn get_template(&self) -> &QueryTemplate {
+        &self.template
+    }
+}
+
+impl Default for Dialect {
+    fn default() -> Self {
+        Self::get(Self::DEFAULT_NAME).expect("the built-in dialects are valid")
+    }
+}
+
+/// Byte ranges of `sql` outside single-quoted string literals
+fn unquoted_ranges(sql: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut in_string = false;
+    for (position, c) in sql.char_indices() {
+        if c == '\'' {
+            if !in_string {
+                ranges.push((start, position));
+            } else {
+                start = position + 1;
+            }
+            in_string = !in_string;
+        }
+    }
+    if !in_string {
+        ranges.push((start, sql.len()));
+    }
+    ranges
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Replace `<n> days` with `format`, `%d` replaced by `<n>`. String literals are left alone.
+pub fn rewrite_days(sql: &str, format: &str) -> String {
+    let mut result = String::with_capacity(sql.len());
+    let mut copied = 0;
+    for (start, end) in unquoted_ranges(sql) {
+        let text = &sql[start..end];
+        let bytes = text.as_bytes();
+        let mut i = 0;
+        while i < bytes.len() {
+            let begins_number =
+                bytes[i].is_ascii_digit() && (i == 0 || !is_identifier_char(bytes[i - 1] as char));
+            if !begins_number {
+                i += 1;
+                continue;
+            }
+            let digits_end = (i..bytes.len())
+                .find(|&j| !bytes[j].is_ascii_digit())
+                .unwrap_or(bytes.len());
+            let word_start = (digits_end..bytes.len())
+                .find(|&j| !bytes[j].is_ascii_whitespace())
+                .unwrap_or(bytes.len());
+            let word_end = word_start + 4;
+            let is_days = word_start > digits_end
+                && text
+                    .get(word_start..word_end)
+                    .is_some_and(|word| word.eq_ignore_ascii_case("days"))
+                && !text[word_end..].starts_with(is_identifier_char);
+            if is_days {
+                result.push_str(&sql[copied..start + i]);
+                result.push_str(&format.replace("%d", &text[i..digits_end]));
+                copied = start + word_end;
+                i = word_end;
+            } else {
+                i = digits_end;
+            }
+        }
+    }
+    result.push_str(&sql[copied..]);
+    result
+}
+
+/// Replace `a || b` with `function(a, b)`. Operands are string literals, possibly qualified
+/// names, function calls and parenthesized expressions; `||` in string literals is left alone.
+pub fn rewrite_concat(sql: &str, function: &str) -> String {
+    let mut sql = sql.to_string();
+    while let Some(operator) = find_unquoted(&sql, "||") {
+        let left = operand_start(&sql[..operator]);
+        let right = operand_end(&sql, operator + 2);
+        let (Some(left), Some(right)) = (left, right) else {
+            break;
+        };
+        let left_operand = sql[left..operator].trim().to_string();
+        let right_operand = sql[operator + 2..right].trim().to_string();
+        sql.replace_range(
+            left..right,
+            &format!("{}({}, {})", function, left_operand, right_operand),
+        );
+    }
+    sql
+}
+
+fn find_unquoted(sql: &str, pattern: &str) -> Option<usize> {
+    unquoted_ranges(sql)
+        .into_iter()
+        .find_map(|(start, end)| sql[start..end].find(pattern).map(|i| start + i))
+}
+
+/// Start of the operand that ends at the end of `text`
+fn operand_start(text: &str) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let mut i = text.trim_end().len();
+    match *bytes.get(i.checked_sub(1)?)? {
+        b'\'' => {
+            i -= 1;
+            // Step back over the literal, including doubled quotes
+            loop {
+                i = text[..i].rfind('\'')?;
+                if i > 0 && bytes[i - 1] == b'\'' {
+                    i -= 1;
+                } else {
+                    return Some(i);
+                }
+            }
+        }
+        b')' => {
+            let mut depth = 0;
+            loop {
+                i = i.checked_sub(1)?;
+                match bytes[i] {
+                    b')' => depth += 1,
+                    b'(' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            // A function name before the parenthesis belongs to the operand
+            while i > 0 && is_identifier_char(bytes[i - 1] as char) {
+                i -= 1;
+            }
+            Some(i)
+        }
+        c if is_identifier_char(c as char) => {
+            while i > 0 && (is_identifier_char(bytes[i - 1] as char) || bytes[i - 1] == b'.') {
+                i -= 1;
+            }
+            Some(i)
+        }
+        _ => None,
+    }
+}
+
+/// End of the operand that starts at `start`
+fn operand_end(sql: &str, start: usize) -> Option<usize> {
+    let bytes = sql.as_bytes();
+    let mut i = start + (sql[start..].len() - sql[start..].trim_start().len());
+    match *bytes.get(i)? {
+        b'\'' => {
+            i += 1;
+            loop {
+                i += sql[i..].find('\'')? + 1;
+                if bytes.get(i) == Some(&b'\'') {
+                    i += 1;
+                } else {
+                    return Some(i);
+                }
+            }
+        }
+        c if c == b'(' || is_identifier_char(c as char) => {
+            while i < bytes.len() && (is_identifier_char(bytes[i] as char) || bytes[i] == b'.') {
+                i += 1;
+            }
+            if bytes.get(i) == Some(&b'(') {
+                let mut depth = 0;
+                loop {
+                    match *bytes.get(i)? {
+                        b'(' => depth += 1,
+                        b')' => {
+                            depth -= 1;
+                            if depth == 0 {
+                                return Some(i + 1);
+                            }
+                        }
+                        _ => {}
+                    }
+                    i += 1;
+                }
+            }
+            Some(i)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles() {
+        for name in Dialect::get_names() {
+            let dialect = Dialect::get(name).unwrap();
+            let defines: Vec<&str> = dialect
+                .get_template()
+                .defines
+                .iter()
+                .map(|define| define.name.as_str())
+                .collect();
+            for required in ["__LIMITA", "__LIMITB", "__LIMITC", "_BEGIN", "_END"] {
+                assert!(defines.contains(&required), "{} lacks {}", name, required);
+            }
+        }
+        assert_eq!(Dialect::default(), Dialect::get("ANSI").unwrap());
+        assert!(Dialect::get("oracle").is_err());
+        assert!(Dialect::get("/nonexistent/dialect.tpl").is_err());
+    }
+
+    #[test]
+    fn test_rewrite_days() {
+        assert_eq!(
+            rewrite_days(
+                "d between cast('2000-01-01' as date) and (cast('2000-01-01' as date) + 30 days)",
+                "interval '%d' day"
+            ),
+            "d between cast('2000-01-01' as date) and (cast('2000-01-01' as date) + interval '30' day)"
+        );
+        assert_eq!(
+            rewrite_days(
+                "x - 14 DAYS and '5 days' and a30 days and 3 daysx",
+                "interval %d days"
+            ),
+            "x - interval 14 days and '5 days' and a30 days and 3 daysx"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_concat() {
+        assert_eq!(
+            rewrite_concat("select 'store' || s_store_id as id", "concat"),
+            "select concat('store', s_store_id) as id"
+        );
+        assert_eq!(
+            rewrite_concat("a.x||upper(b.y) || 'it''s' || 'a||b'", "concat"),
+            "concat(concat(concat(a.x, upper(b.y)), 'it''s'), 'a||b')"
+        );
+        assert_eq!(
+            rewrite_concat("where a = 'x || y'", "concat"),
+            "where a = 'x || y'"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+-- DuckDB
+define __LIMITA = "";
+define __LIMITB = "";
+define __LIMITC = "limit %d";
+define __DAYS = "interval %d day";
+define _BEGIN = "-- start query " + [_QUERY] + " in stream " + [_STREAM] + " using template " + [_TEMPLATE];
+define _END = "-- end query " + [_QUERY] + " in stream " + [_STREAM] + " using template " + [_TEMPLATE];
@@ -0,0 +1,7 @@
+-- PostgreSQL
+define __LIMITA = "";
+define __LIMITB = "";
+define __LIMITC = "limit %d";
+define __DAYS = "interval '%d days'";
+define _BEGIN = "-- start query " + [_QUERY] + " in stream " + [_STREAM] + " using template " + [_TEMPLATE];
+define _END = "-- end query " + [_QUERY] + " in stream " + [_STREAM] + " using template " + [_TEMPLATE];
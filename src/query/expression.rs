@@ -0,0 +1,325 @@
+/// Reference to a substitution, `[NAME]` or `[NAME.2]` for an element of a `ulist()`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tag {
+    /// Substitution name, upper case (names are not case sensitive)
+    pub name: String,
+    /// 1-based element of a list substitution
+    pub index: Option<usize>,
+}
+
+impl Tag {
+    /// Parse the text between the brackets of a tag. Returns `None` if it is not a tag.
+    pub fn parse(text: &str) -> Option<Self> {
+        let (name, index) = match text.split_once('.') {
+            Some((name, index)) => (name, Some(index.parse::<usize>().ok()?)),
+            None => (text, None),
+        };
+        if !is_identifier(name) || index == Some(0) {
+            return None;
+        }
+        Some(Self {
+            name: name.to_uppercase(),
+            index,
+        })
+    }
+}
+
+impl std::fmt::Display for Tag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.index {
+            Some(index) => write!(f, "[{}.{}]", self.name, index),
+            None => write!(f, "[{}]", self.name),
+        }
+    }
+}
+
+fn is_identifier(text: &str) -> bool {
+    let mut chars = text.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Right-hand side of a template `define`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expression {
+    Integer(i64),
+    Text(String),
+    /// Bare word, such as `uniform` or a distribution name
+    Word(String),
+    Tag(Tag),
+    /// `{value, weight}` alternative of `text()`
+    Choice(Box<Expression>, Box<Expression>),
+    /// Function call; the function name is lower case
+    Call(String, Vec<Expression>),
+    Add(Box<Expression>, Box<Expression>),
+    Subtract(Box<Expression>, Box<Expression>),
+    Negate(Box<Expression>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Integer(i64),
+    Text(String),
+    Word(String),
+    Tag(Tag),
+    Symbol(char),
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c == '"' {
+            let end = (i + 1..chars.len())
+                .find(|&j| chars[j] == '"')
+                .ok_or("Unterminated string")?;
+            tokens.push(Token::Text(chars[i + 1..end].iter().collect()));
+            i = end + 1;
+        } else if c == '[' {
+            let end = (i + 1..chars.len())
+                .find(|&j| chars[j] == ']')
+                .ok_or("Unterminated substitution tag")?;
+            let text: String = chars[i + 1..end].iter().collect();
+            let tag =
+                Tag::parse(&text).ok_or_else(|| format!("Invalid substitution tag [{}]", text))?;
+            tokens.push(Token::Tag(tag));
+            i = end + 1;
+        } else if c.is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            let digits: String = chars[start..i].iter().collect();
+            let value = digits
+                .parse()
+                .map_err(|_| format!("Number out of range: {}", digits))?;
+            tokens.push(Token::Integer(value));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Word(chars[start..i].iter().collect()));
+        } else if "(){},+-".contains(c) {
+            tokens.push(Token::Symbol(c));
+            i += 1;
+        } else {
+            return Err(format!("Unexpected character '{}'", c));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Parse the expression of a `define`
+pub fn parse_expression(text: &str) -> Result<Expression, String> {
+    let mut parser = Parser {
+        tokens: tokenize(text)?,
+        position: 0,
+    };
+    let expression = parser.parse_sum()?;
+    match parser.peek() {
+        None => Ok(expression),
+        Some(token) => Err(format!("Unexpected {}", describe(token))),
+    }
+}
+
+fn describe(token: &Token) -> String {
+    match token {
+        Token::Integer(value) => format!("number {}", value),
+        Token::Text(text) => format!("string \"{}\"", text),
+        Token::Word(word) => format!("'{}'", word),
+        Token::Tag(tag) => format!("tag {}", tag),
+        Token::Symbol(c) => format!("'{}'", c),
+    }
+}
+
+/// Recursive-descent parser:
+/// `sum := unary (('+' | '-') unary)*`,
+/// `unary := '-' unary | primary`,
+/// `primary := integer | string | tag | word ['(' [sum (',' sum)*] ')'] | '{' sum ',' sum '}' | '(' sum ')'`
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<Token, String> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or("Unexpected end of expression")?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn accept(&mut self, symbol: char) -> bool {
+        if self.peek() == Some(&Token::Symbol(symbol)) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, symbol: char) -> Result<(), String> {
+        match self.next()? {
+            Token::Symbol(c) if c == symbol => Ok(()),
+            token => Err(format!("Expected '{}', found {}", symbol, describe(&token))),
+        }
+    }
+
+    fn parse_sum(&mut self) -> Result<Expression, String> {
+        let mut expression = self.parse_unary()?;
+        loop {
+            if self.accept('+') {
+                expression = Expression::Add(Box::new(expression), Box::new(self.parse_unary()?));
+            } else if self.accept('-') {
+                expression =
+                    Expression::Subtract(Box::new(expression), Box::new(self.parse_unary()?));
+            } else {
+                return Ok(expression);
+            }
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Expression, String> {
+        if self.accept('-') {
+            return Ok(Expression::Negate(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> Result<Expression, String> {
+        match self.next()? {
+            Token::Integer(value) => Ok(Expression::Integer(value)),
+            Token::Text(text) => Ok(Expression::Text(text)),
+            Token::Tag(tag) => Ok(Expression::Tag(tag)),
+            Token::Word(word) => {
+                if !self.accept('(') {
+                    return Ok(Expression::Word(word));
+                }
+                let mut arguments = Vec::new();
+                if !self.accept(')') {
+                    loop {
+                        arguments.push(self.parse_sum()?);
+                        if self.accept(')') {
+                            break;
+                        }
+                        self.expect(',')?;
+                    }
+                }
+                Ok(Expression::Call(word.to_lowercase(), arguments))
+            }
+            Token::Symbol('{') => {
+                let value = self.parse_sum()?;
+                self.expect(',')?;
+                let weight = self.parse_sum()?;
+                self.expect('}')?;
+                Ok(Expression::Choice(Box::new(value), Box::new(weight)))
+            }
+            Token::Symbol('(') => {
+                let expression = self.parse_sum()?;
+                self.expect(')')?;
+                Ok(expression)
+            }
+            token => Err(format!("Unexpected {}", describe(&token))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn word(text: &str) -> Expression {
+        Expression::Word(text.to_string())
+    }
+
+    #[test]
+    fn test_parse_function_calls() {
+        assert_eq!(
+            parse_expression("random(1, 4, uniform)").unwrap(),
+            Expression::Call(
+                "random".to_string(),
+                vec![
+                    Expression::Integer(1),
+                    Expression::Integer(4),
+                    word("uniform")
+                ]
+            )
+        );
+        assert_eq!(
+            parse_expression("ulist(dist(cities, 1, large), 2)").unwrap(),
+            Expression::Call(
+                "ulist".to_string(),
+                vec![
+                    Expression::Call(
+                        "dist".to_string(),
+                        vec![word("cities"), Expression::Integer(1), word("large")]
+                    ),
+                    Expression::Integer(2)
+                ]
+            )
+        );
+        assert_eq!(
+            parse_expression("text({\"a\", 1}, {\"b c\", 2})").unwrap(),
+            Expression::Call(
+                "text".to_string(),
+                vec![
+                    Expression::Choice(
+                        Box::new(Expression::Text("a".to_string())),
+                        Box::new(Expression::Integer(1))
+                    ),
+                    Expression::Choice(
+                        Box::new(Expression::Text("b c".to_string())),
+                        Box::new(Expression::Integer(2))
+                    )
+                ]
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_arithmetic_and_tags() {
+        assert_eq!(
+            parse_expression("[year] + 1 - -2").unwrap(),
+            Expression::Subtract(
+                Box::new(Expression::Add(
+                    Box::new(Expression::Tag(Tag {
+                        name: "YEAR".to_string(),
+                        index: None
+                    })),
+                    Box::new(Expression::Integer(1))
+                )),
+                Box::new(Expression::Negate(Box::new(Expression::Integer(2))))
+            )
+        );
+        assert_eq!(
+            Tag::parse("COLOR.2"),
+            Some(Tag {
+                name: "COLOR".to_string(),
+                index: Some(2)
+            })
+        );
+        assert_eq!(Tag::parse("COLOR.0"), None);
+        assert_eq!(Tag::parse("1X"), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(parse_expression("random(1, 4").is_err());
+        assert!(parse_expression("\"open").is_err());
+        assert!(parse_expression("1 2").is_err());
+        assert!(parse_expression("{1, 2").is_err());
+        assert!(parse_expression("a ; b").is_err());
+    }
+}
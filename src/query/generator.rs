@@ -0,0 +1,900 @@
+use crate::config::Scaling;
+use crate::distribution::dst_parser::DistributionDefinition;
+use crate::distribution::{DistributionInspector, FileBasedStringValuesDistribution};
+use crate::error::Result;
+use crate::permutations::make_permutation;
+use crate::query::dialect::{rewrite_concat, rewrite_days, Dialect};
+use crate::query::expression::{Expression, Tag};
+use crate::query::qualification::Qualification;
+use crate::query::template::{QueryTemplate, Segment};
+use crate::random::{RandomNumberStreamImpl, RandomValueGenerator};
+use crate::types::Date;
+use crate::TpcdsError;
+use std::collections::{HashMap, HashSet};
+use std::io::Write;
+use std::str::FromStr;
+
+/// dsdgen distribution names that the files in `data/` are named differently
+const DISTRIBUTION_FILE_NAMES: &[(&str, &str)] = &[
+    ("fips_county", "fips"),
+    ("gender", "genders"),
+    ("marital_status", "marital_statuses"),
+];
+
+/// Attempts per value before `ulist()` gives up on finding enough distinct values
+const ULIST_ATTEMPTS_PER_VALUE: usize = 1000;
+
+/// Value of a substitution
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Integer(i64),
+    Text(String),
+    /// Distinct values drawn by `ulist()`, referenced as `[NAME.1]`, `[NAME.2]`, ...
+    List(Vec<Value>),
+}
+
+impl Value {
+    fn as_integer(&self) -> Option<i64> {
+        match self {
+            Value::Integer(value) => Some(*value),
+            Value::Text(text) => text.trim().parse().ok(),
+            Value::List(_) => None,
+        }
+    }
+
+    fn as_text(&self) -> std::result::Result<String, String> {
+        match self {
+            Value::Integer(value) => Ok(value.to_string()),
+            Value::Text(text) => Ok(text.clone()),
+            Value::List(_) => Err("A list needs an element number, e.g. [NAME.1]".to_string()),
+        }
+    }
+}
+
+/// Distribution used by `dist()`, `distmember()` and `distweight()`. Value and weight sets
+/// can be named when the distribution comes from a dsdgen distribution file.
+struct QueryDistribution {
+    distribution: FileBasedStringValuesDistribution,
+    value_names: Vec<String>,
+    weight_names: Vec<String>,
+}
+
+/// Distributions by name: those of a dsdgen distribution file if one was given, otherwise the
+/// built-in distribution files
+#[derive(Default)]
+struct QueryDistributions {
+    definitions: HashMap<String, DistributionDefinition>,
+    loaded: HashMap<String, QueryDistribution>,
+}
+
+impl QueryDistributions {
+    fn get(&mut self, name: &str) -> std::result::Result<&QueryDistribution, String> {
+        let name = name.to_lowercase();
+        if !self.loaded.contains_key(&name) {
+            let distribution = match self.definitions.get(&name) {
+                Some(definition) => QueryDistribution {
+                    distribution: FileBasedStringValuesDistribution::from_distribution_lines(
+                        definition.lines.clone(),
+                        definition.types.len(),
+                        definition.weight_sets,
+                    )
+                    .map_err(|e| e.to_string())?,
+                    value_names: definition.value_names.clone(),
+                    weight_names: definition.weight_names.clone(),
+                },
+                None => {
+                    let file_name = DISTRIBUTION_FILE_NAMES
+                        .iter()
+                        .find(|(dsdgen_name, _)| *dsdgen_name == name)
+                        .map_or(name.as_str(), |(_, file_name)| file_name);
+                    QueryDistribution {
+                        distribution: DistributionInspector::load(file_name)
+                            .map_err(|_| format!("Unknown distribution {}", name))?,
+                        value_names: Vec::new(),
+                        weight_names: Vec::new(),
+                    }
+                }
+            };
+            self.loaded.insert(name.clone(), distribution);
+        }
+        Ok(&self.loaded[&name])
+    }
+}
+
+/// Generates query streams from dsqgen templates (dsqgen). Stream 0 runs the templates in the
+/// order given; every other stream runs them in a random order drawn from its seed. Each query
+/// draws its substitutions from a stream seeded with the seed, the stream number and the
+/// template, so a template gets the same values wherever it appears in a stream.
+pub struct QueryGenerator {
+    templates: Vec<QueryTemplate>,
+    dialect: Dialect,
+    scaling: Scaling,
+    seed: i32,
+    distributions: QueryDistributions,
+    qualification: Option<Qualification>,
+}
+
+impl QueryGenerator {
+    pub const DEFAULT_SEED: i32 = RandomNumberStreamImpl::DEFAULT_SEED_BASE;
+
+    pub fn new(templates: Vec<QueryTemplate>, scaling: Scaling, seed: i32) -> Self {
+        Self {
+            templates,
+            dialect: Dialect::default(),
+            scaling,
+            seed,
+            distributions: QueryDistributions::default(),
+            qualification: None,
+        }
+    }
+
+    /// Write the queries in `dialect` instead of ANSI SQL
+    pub fn with_dialect(mut self, dialect: Dialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
+    /// Take distributions from a dsdgen distribution file such as `tpcds.dst`, which also
+    /// names their value and weight sets
+    pub fn with_distribution_definitions(
+        mut self,
+        definitions: Vec<DistributionDefinition>,
+    ) -> Self {
+        self.distributions.definitions = definitions
+            .into_iter()
+            .map(|definition| (definition.name.to_lowercase(), definition))
+            .collect();
+        self.distributions.loaded.clear();
+        self
+    }
+
+    /// Substitute the values of `qualification` instead of drawing random ones. Every
+    /// template needs a section, and every substitution that would be drawn at random a value.
+    pub fn with_qualification(mut self, qualification: Qualification) -> Self {
+        self.qualification = Some(qualification);
+        self
+    }
+
+    pub fn get_templates(&self) -> &[QueryTemplate] {
+        &self.templates
+    }
+
+    fn get_stream_seed(&self, stream: usize) -> i32 {
+        self.seed.wrapping_add(stream as i32)
+    }
+
+    /// Indexes of the templates in the order stream `stream` runs them
+    pub fn get_stream_order(&self, stream: usize) -> Result<Vec<usize>> {
+        if stream == 0 {
+            return Ok((0..self.templates.len()).collect());
+        }
+        let mut random = RandomNumberStreamImpl::new_with_base(0, self.get_stream_seed(stream), 0)?;
+        Ok(make_permutation(self.templates.len(), &mut random)
+            .into_iter()
+            .map(|index| index as usize)
+            .collect())
+    }
+
+    /// Write every query of stream `stream`
+    pub fn write_stream(&mut self, out: &mut dyn Write, stream: usize) -> Result<()> {
+        for (position, template_index) in self.get_stream_order(stream)?.into_iter().enumerate() {
+            out.write_all(
+                self.generate_query(template_index, position + 1, stream)?
+                    .as_bytes(),
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Generate the query of template `template_index` that runs as query `query_number`
+    /// (1-based) of stream `stream`, between its `_BEGIN` and `_END` lines
+    pub fn generate_query(
+        &mut self,
+        template_index: usize,
+        query_number: usize,
+        stream: usize,
+    ) -> Result<String> {
+        let template = self.templates.get(template_index).ok_or_else(|| {
+            TpcdsError::new(&format!("Template index {} out of range", template_index))
+        })?;
+        let random = RandomNumberStreamImpl::new_with_base(
+            (template_index % 799) as i32 + 1,
+            self.get_stream_seed(stream),
+            0,
+        )?;
+        let mut context = Context {
+            distributions: &mut self.distributions,
+            scaling: &self.scaling,
+            random,
+            values: HashMap::new(),
+        };
+        context.define("_SEED", Value::Integer(self.seed as i64));
+        context.define("_SCALE", Value::Text(self.scaling.get_scale().to_string()));
+        context.define("_QUERY", Value::Integer(query_number as i64));
+        context.define("_STREAM", Value::Integer(stream as i64));
+        context.define("_TEMPLATE", Value::Text(template.name.clone()));
+
+        for define in &self.dialect.get_template().defines {
+            let value = context
+                .evaluate(&define.expression)
+                .map_err(|e| template_error(self.dialect.get_template(), define.line, &e))?;
+            context.define(&define.name, value);
+        }
+
+        let qualification = match &self.qualification {
+            Some(qualification) => Some(qualification.get(&template.name).ok_or_else(|| {
+                TpcdsError::new(&format!(
+                    "No qualification values for template {}",
+                    template.name
+                ))
+            })?),
+            None => None,
+        };
+        if let Some(qualification) = qualification {
+            for define in &qualification.defines {
+                if !template.defines.iter().any(|d| d.name == define.name) {
+                    return Err(template_error(
+                        qualification,
+                        define.line,
+                        &format!("{} does not define {}", template.name, define.name),
+                    ));
+                }
+            }
+        }
+        for define in &template.defines {
+            let qualified = qualification.and_then(|qualification| {
+                qualification
+                    .defines
+                    .iter()
+                    .find(|qualified| qualified.name == define.name)
+                    .map(|qualified| (qualification, qualified))
+            });
+            let (source, define) = match qualified {
+                Some((qualification, qualified)) => (qualification, qualified),
+                None if qualification.is_some() && is_random(&define.expression) => {
+                    return Err(template_error(
+                        template,
+                        define.line,
+                        &format!("No qualification value for {}", define.name),
+                    ));
+                }
+                None => (template, define),
+            };
+            let value = context
+                .evaluate(&define.expression)
+                .map_err(|e| template_error(source, define.line, &e))?;
+            context.define(&define.name, value);
+        }
+        context.define_limits();
+
+        let mut body = String::new();
+        for segment in &template.segments {
+            match segment {
+                Segment::Text(text) => body.push_str(text),
+                Segment::Tag { tag, line } => body.push_str(
+                    &context
+                        .lookup(tag)
+                        .and_then(|value| value.as_text())
+                        .map_err(|e| template_error(template, *line, &e))?,
+                ),
+            }
+        }
+        if context.values.contains_key("__DAYS") {
+            body = rewrite_days(&body, &context.get_text("__DAYS"));
+        }
+        if context.values.contains_key("__CONCAT") {
+            body = rewrite_concat(&body, &context.get_text("__CONCAT"));
+        }
+
+        let mut query = String::new();
+        let begin = context.get_text("_BEGIN");
+        if !begin.is_empty() {
+            query.push_str(&begin);
+            query.push('\n');
+        }
+        query.push_str(&body);
+        query.push('\n');
+        let end = context.get_text("_END");
+        if !end.is_empty() {
+            query.push_str(&end);
+            query.push('\n');
+        }
+        Ok(query)
+    }
+}
+
+/// Whether an expression draws random values
+fn is_random(expression: &Expression) -> bool {
+    match expression {
+        Expression::Call(function, arguments) => {
+            matches!(
+                function.as_str(),
+                "random" | "date" | "dist" | "ulist" | "text"
+            ) || arguments.iter().any(is_random)
+        }
+        Expression::Choice(value, weight) => is_random(value) || is_random(weight),
+        Expression::Add(left, right) | Expression::Subtract(left, right) => {
+            is_random(left) || is_random(right)
+        }
+        Expression::Negate(value) => is_random(value),
+        Expression::Integer(_) | Expression::Text(_) | Expression::Word(_) | Expression::Tag(_) => {
+            false
+        }
+    }
+}
+
+fn template_error(template: &QueryTemplate, line: usize, message: &str) -> TpcdsError {
+    TpcdsError::new(&format!(
+        "Template {}, line {}: {}",
+        template.name, line, message
+    ))
+}
+
+/// Substitutions of one query and the random number stream they are drawn from
+struct Context<'a> {
+    distributions: &'a mut QueryDistributions,
+    scaling: &'a Scaling,
+    random: RandomNumberStreamImpl,
+    values: HashMap<String, Value>,
+}
+
+type EvaluationResult<T> = std::result::Result<T, String>;
+
+impl Context<'_> {
+    fn define(&mut self, name: &str, value: Value) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    fn get_text(&self, name: &str) -> String {
+        self.values
+            .get(name)
+            .and_then(|value| value.as_text().ok())
+            .unwrap_or_default()
+    }
+
+    /// Define `_LIMITA`, `_LIMITB` and `_LIMITC` from the dialect and `_LIMIT`, unless the
+    /// template defined them itself
+    fn define_limits(&mut self) {
+        let limit = self.values.get("_LIMIT").and_then(Value::as_integer);
+        for suffix in ["A", "B", "C"] {
+            let name = format!("_LIMIT{}", suffix);
+            if self.values.contains_key(&name) {
+                continue;
+            }
+            let text = match limit {
+                Some(limit) => self
+                    .get_text(&format!("__LIMIT{}", suffix))
+                    .replace("%d", &limit.to_string()),
+                None => String::new(),
+            };
+            self.define(&name, Value::Text(text));
+        }
+    }
+
+    fn lookup(&self, tag: &Tag) -> EvaluationResult<Value> {
+        let value = self
+            .values
+            .get(&tag.name)
+            .ok_or_else(|| format!("Undefined substitution {}", tag))?;
+        match (tag.index, value) {
+            (None, value) => Ok(value.clone()),
+            (Some(index), Value::List(values)) => values
+                .get(index - 1)
+                .cloned()
+                .ok_or_else(|| format!("{} has only {} values", tag.name, values.len())),
+            (Some(_), _) => Err(format!("{} is not a list", tag.name)),
+        }
+    }
+
+    fn evaluate(&mut self, expression: &Expression) -> EvaluationResult<Value> {
+        match expression {
+            Expression::Integer(value) => Ok(Value::Integer(*value)),
+            Expression::Text(text) => Ok(Value::Text(text.clone())),
+            Expression::Word(word) => Err(format!("Unexpected name '{}'", word)),
+            Expression::Tag(tag) => self.lookup(tag),
+            Expression::Choice(_, _) => Err("{value, weight} is only allowed in text()".into()),
+            Expression::Call(function, arguments) => self.call(function, arguments),
+            Expression::Add(left, right) => {
+                let left = self.evaluate(left)?;
+                let right = self.evaluate(right)?;
+                match (left.as_integer(), right.as_integer()) {
+                    (Some(left), Some(right)) => Ok(Value::Integer(left + right)),
+                    _ => Ok(Value::Text(left.as_text()? + &right.as_text()?)),
+                }
+            }
+            Expression::Subtract(left, right) => {
+                let left = self.evaluate_integer(left)?;
+                Ok(Value::Integer(left - self.evaluate_integer(right)?))
+            }
+            Expression::Negate(value) => Ok(Value::Integer(-self.evaluate_integer(value)?)),
+        }
+    }
+
+    fn evaluate_integer(&mut self, expression: &Expression) -> EvaluationResult<i64> {
+        let value = self.evaluate(expression)?;
+        value.as_integer().ok_or_else(|| {
+            format!(
+                "Expected a number, found '{}'",
+                value.as_text().unwrap_or_default()
+            )
+        })
+    }
+
+    /// Name argument, such as a distribution name: a bare word or an expression
+    fn evaluate_name(&mut self, expression: &Expression) -> EvaluationResult<String> {
+        match expression {
+            Expression::Word(word) => Ok(word.clone()),
+            expression => self.evaluate(expression)?.as_text(),
+        }
+    }
+
+    fn call(&mut self, function: &str, arguments: &[Expression]) -> EvaluationResult<Value> {
+        let expected = match function {
+            "random" | "dist" | "distmember" | "distweight" | "date" => Some(3),
+            "ulist" => Some(2),
+            "rowcount" => Some(1),
+            "text" | "list" => None,
+            _ => return Err(format!("Unknown function {}()", function)),
+        };
+        if expected.is_some_and(|count| count != arguments.len()) {
+            return Err(format!(
+                "{}() takes {} arguments, found {}",
+                function,
+                expected.unwrap_or_default(),
+                arguments.len()
+            ));
+        }
+
+        match function {
+            "random" => {
+                self.expect_uniform(&arguments[2])?;
+                let min = self.evaluate_integer(&arguments[0])?;
+                let max = self.evaluate_integer(&arguments[1])?;
+                Ok(Value::Integer(self.random_between(min, max)?))
+            }
+            "date" => {
+                self.expect_uniform(&arguments[2])?;
+                let min = self.evaluate_date(&arguments[0])?;
+                let max = self.evaluate_date(&arguments[1])?;
+                let julian_days = self.random_between(min as i64, max as i64)?;
+                Ok(Value::Text(
+                    Date::from_julian_days(julian_days as i32).to_string(),
+                ))
+            }
+            "dist" => {
+                let name = self.evaluate_name(&arguments[0])?;
+                let value_set = self.evaluate_set(&name, &arguments[1], SetKind::Value)?;
+                let weight_set = self.evaluate_set(&name, &arguments[2], SetKind::Weight)?;
+                let distribution = &self.distributions.get(&name)?.distribution;
+                let value = distribution
+                    .pick_random_value(value_set, weight_set, &mut self.random)
+                    .map_err(|e| e.to_string())?;
+                Ok(Value::Text(value.to_string()))
+            }
+            "distmember" => {
+                let name = self.evaluate_name(&arguments[0])?;
+                let index = self.evaluate_entry(&name, &arguments[1])?;
+                let value_set = self.evaluate_set(&name, &arguments[2], SetKind::Value)?;
+                let distribution = &self.distributions.get(&name)?.distribution;
+                let value = distribution
+                    .get_value_at_index(value_set, index)
+                    .map_err(|e| e.to_string())?;
+                Ok(Value::Text(value.to_string()))
+            }
+            "distweight" => {
+                let name = self.evaluate_name(&arguments[0])?;
+                let index = self.evaluate_entry(&name, &arguments[1])?;
+                let weight_set = self.evaluate_set(&name, &arguments[2], SetKind::Weight)?;
+                let distribution = &self.distributions.get(&name)?.distribution;
+                let weight = distribution
+                    .get_weight_for_index(index, weight_set)
+                    .map_err(|e| e.to_string())?;
+                Ok(Value::Integer(weight as i64))
+            }
+            "ulist" => {
+                let count = self.evaluate_integer(&arguments[1])?;
+                if count < 1 {
+                    return Err("ulist() needs at least one value".into());
+                }
+                let count = count as usize;
+                let mut values = Vec::new();
+                let mut seen = HashSet::new();
+                for _ in 0..count * ULIST_ATTEMPTS_PER_VALUE {
+                    let value = self.evaluate(&arguments[0])?;
+                    if seen.insert(value.as_text()?) {
+                        values.push(value);
+                        if values.len() == count {
+                            return Ok(Value::List(values));
+                        }
+                    }
+                }
+                Err(format!("ulist() could not draw {} distinct values", count))
+            }
+            "text" => {
+                let mut choices = Vec::new();
+                for argument in arguments {
+                    let Expression::Choice(value, weight) = argument else {
+                        return Err("text() takes {value, weight} arguments".into());
+                    };
+                    choices.push((self.evaluate(value)?, self.evaluate_integer(weight)?));
+                }
+                let total: i64 = choices.iter().map(|(_, weight)| weight.max(&0)).sum();
+                if total < 1 {
+                    return Err("text() needs a positive weight".into());
+                }
+                let mut pick = self.random_between(1, total)?;
+                for (value, weight) in choices {
+                    pick -= weight.max(0);
+                    if pick <= 0 {
+                        return Ok(value);
+                    }
+                }
+                unreachable!("the pick is at most the total weight")
+            }
+            "list" => {
+                if arguments.is_empty() {
+                    return Err("list() needs at least one value".into());
+                }
+                let values = arguments
+                    .iter()
+                    .map(|argument| self.evaluate(argument))
+                    .collect::<EvaluationResult<_>>()?;
+                Ok(Value::List(values))
+            }
+            "rowcount" => {
+                let name = self.evaluate_name(&arguments[0])?;
+                let table = crate::config::Table::from_str(&name)
+                    .map_err(|_| format!("Unknown table {}", name))?;
+                Ok(Value::Integer(self.scaling.get_row_count(table)))
+            }
+            _ => unreachable!("unknown functions are rejected above"),
+        }
+    }
+
+    fn expect_uniform(&mut self, expression: &Expression) -> EvaluationResult<()> {
+        let distribution = self.evaluate_name(expression)?;
+        if distribution.eq_ignore_ascii_case("uniform") {
+            Ok(())
+        } else {
+            Err(format!(
+                "Only uniform random values are supported, not {}",
+                distribution
+            ))
+        }
+    }
+
+    fn random_between(&mut self, min: i64, max: i64) -> EvaluationResult<i64> {
+        if min > max || min < i32::MIN as i64 || max > i32::MAX as i64 {
+            return Err(format!("Invalid range {} to {}", min, max));
+        }
+        Ok(RandomValueGenerator::generate_uniform_random_int(
+            min as i32,
+            max as i32,
+            &mut self.random,
+        ) as i64)
+    }
+
+    /// Julian day of a `YYYY-MM-DD` date
+    fn evaluate_date(&mut self, expression: &Expression) -> EvaluationResult<i32> {
+        let text = self.evaluate(expression)?.as_text()?;
+        let parts: Vec<i32> = text
+            .trim()
+            .split('-')
+            .map(|part| part.parse::<i32>())
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|_| format!("Invalid date '{}'", text))?;
+        match parts[..] {
+            [year, month, day] => Date::new_validated(year, month, day)
+                .map(|date| date.to_julian_days())
+                .map_err(|_| format!("Invalid date '{}'", text)),
+            _ => Err(format!("Invalid date '{}'", text)),
+        }
+    }
+
+    /// 0-based entry of a distribution from a 1-based entry number
+    fn evaluate_entry(&mut self, name: &str, expression: &Expression) -> EvaluationResult<usize> {
+        let entry = self.evaluate_integer(expression)?;
+        let size = self.distributions.get(name)?.distribution.get_size();
+        if entry < 1 || entry as usize > size {
+            return Err(format!(
+                "Distribution {} has {} entries, not {}",
+                name, size, entry
+            ));
+        }
+        Ok(entry as usize - 1)
+    }
+
+    /// 0-based value or weight set from a 1-based number or a set name
+    fn evaluate_set(
+        &mut self,
+        name: &str,
+        expression: &Expression,
+        kind: SetKind,
+    ) -> EvaluationResult<usize> {
+        let set = match expression {
+            Expression::Word(word) => Value::Text(word.clone()),
+            expression => self.evaluate(expression)?,
+        };
+        let distribution = self.distributions.get(name)?;
+        let (names, count) = match kind {
+            SetKind::Value => (
+                &distribution.value_names,
+                distribution.distribution.get_value_lists_count(),
+            ),
+            SetKind::Weight => (
+                &distribution.weight_names,
+                distribution.distribution.get_weight_lists_count(),
+            ),
+        };
+        match set.as_integer() {
+            Some(number) if number >= 1 && number as usize <= count => Ok(number as usize - 1),
+            Some(number) => Err(format!(
+                "Distribution {} has {} {} sets, not {}",
+                name,
+                count,
+                kind.describe(),
+                number
+            )),
+            None => {
+                let set_name = set.as_text()?;
+                names
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(&set_name))
+                    .ok_or_else(|| {
+                        format!(
+                            "Distribution {} has no {} set named {}",
+                            name,
+                            kind.describe(),
+                            set_name
+                        )
+                    })
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum SetKind {
+    Value,
+    Weight,
+}
+
+impl SetKind {
+    fn describe(self) -> &'static str {
+        match self {
+            SetKind::Value => "value",
+            SetKind::Weight => "weight",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn generator(templates: &[(&str, &str)]) -> QueryGenerator {
+        let templates = templates
+            .iter()
+            .map(|(name, content)| QueryTemplate::parse(name, content).unwrap())
+            .collect();
+        QueryGenerator::new(templates, Scaling::new(1.0), QueryGenerator::DEFAULT_SEED)
+    }
+
+    fn query(template: &str) -> Result<String> {
+        generator(&[("q.tpl", template)]).generate_query(0, 1, 0)
+    }
+
+    #[test]
+    fn test_substitutions() {
+        let text = query(
+            "define YEAR = random(1998, 2002, uniform);\n\
+             define NEXT = [YEAR] + 1;\n\
+             define ZONE = text({\"east\", 1}, {\"west\", 0});\n\
+             define ROWS = rowcount(\"call_center\");\n\
+             define DAY = date(\"2000-01-01\", \"2000-01-01\", uniform);\n\
+             define _LIMIT = 100;\n\
+             [_LIMITA] select [_LIMITB] [YEAR], [NEXT], '[ZONE]', [ROWS], '[DAY]' [_LIMITC];\n",
+        )
+        .unwrap();
+
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            "-- start query 1 in stream 0 using template q.tpl"
+        );
+        assert_eq!(lines[2], "-- end query 1 in stream 0 using template q.tpl");
+
+        let fields: Vec<&str> = lines[1].split(", ").collect();
+        let year: i64 = fields[0].trim_start_matches(" select  ").parse().unwrap();
+        assert!((1998..=2002).contains(&year), "{}", lines[1]);
+        assert_eq!(fields[1], (year + 1).to_string());
+        assert_eq!(
+            &fields[2..],
+            &["'east'", "6", "'2000-01-01' fetch first 100 rows only;"]
+        );
+    }
+
+    #[test]
+    fn test_distribution_functions() {
+        let text = query(
+            "define CENTER = dist(call_centers, 1, 2);\n\
+             define FIRST = distmember(call_centers, 1, 1);\n\
+             define WEIGHT = distweight(call_centers, 2, 2);\n\
+             define STATES = ulist(dist(fips_county, 3, 1), 3);\n\
+             [FIRST]|[WEIGHT]|[CENTER]|[STATES.1]|[STATES.2]|[STATES.3]\n",
+        )
+        .unwrap();
+        let fields: Vec<&str> = text.lines().nth(1).unwrap().split('|').collect();
+        assert_eq!(&fields[..2], &["New England", "16"]);
+        assert!(!fields[2].is_empty());
+        let states: HashSet<&str> = fields[3..].iter().copied().collect();
+        assert_eq!(states.len(), 3);
+    }
+
+    #[test]
+    fn test_substitution_errors_report_line() {
+        let error = query("select 1\nfrom [MISSING];\n")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Template q.tpl, line 2: Undefined substitution [MISSING]"
+        );
+
+        let error = query("\ndefine X = dist(no_such_distribution, 1, 1);\nselect 1;")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Template q.tpl, line 2: Unknown distribution no_such_distribution"
+        );
+
+        let error = query("define X = dist(call_centers, 1, large);\nselect 1;")
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.ends_with("has no weight set named large"),
+            "{}",
+            error
+        );
+        assert!(query("define X = random(1, 2, zipf);\nselect 1;").is_err());
+        assert!(query("define X = ulist(random(1, 2, uniform), 3);\nselect 1;").is_err());
+    }
+
+    #[test]
+    fn test_dialects() {
+        let template = "define _LIMIT = 10;\n\
+                        [_LIMITA] select [_LIMITB] 'store' || s_store_id\n\
+                        where d between d0 and (d0 + 30 days)\n\
+                        [_LIMITC];\n";
+        let query_in = |dialect: &str| {
+            let text = generator(&[("q.tpl", template)])
+                .with_dialect(Dialect::get(dialect).unwrap())
+                .generate_query(0, 1, 0)
+                .unwrap();
+            text.lines().skip(1).take(3).collect::<Vec<_>>().join("\n")
+        };
+
+        assert_eq!(
+            query_in("ansi"),
+            " select  'store' || s_store_id\n\
+             where d between d0 and (d0 + interval '30' day)\n\
+             fetch first 10 rows only;"
+        );
+        assert_eq!(
+            query_in("postgres"),
+            " select  'store' || s_store_id\n\
+             where d between d0 and (d0 + interval '30 days')\n\
+             limit 10;"
+        );
+        assert_eq!(
+            query_in("spark"),
+            " select  concat('store', s_store_id)\n\
+             where d between d0 and (d0 + interval 30 days)\n\
+             limit 10;"
+        );
+    }
+
+    #[test]
+    fn test_qualification() {
+        let template = "define YEAR = random(1998, 2002, uniform);\n\
+                        define NEXT = [YEAR] + 1;\n\
+                        define COLOR = ulist(dist(colors, 1, 1), 2);\n\
+                        select [YEAR], [NEXT], '[COLOR.1]', '[COLOR.2]';\n";
+        let qualified = |values: &str| {
+            generator(&[("query1.tpl", template)])
+                .with_qualification(Qualification::parse("qualification", values).unwrap())
+                .generate_query(0, 1, 0)
+                .map(|text| text.lines().nth(1).unwrap().to_string())
+        };
+
+        assert_eq!(
+            qualified("[query1.tpl]\ndefine YEAR = 2000;\ndefine COLOR = list(\"red\", \"tan\");")
+                .unwrap(),
+            "select 2000, 2001, 'red', 'tan';"
+        );
+        assert_eq!(
+            qualified("[query1.tpl]\ndefine YEAR = 2000;")
+                .unwrap_err()
+                .to_string(),
+            "Template query1.tpl, line 3: No qualification value for COLOR"
+        );
+        assert_eq!(
+            qualified("[query1.tpl]\ndefine YEAR = 2000;\ndefine COLOUR = list(\"red\");")
+                .unwrap_err()
+                .to_string(),
+            "Template qualification [query1.tpl], line 3: query1.tpl does not define COLOUR"
+        );
+        assert_eq!(
+            qualified("[query2.tpl]\ndefine YEAR = 2000;")
+                .unwrap_err()
+                .to_string(),
+            "No qualification values for template query1.tpl"
+        );
+    }
+
+    #[test]
+    fn test_bundled_qualification() {
+        // The substitutions of query96.tpl and query3.tpl, with the answer set queries of the
+        // specification
+        let query96 = "define HOUR = text({\"20\", 1}, {\"15\", 1}, {\"16\", 1}, {\"8\", 1});\n\
+                       define DEPCNT = random(0, 9, uniform);\n\
+                       select count(*) where t_hour = [HOUR] and hd_dep_count = [DEPCNT];\n";
+        let query3 = "define AGGC = text({\"ss_ext_sales_price\", 1}, {\"ss_sales_price\", 1});\n\
+                      define MONTH = random(11, 12, uniform);\n\
+                      define MANUFACT = random(1, 1000, uniform);\n\
+                      select sum([AGGC]) where i_manufact_id = [MANUFACT] and d_moy = [MONTH];\n";
+        let mut generator = generator(&[("query96.tpl", query96), ("query3.tpl", query3)])
+            .with_qualification(Qualification::open(Qualification::BUNDLED).unwrap());
+        let mut query = |index: usize| {
+            let text = generator.generate_query(index, index + 1, 0).unwrap();
+            text.lines().nth(1).unwrap().to_string()
+        };
+
+        assert_eq!(
+            query(0),
+            "select count(*) where t_hour = 20 and hd_dep_count = 7;"
+        );
+        assert_eq!(
+            query(1),
+            "select sum(ss_ext_sales_price) where i_manufact_id = 128 and d_moy = 11;"
+        );
+    }
+
+    #[test]
+    fn test_streams_are_reproducible() {
+        let templates: Vec<(String, String)> = (1..=10)
+            .map(|i| {
+                (
+                    format!("query{}.tpl", i),
+                    "define X = random(1, 1000000, uniform);\nselect [X];\n".to_string(),
+                )
+            })
+            .collect();
+        let templates: Vec<(&str, &str)> = templates
+            .iter()
+            .map(|(name, content)| (name.as_str(), content.as_str()))
+            .collect();
+        let stream = |generator: &mut QueryGenerator, stream| {
+            let mut out = Vec::new();
+            generator.write_stream(&mut out, stream).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+
+        let mut first = generator(&templates);
+        let mut second = generator(&templates);
+        assert_eq!(stream(&mut first, 0), stream(&mut second, 0));
+        assert_eq!(stream(&mut first, 1), stream(&mut second, 1));
+        assert_ne!(stream(&mut first, 0), stream(&mut first, 1));
+
+        assert_eq!(
+            first.get_stream_order(0).unwrap(),
+            (0..10).collect::<Vec<_>>()
+        );
+        let mut order = first.get_stream_order(1).unwrap();
+        assert_ne!(order, (0..10).collect::<Vec<_>>());
+        order.sort();
+        assert_eq!(order, (0..10).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,10 @@
+pub mod dialect;
+pub mod expression;
+pub mod generator;
+pub mod qualification;
+pub mod template;
+
+pub use dialect::Dialect;
+pub use generator::QueryGenerator;
+pub use qualification::Qualification;
+pub use template::{load_templates, QueryTemplate};
@@ -0,0 +1,195 @@
+use crate::error::Result;
+use crate::query::template::QueryTemplate;
+use crate::TpcdsError;
+use std::fs;
+use std::path::Path;
+
+/// Qualification substitution values: the fixed values of the random substitutions of every
+/// template, for comparing query results to the answer sets of the TPC-DS specification.
+///
+/// The file has a `[template]` section per template with the `define`s that replace the
+/// template's own, in template syntax:
+///
+/// ```text
+/// [query96.tpl]
+/// define HOUR = 20;
+/// define DEPCNT = 7;
+/// ```
+///
+/// `list(...)` gives the values of a `ulist()` substitution.
+///
+/// The values of the specification are bundled for 8 of its 99 templates only, see
+/// [`Self::BUNDLED`]: they do not qualify a query set on their own.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qualification {
+    /// Section name and its defines
+    sections: Vec<(String, QueryTemplate)>,
+}
+
+impl Qualification {
+    /// Name of the bundled values, those of the specification for the templates in
+    /// `qualification.txt`
+    pub const BUNDLED: &'static str = "bundled";
+    const BUNDLED_CONTENT: &'static str = include_str!("qualification.txt");
+
+    /// The bundled values for [`Self::BUNDLED`], or the qualification file at `name`
+    pub fn open(name: &str) -> Result<Self> {
+        if name.eq_ignore_ascii_case(Self::BUNDLED) {
+            return Self::parse("qualification.txt", Self::BUNDLED_CONTENT);
+        }
+        Self::load(Path::new(name))
+    }
+
+    /// Read and parse a qualification file
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            TpcdsError::io(
+                &format!("Failed to read qualification file {}", path.display()),
+                e,
+            )
+        })?;
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Self::parse(&name, &content)
+    }
+
+    /// Parse the contents of the qualification file `name`
+    pub fn parse(name: &str, content: &str) -> Result<Self> {
+        let mut sections: Vec<(String, String)> = Vec::new();
+        for (index, line) in content.lines().enumerate() {
+            let trimmed = line.trim();
+            if let Some(section) = trimmed
+                .strip_prefix('[')
+                .and_then(|rest| rest.strip_suffix(']'))
+            {
+                if sections
+                    .iter()
+                    .any(|(other, _)| other.eq_ignore_ascii_case(section))
+                {
+                    return Err(TpcdsError::new(&format!(
+                        "Qualification file {}, line {}: Duplicate section [{}]",
+                        name,
+                        index + 1,
+                        section
+                    )));
+                }
+                // Blank lines up to the header keep the line numbers of the file
+                sections.push((section.to_string(), "\n".repeat(index + 1)));
+            } else if let Some((_, text)) = sections.last_mut() {
+                text.push_str(line);
+                text.push('\n');
+            } else if !trimmed.is_empty() && !trimmed.starts_with("--") {
+                return Err(TpcdsError::new(&format!(
+                    "Qualification file {}, line {}: Expected a [template] section",
+                    name,
+                    index + 1
+                )));
+            }
+        }
+
+        let sections = sections
+            .into_iter()
+            .map(|(section, text)| {
+                let defines = QueryTemplate::parse(&format!("{} [{}]", name, section), &text)?;
+                if !defines.segments.is_empty() {
+                    return Err(TpcdsError::new(&format!(
+                        "Qualification file {}: Section [{}] may only contain defines",
+                        name, section
+                    )));
+                }
+                Ok((section, defines))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { sections })
+    }
+
+    /// Qualification values of `template`, as the defines of a template named after its
+    /// section
+    pub fn get(&self, template: &str) -> Option<&QueryTemplate> {
+        self.sections
+            .iter()
+            .find(|(section, _)| section.eq_ignore_ascii_case(template))
+            .map(|(_, defines)| defines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sections() {
+        let qualification = Qualification::parse(
+            "qualification",
+            "-- values for SF=1\n\
+             \n\
+             [query1.tpl]\n\
+             define YEAR = 2000;\n\
+             [query2.tpl]\n\
+             define STATE = \"TN\";\n\
+             define COLOR = list(\"red\",\n\
+             \x20   \"tan\");\n",
+        )
+        .unwrap();
+
+        let names = |template: &str| -> Vec<(String, usize)> {
+            qualification
+                .get(template)
+                .unwrap()
+                .defines
+                .iter()
+                .map(|define| (define.name.clone(), define.line))
+                .collect()
+        };
+        assert_eq!(names("query1.tpl"), vec![("YEAR".to_string(), 4)]);
+        assert_eq!(
+            names("QUERY2.TPL"),
+            vec![("STATE".to_string(), 6), ("COLOR".to_string(), 7)]
+        );
+        assert!(qualification.get("query3.tpl").is_none());
+    }
+
+    #[test]
+    fn test_bundled_values() {
+        let qualification = Qualification::open(Qualification::BUNDLED).unwrap();
+        let values = |template: &str| -> Vec<String> {
+            qualification
+                .get(template)
+                .unwrap()
+                .defines
+                .iter()
+                .map(|define| define.name.clone())
+                .collect()
+        };
+        assert_eq!(values("query96.tpl"), vec!["HOUR", "DEPCNT"]);
+        assert_eq!(values("query3.tpl"), vec!["MONTH", "MANUFACT", "AGGC"]);
+        assert!(qualification.get("query2.tpl").is_none());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |content: &str| {
+            Qualification::parse("qualification", content)
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(
+            error("define YEAR = 2000;\n"),
+            "Qualification file qualification, line 1: Expected a [template] section"
+        );
+        assert_eq!(
+            error("[query1.tpl]\n[QUERY1.tpl]\n"),
+            "Qualification file qualification, line 2: Duplicate section [QUERY1.tpl]"
+        );
+        assert_eq!(
+            error("[query1.tpl]\nselect 1;\n"),
+            "Qualification file qualification: Section [query1.tpl] may only contain defines"
+        );
+        assert_eq!(
+            error("[query1.tpl]\n\ndefine YEAR = random(;\n"),
+            "Template qualification [query1.tpl], line 3: Unexpected end of expression"
+        );
+    }
+}
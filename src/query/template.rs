@@ -0,0 +1,334 @@
+use crate::error::Result;
+use crate::query::expression::{parse_expression, Expression, Tag};
+use crate::TpcdsError;
+use std::fs;
+use std::path::Path;
+
+/// `define NAME = expression;` statement of a template
+#[derive(Debug, Clone, PartialEq)]
+pub struct Define {
+    /// Substitution name, upper case
+    pub name: String,
+    pub expression: Expression,
+    /// Line of the template the define starts on
+    pub line: usize,
+}
+
+/// Piece of the query text of a template
+#[derive(Debug, Clone, PartialEq)]
+pub enum Segment {
+    Text(String),
+    Tag { tag: Tag, line: usize },
+}
+
+/// A dsqgen query template (`.tpl`): substitution defines and the query text they are
+/// substituted into. Lines starting with `--` are comments and are left out of the query.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryTemplate {
+    /// File name, e.g. `query1.tpl`
+    pub name: String,
+    pub defines: Vec<Define>,
+    pub segments: Vec<Segment>,
+}
+
+impl QueryTemplate {
+    /// Read and parse a template file
+    pub fn load(path: &Path) -> Result<Self> {
+        let bytes = fs::read(path).map_err(|e| {
+            TpcdsError::io(&format!("Failed to read template {}", path.display()), e)
+        })?;
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        Self::parse(&name, &String::from_utf8_lossy(&bytes))
+    }
+
+    /// Parse the contents of the template `name`
+    pub fn parse(name: &str, content: &str) -> Result<Self> {
+        let error = |line: usize, message: &str| {
+            TpcdsError::new(&format!("Template {}, line {}: {}", name, line, message))
+        };
+
+        let mut defines = Vec::new();
+        let mut query_lines: Vec<(usize, &str)> = Vec::new();
+        // Text of a define that continues on the following lines, with its first line
+        let mut pending_define: Option<(usize, String)> = None;
+        for (index, line) in content.lines().enumerate() {
+            let line_number = index + 1;
+            let trimmed = line.trim_start();
+            let text = match pending_define.take() {
+                Some((start, mut text)) => {
+                    text.push('\n');
+                    text.push_str(line);
+                    Some((start, text))
+                }
+                None if starts_with_keyword(trimmed, "define") => {
+                    Some((line_number, trimmed["define".len()..].to_string()))
+                }
+                None => {
+                    if !trimmed.starts_with("--") {
+                        query_lines.push((line_number, line));
+                    }
+                    None
+                }
+            };
+            if let Some((start, text)) = text {
+                match find_statement_end(&text) {
+                    Some(end) => {
+                        defines
+                            .push(parse_define(&text[..end], start).map_err(|e| error(start, &e))?);
+                        if !text[end + 1..].trim().is_empty() {
+                            return Err(error(line_number, "Unexpected text after define"));
+                        }
+                    }
+                    None => pending_define = Some((start, text)),
+                }
+            }
+        }
+        if let Some((start, _)) = pending_define {
+            return Err(error(start, "Define is not terminated by ';'"));
+        }
+
+        // Leading and trailing blank lines are not part of the query
+        while query_lines
+            .first()
+            .is_some_and(|(_, line)| line.trim().is_empty())
+        {
+            query_lines.remove(0);
+        }
+        while query_lines
+            .last()
+            .is_some_and(|(_, line)| line.trim().is_empty())
+        {
+            query_lines.pop();
+        }
+
+        let mut segments = Vec::new();
+        for (i, (line_number, line)) in query_lines.iter().enumerate() {
+            if i > 0 {
+                push_text(&mut segments, "\n");
+            }
+            split_tags(line.trim_end(), *line_number, &mut segments);
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            defines,
+            segments,
+        })
+    }
+}
+
+fn starts_with_keyword(text: &str, keyword: &str) -> bool {
+    text.get(..keyword.len())
+        .is_some_and(|start| start.eq_ignore_ascii_case(keyword))
+        && text[keyword.len()..].starts_with(char::is_whitespace)
+}
+
+/// Position of the `;` ending a statement, ignoring the ones in strings
+fn find_statement_end(text: &str) -> Option<usize> {
+    let mut in_string = false;
+    for (position, c) in text.char_indices() {
+        match c {
+            '"' => in_string = !in_string,
+            ';' if !in_string => return Some(position),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Parse `NAME = expression` (the text between `define` and `;`) starting on `line`
+fn parse_define(text: &str, line: usize) -> std::result::Result<Define, String> {
+    let (name, expression) = text
+        .split_once('=')
+        .ok_or("Expected 'define NAME = expression;'")?;
+    let name = name.trim();
+    let tag = Tag::parse(name)
+        .filter(|tag| tag.index.is_none())
+        .ok_or_else(|| format!("Invalid substitution name '{}'", name))?;
+    Ok(Define {
+        name: tag.name,
+        expression: parse_expression(expression)?,
+        line,
+    })
+}
+
+fn push_text(segments: &mut Vec<Segment>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    if let Some(Segment::Text(last)) = segments.last_mut() {
+        last.push_str(text);
+    } else {
+        segments.push(Segment::Text(text.to_string()));
+    }
+}
+
+/// Split a line of query text into text and `[TAG]` segments. Brackets that do not hold a tag
+/// are kept as text.
+fn split_tags(line: &str, line_number: usize, segments: &mut Vec<Segment>) {
+    let mut rest = line;
+    while let Some(open) = rest.find('[') {
+        let tag = rest[open + 1..]
+            .find(']')
+            .and_then(|close| Some((Tag::parse(&rest[open + 1..open + 1 + close])?, close)));
+        match tag {
+            Some((tag, close)) => {
+                push_text(segments, &rest[..open]);
+                segments.push(Segment::Tag {
+                    tag,
+                    line: line_number,
+                });
+                rest = &rest[open + close + 2..];
+            }
+            None => {
+                push_text(segments, &rest[..=open]);
+                rest = &rest[open + 1..];
+            }
+        }
+    }
+    push_text(segments, rest);
+}
+
+/// Load the templates of a query stream from `directory`. With an `input` file, the templates
+/// are the file names it lists, one per line (blank lines and lines starting with `#` or `--`
+/// are skipped); otherwise every `.tpl` file, in the order of the number in its name
+/// (`query2.tpl` before `query10.tpl`).
+pub fn load_templates(directory: &Path, input: Option<&Path>) -> Result<Vec<QueryTemplate>> {
+    let names: Vec<String> = match input {
+        Some(input) => fs::read_to_string(input)
+            .map_err(|e| {
+                TpcdsError::io(
+                    &format!("Failed to read template list {}", input.display()),
+                    e,
+                )
+            })?
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#') && !line.starts_with("--"))
+            .map(str::to_string)
+            .collect(),
+        None => {
+            let mut names = Vec::new();
+            for entry in fs::read_dir(directory).map_err(|e| {
+                TpcdsError::io(
+                    &format!("Failed to read template directory {}", directory.display()),
+                    e,
+                )
+            })? {
+                let name = entry?.file_name().to_string_lossy().to_string();
+                if name.ends_with(".tpl") {
+                    names.push(name);
+                }
+            }
+            names.sort_by_key(|name| (template_number(name), name.clone()));
+            names
+        }
+    };
+    if names.is_empty() {
+        return Err(TpcdsError::new(&format!(
+            "No query templates found in {}",
+            directory.display()
+        )));
+    }
+    names
+        .iter()
+        .map(|name| QueryTemplate::load(&directory.join(name)))
+        .collect()
+}
+
+/// Number in a template name, e.g. 14 for `query14.tpl`
+fn template_number(name: &str) -> u32 {
+    let digits: String = name
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str, index: Option<usize>, line: usize) -> Segment {
+        Segment::Tag {
+            tag: Tag {
+                name: name.to_string(),
+                index,
+            },
+            line,
+        }
+    }
+
+    #[test]
+    fn test_parse_template() {
+        let template = QueryTemplate::parse(
+            "query96.tpl",
+            "-- a comment\n\
+             define HOUR = random(8, 20, uniform);\n\
+             Define COLOR = ulist(dist(colors, 1, 1),\n\
+             \x20   2);\n\
+             define _LIMIT=100;\n\
+             \n\
+             [_LIMITA] select [_LIMITB] count(*)\n\
+             from t where h = [HOUR] and c in ('[COLOR.1]', '[COLOR.2]') and a[1] = 0\n\
+             [_LIMITC];\n\
+             \n",
+        )
+        .unwrap();
+
+        let names: Vec<&str> = template.defines.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(names, vec!["HOUR", "COLOR", "_LIMIT"]);
+        let lines: Vec<usize> = template.defines.iter().map(|d| d.line).collect();
+        assert_eq!(lines, vec![2, 3, 5]);
+
+        assert_eq!(
+            template.segments,
+            vec![
+                tag("_LIMITA", None, 7),
+                Segment::Text(" select ".to_string()),
+                tag("_LIMITB", None, 7),
+                Segment::Text(" count(*)\nfrom t where h = ".to_string()),
+                tag("HOUR", None, 8),
+                Segment::Text(" and c in ('".to_string()),
+                tag("COLOR", Some(1), 8),
+                Segment::Text("', '".to_string()),
+                tag("COLOR", Some(2), 8),
+                Segment::Text("') and a[1] = 0\n".to_string()),
+                tag("_LIMITC", None, 9),
+                Segment::Text(";".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_report_line() {
+        let error = QueryTemplate::parse("q.tpl", "select 1;\ndefine X = random(1, ;\n")
+            .unwrap_err()
+            .to_string();
+        assert!(error.starts_with("Template q.tpl, line 2:"), "{}", error);
+
+        let error = QueryTemplate::parse("q.tpl", "define X = 1\nselect 1\n")
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Template q.tpl, line 1: Define is not terminated by ';'"
+        );
+
+        assert!(QueryTemplate::parse("q.tpl", "define 1X = 1;").is_err());
+    }
+
+    #[test]
+    fn test_template_order() {
+        let mut names = vec!["query10.tpl", "query2.tpl", "query1.tpl", "other.tpl"];
+        names.sort_by_key(|name| (template_number(name), name.to_string()));
+        assert_eq!(
+            names,
+            vec!["query1.tpl", "query2.tpl", "query10.tpl", "other.tpl"]
+        );
+    }
+}
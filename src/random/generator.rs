@@ -1,3 +1,4 @@
+use crate::error::Result;
 use crate::random::stream::RandomNumberStream;
 use crate::types::{Date, Decimal};
 
@@ -36,7 +37,7 @@ impl RandomValueGenerator {
         min: Decimal,
         max: Decimal,
         random_number_stream: &mut dyn RandomNumberStream,
-    ) -> Decimal {
+    ) -> crate::error::Result<Decimal> {
         let precision = if min.get_precision() < max.get_precision() {
             min.get_precision()
         } else {
@@ -48,7 +49,7 @@ impl RandomValueGenerator {
         number %= max.get_number() - min.get_number() + 1;
         number += min.get_number();
 
-        Decimal::new(number, precision).unwrap()
+        Decimal::new(number, precision)
     }
 
     pub fn generate_uniform_random_date(
@@ -153,14 +154,14 @@ impl RandomValueGenerator {
         min_length: i32,
         max_length: i32,
         random_number_stream: &mut dyn RandomNumberStream,
-    ) -> String {
+    ) -> Result<String> {
         let mut is_sentence_beginning = true;
         let mut text = String::new();
         let mut target_length =
             Self::generate_uniform_random_int(min_length, max_length, random_number_stream);
 
         while target_length > 0 {
-            let mut generated = Self::generate_random_sentence(random_number_stream);
+            let mut generated = Self::generate_random_sentence(random_number_stream)?;
             if is_sentence_beginning && !generated.is_empty() {
                 let first_char = generated
                     .chars()
@@ -188,7 +189,7 @@ impl RandomValueGenerator {
             }
         }
 
-        text
+        Ok(text)
     }
 
     /// Generate a random URL (generateRandomUrl)
@@ -198,39 +199,29 @@ impl RandomValueGenerator {
     }
 
     // Generate random sentence following Java implementation exactly
-    fn generate_random_sentence(random_number_stream: &mut dyn RandomNumberStream) -> String {
+    fn generate_random_sentence(
+        random_number_stream: &mut dyn RandomNumberStream,
+    ) -> Result<String> {
         use crate::distribution::*;
 
         let mut verbiage = String::new();
-        let syntax = pick_random_sentence(random_number_stream).unwrap_or("N V.");
+        let syntax = pick_random_sentence(random_number_stream)?;
 
         for ch in syntax.chars() {
             match ch {
-                'N' => verbiage.push_str(pick_random_noun(random_number_stream).unwrap_or("thing")),
-                'V' => verbiage.push_str(pick_random_verb(random_number_stream).unwrap_or("is")),
-                'J' => {
-                    verbiage.push_str(pick_random_adjective(random_number_stream).unwrap_or("good"))
-                }
-                'D' => {
-                    verbiage.push_str(pick_random_adverb(random_number_stream).unwrap_or("well"))
-                }
-                'X' => {
-                    verbiage.push_str(pick_random_auxiliary(random_number_stream).unwrap_or("can"))
-                }
-                'P' => {
-                    verbiage.push_str(pick_random_preposition(random_number_stream).unwrap_or("to"))
-                }
-                'A' => {
-                    verbiage.push_str(pick_random_article(random_number_stream).unwrap_or("the"))
-                }
-                'T' => {
-                    verbiage.push_str(pick_random_terminator(random_number_stream).unwrap_or("."))
-                }
+                'N' => verbiage.push_str(pick_random_noun(random_number_stream)?),
+                'V' => verbiage.push_str(pick_random_verb(random_number_stream)?),
+                'J' => verbiage.push_str(pick_random_adjective(random_number_stream)?),
+                'D' => verbiage.push_str(pick_random_adverb(random_number_stream)?),
+                'X' => verbiage.push_str(pick_random_auxiliary(random_number_stream)?),
+                'P' => verbiage.push_str(pick_random_preposition(random_number_stream)?),
+                'A' => verbiage.push_str(pick_random_article(random_number_stream)?),
+                'T' => verbiage.push_str(pick_random_terminator(random_number_stream)?),
                 _ => verbiage.push(ch), // this is for adding punctuation and white space.
             }
         }
 
-        verbiage
+        Ok(verbiage)
     }
 
     // Generate word based on seed and syllables distribution (exact Java implementation)
@@ -238,18 +229,16 @@ impl RandomValueGenerator {
         seed: i32,
         max_chars: i32,
         _random_number_stream: &mut dyn RandomNumberStream,
-    ) -> String {
+    ) -> crate::error::Result<String> {
         use crate::distribution::get_syllables_distribution;
 
-        let distribution = get_syllables_distribution();
+        let distribution = get_syllables_distribution()?;
         let size = distribution.get_size();
         let mut word = String::new();
         let mut seed = seed as i64;
 
         while seed > 0 {
-            let syllable = distribution
-                .get_value_at_index(0, (seed % size as i64) as usize)
-                .unwrap_or("syl");
+            let syllable = distribution.get_value_at_index(0, (seed % size as i64) as usize)?;
             seed /= size as i64;
 
             if (word.len() + syllable.len()) <= max_chars as usize {
@@ -259,11 +248,12 @@ impl RandomValueGenerator {
             }
         }
 
-        word
+        Ok(word)
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::manual_range_contains, unused_variables)]
 mod tests {
     use super::*;
     use crate::random::stream::RandomNumberStreamImpl;
@@ -287,7 +277,8 @@ mod tests {
         let mut stream = RandomNumberStreamImpl::new(1).unwrap();
         let min = Decimal::new(100, 2).unwrap(); // 1.00
         let max = Decimal::new(500, 2).unwrap(); // 5.00
-        let result = RandomValueGenerator::generate_uniform_random_decimal(min, max, &mut stream);
+        let result =
+            RandomValueGenerator::generate_uniform_random_decimal(min, max, &mut stream).unwrap();
 
         assert!(result.get_number() >= min.get_number() && result.get_number() <= max.get_number());
         assert_eq!(result.get_precision(), 2);
@@ -361,4 +352,23 @@ mod tests {
             assert!(charset.contains(ch));
         }
     }
+
+    #[test]
+    fn test_generate_word() {
+        let mut stream = RandomNumberStreamImpl::new(1).unwrap();
+        let syllables = crate::distribution::get_syllables_distribution().unwrap();
+        let first = syllables.get_value_at_index(0, 1).unwrap();
+
+        let word = RandomValueGenerator::generate_word(1, 100, &mut stream).unwrap();
+        assert_eq!(word, first);
+        assert_eq!(
+            RandomValueGenerator::generate_word(0, 100, &mut stream).unwrap(),
+            ""
+        );
+        // Syllables that do not fit are left out
+        assert_eq!(
+            RandomValueGenerator::generate_word(1, first.len() as i32 - 1, &mut stream).unwrap(),
+            ""
+        );
+    }
 }
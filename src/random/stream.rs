@@ -1,4 +1,4 @@
-use crate::{check_argument, error::Result, TpcdsError};
+use crate::{check_argument, error::Result};
 
 pub trait RandomNumberStream: Send + Sync {
     fn next_random(&mut self) -> i64;
@@ -20,7 +20,8 @@ pub struct RandomNumberStreamImpl {
 }
 
 impl RandomNumberStreamImpl {
-    const DEFAULT_SEED_BASE: i32 = 19620718;
+    /// Base seed of the column streams, fixed by the TPC-DS specification
+    pub const DEFAULT_SEED_BASE: i32 = 19620718;
     const MULTIPLIER: i64 = 16807;
     const QUOTIENT: i64 = 127773; // the quotient MAX_INT / MULTIPLIER
     const REMAINDER: i64 = 2836; // the remainder MAX_INT % MULTIPLIER
@@ -112,6 +113,7 @@ impl RandomNumberStream for RandomNumberStreamImpl {
 }
 
 #[cfg(test)]
+#[allow(clippy::manual_range_contains)]
 mod tests {
     use super::*;
 
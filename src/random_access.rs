@@ -0,0 +1,117 @@
+use crate::config::Session;
+use crate::error::Result;
+use crate::row::TableRow;
+use crate::slowly_changing_dimension_utils::compute_scd_key;
+use crate::table::Table;
+use crate::TpcdsError;
+
+/// Generate row `row_number` (1-based) of `table` without generating the rows before it.
+///
+/// The random number streams jump straight to the row with `skip_rows`. Tables that keep history
+/// build each revision of a business key from the previous one, so for those generation starts
+/// at the first revision of the row's business key, at most a few rows earlier.
+pub fn row_at(table: Table, row_number: i64, session: &Session) -> Result<Box<dyn TableRow>> {
+    if table.is_refresh_table() {
+        return Err(TpcdsError::new(&format!(
+            "Table {} is a refresh table, row_at only generates base tables",
+            table
+        )));
+    }
+    let row_count = session.get_scaling().get_row_count(table.into());
+    if row_number < 1 || row_number > row_count {
+        return Err(TpcdsError::new(&format!(
+            "Row number {} is out of range for {}, which has {} rows",
+            row_number, table, row_count
+        )));
+    }
+
+    let mut row_generator = table
+        .create_row_generator()
+        .ok_or_else(|| TpcdsError::new(&format!("Table {} has not been ported yet", table)))?;
+
+    let mut current_row_number = get_first_revision(table, row_number)?;
+    row_generator.skip_rows_until_starting_row_number(current_row_number);
+    loop {
+        let result = row_generator
+            .generate_row_and_child_rows(current_row_number, session, None, None)
+            .map_err(|e| TpcdsError::generation(table, current_row_number, e))?;
+        if current_row_number == row_number {
+            return result.into_rows().into_iter().next().ok_or_else(|| {
+                TpcdsError::new(&format!(
+                    "No row generated for {} row {}",
+                    table, row_number
+                ))
+            });
+        }
+        if result.should_end_row() {
+            row_generator.consume_remaining_seeds_for_row();
+            current_row_number += 1;
+        }
+    }
+}
+
+/// First row of the business key that `row_number` is a revision of
+fn get_first_revision(table: Table, row_number: i64) -> Result<i64> {
+    let mut first_revision = row_number;
+    if table.keeps_history() {
+        while first_revision > 1 && !compute_scd_key(table, first_revision)?.is_new_business_key() {
+            first_revision -= 1;
+        }
+    }
+    Ok(first_revision)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequential_rows(table: Table, session: &Session, count: i64) -> Vec<Vec<String>> {
+        let mut row_generator = table.create_row_generator().unwrap();
+        let mut rows = Vec::new();
+        let mut row_number = 1;
+        while row_number <= count {
+            let result = row_generator
+                .generate_row_and_child_rows(row_number, session, None, None)
+                .unwrap();
+            rows.push(result.get_rows()[0].get_values());
+            if result.should_end_row() {
+                row_generator.consume_remaining_seeds_for_row();
+                row_number += 1;
+            }
+        }
+        rows
+    }
+
+    #[test]
+    fn test_row_at_matches_sequential_generation() {
+        let session = Session::get_default_session();
+        for table in [
+            Table::CallCenter,
+            Table::WebSite,
+            Table::WebPage,
+            Table::Promotion,
+        ] {
+            let row_count = session.get_scaling().get_row_count(table.into());
+            let rows = sequential_rows(table, &session, row_count);
+            for row_number in 1..=row_count {
+                assert_eq!(
+                    row_at(table, row_number, &session).unwrap().get_values(),
+                    rows[row_number as usize - 1],
+                    "{} row {}",
+                    table,
+                    row_number
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_row_at_out_of_range() {
+        let session = Session::get_default_session();
+        assert!(row_at(Table::CallCenter, 0, &session).is_err());
+        assert!(row_at(Table::CallCenter, 7, &session).is_err());
+        assert!(row_at(Table::CallCenter, 6, &session).is_ok());
+        assert!(row_at(Table::Item, 1, &session).is_err());
+        assert!(row_at(Table::SCallCenter, 1, &session.with_update(1)).is_err());
+    }
+}
@@ -1,22 +1,71 @@
+use crate::error::Result;
 use crate::generator::GeneratorColumn;
-use crate::random::RandomNumberStream;
+use crate::random::{RandomNumberStream, RandomNumberStreamImpl};
 use crate::table::Table;
+use std::collections::hash_map::Entry;
 use std::collections::HashMap;
+use std::fmt;
+
+/// A generator column that drew more random numbers for a row than its `get_seeds_per_row`.
+/// `consume_remaining_seeds_for_row` only pads streams up to their budget, so an overdraw shifts
+/// every following row of the column.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedBudgetViolation {
+    pub table: Table,
+    /// Generator column name, e.g. `CcCallCenterSk`
+    pub column: String,
+    pub global_column_number: i32,
+    pub row_number: i64,
+    pub seeds_used: i32,
+    pub seeds_per_row: i32,
+}
+
+impl fmt::Display for SeedBudgetViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} column {} ({}) drew {} seeds in row {}, budget is {}",
+            self.table,
+            self.column,
+            self.global_column_number,
+            self.seeds_used,
+            self.row_number,
+            self.seeds_per_row
+        )
+    }
+}
 
 /// Abstract base for row generators (AbstractRowGenerator)
 /// Handles common functionality like random number stream management
 pub struct AbstractRowGenerator {
     table: Table,
     random_number_streams: HashMap<i32, Box<dyn RandomNumberStream>>,
+    /// Row whose seeds are being drawn, for audit reports
+    row_number: i64,
+    /// Violations found so far, `None` unless the seed audit is enabled
+    seed_budget_violations: Option<Vec<SeedBudgetViolation>>,
 }
 
 impl AbstractRowGenerator {
     /// Create a new abstract row generator for the given table
+    ///
+    /// Streams for every generator column of the table are created up front so that
+    /// `consume_remaining_seeds_for_row` and `skip_rows_until_starting_row_number` keep
+    /// all of them in step, even the ones a given row does not draw from.
     pub fn new(table: Table) -> Self {
-        Self {
+        let mut generator = Self {
             table,
             random_number_streams: HashMap::new(),
+            row_number: 1,
+            seed_budget_violations: None,
+        };
+        for i in 0..table.get_generator_column_count() {
+            if let Some(column) = table.get_generator_column_by_index(i) {
+                // A stream that cannot be created fails the first row that draws from it
+                let _ = generator.get_random_number_stream(column);
+            }
         }
+        generator
     }
 
     /// Get the table this generator is for
@@ -28,32 +77,38 @@ impl AbstractRowGenerator {
     pub fn get_random_number_stream(
         &mut self,
         column: &dyn GeneratorColumn,
-    ) -> &mut dyn RandomNumberStream {
+    ) -> Result<&mut dyn RandomNumberStream> {
         let global_column_number = column.get_global_column_number();
-
-        self.random_number_streams
-            .entry(global_column_number)
-            .or_insert_with(|| {
-                // Create a new stream for this column
-                let seeds_per_row = column.get_seeds_per_row();
-                let stream = crate::random::RandomNumberStreamImpl::new_with_column(
+        let stream = match self.random_number_streams.entry(global_column_number) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                entry.insert(Box::new(RandomNumberStreamImpl::new_with_column(
                     global_column_number,
-                    seeds_per_row,
-                )
-                .expect("Failed to create random number stream");
-                Box::new(stream)
-            });
-
-        self.random_number_streams
-            .get_mut(&global_column_number)
-            .unwrap()
-            .as_mut()
+                    column.get_seeds_per_row(),
+                )?))
+            }
+        };
+        Ok(stream.as_mut())
+    }
+
+    /// Record every column that draws more seeds in a row than its budget. Overdraws are checked
+    /// when the row ends, in `consume_remaining_seeds_for_row`.
+    pub fn enable_seed_audit(&mut self) {
+        self.seed_budget_violations.get_or_insert_with(Vec::new);
+    }
+
+    /// Columns that exceeded their seed budget, in row order (empty unless the audit is enabled)
+    pub fn get_seed_budget_violations(&self) -> &[SeedBudgetViolation] {
+        self.seed_budget_violations.as_deref().unwrap_or(&[])
     }
 
     /// Consume remaining seeds for all streams (AbstractRowGenerator.consumeRemainingSeedsForRow)
     pub fn consume_remaining_seeds_for_row(&mut self) {
         use crate::random::RandomValueGenerator;
 
+        if self.seed_budget_violations.is_some() {
+            self.audit_seeds_used();
+        }
         for stream in self.random_number_streams.values_mut() {
             // Consume remaining seeds until each stream has used its full seeds_per_row allocation
             while stream.get_seeds_used() < stream.get_seeds_per_row() {
@@ -62,13 +117,41 @@ impl AbstractRowGenerator {
             // Reset seeds used count for next row
             stream.reset_seeds_used();
         }
+        self.row_number += 1;
+    }
+
+    fn audit_seeds_used(&mut self) {
+        for i in 0..self.table.get_generator_column_count() {
+            let Some(column) = self.table.get_generator_column_by_index(i) else {
+                continue;
+            };
+            let global_column_number = column.get_global_column_number();
+            let Some(stream) = self.random_number_streams.get(&global_column_number) else {
+                continue;
+            };
+            if stream.get_seeds_used() > stream.get_seeds_per_row() {
+                let violation = SeedBudgetViolation {
+                    table: self.table,
+                    column: format!("{:?}", column),
+                    global_column_number,
+                    row_number: self.row_number,
+                    seeds_used: stream.get_seeds_used(),
+                    seeds_per_row: stream.get_seeds_per_row(),
+                };
+                if let Some(violations) = self.seed_budget_violations.as_mut() {
+                    violations.push(violation);
+                }
+            }
+        }
     }
 
     /// Skip rows for all streams until reaching the starting row number
+    /// (row numbers are 1-based, so starting at row 1 skips nothing)
     pub fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
         for stream in self.random_number_streams.values_mut() {
-            stream.skip_rows(starting_row_number);
+            stream.skip_rows(starting_row_number - 1);
         }
+        self.row_number = starting_row_number;
     }
 
     /// Advance all streams to the next row
@@ -108,11 +191,47 @@ mod tests {
         let mut generator = AbstractRowGenerator::new(Table::CallCenter);
         let column = &CallCenterGeneratorColumn::CcCallCenterSk;
 
-        let _stream1 = generator.get_random_number_stream(column);
-        let _stream2 = generator.get_random_number_stream(column);
+        let stream_count = generator.random_number_streams.len();
+        let _stream1 = generator.get_random_number_stream(column).unwrap();
+        let _stream2 = generator.get_random_number_stream(column).unwrap();
 
         // Should reuse the same stream for the same column
-        assert_eq!(generator.random_number_streams.len(), 1);
+        assert_eq!(generator.random_number_streams.len(), stream_count);
+    }
+
+    #[test]
+    fn test_seed_audit_reports_overdraw() {
+        let mut generator = AbstractRowGenerator::new(Table::CallCenter);
+        let column = &CallCenterGeneratorColumn::CcEmployees;
+        let seeds_per_row = column.get_seeds_per_row();
+
+        // Without the audit an overdraw goes unnoticed
+        for _ in 0..=seeds_per_row {
+            generator
+                .get_random_number_stream(column)
+                .unwrap()
+                .next_random();
+        }
+        generator.consume_remaining_seeds_for_row();
+        assert!(generator.get_seed_budget_violations().is_empty());
+
+        generator.enable_seed_audit();
+        generator.skip_rows_until_starting_row_number(4);
+        generator.consume_remaining_seeds_for_row();
+        for _ in 0..=seeds_per_row {
+            generator
+                .get_random_number_stream(column)
+                .unwrap()
+                .next_random();
+        }
+        generator.consume_remaining_seeds_for_row();
+
+        let violations = generator.get_seed_budget_violations();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].column, "CcEmployees");
+        assert_eq!(violations[0].row_number, 5);
+        assert_eq!(violations[0].seeds_used, seeds_per_row + 1);
+        assert_eq!(violations[0].seeds_per_row, seeds_per_row);
     }
 
     #[test]
@@ -121,10 +240,45 @@ mod tests {
         let col1 = &CallCenterGeneratorColumn::CcCallCenterSk;
         let col2 = &CallCenterGeneratorColumn::CcCallCenterId;
 
-        let _stream1 = generator.get_random_number_stream(col1);
-        let _stream2 = generator.get_random_number_stream(col2);
+        let _stream1 = generator.get_random_number_stream(col1).unwrap();
+        let _stream2 = generator.get_random_number_stream(col2).unwrap();
 
         // Should create separate streams for different columns
-        assert_eq!(generator.random_number_streams.len(), 2);
+        assert_ne!(
+            col1.get_global_column_number(),
+            col2.get_global_column_number()
+        );
+        assert_eq!(
+            generator.random_number_streams.len(),
+            Table::CallCenter.get_generator_column_count()
+        );
+    }
+
+    #[test]
+    fn test_skip_rows_matches_sequential_generation() {
+        let column = &CallCenterGeneratorColumn::CcEmployees;
+
+        let mut sequential = AbstractRowGenerator::new(Table::CallCenter);
+        for _ in 0..3 {
+            sequential
+                .get_random_number_stream(column)
+                .unwrap()
+                .next_random();
+            sequential.consume_remaining_seeds_for_row();
+        }
+
+        let mut skipped = AbstractRowGenerator::new(Table::CallCenter);
+        skipped.skip_rows_until_starting_row_number(4);
+
+        assert_eq!(
+            sequential
+                .get_random_number_stream(column)
+                .unwrap()
+                .next_random(),
+            skipped
+                .get_random_number_stream(column)
+                .unwrap()
+                .next_random()
+        );
     }
 }
@@ -18,12 +18,12 @@ pub struct CallCenterRowGenerator {
 
 // Constants matching Java implementation
 // We'll define these as functions since const Decimal isn't available
-fn min_tax_percentage() -> Decimal {
-    Decimal::new(0, 2).unwrap()
+fn min_tax_percentage() -> Result<Decimal> {
+    Decimal::new(0, 2)
 }
 
-fn max_tax_percentage() -> Decimal {
-    Decimal::new(12, 2).unwrap()
+fn max_tax_percentage() -> Result<Decimal> {
+    Decimal::new(12, 2)
 }
 const WIDTH_CC_DIVISION_NAME: i32 = 50;
 const WIDTH_CC_MARKET_CLASS: i32 = 50;
@@ -55,14 +55,14 @@ impl CallCenterRowGenerator {
         // Create null bit map (createNullBitMap call)
         let nulls_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcNulls);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcNulls)?;
         let _threshold = RandomValueGenerator::generate_uniform_random_int(0, 9999, nulls_stream);
         let _bit_map = RandomValueGenerator::generate_uniform_random_int(1, i32::MAX, nulls_stream);
 
         // The id combined with start and end dates represent the unique key for this row.
         // The id is what would be a primary key if there were only one version of each row
         // the start and end dates are the version information for the row.
-        let scd_key: SlowlyChangingDimensionKey = compute_scd_key(Table::CallCenter, row_number);
+        let scd_key: SlowlyChangingDimensionKey = compute_scd_key(Table::CallCenter, row_number)?;
 
         let end_date_str = if scd_key.get_end_date() == -1 {
             String::new() // Empty string for null end dates
@@ -77,19 +77,17 @@ impl CallCenterRowGenerator {
         let (cc_open_date_id, cc_name, cc_address) = if is_new_business_key {
             let open_date_stream = self
                 .abstract_generator
-                .get_random_number_stream(&CallCenterGeneratorColumn::CcOpenDateId);
+                .get_random_number_stream(&CallCenterGeneratorColumn::CcOpenDateId)?;
             let open_date_random =
                 RandomValueGenerator::generate_uniform_random_int(-365, 0, open_date_stream);
             let open_date_julian = JULIAN_DATE_START - open_date_random as i64;
             let open_date_id = open_date_julian.to_string();
 
-            let number_of_call_centers =
-                CallCenterDistributions::get_number_of_call_centers().unwrap_or(12);
+            let number_of_call_centers = CallCenterDistributions::get_number_of_call_centers()?;
             let suffix = (row_number / number_of_call_centers as i64) as i32;
             let cc_name = CallCenterDistributions::get_call_center_at_index(
                 (row_number % number_of_call_centers as i64) as usize,
-            )
-            .unwrap_or("Unknown");
+            )?;
 
             let final_cc_name = if suffix > 0 {
                 format!("{}_{}", cc_name, suffix)
@@ -100,7 +98,7 @@ impl CallCenterRowGenerator {
             // Generate address
             let address_stream = self
                 .abstract_generator
-                .get_random_number_stream(&CallCenterGeneratorColumn::CcAddress);
+                .get_random_number_stream(&CallCenterGeneratorColumn::CcAddress)?;
             let address =
                 Address::make_address_for_column(Table::CallCenter, address_stream, scaling)?;
 
@@ -114,7 +112,7 @@ impl CallCenterRowGenerator {
                     prev_row.get_cc_address().clone(),
                 )
             } else {
-                return Err(crate::TpcdsError::new(
+                return Err(crate::TpcdsError::invalid_state(
                     "previousRow has not yet been initialized",
                 ));
             }
@@ -123,7 +121,7 @@ impl CallCenterRowGenerator {
         // Select the random number that controls if a field changes from one record to the next.
         let scd_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcScd);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcScd)?;
         let mut field_change_flag = scd_stream.next_random() as i32;
 
         // The rest of the fields can either be a new data value or not.
@@ -135,14 +133,13 @@ impl CallCenterRowGenerator {
         // so it always uses the new value
         let class_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcClass);
-        let cc_class =
-            CallCenterDistributions::pick_random_call_center_class(class_stream).unwrap_or("large");
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcClass)?;
+        let cc_class = CallCenterDistributions::pick_random_call_center_class(class_stream)?;
         field_change_flag >>= 1;
 
         let employees_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcEmployees);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcEmployees)?;
         let mut cc_employees = RandomValueGenerator::generate_uniform_random_int(
             1,
             MAX_NUMBER_OF_EMPLOYEES_UNSCALED
@@ -162,7 +159,7 @@ impl CallCenterRowGenerator {
 
         let sq_ft_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcSqFt);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcSqFt)?;
         let mut cc_sq_ft =
             RandomValueGenerator::generate_uniform_random_int(100, 700, sq_ft_stream)
                 * cc_employees;
@@ -179,14 +176,13 @@ impl CallCenterRowGenerator {
         // Another casualty of the bug with pointer types in the C code. Will always use a new value.
         let hours_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcHours);
-        let cc_hours = CallCenterDistributions::pick_random_call_center_hours(hours_stream)
-            .unwrap_or("8AM-8PM");
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcHours)?;
+        let cc_hours = CallCenterDistributions::pick_random_call_center_hours(hours_stream)?;
         field_change_flag >>= 1;
 
         let manager_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcManager);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcManager)?;
         let manager_first_name = NamesDistributions::pick_random_first_name(
             if session.is_sexist() {
                 FirstNamesWeights::MaleFrequency
@@ -194,10 +190,8 @@ impl CallCenterRowGenerator {
                 FirstNamesWeights::GeneralFrequency
             },
             manager_stream,
-        )
-        .unwrap_or("John");
-        let manager_last_name =
-            NamesDistributions::pick_random_last_name(manager_stream).unwrap_or("Smith");
+        )?;
+        let manager_last_name = NamesDistributions::pick_random_last_name(manager_stream)?;
         let mut cc_manager = format!("{} {}", manager_first_name, manager_last_name);
         if let Some(ref prev_row) = self.previous_row {
             cc_manager = get_value_for_slowly_changing_dimension(
@@ -211,7 +205,7 @@ impl CallCenterRowGenerator {
 
         let market_id_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcMarketId);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcMarketId)?;
         let mut cc_market_id =
             RandomValueGenerator::generate_uniform_random_int(1, 6, market_id_stream);
         if let Some(ref prev_row) = self.previous_row {
@@ -226,12 +220,12 @@ impl CallCenterRowGenerator {
 
         let market_class_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcMarketClass);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcMarketClass)?;
         let mut cc_market_class = RandomValueGenerator::generate_random_text(
             20,
             WIDTH_CC_MARKET_CLASS,
             market_class_stream,
-        );
+        )?;
         if let Some(ref prev_row) = self.previous_row {
             cc_market_class = get_value_for_slowly_changing_dimension(
                 field_change_flag,
@@ -244,12 +238,12 @@ impl CallCenterRowGenerator {
 
         let market_desc_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcMarketDesc);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcMarketDesc)?;
         let mut cc_market_desc = RandomValueGenerator::generate_random_text(
             20,
             WIDTH_CC_MARKET_DESC,
             market_desc_stream,
-        );
+        )?;
         if let Some(ref prev_row) = self.previous_row {
             cc_market_desc = get_value_for_slowly_changing_dimension(
                 field_change_flag,
@@ -262,7 +256,7 @@ impl CallCenterRowGenerator {
 
         let market_manager_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcMarketManager);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcMarketManager)?;
         let market_manager_first_name = NamesDistributions::pick_random_first_name(
             if session.is_sexist() {
                 FirstNamesWeights::MaleFrequency
@@ -270,10 +264,9 @@ impl CallCenterRowGenerator {
                 FirstNamesWeights::GeneralFrequency
             },
             market_manager_stream,
-        )
-        .unwrap_or("Jane");
+        )?;
         let market_manager_last_name =
-            NamesDistributions::pick_random_last_name(market_manager_stream).unwrap_or("Doe");
+            NamesDistributions::pick_random_last_name(market_manager_stream)?;
         let mut cc_market_manager =
             format!("{} {}", market_manager_first_name, market_manager_last_name);
         if let Some(ref prev_row) = self.previous_row {
@@ -288,7 +281,7 @@ impl CallCenterRowGenerator {
 
         let company_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcCompany);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcCompany)?;
         let mut cc_company =
             RandomValueGenerator::generate_uniform_random_int(1, 6, company_stream);
         if let Some(ref prev_row) = self.previous_row {
@@ -303,7 +296,7 @@ impl CallCenterRowGenerator {
 
         let division_id_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcCompany); // Note: uses same stream as company
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcCompany)?; // Note: uses same stream as company
         let mut cc_division_id =
             RandomValueGenerator::generate_uniform_random_int(1, 6, division_id_stream);
         if let Some(ref prev_row) = self.previous_row {
@@ -318,12 +311,12 @@ impl CallCenterRowGenerator {
 
         let division_name_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcDivisionName);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcDivisionName)?;
         let mut cc_division_name = RandomValueGenerator::generate_word(
             cc_division_id,
             WIDTH_CC_DIVISION_NAME,
             division_name_stream,
-        );
+        )?;
         if let Some(ref prev_row) = self.previous_row {
             cc_division_name = get_value_for_slowly_changing_dimension(
                 field_change_flag,
@@ -336,9 +329,9 @@ impl CallCenterRowGenerator {
 
         let company_name_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcCompanyName);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcCompanyName)?;
         let mut cc_company_name =
-            RandomValueGenerator::generate_word(cc_company, 10, company_name_stream);
+            RandomValueGenerator::generate_word(cc_company, 10, company_name_stream)?;
         if let Some(ref prev_row) = self.previous_row {
             cc_company_name = get_value_for_slowly_changing_dimension(
                 field_change_flag,
@@ -351,12 +344,12 @@ impl CallCenterRowGenerator {
 
         let tax_percentage_stream = self
             .abstract_generator
-            .get_random_number_stream(&CallCenterGeneratorColumn::CcTaxPercentage);
+            .get_random_number_stream(&CallCenterGeneratorColumn::CcTaxPercentage)?;
         let mut cc_tax_percentage = RandomValueGenerator::generate_uniform_random_decimal(
-            min_tax_percentage(),
-            max_tax_percentage(),
+            min_tax_percentage()?,
+            max_tax_percentage()?,
             tax_percentage_stream,
-        );
+        )?;
         if let Some(ref prev_row) = self.previous_row {
             cc_tax_percentage = get_value_for_slowly_changing_dimension(
                 field_change_flag,
@@ -411,13 +404,12 @@ impl RowGenerator for CallCenterRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row)))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.abstract_generator.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.abstract_generator
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
     }
 }
 
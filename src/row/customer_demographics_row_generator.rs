@@ -39,7 +39,7 @@ impl CustomerDemographicsRowGenerator {
         // Create null bit map (createNullBitMap call)
         let nulls_stream = self
             .abstract_generator
-            .get_random_number_stream(&CustomerDemographicsGeneratorColumn::CdNulls);
+            .get_random_number_stream(&CustomerDemographicsGeneratorColumn::CdNulls)?;
         let threshold = RandomValueGenerator::generate_uniform_random_int(0, 9999, nulls_stream);
         let bit_map =
             RandomValueGenerator::generate_uniform_random_key(1, i32::MAX as i64, nulls_stream);
@@ -56,28 +56,28 @@ impl CustomerDemographicsRowGenerator {
         let mut index = cd_demo_sk - 1;
 
         // Get gender and divide index
-        let cd_gender = DemographicsDistributions::get_gender_for_index_mod_size(index);
-        index /= DemographicsDistributions::get_gender_size() as i64;
+        let cd_gender = DemographicsDistributions::get_gender_for_index_mod_size(index)?;
+        index /= DemographicsDistributions::get_gender_size()? as i64;
 
         // Get marital status and divide index
         let cd_marital_status =
-            DemographicsDistributions::get_marital_status_for_index_mod_size(index);
-        index /= DemographicsDistributions::get_marital_status_size() as i64;
+            DemographicsDistributions::get_marital_status_for_index_mod_size(index)?;
+        index /= DemographicsDistributions::get_marital_status_size()? as i64;
 
         // Get education and divide index
         let cd_education_status =
-            DemographicsDistributions::get_education_for_index_mod_size(index);
-        index /= DemographicsDistributions::get_education_size() as i64;
+            DemographicsDistributions::get_education_for_index_mod_size(index)?;
+        index /= DemographicsDistributions::get_education_size()? as i64;
 
         // Get purchase band and divide index
         let cd_purchase_estimate =
-            DemographicsDistributions::get_purchase_band_for_index_mod_size(index);
-        index /= DemographicsDistributions::get_purchase_band_size() as i64;
+            DemographicsDistributions::get_purchase_band_for_index_mod_size(index)?;
+        index /= DemographicsDistributions::get_purchase_band_size()? as i64;
 
         // Get credit rating and divide index
         let cd_credit_rating =
-            DemographicsDistributions::get_credit_rating_for_index_mod_size(index);
-        index /= DemographicsDistributions::get_credit_rating_size() as i64;
+            DemographicsDistributions::get_credit_rating_for_index_mod_size(index)?;
+        index /= DemographicsDistributions::get_credit_rating_size()? as i64;
 
         // Get dependent counts using modulo (no division lookup needed)
         let cd_dep_count = (index % Self::MAX_CHILDREN) as i32;
@@ -115,12 +115,11 @@ impl RowGenerator for CustomerDemographicsRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row)))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.abstract_generator.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.abstract_generator
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
     }
 }
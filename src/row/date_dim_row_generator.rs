@@ -73,7 +73,7 @@ impl RowGenerator for DateDimRowGenerator {
 
         // Get day index for distributions (1-based day of year)
         let day_index = date.day_of_year();
-        let d_qoy = CalendarDistribution::get_quarter_at_index(day_index);
+        let d_qoy = CalendarDistribution::get_quarter_at_index(day_index)?;
 
         // Fiscal year is identical to calendar year in TPC-DS
         let d_fy_year = d_year;
@@ -87,7 +87,7 @@ impl RowGenerator for DateDimRowGenerator {
         let d_quarter_name = format!("{}Q{}", d_year, d_qoy);
 
         // Determine holiday and weekend flags
-        let d_holiday = CalendarDistribution::get_is_holiday_flag_at_index(day_index) != 0;
+        let d_holiday = CalendarDistribution::get_is_holiday_flag_at_index(day_index)? != 0;
         // Note: Java implementation has a bug where Friday and Saturday are weekend days
         // We replicate this bug for compatibility
         let d_weekend = d_dow == 5 || d_dow == 6; // Friday or Saturday (bug compatibility)
@@ -101,9 +101,9 @@ impl RowGenerator for DateDimRowGenerator {
             } else {
                 365
             };
-            CalendarDistribution::get_is_holiday_flag_at_index(last_day_prev_year) != 0
+            CalendarDistribution::get_is_holiday_flag_at_index(last_day_prev_year)? != 0
         } else {
-            CalendarDistribution::get_is_holiday_flag_at_index(day_index - 1) != 0
+            CalendarDistribution::get_is_holiday_flag_at_index(day_index - 1)? != 0
         };
 
         // First and last day of month (as julian days)
@@ -160,12 +160,11 @@ impl RowGenerator for DateDimRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row) as Box<dyn TableRow>))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.base.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.base
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.base
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.base
     }
 }
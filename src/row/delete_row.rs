@@ -0,0 +1,33 @@
+use crate::row::TableRow;
+use crate::types::Date;
+
+/// Row structure for the DELETE and INVENTORY_DELETE refresh tables: a range of julian dates
+/// whose rows are deleted (DeleteRow)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeleteRow {
+    date1: i64,
+    date2: i64,
+}
+
+impl DeleteRow {
+    pub fn new(date1: i64, date2: i64) -> Self {
+        Self { date1, date2 }
+    }
+
+    pub fn get_date1(&self) -> i64 {
+        self.date1
+    }
+
+    pub fn get_date2(&self) -> i64 {
+        self.date2
+    }
+}
+
+impl TableRow for DeleteRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            Date::julian_to_date_string(self.date1),
+            Date::julian_to_date_string(self.date2),
+        ]
+    }
+}
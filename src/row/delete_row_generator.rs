@@ -0,0 +1,87 @@
+use crate::config::Session;
+use crate::error::Result;
+use crate::row::{AbstractRowGenerator, DeleteRow, RowGenerator, RowGeneratorResult};
+use crate::table::Table;
+use crate::update::UpdateDates;
+use crate::TpcdsError;
+
+/// Row generator for the DELETE and INVENTORY_DELETE refresh tables: the sales or inventory
+/// date ranges of the refresh's update dates
+pub struct DeleteRowGenerator {
+    table: Table,
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+}
+
+impl DeleteRowGenerator {
+    /// Create the generator of `table`, `Table::Delete` or `Table::InventoryDelete`
+    pub fn new(table: Table) -> Self {
+        Self {
+            table,
+            abstract_generator: AbstractRowGenerator::new(table),
+            update_dates: None,
+        }
+    }
+
+    fn generate_delete_row(&mut self, row_number: i64, session: &Session) -> Result<DeleteRow> {
+        let update = session.get_update();
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let date_ranges = match self.table {
+            Table::Delete => update_dates.get_sales_date_ranges(),
+            Table::InventoryDelete => update_dates.get_inventory_date_ranges(),
+            table => return Err(TpcdsError::new(&format!("{} is not a delete table", table))),
+        };
+        let (date1, date2) = date_ranges[((row_number - 1) % date_ranges.len() as i64) as usize];
+        Ok(DeleteRow::new(date1, date2))
+    }
+}
+
+impl RowGenerator for DeleteRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_delete_row(row_number, session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delete_rows_are_the_update_date_ranges() {
+        let session = Session::get_default_session().with_update(2);
+        let update_dates = UpdateDates::new(2).unwrap();
+
+        let mut generator = DeleteRowGenerator::new(Table::Delete);
+        let mut inventory_generator = DeleteRowGenerator::new(Table::InventoryDelete);
+        for (i, row_number) in (4..=6).enumerate() {
+            let row = generator.generate_delete_row(row_number, &session).unwrap();
+            let range = update_dates.get_sales_date_ranges()[i];
+            assert_eq!((row.get_date1(), row.get_date2()), range);
+
+            let row = inventory_generator
+                .generate_delete_row(row_number, &session)
+                .unwrap();
+            let range = update_dates.get_inventory_date_ranges()[i];
+            assert_eq!((row.get_date1(), row.get_date2()), range);
+        }
+    }
+}
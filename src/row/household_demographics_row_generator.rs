@@ -50,7 +50,7 @@ impl HouseholdDemographicsRowGenerator {
         // Create null bit map (createNullBitMap call)
         let nulls_stream = self
             .abstract_generator
-            .get_random_number_stream(&HouseholdDemographicsGeneratorColumn::HdNulls);
+            .get_random_number_stream(&HouseholdDemographicsGeneratorColumn::HdNulls)?;
         let threshold = RandomValueGenerator::generate_uniform_random_int(0, 9999, nulls_stream);
         let bit_map =
             RandomValueGenerator::generate_uniform_random_key(1, i32::MAX as i64, nulls_stream);
@@ -68,21 +68,21 @@ impl HouseholdDemographicsRowGenerator {
 
         // Get income band id using modulo
         let hd_income_band_sk =
-            (index % DemographicsDistributions::get_income_band_size() as i64) + 1;
-        index /= DemographicsDistributions::get_income_band_size() as i64;
+            (index % DemographicsDistributions::get_income_band_size()? as i64) + 1;
+        index /= DemographicsDistributions::get_income_band_size()? as i64;
 
         // Get buy potential and divide index
         let hd_buy_potential =
-            DemographicsDistributions::get_buy_potential_for_index_mod_size(index);
-        index /= DemographicsDistributions::get_buy_potential_size() as i64;
+            DemographicsDistributions::get_buy_potential_for_index_mod_size(index)?;
+        index /= DemographicsDistributions::get_buy_potential_size()? as i64;
 
         // Get dependent count and divide index
-        let hd_dep_count = DemographicsDistributions::get_dep_count_for_index_mod_size(index);
-        index /= DemographicsDistributions::get_dep_count_size() as i64;
+        let hd_dep_count = DemographicsDistributions::get_dep_count_for_index_mod_size(index)?;
+        index /= DemographicsDistributions::get_dep_count_size()? as i64;
 
         // Get vehicle count (no division needed, last in sequence)
         let hd_vehicle_count =
-            DemographicsDistributions::get_vehicle_count_for_index_mod_size(index);
+            DemographicsDistributions::get_vehicle_count_for_index_mod_size(index)?;
 
         Ok(HouseholdDemographicsRow::builder()
             .set_hd_demo_sk(hd_demo_sk)
@@ -107,12 +107,11 @@ impl RowGenerator for HouseholdDemographicsRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row)))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.abstract_generator.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.abstract_generator
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
     }
 }
@@ -34,7 +34,7 @@ impl IncomeBandRowGenerator {
         // Create null bit map (createNullBitMap call)
         let nulls_stream = self
             .abstract_generator
-            .get_random_number_stream(&IncomeBandGeneratorColumn::IbNulls);
+            .get_random_number_stream(&IncomeBandGeneratorColumn::IbNulls)?;
         let threshold = RandomValueGenerator::generate_uniform_random_int(0, 9999, nulls_stream);
         let bit_map =
             RandomValueGenerator::generate_uniform_random_key(1, i32::MAX as i64, nulls_stream);
@@ -75,12 +75,11 @@ impl RowGenerator for IncomeBandRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row)))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.abstract_generator.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.abstract_generator
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
     }
 }
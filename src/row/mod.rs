@@ -5,6 +5,8 @@ pub mod customer_demographics_row;
 pub mod customer_demographics_row_generator;
 pub mod date_dim_row;
 pub mod date_dim_row_generator;
+pub mod delete_row;
+pub mod delete_row_generator;
 pub mod household_demographics_row;
 pub mod household_demographics_row_generator;
 pub mod income_band_row;
@@ -14,6 +16,32 @@ pub mod promotion_row_generator;
 pub mod reason_row;
 pub mod reason_row_generator;
 pub mod row_generator;
+pub mod s_call_center_row;
+pub mod s_call_center_row_generator;
+pub mod s_catalog_order_row;
+pub mod s_catalog_order_row_generator;
+pub mod s_catalog_returns_row;
+pub mod s_catalog_returns_row_generator;
+pub mod s_inventory_row;
+pub mod s_inventory_row_generator;
+pub mod s_item_row;
+pub mod s_item_row_generator;
+pub mod s_promotion_row;
+pub mod s_promotion_row_generator;
+pub mod s_purchase_row;
+pub mod s_purchase_row_generator;
+pub mod s_store_returns_row;
+pub mod s_store_returns_row_generator;
+pub mod s_warehouse_row;
+pub mod s_warehouse_row_generator;
+pub mod s_web_order_row;
+pub mod s_web_order_row_generator;
+pub mod s_web_page_row;
+pub mod s_web_page_row_generator;
+pub mod s_web_returns_row;
+pub mod s_web_returns_row_generator;
+pub mod s_zip_to_gmt_row;
+pub mod s_zip_to_gmt_row_generator;
 pub mod ship_mode_row;
 pub mod ship_mode_row_generator;
 pub mod table_row;
@@ -26,13 +54,15 @@ pub mod web_page_row_generator;
 pub mod web_site_row;
 pub mod web_site_row_generator;
 
-pub use abstract_row_generator::AbstractRowGenerator;
+pub use abstract_row_generator::{AbstractRowGenerator, SeedBudgetViolation};
 pub use call_center_row::CallCenterRow;
 pub use call_center_row_generator::CallCenterRowGenerator;
 pub use customer_demographics_row::CustomerDemographicsRow;
 pub use customer_demographics_row_generator::CustomerDemographicsRowGenerator;
 pub use date_dim_row::DateDimRow;
 pub use date_dim_row_generator::DateDimRowGenerator;
+pub use delete_row::DeleteRow;
+pub use delete_row_generator::DeleteRowGenerator;
 pub use household_demographics_row::HouseholdDemographicsRow;
 pub use household_demographics_row_generator::HouseholdDemographicsRowGenerator;
 pub use income_band_row::IncomeBandRow;
@@ -42,6 +72,34 @@ pub use promotion_row_generator::PromotionRowGenerator;
 pub use reason_row::ReasonRow;
 pub use reason_row_generator::ReasonRowGenerator;
 pub use row_generator::{RowGenerator, RowGeneratorResult};
+pub use s_call_center_row::SCallCenterRow;
+pub use s_call_center_row_generator::SCallCenterRowGenerator;
+pub use s_catalog_order_row::{SCatalogOrderLineitemRow, SCatalogOrderRow};
+pub use s_catalog_order_row_generator::{
+    SCatalogOrderLineitemRowGenerator, SCatalogOrderRowGenerator,
+};
+pub use s_catalog_returns_row::SCatalogReturnsRow;
+pub use s_catalog_returns_row_generator::SCatalogReturnsRowGenerator;
+pub use s_inventory_row::SInventoryRow;
+pub use s_inventory_row_generator::SInventoryRowGenerator;
+pub use s_item_row::SItemRow;
+pub use s_item_row_generator::SItemRowGenerator;
+pub use s_promotion_row::SPromotionRow;
+pub use s_promotion_row_generator::SPromotionRowGenerator;
+pub use s_purchase_row::{SPurchaseLineitemRow, SPurchaseRow};
+pub use s_purchase_row_generator::{SPurchaseLineitemRowGenerator, SPurchaseRowGenerator};
+pub use s_store_returns_row::SStoreReturnsRow;
+pub use s_store_returns_row_generator::SStoreReturnsRowGenerator;
+pub use s_warehouse_row::SWarehouseRow;
+pub use s_warehouse_row_generator::SWarehouseRowGenerator;
+pub use s_web_order_row::{SWebOrderLineitemRow, SWebOrderRow};
+pub use s_web_order_row_generator::{SWebOrderLineitemRowGenerator, SWebOrderRowGenerator};
+pub use s_web_page_row::SWebPageRow;
+pub use s_web_page_row_generator::SWebPageRowGenerator;
+pub use s_web_returns_row::SWebReturnsRow;
+pub use s_web_returns_row_generator::SWebReturnsRowGenerator;
+pub use s_zip_to_gmt_row::SZipToGmtRow;
+pub use s_zip_to_gmt_row_generator::SZipToGmtRowGenerator;
 pub use ship_mode_row::ShipModeRow;
 pub use ship_mode_row_generator::ShipModeRowGenerator;
 pub use table_row::TableRow;
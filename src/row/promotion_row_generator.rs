@@ -22,13 +22,13 @@ use crate::row::{AbstractRowGenerator, PromotionRow, RowGenerator, RowGeneratorR
 use crate::table::Table;
 use crate::types::{Date, Decimal};
 
-const PROMO_START_MIN: i32 = -720;
-const PROMO_START_MAX: i32 = 100;
-const PROMO_LENGTH_MIN: i32 = 1;
-const PROMO_LENGTH_MAX: i32 = 60;
-const PROMO_NAME_LENGTH: i32 = 5;
-const PROMO_DETAIL_LENGTH_MIN: i32 = 20;
-const PROMO_DETAIL_LENGTH_MAX: i32 = 60;
+pub(crate) const PROMO_START_MIN: i32 = -720;
+pub(crate) const PROMO_START_MAX: i32 = 100;
+pub(crate) const PROMO_LENGTH_MIN: i32 = 1;
+pub(crate) const PROMO_LENGTH_MAX: i32 = 60;
+pub(crate) const PROMO_NAME_LENGTH: i32 = 5;
+pub(crate) const PROMO_DETAIL_LENGTH_MIN: i32 = 20;
+pub(crate) const PROMO_DETAIL_LENGTH_MAX: i32 = 60;
 
 pub struct PromotionRowGenerator {
     abstract_row_generator: AbstractRowGenerator,
@@ -61,7 +61,7 @@ impl RowGenerator for PromotionRowGenerator {
         let null_bit_map = create_null_bit_map(
             Table::Promotion,
             self.abstract_row_generator
-                .get_random_number_stream(&PromotionGeneratorColumn::PNulls),
+                .get_random_number_stream(&PromotionGeneratorColumn::PNulls)?,
         );
 
         let p_promo_sk = row_number;
@@ -72,7 +72,7 @@ impl RowGenerator for PromotionRowGenerator {
                 PROMO_START_MIN,
                 PROMO_START_MAX,
                 self.abstract_row_generator
-                    .get_random_number_stream(&PromotionGeneratorColumn::PStartDateId),
+                    .get_random_number_stream(&PromotionGeneratorColumn::PStartDateId)?,
             ) as i64;
 
         let p_end_date_id = p_start_date_id
@@ -80,13 +80,13 @@ impl RowGenerator for PromotionRowGenerator {
                 PROMO_LENGTH_MIN,
                 PROMO_LENGTH_MAX,
                 self.abstract_row_generator
-                    .get_random_number_stream(&PromotionGeneratorColumn::PEndDateId),
+                    .get_random_number_stream(&PromotionGeneratorColumn::PEndDateId)?,
             ) as i64;
 
         let p_item_sk = generate_join_key(
             &PromotionGeneratorColumn::PItemSk,
             self.abstract_row_generator
-                .get_random_number_stream(&PromotionGeneratorColumn::PItemSk),
+                .get_random_number_stream(&PromotionGeneratorColumn::PItemSk)?,
             ConfigTable::Item,
             1,
             scaling,
@@ -99,15 +99,15 @@ impl RowGenerator for PromotionRowGenerator {
             row_number as i32,
             PROMO_NAME_LENGTH,
             self.abstract_row_generator
-                .get_random_number_stream(&PromotionGeneratorColumn::PPromoName),
-        );
+                .get_random_number_stream(&PromotionGeneratorColumn::PPromoName)?,
+        )?;
 
         // Generate channel flags using a single random int (0-511)
         let mut flags = RandomValueGenerator::generate_uniform_random_int(
             0,
             511,
             self.abstract_row_generator
-                .get_random_number_stream(&PromotionGeneratorColumn::PChannelDmail),
+                .get_random_number_stream(&PromotionGeneratorColumn::PChannelDmail)?,
         );
 
         let p_channel_dmail = (flags & 0x01) != 0;
@@ -140,8 +140,8 @@ impl RowGenerator for PromotionRowGenerator {
             PROMO_DETAIL_LENGTH_MIN,
             PROMO_DETAIL_LENGTH_MAX,
             self.abstract_row_generator
-                .get_random_number_stream(&PromotionGeneratorColumn::PChannelDetails),
-        );
+                .get_random_number_stream(&PromotionGeneratorColumn::PChannelDetails)?,
+        )?;
 
         let p_purpose = "Unknown".to_string();
 
@@ -169,18 +169,17 @@ impl RowGenerator for PromotionRowGenerator {
         ))))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.abstract_row_generator
-            .consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_row_generator
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.abstract_row_generator
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_row_generator
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants, unused_variables)]
 mod tests {
     use super::*;
 
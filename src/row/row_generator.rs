@@ -1,5 +1,5 @@
 use crate::config::Session;
-use crate::row::TableRow;
+use crate::row::{AbstractRowGenerator, SeedBudgetViolation, TableRow};
 
 /// Result of row generation (RowGeneratorResult)
 pub struct RowGeneratorResult {
@@ -29,6 +29,11 @@ impl RowGeneratorResult {
         &self.rows
     }
 
+    /// Take ownership of the generated rows
+    pub fn into_rows(self) -> Vec<Box<dyn TableRow>> {
+        self.rows
+    }
+
     /// Check if row generation should end
     pub fn should_end_row(&self) -> bool {
         self.should_end_row
@@ -46,11 +51,33 @@ pub trait RowGenerator: Send + Sync {
         child_row_generator: Option<&mut dyn RowGenerator>,
     ) -> crate::error::Result<RowGeneratorResult>;
 
+    /// Get the random number streams of the generator's columns
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator;
+
+    /// Get the random number streams of the generator's columns, to draw from
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator;
+
     /// Consume remaining seeds for the current row
-    fn consume_remaining_seeds_for_row(&mut self);
+    fn consume_remaining_seeds_for_row(&mut self) {
+        self.get_abstract_generator_mut()
+            .consume_remaining_seeds_for_row();
+    }
 
     /// Skip rows until reaching the starting row number
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64);
+    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
+        self.get_abstract_generator_mut()
+            .skip_rows_until_starting_row_number(starting_row_number);
+    }
+
+    /// Record generator columns that draw more seeds per row than their budget
+    fn enable_seed_audit(&mut self) {
+        self.get_abstract_generator_mut().enable_seed_audit();
+    }
+
+    /// Seed budget violations found since the audit was enabled
+    fn get_seed_budget_violations(&self) -> &[SeedBudgetViolation] {
+        self.get_abstract_generator().get_seed_budget_violations()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,92 @@
+use crate::row::TableRow;
+use crate::types::{Date, Decimal};
+
+/// Row structure for the S_CALL_CENTER refresh table (SCallCenterRow)
+#[derive(Debug, Clone)]
+pub struct SCallCenterRow {
+    null_bit_map: i64,
+    call_center_id: String,
+    call_open_date: i64,
+    call_closed_date: i64,
+    call_center_name: String,
+    call_center_class: String,
+    call_center_employees: i32,
+    call_center_sq_ft: i32,
+    call_center_hours: String,
+    call_center_manager: String,
+    call_center_tax_percentage: Decimal,
+}
+
+impl SCallCenterRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        null_bit_map: i64,
+        call_center_id: String,
+        call_open_date: i64,
+        call_closed_date: i64,
+        call_center_name: String,
+        call_center_class: String,
+        call_center_employees: i32,
+        call_center_sq_ft: i32,
+        call_center_hours: String,
+        call_center_manager: String,
+        call_center_tax_percentage: Decimal,
+    ) -> Self {
+        Self {
+            null_bit_map,
+            call_center_id,
+            call_open_date,
+            call_closed_date,
+            call_center_name,
+            call_center_class,
+            call_center_employees,
+            call_center_sq_ft,
+            call_center_hours,
+            call_center_manager,
+            call_center_tax_percentage,
+        }
+    }
+
+    pub fn get_call_center_id(&self) -> &str {
+        &self.call_center_id
+    }
+
+    /// Check if a column should be null based on the null bitmap (TableRowWithNulls logic)
+    fn should_be_null(&self, column_position: i32) -> bool {
+        ((self.null_bit_map >> column_position) & 1) == 1
+    }
+
+    fn get_string_or_null<T: ToString>(&self, value: T, column_position: i32) -> String {
+        if self.should_be_null(column_position) {
+            String::new()
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// Julian dates of -1 are null
+    fn get_date_string_or_null(&self, julian_days: i64, column_position: i32) -> String {
+        if self.should_be_null(column_position) || julian_days == -1 {
+            String::new()
+        } else {
+            Date::julian_to_date_string(julian_days)
+        }
+    }
+}
+
+impl TableRow for SCallCenterRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.get_string_or_null(&self.call_center_id, 0),
+            self.get_date_string_or_null(self.call_open_date, 1),
+            self.get_date_string_or_null(self.call_closed_date, 2),
+            self.get_string_or_null(&self.call_center_name, 3),
+            self.get_string_or_null(&self.call_center_class, 4),
+            self.get_string_or_null(self.call_center_employees, 5),
+            self.get_string_or_null(self.call_center_sq_ft, 6),
+            self.get_string_or_null(&self.call_center_hours, 7),
+            self.get_string_or_null(&self.call_center_manager, 8),
+            self.get_string_or_null(self.call_center_tax_percentage, 9),
+        ]
+    }
+}
@@ -0,0 +1,184 @@
+use crate::config::Session;
+use crate::distribution::{CallCenterDistributions, FirstNamesWeights, NamesDistributions};
+use crate::error::Result;
+use crate::generator::SCallCenterGeneratorColumn;
+use crate::nulls::create_null_bit_map;
+use crate::random::RandomValueGenerator;
+use crate::row::{AbstractRowGenerator, RowGenerator, RowGeneratorResult, SCallCenterRow};
+use crate::slowly_changing_dimension_utils::{compute_scd_key, match_surrogate_key};
+use crate::table::Table;
+use crate::types::{Date, Decimal};
+
+const MAX_NUMBER_OF_EMPLOYEES_UNSCALED: i32 = 7;
+
+/// Row generator for the S_CALL_CENTER refresh table. Each row gives new attributes to an
+/// existing call center, named by its business key.
+/// Approximate: the call centers changed and their new attributes are not drawn as dsdgen draws
+/// them, so the output is not TPC-DS compliant.
+pub struct SCallCenterRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+}
+
+impl Default for SCallCenterRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SCallCenterRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SCallCenter),
+        }
+    }
+
+    fn generate_s_call_center_row(&mut self, session: &Session) -> Result<SCallCenterRow> {
+        let null_bit_map = create_null_bit_map(
+            Table::SCallCenter,
+            self.abstract_generator
+                .get_random_number_stream(&SCallCenterGeneratorColumn::SccNulls)?,
+        );
+        let scaling = session.get_scaling();
+
+        // The first revision of the call center carries its business key and name
+        let id_count = scaling.get_id_count(crate::config::Table::CallCenter);
+        let unique = RandomValueGenerator::generate_uniform_random_key(
+            1,
+            id_count,
+            self.abstract_generator
+                .get_random_number_stream(&SCallCenterGeneratorColumn::SccCallCenterId)?,
+        );
+        let first_revision = match_surrogate_key(
+            unique,
+            Date::JULIAN_DATA_START_DATE,
+            crate::config::Table::CallCenter,
+            scaling,
+        )?;
+        let call_center_id = compute_scd_key(Table::CallCenter, first_revision)?
+            .get_business_key()
+            .to_string();
+
+        let number_of_call_centers = CallCenterDistributions::get_number_of_call_centers()? as i64;
+        let name = CallCenterDistributions::get_call_center_at_index(
+            (first_revision % number_of_call_centers) as usize,
+        )?;
+        let suffix = first_revision / number_of_call_centers;
+        let call_center_name = if suffix > 0 {
+            format!("{}_{}", name, suffix)
+        } else {
+            name.to_string()
+        };
+
+        let call_open_date = Date::JULIAN_DATA_START_DATE
+            + RandomValueGenerator::generate_uniform_random_int(
+                0,
+                365,
+                self.abstract_generator
+                    .get_random_number_stream(&SCallCenterGeneratorColumn::SccOpenDate)?,
+            ) as i64;
+
+        let call_center_class = CallCenterDistributions::pick_random_call_center_class(
+            self.abstract_generator
+                .get_random_number_stream(&SCallCenterGeneratorColumn::SccClass)?,
+        )?;
+
+        let scale = scaling.get_scale().ceil() as i32;
+        let call_center_employees = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            MAX_NUMBER_OF_EMPLOYEES_UNSCALED * scale * scale,
+            self.abstract_generator
+                .get_random_number_stream(&SCallCenterGeneratorColumn::SccEmployees)?,
+        );
+        let call_center_sq_ft = RandomValueGenerator::generate_uniform_random_int(
+            100,
+            700,
+            self.abstract_generator
+                .get_random_number_stream(&SCallCenterGeneratorColumn::SccSqFt)?,
+        ) * call_center_employees;
+
+        let call_center_hours = CallCenterDistributions::pick_random_call_center_hours(
+            self.abstract_generator
+                .get_random_number_stream(&SCallCenterGeneratorColumn::SccHours)?,
+        )?;
+
+        let manager_stream = self
+            .abstract_generator
+            .get_random_number_stream(&SCallCenterGeneratorColumn::SccManager)?;
+        let manager_first_name = NamesDistributions::pick_random_first_name(
+            if session.is_sexist() {
+                FirstNamesWeights::MaleFrequency
+            } else {
+                FirstNamesWeights::GeneralFrequency
+            },
+            manager_stream,
+        )?;
+        let manager_last_name = NamesDistributions::pick_random_last_name(manager_stream)?;
+
+        let call_center_tax_percentage = RandomValueGenerator::generate_uniform_random_decimal(
+            Decimal::new(0, 2)?,
+            Decimal::new(12, 2)?,
+            self.abstract_generator
+                .get_random_number_stream(&SCallCenterGeneratorColumn::SccTaxPercentage)?,
+        )?;
+
+        Ok(SCallCenterRow::new(
+            null_bit_map,
+            call_center_id,
+            call_open_date,
+            -1,
+            call_center_name,
+            call_center_class.to_string(),
+            call_center_employees,
+            call_center_sq_ft,
+            call_center_hours.to_string(),
+            format!("{} {}", manager_first_name, manager_last_name),
+            call_center_tax_percentage,
+        ))
+    }
+}
+
+impl RowGenerator for SCallCenterRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        _row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_call_center_row(session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_call_center_ids_exist_in_base_data() {
+        let session = Session::get_default_session();
+        let call_center_ids: Vec<String> = (1..=6)
+            .map(|row| {
+                compute_scd_key(Table::CallCenter, row)
+                    .unwrap()
+                    .get_business_key()
+                    .to_string()
+            })
+            .collect();
+
+        let mut generator = SCallCenterRowGenerator::new();
+        for _ in 0..3 {
+            let row = generator.generate_s_call_center_row(&session).unwrap();
+            assert!(call_center_ids.contains(&row.get_call_center_id().to_string()));
+            generator.consume_remaining_seeds_for_row();
+        }
+    }
+}
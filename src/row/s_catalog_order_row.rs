@@ -0,0 +1,164 @@
+use crate::row::TableRow;
+use crate::types::{Date, Decimal};
+
+/// Row structure for the S_CATALOG_ORDER refresh table: a catalog order (SCatalogOrderRow)
+#[derive(Debug, Clone)]
+pub struct SCatalogOrderRow {
+    cord_order_id: i64,
+    cord_bill_customer_id: String,
+    cord_ship_customer_id: String,
+    cord_order_date: i64,
+    cord_order_time: i32,
+    cord_ship_mode_id: String,
+    cord_call_center_id: String,
+    cord_order_comments: String,
+}
+
+impl SCatalogOrderRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        cord_order_id: i64,
+        cord_bill_customer_id: String,
+        cord_ship_customer_id: String,
+        cord_order_date: i64,
+        cord_order_time: i32,
+        cord_ship_mode_id: String,
+        cord_call_center_id: String,
+        cord_order_comments: String,
+    ) -> Self {
+        Self {
+            cord_order_id,
+            cord_bill_customer_id,
+            cord_ship_customer_id,
+            cord_order_date,
+            cord_order_time,
+            cord_ship_mode_id,
+            cord_call_center_id,
+            cord_order_comments,
+        }
+    }
+
+    pub fn get_cord_order_id(&self) -> i64 {
+        self.cord_order_id
+    }
+
+    pub fn get_cord_order_date(&self) -> i64 {
+        self.cord_order_date
+    }
+}
+
+impl TableRow for SCatalogOrderRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.cord_order_id.to_string(),
+            self.cord_bill_customer_id.clone(),
+            self.cord_ship_customer_id.clone(),
+            Date::julian_to_date_string(self.cord_order_date),
+            self.cord_order_time.to_string(),
+            self.cord_ship_mode_id.clone(),
+            self.cord_call_center_id.clone(),
+            self.cord_order_comments.clone(),
+        ]
+    }
+}
+
+/// Row structure for the S_CATALOG_ORDER_LINEITEM refresh table: a line of a catalog order (SCatalogOrderLineitemRow)
+#[derive(Debug, Clone)]
+pub struct SCatalogOrderLineitemRow {
+    clin_order_id: i64,
+    clin_line_number: i32,
+    clin_item_id: String,
+    clin_promotion_id: String,
+    clin_quantity: i32,
+    clin_sales_price: Decimal,
+    clin_coupon_amt: Decimal,
+    clin_warehouse_id: String,
+    clin_ship_date: i64,
+    clin_catalog_number: i32,
+    clin_catalog_page_number: i32,
+    clin_ship_cost: Decimal,
+}
+
+impl SCatalogOrderLineitemRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        clin_order_id: i64,
+        clin_line_number: i32,
+        clin_item_id: String,
+        clin_promotion_id: String,
+        clin_quantity: i32,
+        clin_sales_price: Decimal,
+        clin_coupon_amt: Decimal,
+        clin_warehouse_id: String,
+        clin_ship_date: i64,
+        clin_catalog_number: i32,
+        clin_catalog_page_number: i32,
+        clin_ship_cost: Decimal,
+    ) -> Self {
+        Self {
+            clin_order_id,
+            clin_line_number,
+            clin_item_id,
+            clin_promotion_id,
+            clin_quantity,
+            clin_sales_price,
+            clin_coupon_amt,
+            clin_warehouse_id,
+            clin_ship_date,
+            clin_catalog_number,
+            clin_catalog_page_number,
+            clin_ship_cost,
+        }
+    }
+
+    pub fn get_clin_order_id(&self) -> i64 {
+        self.clin_order_id
+    }
+
+    pub fn get_clin_line_number(&self) -> i32 {
+        self.clin_line_number
+    }
+
+    pub fn get_clin_item_id(&self) -> &str {
+        &self.clin_item_id
+    }
+
+    pub fn get_clin_quantity(&self) -> i32 {
+        self.clin_quantity
+    }
+
+    pub fn get_clin_sales_price(&self) -> Decimal {
+        self.clin_sales_price
+    }
+
+    pub fn get_clin_ship_date(&self) -> i64 {
+        self.clin_ship_date
+    }
+
+    pub fn get_clin_catalog_number(&self) -> i32 {
+        self.clin_catalog_number
+    }
+
+    pub fn get_clin_catalog_page_number(&self) -> i32 {
+        self.clin_catalog_page_number
+    }
+}
+
+impl TableRow for SCatalogOrderLineitemRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.clin_order_id.to_string(),
+            self.clin_line_number.to_string(),
+            self.clin_item_id.clone(),
+            self.clin_promotion_id.clone(),
+            self.clin_quantity.to_string(),
+            self.clin_sales_price.to_string(),
+            self.clin_coupon_amt.to_string(),
+            self.clin_warehouse_id.clone(),
+            Date::julian_to_date_string(self.clin_ship_date),
+            self.clin_catalog_number.to_string(),
+            self.clin_catalog_page_number.to_string(),
+            self.clin_ship_cost.to_string(),
+        ]
+    }
+}
@@ -0,0 +1,410 @@
+use crate::config::{Scaling, Session};
+use crate::error::Result;
+use crate::generator::{SCatalogOrderGeneratorColumn, SCatalogOrderLineitemGeneratorColumn};
+use crate::random::{RandomNumberStream, RandomValueGenerator};
+use crate::row::{
+    AbstractRowGenerator, RowGenerator, RowGeneratorResult, SCatalogOrderLineitemRow,
+    SCatalogOrderRow,
+};
+use crate::table::Table;
+use crate::update::{self, SoldLine, UpdateDates};
+
+const MIN_LINES_PER_ORDER: i32 = 4;
+const MAX_LINES_PER_ORDER: i32 = 14;
+
+/// Orders ship within three months, but never on the day they are placed
+const MIN_SHIP_DELAY: i32 = 2;
+const MAX_SHIP_DELAY: i32 = 90;
+
+/// Catalog pages are split evenly between the catalogs published each year of the data
+const CATALOGS_PER_YEAR: i64 = 18;
+const CATALOG_YEARS: i64 = 2002 - 1998 + 2;
+
+/// Row generator for the S_CATALOG_ORDER refresh table: the catalog orders of a refresh, one row
+/// per order. Their lines are generated by `SCatalogOrderLineitemRowGenerator`.
+/// Approximate: order dates come from `UpdateDates`, and customers, ship modes and call centers
+/// are picked uniformly, unlike dsdgen; the output is not TPC-DS compliant.
+pub struct SCatalogOrderRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+}
+
+impl Default for SCatalogOrderRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SCatalogOrderRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SCatalogOrder),
+            update_dates: None,
+        }
+    }
+
+    /// Date of order `order_id`, as generated in s_catalog_order
+    pub fn get_order_date(order_id: i64, update_dates: &UpdateDates) -> Result<i64> {
+        let mut stream =
+            update::get_line_stream(&SCatalogOrderGeneratorColumn::CordOrderDate, order_id, 1)?;
+        Ok(update::pick_sales_date(update_dates, &mut stream))
+    }
+
+    fn generate_s_catalog_order_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SCatalogOrderRow> {
+        let scaling = session.get_scaling();
+        let cord_bill_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderGeneratorColumn::CordBillCustomerId)?,
+        )?;
+        let cord_ship_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderGeneratorColumn::CordShipCustomerId)?,
+        )?;
+
+        let update = session.get_update();
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let cord_order_date = update::pick_sales_date(
+            update_dates,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderGeneratorColumn::CordOrderDate)?,
+        );
+        let cord_order_time = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            86399,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderGeneratorColumn::CordOrderTime)?,
+        );
+        let cord_ship_mode_id = update::pick_business_key(
+            crate::config::Table::ShipMode,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderGeneratorColumn::CordShipModeId)?,
+        )?;
+        let cord_call_center_id = update::pick_business_key(
+            crate::config::Table::CallCenter,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderGeneratorColumn::CordCallCenterId)?,
+        )?;
+        let cord_order_comments = RandomValueGenerator::generate_random_text(
+            20,
+            100,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderGeneratorColumn::CordOrderComments)?,
+        )?;
+
+        Ok(SCatalogOrderRow::new(
+            row_number,
+            cord_bill_customer_id,
+            cord_ship_customer_id,
+            cord_order_date,
+            cord_order_time,
+            cord_ship_mode_id,
+            cord_call_center_id,
+            cord_order_comments,
+        ))
+    }
+}
+
+impl RowGenerator for SCatalogOrderRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_catalog_order_row(row_number, session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+/// Row generator for the S_CATALOG_ORDER_LINEITEM refresh table. A row number is an order: each
+/// call generates one of its lines and the row ends with the last line.
+/// Approximate: lines sell consecutive items and use simplified prices, quantities and ship
+/// costs instead of dsdgen's pricing, so the output is not TPC-DS compliant.
+pub struct SCatalogOrderLineitemRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+    /// Lines of the current order
+    line_count: i32,
+    /// Last line generated for the current order, 0 before its first line
+    line_number: i32,
+    /// Item of the first line of the current order
+    first_item: i64,
+}
+
+impl Default for SCatalogOrderLineitemRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SCatalogOrderLineitemRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SCatalogOrderLineitem),
+            update_dates: None,
+            line_count: 0,
+            line_number: 0,
+            first_item: 0,
+        }
+    }
+
+    /// Number of lines of order `order_id`, as generated in s_catalog_order_lineitem
+    pub fn get_line_count(order_id: i64, scaling: &Scaling) -> Result<i32> {
+        let mut stream = update::get_line_stream(
+            &SCatalogOrderLineitemGeneratorColumn::ClinLineCount,
+            order_id,
+            1,
+        )?;
+        Ok(Self::pick_line_count(scaling, &mut stream))
+    }
+
+    /// Item, quantity and sales price of line `line_number` of order `order_id`, as generated in
+    /// s_catalog_order_lineitem
+    pub fn get_sold_line(order_id: i64, line_number: i32, scaling: &Scaling) -> Result<SoldLine> {
+        let mut item_stream = update::get_line_stream(
+            &SCatalogOrderLineitemGeneratorColumn::ClinItemId,
+            order_id,
+            1,
+        )?;
+        let first_item = Self::pick_first_item(scaling, &mut item_stream);
+        let mut quantity_stream = update::get_line_stream(
+            &SCatalogOrderLineitemGeneratorColumn::ClinQuantity,
+            order_id,
+            line_number,
+        )?;
+        let mut price_stream = update::get_line_stream(
+            &SCatalogOrderLineitemGeneratorColumn::ClinSalesPrice,
+            order_id,
+            line_number,
+        )?;
+        Ok(SoldLine::new(
+            update::get_line_item_id(first_item, line_number, scaling)?,
+            update::pick_quantity(&mut quantity_stream),
+            update::pick_sales_price(&mut price_stream)?,
+        ))
+    }
+
+    /// Ship date of line `line_number` of order `order_id`, as generated in
+    /// s_catalog_order_lineitem
+    pub fn get_ship_date(
+        order_id: i64,
+        line_number: i32,
+        update_dates: &UpdateDates,
+    ) -> Result<i64> {
+        let order_date = SCatalogOrderRowGenerator::get_order_date(order_id, update_dates)?;
+        let mut stream = update::get_line_stream(
+            &SCatalogOrderLineitemGeneratorColumn::ClinShipDate,
+            order_id,
+            line_number,
+        )?;
+        Ok(order_date + Self::pick_ship_delay(&mut stream) as i64)
+    }
+
+    fn pick_line_count(scaling: &Scaling, stream: &mut dyn RandomNumberStream) -> i32 {
+        update::pick_line_count(MIN_LINES_PER_ORDER, MAX_LINES_PER_ORDER, scaling, stream)
+    }
+
+    fn pick_first_item(scaling: &Scaling, stream: &mut dyn RandomNumberStream) -> i64 {
+        RandomValueGenerator::generate_uniform_random_key(
+            1,
+            scaling.get_id_count(crate::config::Table::Item),
+            stream,
+        )
+    }
+
+    fn pick_ship_delay(stream: &mut dyn RandomNumberStream) -> i32 {
+        RandomValueGenerator::generate_uniform_random_int(MIN_SHIP_DELAY, MAX_SHIP_DELAY, stream)
+    }
+
+    /// Catalog and page number of catalog page `catalog_page`, a row number of catalog_page
+    fn get_catalog_page_number(catalog_page: i64, scaling: &Scaling) -> (i32, i32) {
+        let pages_per_catalog = (scaling.get_row_count(crate::config::Table::CatalogPage)
+            / CATALOGS_PER_YEAR
+            / CATALOG_YEARS)
+            .max(1);
+        (
+            ((catalog_page - 1) / pages_per_catalog + 1) as i32,
+            ((catalog_page - 1) % pages_per_catalog + 1) as i32,
+        )
+    }
+
+    fn generate_s_catalog_order_lineitem_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SCatalogOrderLineitemRow> {
+        let scaling = session.get_scaling();
+        if self.line_number == 0 {
+            self.line_count = Self::pick_line_count(
+                scaling,
+                self.abstract_generator.get_random_number_stream(
+                    &SCatalogOrderLineitemGeneratorColumn::ClinLineCount,
+                )?,
+            );
+            self.first_item = Self::pick_first_item(
+                scaling,
+                self.abstract_generator
+                    .get_random_number_stream(&SCatalogOrderLineitemGeneratorColumn::ClinItemId)?,
+            );
+        }
+        self.line_number += 1;
+
+        let clin_item_id = update::get_line_item_id(self.first_item, self.line_number, scaling)?;
+        let clin_promotion_id = update::pick_business_key(
+            crate::config::Table::Promotion,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderLineitemGeneratorColumn::ClinPromotionId)?,
+        )?;
+        let clin_quantity = update::pick_quantity(
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderLineitemGeneratorColumn::ClinQuantity)?,
+        );
+        let clin_sales_price = update::pick_sales_price(
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderLineitemGeneratorColumn::ClinSalesPrice)?,
+        )?;
+        let clin_coupon_amt = update::pick_coupon_amount(
+            clin_sales_price,
+            clin_quantity,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderLineitemGeneratorColumn::ClinCouponAmt)?,
+        )?;
+        let clin_warehouse_id = update::pick_business_key(
+            crate::config::Table::Warehouse,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderLineitemGeneratorColumn::ClinWarehouseId)?,
+        )?;
+
+        let update = session.get_update();
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let order_date = SCatalogOrderRowGenerator::get_order_date(row_number, update_dates)?;
+        let clin_ship_date =
+            order_date
+                + Self::pick_ship_delay(self.abstract_generator.get_random_number_stream(
+                    &SCatalogOrderLineitemGeneratorColumn::ClinShipDate,
+                )?) as i64;
+
+        let catalog_page = RandomValueGenerator::generate_uniform_random_key(
+            1,
+            scaling.get_row_count(crate::config::Table::CatalogPage),
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderLineitemGeneratorColumn::ClinCatalogPage)?,
+        );
+        let (clin_catalog_number, clin_catalog_page_number) =
+            Self::get_catalog_page_number(catalog_page, scaling);
+        let clin_ship_cost = update::pick_ship_cost(
+            clin_sales_price,
+            clin_quantity,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogOrderLineitemGeneratorColumn::ClinShipCost)?,
+        )?;
+
+        Ok(SCatalogOrderLineitemRow::new(
+            row_number,
+            self.line_number,
+            clin_item_id,
+            clin_promotion_id,
+            clin_quantity,
+            clin_sales_price,
+            clin_coupon_amt,
+            clin_warehouse_id,
+            clin_ship_date,
+            clin_catalog_number,
+            clin_catalog_page_number,
+            clin_ship_cost,
+        ))
+    }
+}
+
+impl RowGenerator for SCatalogOrderLineitemRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_catalog_order_lineitem_row(row_number, session)?;
+        let is_last_line = self.line_number >= self.line_count;
+        if is_last_line {
+            self.line_number = 0;
+        }
+        Ok(RowGeneratorResult::new_with_multiple(
+            vec![Box::new(row)],
+            is_last_line,
+        ))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_ship_after_their_order() {
+        let session = Session::get_default_session().with_update(1);
+        let scaling = session.get_scaling();
+        let update_dates = UpdateDates::new(1).unwrap();
+        let order_date = SCatalogOrderRowGenerator::get_order_date(3, &update_dates).unwrap();
+
+        let mut generator = SCatalogOrderLineitemRowGenerator::new();
+        generator.skip_rows_until_starting_row_number(3);
+        let line_count = SCatalogOrderLineitemRowGenerator::get_line_count(3, scaling).unwrap();
+        for line_number in 1..=line_count {
+            let row = generator
+                .generate_s_catalog_order_lineitem_row(3, &session)
+                .unwrap();
+            let sold =
+                SCatalogOrderLineitemRowGenerator::get_sold_line(3, line_number, scaling).unwrap();
+            assert_eq!(row.get_clin_item_id(), sold.get_item_id());
+            assert_eq!(row.get_clin_quantity(), sold.get_quantity());
+            assert_eq!(row.get_clin_sales_price(), sold.get_sales_price());
+            assert_eq!(
+                row.get_clin_ship_date(),
+                SCatalogOrderLineitemRowGenerator::get_ship_date(3, line_number, &update_dates)
+                    .unwrap()
+            );
+            assert!(row.get_clin_ship_date() >= order_date + MIN_SHIP_DELAY as i64);
+            assert!(row.get_clin_ship_date() <= order_date + MAX_SHIP_DELAY as i64);
+            assert!(row.get_clin_catalog_number() >= 1);
+            assert!(row.get_clin_catalog_page_number() >= 1);
+        }
+    }
+}
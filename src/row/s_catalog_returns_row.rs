@@ -0,0 +1,127 @@
+use crate::row::TableRow;
+use crate::types::{Date, Decimal};
+
+/// Row structure for the S_CATALOG_RETURNS refresh table: a returned catalog order line (SCatalogReturnsRow)
+#[derive(Debug, Clone)]
+pub struct SCatalogReturnsRow {
+    cret_call_center_id: String,
+    cret_order_id: i64,
+    cret_line_number: i32,
+    cret_item_id: String,
+    cret_return_customer_id: String,
+    cret_refund_customer_id: String,
+    cret_return_date: i64,
+    cret_return_time: i32,
+    cret_return_qty: i32,
+    cret_return_amt: Decimal,
+    cret_return_tax: Decimal,
+    cret_return_fee: Decimal,
+    cret_return_ship_cost: Decimal,
+    cret_refunded_cash: Decimal,
+    cret_reversed_charge: Decimal,
+    cret_merchant_credit: Decimal,
+    cret_reason_id: String,
+    cret_shipmode_id: String,
+    cret_catalog_page_id: String,
+    cret_warehouse_id: String,
+}
+
+impl SCatalogReturnsRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        cret_call_center_id: String,
+        cret_order_id: i64,
+        cret_line_number: i32,
+        cret_item_id: String,
+        cret_return_customer_id: String,
+        cret_refund_customer_id: String,
+        cret_return_date: i64,
+        cret_return_time: i32,
+        cret_return_qty: i32,
+        cret_return_amt: Decimal,
+        cret_return_tax: Decimal,
+        cret_return_fee: Decimal,
+        cret_return_ship_cost: Decimal,
+        cret_refunded_cash: Decimal,
+        cret_reversed_charge: Decimal,
+        cret_merchant_credit: Decimal,
+        cret_reason_id: String,
+        cret_shipmode_id: String,
+        cret_catalog_page_id: String,
+        cret_warehouse_id: String,
+    ) -> Self {
+        Self {
+            cret_call_center_id,
+            cret_order_id,
+            cret_line_number,
+            cret_item_id,
+            cret_return_customer_id,
+            cret_refund_customer_id,
+            cret_return_date,
+            cret_return_time,
+            cret_return_qty,
+            cret_return_amt,
+            cret_return_tax,
+            cret_return_fee,
+            cret_return_ship_cost,
+            cret_refunded_cash,
+            cret_reversed_charge,
+            cret_merchant_credit,
+            cret_reason_id,
+            cret_shipmode_id,
+            cret_catalog_page_id,
+            cret_warehouse_id,
+        }
+    }
+
+    pub fn get_cret_order_id(&self) -> i64 {
+        self.cret_order_id
+    }
+
+    pub fn get_cret_line_number(&self) -> i32 {
+        self.cret_line_number
+    }
+
+    pub fn get_cret_item_id(&self) -> &str {
+        &self.cret_item_id
+    }
+
+    pub fn get_cret_return_date(&self) -> i64 {
+        self.cret_return_date
+    }
+
+    pub fn get_cret_return_qty(&self) -> i32 {
+        self.cret_return_qty
+    }
+
+    pub fn get_cret_return_amt(&self) -> Decimal {
+        self.cret_return_amt
+    }
+}
+
+impl TableRow for SCatalogReturnsRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.cret_call_center_id.clone(),
+            self.cret_order_id.to_string(),
+            self.cret_line_number.to_string(),
+            self.cret_item_id.clone(),
+            self.cret_return_customer_id.clone(),
+            self.cret_refund_customer_id.clone(),
+            Date::julian_to_date_string(self.cret_return_date),
+            self.cret_return_time.to_string(),
+            self.cret_return_qty.to_string(),
+            self.cret_return_amt.to_string(),
+            self.cret_return_tax.to_string(),
+            self.cret_return_fee.to_string(),
+            self.cret_return_ship_cost.to_string(),
+            self.cret_refunded_cash.to_string(),
+            self.cret_reversed_charge.to_string(),
+            self.cret_merchant_credit.to_string(),
+            self.cret_reason_id.clone(),
+            self.cret_shipmode_id.clone(),
+            self.cret_catalog_page_id.clone(),
+            self.cret_warehouse_id.clone(),
+        ]
+    }
+}
@@ -0,0 +1,210 @@
+use crate::config::Session;
+use crate::error::Result;
+use crate::generator::SCatalogReturnsGeneratorColumn;
+use crate::random::RandomValueGenerator;
+use crate::row::{
+    AbstractRowGenerator, RowGenerator, RowGeneratorResult, SCatalogOrderLineitemRowGenerator,
+    SCatalogReturnsRow,
+};
+use crate::table::Table;
+use crate::update::{self, ReturnAmounts, UpdateDates};
+use crate::TpcdsError;
+
+/// Items are returned within three months of their delivery
+const MAX_DAYS_TO_RETURN: i32 = 90;
+
+/// Row generator for the S_CATALOG_RETURNS refresh table: a line of a different catalog order of
+/// the refresh for each row, returned after it shipped.
+/// Approximate: the returned lines and the return amounts do not follow dsdgen's sales pricing,
+/// so the output is not TPC-DS compliant.
+pub struct SCatalogReturnsRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+    /// Orders returned in the refresh being generated
+    returned_orders: Option<(i32, Vec<i64>)>,
+}
+
+impl Default for SCatalogReturnsRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SCatalogReturnsRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SCatalogReturns),
+            update_dates: None,
+            returned_orders: None,
+        }
+    }
+
+    fn generate_s_catalog_returns_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SCatalogReturnsRow> {
+        let scaling = session.get_scaling();
+        let update = session.get_update();
+        let returned_orders = match &mut self.returned_orders {
+            Some((cached, orders)) if *cached == update => orders,
+            cache => {
+                let orders = update::get_returned_orders(
+                    Table::SCatalogReturns,
+                    Table::SCatalogOrder,
+                    &SCatalogReturnsGeneratorColumn::CretOrderId,
+                    scaling,
+                    update,
+                )?;
+                &mut cache.insert((update, orders)).1
+            }
+        };
+        let index =
+            row_number - 1 - update::get_row_offset(Table::SCatalogReturns, scaling, update)?;
+        let cret_order_id = *returned_orders.get(index as usize).ok_or_else(|| {
+            TpcdsError::new(&format!(
+                "Row {} of {} is not part of refresh {}",
+                row_number,
+                Table::SCatalogReturns,
+                update
+            ))
+        })?;
+
+        let cret_call_center_id = update::pick_business_key(
+            crate::config::Table::CallCenter,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretCallCenterId)?,
+        )?;
+        let line_count = SCatalogOrderLineitemRowGenerator::get_line_count(cret_order_id, scaling)?;
+        let cret_line_number = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            line_count,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretLineNumber)?,
+        );
+        let sold_line = SCatalogOrderLineitemRowGenerator::get_sold_line(
+            cret_order_id,
+            cret_line_number,
+            scaling,
+        )?;
+
+        let cret_return_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretReturnCustomerId)?,
+        )?;
+        let cret_refund_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretRefundCustomerId)?,
+        )?;
+
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let ship_date = SCatalogOrderLineitemRowGenerator::get_ship_date(
+            cret_order_id,
+            cret_line_number,
+            update_dates,
+        )?;
+        let cret_return_date = ship_date
+            + RandomValueGenerator::generate_uniform_random_int(
+                1,
+                MAX_DAYS_TO_RETURN,
+                self.abstract_generator
+                    .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretReturnDate)?,
+            ) as i64;
+        let cret_return_time = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            86399,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretReturnTime)?,
+        );
+
+        let cret_return_qty = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            sold_line.get_quantity(),
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretReturnQty)?,
+        );
+        let amounts = ReturnAmounts::new(
+            sold_line.get_sales_price(),
+            cret_return_qty,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretAmounts)?,
+        )?;
+        let cret_reason_id = update::pick_business_key(
+            crate::config::Table::Reason,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretReasonId)?,
+        )?;
+        let cret_shipmode_id = update::pick_business_key(
+            crate::config::Table::ShipMode,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretShipModeId)?,
+        )?;
+        let cret_catalog_page_id = update::pick_business_key(
+            crate::config::Table::CatalogPage,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretCatalogPageId)?,
+        )?;
+        let cret_warehouse_id = update::pick_business_key(
+            crate::config::Table::Warehouse,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SCatalogReturnsGeneratorColumn::CretWarehouseId)?,
+        )?;
+
+        Ok(SCatalogReturnsRow::new(
+            cret_call_center_id,
+            cret_order_id,
+            cret_line_number,
+            sold_line.get_item_id().to_string(),
+            cret_return_customer_id,
+            cret_refund_customer_id,
+            cret_return_date,
+            cret_return_time,
+            cret_return_qty,
+            amounts.get_return_amount(),
+            amounts.get_return_tax(),
+            amounts.get_fee(),
+            amounts.get_ship_cost(),
+            amounts.get_refunded_cash(),
+            amounts.get_reversed_charge(),
+            amounts.get_credit(),
+            cret_reason_id,
+            cret_shipmode_id,
+            cret_catalog_page_id,
+            cret_warehouse_id,
+        ))
+    }
+}
+
+impl RowGenerator for SCatalogReturnsRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_catalog_returns_row(row_number, session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
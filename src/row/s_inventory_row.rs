@@ -0,0 +1,66 @@
+use crate::row::TableRow;
+use crate::types::Date;
+
+/// Row structure for the S_INVENTORY refresh table (SInventoryRow)
+#[derive(Debug, Clone)]
+pub struct SInventoryRow {
+    null_bit_map: i64,
+    invn_warehouse_id: String,
+    invn_item_id: String,
+    invn_date: i64,
+    invn_qty_on_hand: i32,
+}
+
+impl SInventoryRow {
+    pub fn new(
+        null_bit_map: i64,
+        invn_warehouse_id: String,
+        invn_item_id: String,
+        invn_date: i64,
+        invn_qty_on_hand: i32,
+    ) -> Self {
+        Self {
+            null_bit_map,
+            invn_warehouse_id,
+            invn_item_id,
+            invn_date,
+            invn_qty_on_hand,
+        }
+    }
+
+    pub fn get_invn_warehouse_id(&self) -> &str {
+        &self.invn_warehouse_id
+    }
+
+    pub fn get_invn_item_id(&self) -> &str {
+        &self.invn_item_id
+    }
+
+    pub fn get_invn_date(&self) -> i64 {
+        self.invn_date
+    }
+
+    /// Check if a column should be null based on the null bitmap (TableRowWithNulls logic)
+    fn should_be_null(&self, column_position: i32) -> bool {
+        ((self.null_bit_map >> column_position) & 1) == 1
+    }
+
+    fn get_string_or_null<T: ToString>(&self, value: T, column_position: i32) -> String {
+        if self.should_be_null(column_position) {
+            String::new()
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+impl TableRow for SInventoryRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.get_string_or_null(&self.invn_warehouse_id, 0),
+            self.get_string_or_null(&self.invn_item_id, 1),
+            self.get_string_or_null(Date::julian_to_date_string(self.invn_date), 2),
+            self.get_string_or_null(self.invn_qty_on_hand, 3),
+        ]
+    }
+}
@@ -0,0 +1,114 @@
+use crate::business_key_generator::make_business_key;
+use crate::config::Session;
+use crate::error::Result;
+use crate::generator::SInventoryGeneratorColumn;
+use crate::nulls::create_null_bit_map;
+use crate::random::RandomValueGenerator;
+use crate::row::{AbstractRowGenerator, RowGenerator, RowGeneratorResult, SInventoryRow};
+use crate::slowly_changing_dimension_utils::{compute_scd_key, match_surrogate_key};
+use crate::table::Table;
+use crate::types::Date;
+use crate::update::UpdateDates;
+
+/// Row generator for the S_INVENTORY refresh table: the quantity on hand of every item in
+/// every warehouse, counted on one of the refresh's inventory dates.
+/// Approximate: the inventory dates are those of `UpdateDates`, not dsdgen's, so the output is
+/// not TPC-DS compliant.
+pub struct SInventoryRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+}
+
+impl Default for SInventoryRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SInventoryRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SInventory),
+            update_dates: None,
+        }
+    }
+
+    fn generate_s_inventory_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SInventoryRow> {
+        let null_bit_map = create_null_bit_map(
+            Table::SInventory,
+            self.abstract_generator
+                .get_random_number_stream(&SInventoryGeneratorColumn::InvnNulls)?,
+        );
+        let scaling = session.get_scaling();
+
+        // Items vary fastest, then warehouses
+        let item_count = scaling.get_id_count(crate::config::Table::Item);
+        let warehouse_count = scaling.get_row_count(crate::config::Table::Warehouse);
+        let index = (row_number - 1) % (item_count * warehouse_count);
+        let item = match_surrogate_key(
+            index % item_count + 1,
+            Date::JULIAN_DATA_START_DATE,
+            crate::config::Table::Item,
+            scaling,
+        )?;
+        let invn_item_id = compute_scd_key(Table::Item, item)?
+            .get_business_key()
+            .to_string();
+        let invn_warehouse_id = make_business_key(index / item_count + 1);
+
+        let update = session.get_update();
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let inventory_date_ranges = update_dates.get_inventory_date_ranges();
+        let range = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            inventory_date_ranges.len() as i32 - 1,
+            self.abstract_generator
+                .get_random_number_stream(&SInventoryGeneratorColumn::InvnDate)?,
+        );
+        let invn_date = inventory_date_ranges[range as usize].0;
+
+        let invn_qty_on_hand = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            1000,
+            self.abstract_generator
+                .get_random_number_stream(&SInventoryGeneratorColumn::InvnQtyOnHand)?,
+        );
+
+        Ok(SInventoryRow::new(
+            null_bit_map,
+            invn_warehouse_id,
+            invn_item_id,
+            invn_date,
+            invn_qty_on_hand,
+        ))
+    }
+}
+
+impl RowGenerator for SInventoryRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_inventory_row(row_number, session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
@@ -0,0 +1,91 @@
+use crate::row::TableRow;
+use crate::types::Decimal;
+
+/// Row structure for the S_ITEM refresh table (SItemRow)
+#[derive(Debug, Clone)]
+pub struct SItemRow {
+    null_bit_map: i64,
+    item_item_id: String,
+    item_item_description: String,
+    item_list_price: Decimal,
+    item_wholesale_cost: Decimal,
+    item_size: String,
+    item_formulation: String,
+    item_color: String,
+    item_units: String,
+    item_container: String,
+    item_manager_id: i32,
+}
+
+impl SItemRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        null_bit_map: i64,
+        item_item_id: String,
+        item_item_description: String,
+        item_list_price: Decimal,
+        item_wholesale_cost: Decimal,
+        item_size: String,
+        item_formulation: String,
+        item_color: String,
+        item_units: String,
+        item_container: String,
+        item_manager_id: i32,
+    ) -> Self {
+        Self {
+            null_bit_map,
+            item_item_id,
+            item_item_description,
+            item_list_price,
+            item_wholesale_cost,
+            item_size,
+            item_formulation,
+            item_color,
+            item_units,
+            item_container,
+            item_manager_id,
+        }
+    }
+
+    pub fn get_item_item_id(&self) -> &str {
+        &self.item_item_id
+    }
+
+    pub fn get_item_list_price(&self) -> Decimal {
+        self.item_list_price
+    }
+
+    pub fn get_item_wholesale_cost(&self) -> Decimal {
+        self.item_wholesale_cost
+    }
+
+    /// Check if a column should be null based on the null bitmap (TableRowWithNulls logic)
+    fn should_be_null(&self, column_position: i32) -> bool {
+        ((self.null_bit_map >> column_position) & 1) == 1
+    }
+
+    fn get_string_or_null<T: ToString>(&self, value: T, column_position: i32) -> String {
+        if self.should_be_null(column_position) {
+            String::new()
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+impl TableRow for SItemRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.get_string_or_null(&self.item_item_id, 0),
+            self.get_string_or_null(&self.item_item_description, 1),
+            self.get_string_or_null(self.item_list_price, 2),
+            self.get_string_or_null(self.item_wholesale_cost, 3),
+            self.get_string_or_null(&self.item_size, 4),
+            self.get_string_or_null(&self.item_formulation, 5),
+            self.get_string_or_null(&self.item_color, 6),
+            self.get_string_or_null(&self.item_units, 7),
+            self.get_string_or_null(&self.item_container, 8),
+            self.get_string_or_null(self.item_manager_id, 9),
+        ]
+    }
+}
@@ -0,0 +1,161 @@
+use crate::config::Session;
+use crate::distribution::ItemDistributions;
+use crate::error::Result;
+use crate::generator::SItemGeneratorColumn;
+use crate::nulls::create_null_bit_map;
+use crate::random::RandomValueGenerator;
+use crate::row::{AbstractRowGenerator, RowGenerator, RowGeneratorResult, SItemRow};
+use crate::slowly_changing_dimension_utils::{compute_scd_key, match_surrogate_key};
+use crate::table::Table;
+use crate::types::{Date, Decimal};
+
+const ITEM_DESCRIPTION_LENGTH: i32 = 200;
+const ITEM_FORMULATION_LENGTH: usize = 20;
+const ITEM_MANAGER_ID_MAX: i32 = 100;
+
+/// Row generator for the S_ITEM refresh table. Each row gives new attributes to an existing
+/// item, named by its business key.
+/// Approximate: prices, costs and manager ids are drawn from uniform ranges rather than dsdgen's
+/// item distributions, so the output is not TPC-DS compliant.
+pub struct SItemRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+}
+
+impl Default for SItemRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SItemRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SItem),
+        }
+    }
+
+    fn generate_s_item_row(&mut self, session: &Session) -> Result<SItemRow> {
+        let null_bit_map = create_null_bit_map(
+            Table::SItem,
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemNulls)?,
+        );
+        let scaling = session.get_scaling();
+
+        let id_count = scaling.get_id_count(crate::config::Table::Item);
+        let unique = RandomValueGenerator::generate_uniform_random_key(
+            1,
+            id_count,
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemItemId)?,
+        );
+        let first_revision = match_surrogate_key(
+            unique,
+            Date::JULIAN_DATA_START_DATE,
+            crate::config::Table::Item,
+            scaling,
+        )?;
+        let item_item_id = compute_scd_key(Table::Item, first_revision)?
+            .get_business_key()
+            .to_string();
+
+        let item_item_description = RandomValueGenerator::generate_random_text(
+            1,
+            ITEM_DESCRIPTION_LENGTH,
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemDescription)?,
+        )?;
+
+        let item_list_price = RandomValueGenerator::generate_uniform_random_decimal(
+            Decimal::new(9, 2)?,
+            Decimal::new(9999, 2)?,
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemListPrice)?,
+        )?;
+        let item_wholesale_cost = RandomValueGenerator::generate_uniform_random_decimal(
+            Decimal::new(1, 2)?,
+            item_list_price,
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemWholesaleCost)?,
+        )?;
+
+        let item_size = ItemDistributions::pick_random_size(
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemSize)?,
+        )?;
+        let item_formulation = RandomValueGenerator::generate_random_digits(
+            ITEM_FORMULATION_LENGTH,
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemFormulation)?,
+        );
+        let item_color = ItemDistributions::pick_random_color(
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemColor)?,
+        )?;
+        let item_units = ItemDistributions::pick_random_units(
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemUnits)?,
+        )?;
+        let item_manager_id = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            ITEM_MANAGER_ID_MAX,
+            self.abstract_generator
+                .get_random_number_stream(&SItemGeneratorColumn::SItemManagerId)?,
+        );
+
+        Ok(SItemRow::new(
+            null_bit_map,
+            item_item_id,
+            item_item_description,
+            item_list_price,
+            item_wholesale_cost,
+            item_size.to_string(),
+            item_formulation,
+            item_color.to_string(),
+            item_units.to_string(),
+            "Unknown".to_string(),
+            item_manager_id,
+        ))
+    }
+}
+
+impl RowGenerator for SItemRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        _row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_item_row(session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wholesale_cost_does_not_exceed_list_price() {
+        let session = Session::get_default_session();
+        let mut generator = SItemRowGenerator::new();
+        for _ in 0..20 {
+            let row = generator.generate_s_item_row(&session).unwrap();
+            assert_eq!(row.get_item_item_id().len(), 16);
+            assert!(
+                row.get_item_wholesale_cost().get_number()
+                    <= row.get_item_list_price().get_number()
+            );
+            generator.consume_remaining_seeds_for_row();
+        }
+    }
+}
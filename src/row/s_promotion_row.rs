@@ -0,0 +1,131 @@
+use crate::row::TableRow;
+use crate::types::{Date, Decimal};
+
+/// Row structure for the S_PROMOTION refresh table (SPromotionRow)
+#[derive(Debug, Clone)]
+pub struct SPromotionRow {
+    null_bit_map: i64,
+    prom_promotion_id: String,
+    prom_promotion_name: String,
+    prom_start_date: i64,
+    prom_end_date: i64,
+    prom_cost: Decimal,
+    prom_response_target: i32,
+    prom_channel_dmail: bool,
+    prom_channel_email: bool,
+    prom_channel_catalog: bool,
+    prom_channel_tv: bool,
+    prom_channel_radio: bool,
+    prom_channel_press: bool,
+    prom_channel_event: bool,
+    prom_channel_demo: bool,
+    prom_channel_details: String,
+    prom_purpose: String,
+    prom_discount_active: bool,
+}
+
+impl SPromotionRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        null_bit_map: i64,
+        prom_promotion_id: String,
+        prom_promotion_name: String,
+        prom_start_date: i64,
+        prom_end_date: i64,
+        prom_cost: Decimal,
+        prom_response_target: i32,
+        prom_channel_dmail: bool,
+        prom_channel_email: bool,
+        prom_channel_catalog: bool,
+        prom_channel_tv: bool,
+        prom_channel_radio: bool,
+        prom_channel_press: bool,
+        prom_channel_event: bool,
+        prom_channel_demo: bool,
+        prom_channel_details: String,
+        prom_purpose: String,
+        prom_discount_active: bool,
+    ) -> Self {
+        Self {
+            null_bit_map,
+            prom_promotion_id,
+            prom_promotion_name,
+            prom_start_date,
+            prom_end_date,
+            prom_cost,
+            prom_response_target,
+            prom_channel_dmail,
+            prom_channel_email,
+            prom_channel_catalog,
+            prom_channel_tv,
+            prom_channel_radio,
+            prom_channel_press,
+            prom_channel_event,
+            prom_channel_demo,
+            prom_channel_details,
+            prom_purpose,
+            prom_discount_active,
+        }
+    }
+
+    pub fn get_prom_promotion_id(&self) -> &str {
+        &self.prom_promotion_id
+    }
+
+    pub fn get_prom_start_date(&self) -> i64 {
+        self.prom_start_date
+    }
+
+    pub fn get_prom_end_date(&self) -> i64 {
+        self.prom_end_date
+    }
+
+    /// Check if a column should be null based on the null bitmap (TableRowWithNulls logic)
+    fn should_be_null(&self, column_position: i32) -> bool {
+        ((self.null_bit_map >> column_position) & 1) == 1
+    }
+
+    fn get_string_or_null<T: ToString>(&self, value: T, column_position: i32) -> String {
+        if self.should_be_null(column_position) {
+            String::new()
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn get_date_string_or_null(&self, julian_days: i64, column_position: i32) -> String {
+        if self.should_be_null(column_position) {
+            String::new()
+        } else {
+            Date::julian_to_date_string(julian_days)
+        }
+    }
+
+    fn get_flag_string_or_null(&self, value: bool, column_position: i32) -> String {
+        self.get_string_or_null(if value { "Y" } else { "N" }, column_position)
+    }
+}
+
+impl TableRow for SPromotionRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.get_string_or_null(&self.prom_promotion_id, 0),
+            self.get_string_or_null(&self.prom_promotion_name, 1),
+            self.get_date_string_or_null(self.prom_start_date, 2),
+            self.get_date_string_or_null(self.prom_end_date, 3),
+            self.get_string_or_null(self.prom_cost, 4),
+            self.get_string_or_null(self.prom_response_target, 5),
+            self.get_flag_string_or_null(self.prom_channel_dmail, 6),
+            self.get_flag_string_or_null(self.prom_channel_email, 7),
+            self.get_flag_string_or_null(self.prom_channel_catalog, 8),
+            self.get_flag_string_or_null(self.prom_channel_tv, 9),
+            self.get_flag_string_or_null(self.prom_channel_radio, 10),
+            self.get_flag_string_or_null(self.prom_channel_press, 11),
+            self.get_flag_string_or_null(self.prom_channel_event, 12),
+            self.get_flag_string_or_null(self.prom_channel_demo, 13),
+            self.get_string_or_null(&self.prom_channel_details, 14),
+            self.get_string_or_null(&self.prom_purpose, 15),
+            self.get_flag_string_or_null(self.prom_discount_active, 16),
+        ]
+    }
+}
@@ -0,0 +1,155 @@
+use crate::business_key_generator::make_business_key;
+use crate::config::Session;
+use crate::error::Result;
+use crate::generator::SPromotionGeneratorColumn;
+use crate::nulls::create_null_bit_map;
+use crate::random::RandomValueGenerator;
+use crate::row::promotion_row_generator::{
+    PROMO_DETAIL_LENGTH_MAX, PROMO_DETAIL_LENGTH_MIN, PROMO_LENGTH_MAX, PROMO_LENGTH_MIN,
+    PROMO_NAME_LENGTH, PROMO_START_MAX, PROMO_START_MIN,
+};
+use crate::row::{AbstractRowGenerator, RowGenerator, RowGeneratorResult, SPromotionRow};
+use crate::table::Table;
+use crate::types::{Date, Decimal};
+
+/// Row generator for the S_PROMOTION refresh table. Each row gives new attributes to an
+/// existing promotion, named by its business key.
+/// Approximate: the new dates and channel flags are not drawn as dsdgen draws them, so the
+/// output is not TPC-DS compliant.
+pub struct SPromotionRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+}
+
+impl Default for SPromotionRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SPromotionRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SPromotion),
+        }
+    }
+
+    fn generate_s_promotion_row(&mut self, session: &Session) -> Result<SPromotionRow> {
+        let null_bit_map = create_null_bit_map(
+            Table::SPromotion,
+            self.abstract_generator
+                .get_random_number_stream(&SPromotionGeneratorColumn::SPromNulls)?,
+        );
+
+        // The promotion keeps the business key and name it has in the base data
+        let promotion = RandomValueGenerator::generate_uniform_random_key(
+            1,
+            session
+                .get_scaling()
+                .get_row_count(crate::config::Table::Promotion),
+            self.abstract_generator
+                .get_random_number_stream(&SPromotionGeneratorColumn::SPromPromotionId)?,
+        );
+        let prom_promotion_id = make_business_key(promotion);
+        let prom_promotion_name = RandomValueGenerator::generate_word(
+            promotion as i32,
+            PROMO_NAME_LENGTH,
+            self.abstract_generator
+                .get_random_number_stream(&SPromotionGeneratorColumn::SPromPromotionId)?,
+        )?;
+
+        let prom_start_date = Date::JULIAN_DATE_MINIMUM as i64
+            + RandomValueGenerator::generate_uniform_random_int(
+                PROMO_START_MIN,
+                PROMO_START_MAX,
+                self.abstract_generator
+                    .get_random_number_stream(&SPromotionGeneratorColumn::SPromStartDate)?,
+            ) as i64;
+        let prom_end_date = prom_start_date
+            + RandomValueGenerator::generate_uniform_random_int(
+                PROMO_LENGTH_MIN,
+                PROMO_LENGTH_MAX,
+                self.abstract_generator
+                    .get_random_number_stream(&SPromotionGeneratorColumn::SPromEndDate)?,
+            ) as i64;
+
+        // One bit per channel, then one for the discount
+        let flags = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            511,
+            self.abstract_generator
+                .get_random_number_stream(&SPromotionGeneratorColumn::SPromChannels)?,
+        );
+        let flag = |bit: i32| (flags >> bit) & 0x01 != 0;
+
+        let prom_channel_details = RandomValueGenerator::generate_random_text(
+            PROMO_DETAIL_LENGTH_MIN,
+            PROMO_DETAIL_LENGTH_MAX,
+            self.abstract_generator
+                .get_random_number_stream(&SPromotionGeneratorColumn::SPromChannelDetails)?,
+        )?;
+
+        Ok(SPromotionRow::new(
+            null_bit_map,
+            prom_promotion_id,
+            prom_promotion_name,
+            prom_start_date,
+            prom_end_date,
+            Decimal::new(100000, 2)?,
+            1,
+            flag(0),
+            flag(1),
+            flag(2),
+            flag(3),
+            flag(4),
+            flag(5),
+            flag(6),
+            flag(7),
+            prom_channel_details,
+            "Unknown".to_string(),
+            flag(8),
+        ))
+    }
+}
+
+impl RowGenerator for SPromotionRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        _row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_promotion_row(session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_promotion_ids_exist_in_base_data() {
+        let session = Session::get_default_session();
+        let promotion_count = session
+            .get_scaling()
+            .get_row_count(crate::config::Table::Promotion);
+        let promotion_ids: Vec<String> = (1..=promotion_count).map(make_business_key).collect();
+
+        let mut generator = SPromotionRowGenerator::new();
+        for _ in 0..10 {
+            let row = generator.generate_s_promotion_row(&session).unwrap();
+            assert!(promotion_ids.contains(&row.get_prom_promotion_id().to_string()));
+            assert!(row.get_prom_end_date() > row.get_prom_start_date());
+            generator.consume_remaining_seeds_for_row();
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use crate::row::TableRow;
+use crate::types::{Date, Decimal};
+
+/// Row structure for the S_PURCHASE refresh table: a store purchase (SPurchaseRow)
+#[derive(Debug, Clone)]
+pub struct SPurchaseRow {
+    purc_purchase_id: i64,
+    purc_store_id: String,
+    purc_customer_id: String,
+    purc_purchase_date: i64,
+    purc_purchase_time: i32,
+    purc_register_id: i32,
+    purc_clerk_id: i32,
+    purc_comment: String,
+}
+
+impl SPurchaseRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        purc_purchase_id: i64,
+        purc_store_id: String,
+        purc_customer_id: String,
+        purc_purchase_date: i64,
+        purc_purchase_time: i32,
+        purc_register_id: i32,
+        purc_clerk_id: i32,
+        purc_comment: String,
+    ) -> Self {
+        Self {
+            purc_purchase_id,
+            purc_store_id,
+            purc_customer_id,
+            purc_purchase_date,
+            purc_purchase_time,
+            purc_register_id,
+            purc_clerk_id,
+            purc_comment,
+        }
+    }
+
+    pub fn get_purc_purchase_id(&self) -> i64 {
+        self.purc_purchase_id
+    }
+
+    pub fn get_purc_store_id(&self) -> &str {
+        &self.purc_store_id
+    }
+
+    pub fn get_purc_purchase_date(&self) -> i64 {
+        self.purc_purchase_date
+    }
+}
+
+impl TableRow for SPurchaseRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.purc_purchase_id.to_string(),
+            self.purc_store_id.clone(),
+            self.purc_customer_id.clone(),
+            Date::julian_to_date_string(self.purc_purchase_date),
+            self.purc_purchase_time.to_string(),
+            self.purc_register_id.to_string(),
+            self.purc_clerk_id.to_string(),
+            self.purc_comment.clone(),
+        ]
+    }
+}
+
+/// Row structure for the S_PURCHASE_LINEITEM refresh table: a line of a store purchase (SPurchaseLineitemRow)
+#[derive(Debug, Clone)]
+pub struct SPurchaseLineitemRow {
+    plin_purchase_id: i64,
+    plin_line_number: i32,
+    plin_item_id: String,
+    plin_promotion_id: String,
+    plin_quantity: i32,
+    plin_sale_price: Decimal,
+    plin_coupon_amt: Decimal,
+    plin_comment: String,
+}
+
+impl SPurchaseLineitemRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        plin_purchase_id: i64,
+        plin_line_number: i32,
+        plin_item_id: String,
+        plin_promotion_id: String,
+        plin_quantity: i32,
+        plin_sale_price: Decimal,
+        plin_coupon_amt: Decimal,
+        plin_comment: String,
+    ) -> Self {
+        Self {
+            plin_purchase_id,
+            plin_line_number,
+            plin_item_id,
+            plin_promotion_id,
+            plin_quantity,
+            plin_sale_price,
+            plin_coupon_amt,
+            plin_comment,
+        }
+    }
+
+    pub fn get_plin_purchase_id(&self) -> i64 {
+        self.plin_purchase_id
+    }
+
+    pub fn get_plin_line_number(&self) -> i32 {
+        self.plin_line_number
+    }
+
+    pub fn get_plin_item_id(&self) -> &str {
+        &self.plin_item_id
+    }
+
+    pub fn get_plin_quantity(&self) -> i32 {
+        self.plin_quantity
+    }
+
+    pub fn get_plin_sale_price(&self) -> Decimal {
+        self.plin_sale_price
+    }
+}
+
+impl TableRow for SPurchaseLineitemRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.plin_purchase_id.to_string(),
+            self.plin_line_number.to_string(),
+            self.plin_item_id.clone(),
+            self.plin_promotion_id.clone(),
+            self.plin_quantity.to_string(),
+            self.plin_sale_price.to_string(),
+            self.plin_coupon_amt.to_string(),
+            self.plin_comment.clone(),
+        ]
+    }
+}
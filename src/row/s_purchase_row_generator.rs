@@ -0,0 +1,346 @@
+use crate::config::{Scaling, Session};
+use crate::error::Result;
+use crate::generator::{SPurchaseGeneratorColumn, SPurchaseLineitemGeneratorColumn};
+use crate::random::{RandomNumberStream, RandomValueGenerator};
+use crate::row::{
+    AbstractRowGenerator, RowGenerator, RowGeneratorResult, SPurchaseLineitemRow, SPurchaseRow,
+};
+use crate::table::Table;
+use crate::update::{self, SoldLine, UpdateDates};
+
+/// Purchases are rung up while the stores are open, from 8:00 to 22:00
+const STORE_OPENING_TIME: i32 = 8 * 3600;
+const STORE_CLOSING_TIME: i32 = 22 * 3600 - 1;
+
+const MIN_LINES_PER_PURCHASE: i32 = 8;
+const MAX_LINES_PER_PURCHASE: i32 = 16;
+
+/// Row generator for the S_PURCHASE refresh table: the store purchases of a refresh, one row per
+/// purchase. Their lines are generated by `SPurchaseLineitemRowGenerator`.
+/// Approximate: purchase dates come from `UpdateDates`, and stores, customers, registers and
+/// clerks are picked uniformly, unlike dsdgen; the output is not TPC-DS compliant.
+pub struct SPurchaseRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+}
+
+impl Default for SPurchaseRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SPurchaseRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SPurchase),
+            update_dates: None,
+        }
+    }
+
+    /// Date of purchase `purchase_id`, as generated in s_purchase
+    pub fn get_purchase_date(purchase_id: i64, update_dates: &UpdateDates) -> Result<i64> {
+        let mut stream =
+            update::get_line_stream(&SPurchaseGeneratorColumn::PurcPurchaseDate, purchase_id, 1)?;
+        Ok(update::pick_sales_date(update_dates, &mut stream))
+    }
+
+    fn generate_s_purchase_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SPurchaseRow> {
+        let scaling = session.get_scaling();
+        let purc_store_id = update::pick_business_key(
+            crate::config::Table::Store,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseGeneratorColumn::PurcStoreId)?,
+        )?;
+        let purc_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseGeneratorColumn::PurcCustomerId)?,
+        )?;
+
+        let update = session.get_update();
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let purc_purchase_date = update::pick_sales_date(
+            update_dates,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseGeneratorColumn::PurcPurchaseDate)?,
+        );
+        let purc_purchase_time = RandomValueGenerator::generate_uniform_random_int(
+            STORE_OPENING_TIME,
+            STORE_CLOSING_TIME,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseGeneratorColumn::PurcPurchaseTime)?,
+        );
+        let purc_register_id = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            20,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseGeneratorColumn::PurcRegisterId)?,
+        );
+        let purc_clerk_id = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            100,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseGeneratorColumn::PurcClerkId)?,
+        );
+        let purc_comment = RandomValueGenerator::generate_random_text(
+            20,
+            100,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseGeneratorColumn::PurcComment)?,
+        )?;
+
+        Ok(SPurchaseRow::new(
+            row_number,
+            purc_store_id,
+            purc_customer_id,
+            purc_purchase_date,
+            purc_purchase_time,
+            purc_register_id,
+            purc_clerk_id,
+            purc_comment,
+        ))
+    }
+}
+
+impl RowGenerator for SPurchaseRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_purchase_row(row_number, session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+/// Row generator for the S_PURCHASE_LINEITEM refresh table. A row number is a purchase: each call
+/// generates one of its lines and the row ends with the last line.
+/// Approximate: lines sell consecutive items at simplified prices and quantities instead of
+/// dsdgen's pricing, so the output is not TPC-DS compliant.
+pub struct SPurchaseLineitemRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Lines of the current purchase
+    line_count: i32,
+    /// Last line generated for the current purchase, 0 before its first line
+    line_number: i32,
+    /// Item of the first line of the current purchase
+    first_item: i64,
+}
+
+impl Default for SPurchaseLineitemRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SPurchaseLineitemRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SPurchaseLineitem),
+            line_count: 0,
+            line_number: 0,
+            first_item: 0,
+        }
+    }
+
+    /// Number of lines of purchase `purchase_id`, as generated in s_purchase_lineitem
+    pub fn get_line_count(purchase_id: i64, scaling: &Scaling) -> Result<i32> {
+        let mut stream = update::get_line_stream(
+            &SPurchaseLineitemGeneratorColumn::PlinLineCount,
+            purchase_id,
+            1,
+        )?;
+        Ok(Self::pick_line_count(scaling, &mut stream))
+    }
+
+    /// Item, quantity and sales price of line `line_number` of purchase `purchase_id`, as
+    /// generated in s_purchase_lineitem
+    pub fn get_sold_line(
+        purchase_id: i64,
+        line_number: i32,
+        scaling: &Scaling,
+    ) -> Result<SoldLine> {
+        let mut item_stream = update::get_line_stream(
+            &SPurchaseLineitemGeneratorColumn::PlinItemId,
+            purchase_id,
+            1,
+        )?;
+        let first_item = Self::pick_first_item(scaling, &mut item_stream);
+        let mut quantity_stream = update::get_line_stream(
+            &SPurchaseLineitemGeneratorColumn::PlinQuantity,
+            purchase_id,
+            line_number,
+        )?;
+        let mut price_stream = update::get_line_stream(
+            &SPurchaseLineitemGeneratorColumn::PlinSalePrice,
+            purchase_id,
+            line_number,
+        )?;
+        Ok(SoldLine::new(
+            update::get_line_item_id(first_item, line_number, scaling)?,
+            update::pick_quantity(&mut quantity_stream),
+            update::pick_sales_price(&mut price_stream)?,
+        ))
+    }
+
+    fn pick_line_count(scaling: &Scaling, stream: &mut dyn RandomNumberStream) -> i32 {
+        update::pick_line_count(
+            MIN_LINES_PER_PURCHASE,
+            MAX_LINES_PER_PURCHASE,
+            scaling,
+            stream,
+        )
+    }
+
+    fn pick_first_item(scaling: &Scaling, stream: &mut dyn RandomNumberStream) -> i64 {
+        RandomValueGenerator::generate_uniform_random_key(
+            1,
+            scaling.get_id_count(crate::config::Table::Item),
+            stream,
+        )
+    }
+
+    fn generate_s_purchase_lineitem_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SPurchaseLineitemRow> {
+        let scaling = session.get_scaling();
+        if self.line_number == 0 {
+            self.line_count = Self::pick_line_count(
+                scaling,
+                self.abstract_generator
+                    .get_random_number_stream(&SPurchaseLineitemGeneratorColumn::PlinLineCount)?,
+            );
+            self.first_item = Self::pick_first_item(
+                scaling,
+                self.abstract_generator
+                    .get_random_number_stream(&SPurchaseLineitemGeneratorColumn::PlinItemId)?,
+            );
+        }
+        self.line_number += 1;
+
+        let plin_item_id = update::get_line_item_id(self.first_item, self.line_number, scaling)?;
+        let plin_promotion_id = update::pick_business_key(
+            crate::config::Table::Promotion,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseLineitemGeneratorColumn::PlinPromotionId)?,
+        )?;
+        let plin_quantity = update::pick_quantity(
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseLineitemGeneratorColumn::PlinQuantity)?,
+        );
+        let plin_sale_price = update::pick_sales_price(
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseLineitemGeneratorColumn::PlinSalePrice)?,
+        )?;
+        let plin_coupon_amt = update::pick_coupon_amount(
+            plin_sale_price,
+            plin_quantity,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseLineitemGeneratorColumn::PlinCouponAmt)?,
+        )?;
+        let plin_comment = RandomValueGenerator::generate_random_text(
+            20,
+            100,
+            self.abstract_generator
+                .get_random_number_stream(&SPurchaseLineitemGeneratorColumn::PlinComment)?,
+        )?;
+
+        Ok(SPurchaseLineitemRow::new(
+            row_number,
+            self.line_number,
+            plin_item_id,
+            plin_promotion_id,
+            plin_quantity,
+            plin_sale_price,
+            plin_coupon_amt,
+            plin_comment,
+        ))
+    }
+}
+
+impl RowGenerator for SPurchaseLineitemRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_purchase_lineitem_row(row_number, session)?;
+        let is_last_line = self.line_number >= self.line_count;
+        if is_last_line {
+            self.line_number = 0;
+        }
+        Ok(RowGeneratorResult::new_with_multiple(
+            vec![Box::new(row)],
+            is_last_line,
+        ))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sold_lines_match_generated_lines() {
+        let session = Session::get_default_session().with_update(1);
+        let scaling = session.get_scaling();
+        let mut generator = SPurchaseLineitemRowGenerator::new();
+        generator.skip_rows_until_starting_row_number(5);
+
+        let line_count = SPurchaseLineitemRowGenerator::get_line_count(5, scaling).unwrap();
+        assert!((MIN_LINES_PER_PURCHASE..=MAX_LINES_PER_PURCHASE).contains(&line_count));
+        let mut items = Vec::new();
+        for line_number in 1..=line_count {
+            let row = generator
+                .generate_s_purchase_lineitem_row(5, &session)
+                .unwrap();
+            let sold =
+                SPurchaseLineitemRowGenerator::get_sold_line(5, line_number, scaling).unwrap();
+            assert_eq!(row.get_plin_line_number(), line_number);
+            assert_eq!(row.get_plin_item_id(), sold.get_item_id());
+            assert_eq!(row.get_plin_quantity(), sold.get_quantity());
+            assert_eq!(row.get_plin_sale_price(), sold.get_sales_price());
+            items.push(sold.get_item_id().to_string());
+        }
+        assert_eq!(generator.line_number, line_count);
+
+        // No item is sold twice in a purchase
+        items.sort();
+        items.dedup();
+        assert_eq!(items.len(), line_count as usize);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::row::TableRow;
+use crate::types::{Date, Decimal};
+
+/// Row structure for the S_STORE_RETURNS refresh table: a returned store purchase line (SStoreReturnsRow)
+#[derive(Debug, Clone)]
+pub struct SStoreReturnsRow {
+    sret_store_id: String,
+    sret_purchase_id: i64,
+    sret_line_number: i32,
+    sret_item_id: String,
+    sret_customer_id: String,
+    sret_return_date: i64,
+    sret_return_time: i32,
+    sret_return_qty: i32,
+    sret_return_amt: Decimal,
+    sret_return_tax: Decimal,
+    sret_return_fee: Decimal,
+    sret_return_ship_cost: Decimal,
+    sret_refunded_cash: Decimal,
+    sret_reversed_charge: Decimal,
+    sret_store_credit: Decimal,
+    sret_reason_id: String,
+}
+
+impl SStoreReturnsRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sret_store_id: String,
+        sret_purchase_id: i64,
+        sret_line_number: i32,
+        sret_item_id: String,
+        sret_customer_id: String,
+        sret_return_date: i64,
+        sret_return_time: i32,
+        sret_return_qty: i32,
+        sret_return_amt: Decimal,
+        sret_return_tax: Decimal,
+        sret_return_fee: Decimal,
+        sret_return_ship_cost: Decimal,
+        sret_refunded_cash: Decimal,
+        sret_reversed_charge: Decimal,
+        sret_store_credit: Decimal,
+        sret_reason_id: String,
+    ) -> Self {
+        Self {
+            sret_store_id,
+            sret_purchase_id,
+            sret_line_number,
+            sret_item_id,
+            sret_customer_id,
+            sret_return_date,
+            sret_return_time,
+            sret_return_qty,
+            sret_return_amt,
+            sret_return_tax,
+            sret_return_fee,
+            sret_return_ship_cost,
+            sret_refunded_cash,
+            sret_reversed_charge,
+            sret_store_credit,
+            sret_reason_id,
+        }
+    }
+
+    pub fn get_sret_purchase_id(&self) -> i64 {
+        self.sret_purchase_id
+    }
+
+    pub fn get_sret_line_number(&self) -> i32 {
+        self.sret_line_number
+    }
+
+    pub fn get_sret_item_id(&self) -> &str {
+        &self.sret_item_id
+    }
+
+    pub fn get_sret_return_date(&self) -> i64 {
+        self.sret_return_date
+    }
+
+    pub fn get_sret_return_qty(&self) -> i32 {
+        self.sret_return_qty
+    }
+
+    pub fn get_sret_return_amt(&self) -> Decimal {
+        self.sret_return_amt
+    }
+}
+
+impl TableRow for SStoreReturnsRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.sret_store_id.clone(),
+            self.sret_purchase_id.to_string(),
+            self.sret_line_number.to_string(),
+            self.sret_item_id.clone(),
+            self.sret_customer_id.clone(),
+            Date::julian_to_date_string(self.sret_return_date),
+            self.sret_return_time.to_string(),
+            self.sret_return_qty.to_string(),
+            self.sret_return_amt.to_string(),
+            self.sret_return_tax.to_string(),
+            self.sret_return_fee.to_string(),
+            self.sret_return_ship_cost.to_string(),
+            self.sret_refunded_cash.to_string(),
+            self.sret_reversed_charge.to_string(),
+            self.sret_store_credit.to_string(),
+            self.sret_reason_id.clone(),
+        ]
+    }
+}
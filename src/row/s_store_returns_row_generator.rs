@@ -0,0 +1,223 @@
+use crate::config::Session;
+use crate::error::Result;
+use crate::generator::SStoreReturnsGeneratorColumn;
+use crate::random::RandomValueGenerator;
+use crate::row::{
+    AbstractRowGenerator, RowGenerator, RowGeneratorResult, SPurchaseLineitemRowGenerator,
+    SPurchaseRowGenerator, SStoreReturnsRow,
+};
+use crate::table::Table;
+use crate::update::{self, ReturnAmounts, UpdateDates};
+use crate::TpcdsError;
+
+/// Items are returned within three months of their purchase
+const MAX_DAYS_TO_RETURN: i32 = 90;
+
+/// Row generator for the S_STORE_RETURNS refresh table: a line of a different purchase of the
+/// refresh for each row, returned after the purchase.
+/// Approximate: which purchases are returned, and for how much, is not derived as in dsdgen, so
+/// the output is not TPC-DS compliant.
+pub struct SStoreReturnsRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+    /// Purchases returned in the refresh being generated
+    returned_purchases: Option<(i32, Vec<i64>)>,
+}
+
+impl Default for SStoreReturnsRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SStoreReturnsRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SStoreReturns),
+            update_dates: None,
+            returned_purchases: None,
+        }
+    }
+
+    fn generate_s_store_returns_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SStoreReturnsRow> {
+        let scaling = session.get_scaling();
+        let update = session.get_update();
+        let returned_purchases = match &mut self.returned_purchases {
+            Some((cached, purchases)) if *cached == update => purchases,
+            cache => {
+                let purchases = update::get_returned_orders(
+                    Table::SStoreReturns,
+                    Table::SPurchase,
+                    &SStoreReturnsGeneratorColumn::SretPurchaseId,
+                    scaling,
+                    update,
+                )?;
+                &mut cache.insert((update, purchases)).1
+            }
+        };
+        let index = row_number - 1 - update::get_row_offset(Table::SStoreReturns, scaling, update)?;
+        let sret_purchase_id = *returned_purchases.get(index as usize).ok_or_else(|| {
+            TpcdsError::new(&format!(
+                "Row {} of {} is not part of refresh {}",
+                row_number,
+                Table::SStoreReturns,
+                update
+            ))
+        })?;
+
+        let line_count = SPurchaseLineitemRowGenerator::get_line_count(sret_purchase_id, scaling)?;
+        let sret_line_number = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            line_count,
+            self.abstract_generator
+                .get_random_number_stream(&SStoreReturnsGeneratorColumn::SretLineNumber)?,
+        );
+        let sold_line = SPurchaseLineitemRowGenerator::get_sold_line(
+            sret_purchase_id,
+            sret_line_number,
+            scaling,
+        )?;
+
+        let sret_store_id = update::pick_business_key(
+            crate::config::Table::Store,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SStoreReturnsGeneratorColumn::SretStoreId)?,
+        )?;
+        let sret_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SStoreReturnsGeneratorColumn::SretCustomerId)?,
+        )?;
+
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let purchase_date =
+            SPurchaseRowGenerator::get_purchase_date(sret_purchase_id, update_dates)?;
+        let sret_return_date = purchase_date
+            + RandomValueGenerator::generate_uniform_random_int(
+                1,
+                MAX_DAYS_TO_RETURN,
+                self.abstract_generator
+                    .get_random_number_stream(&SStoreReturnsGeneratorColumn::SretReturnDate)?,
+            ) as i64;
+        let sret_return_time = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            86399,
+            self.abstract_generator
+                .get_random_number_stream(&SStoreReturnsGeneratorColumn::SretReturnTime)?,
+        );
+
+        let sret_return_qty = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            sold_line.get_quantity(),
+            self.abstract_generator
+                .get_random_number_stream(&SStoreReturnsGeneratorColumn::SretReturnQty)?,
+        );
+        let amounts = ReturnAmounts::new(
+            sold_line.get_sales_price(),
+            sret_return_qty,
+            self.abstract_generator
+                .get_random_number_stream(&SStoreReturnsGeneratorColumn::SretAmounts)?,
+        )?;
+        let sret_reason_id = update::pick_business_key(
+            crate::config::Table::Reason,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SStoreReturnsGeneratorColumn::SretReasonId)?,
+        )?;
+
+        Ok(SStoreReturnsRow::new(
+            sret_store_id,
+            sret_purchase_id,
+            sret_line_number,
+            sold_line.get_item_id().to_string(),
+            sret_customer_id,
+            sret_return_date,
+            sret_return_time,
+            sret_return_qty,
+            amounts.get_return_amount(),
+            amounts.get_return_tax(),
+            amounts.get_fee(),
+            amounts.get_ship_cost(),
+            amounts.get_refunded_cash(),
+            amounts.get_reversed_charge(),
+            amounts.get_credit(),
+            sret_reason_id,
+        ))
+    }
+}
+
+impl RowGenerator for SStoreReturnsRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_store_returns_row(row_number, session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_returns_refer_to_sold_lines() {
+        let session = Session::get_default_session().with_update(2);
+        let scaling = session.get_scaling();
+        let update_dates = UpdateDates::new(2).unwrap();
+        let first_row = update::get_row_offset(Table::SStoreReturns, scaling, 2).unwrap() + 1;
+        let purchases = update::get_row_count(Table::SPurchase, scaling).unwrap();
+
+        let mut generator = SStoreReturnsRowGenerator::new();
+        generator.skip_rows_until_starting_row_number(first_row);
+        let mut returned = Vec::new();
+        for row_number in first_row..first_row + 20 {
+            let row = generator
+                .generate_s_store_returns_row(row_number, &session)
+                .unwrap();
+            generator.consume_remaining_seeds_for_row();
+
+            // Purchases of the second refresh follow the purchases of the first one
+            let purchase_id = row.get_sret_purchase_id();
+            assert!(purchase_id > purchases && purchase_id <= 2 * purchases);
+            let sold_line = SPurchaseLineitemRowGenerator::get_sold_line(
+                purchase_id,
+                row.get_sret_line_number(),
+                scaling,
+            )
+            .unwrap();
+            assert_eq!(row.get_sret_item_id(), sold_line.get_item_id());
+            assert!(row.get_sret_return_qty() <= sold_line.get_quantity());
+            assert!(
+                row.get_sret_return_date()
+                    > SPurchaseRowGenerator::get_purchase_date(purchase_id, &update_dates).unwrap()
+            );
+            returned.push(purchase_id);
+        }
+
+        returned.sort();
+        returned.dedup();
+        assert_eq!(returned.len(), 20);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::row::TableRow;
+
+/// Row structure for the S_WAREHOUSE refresh table (SWarehouseRow)
+#[derive(Debug, Clone)]
+pub struct SWarehouseRow {
+    null_bit_map: i64,
+    wrhs_warehouse_id: String,
+    wrhs_warehouse_desc: String,
+    wrhs_warehouse_sq_ft: i32,
+}
+
+impl SWarehouseRow {
+    pub fn new(
+        null_bit_map: i64,
+        wrhs_warehouse_id: String,
+        wrhs_warehouse_desc: String,
+        wrhs_warehouse_sq_ft: i32,
+    ) -> Self {
+        Self {
+            null_bit_map,
+            wrhs_warehouse_id,
+            wrhs_warehouse_desc,
+            wrhs_warehouse_sq_ft,
+        }
+    }
+
+    pub fn get_wrhs_warehouse_id(&self) -> &str {
+        &self.wrhs_warehouse_id
+    }
+
+    /// Check if a column should be null based on the null bitmap (TableRowWithNulls logic)
+    fn should_be_null(&self, column_position: i32) -> bool {
+        ((self.null_bit_map >> column_position) & 1) == 1
+    }
+
+    fn get_string_or_null<T: ToString>(&self, value: T, column_position: i32) -> String {
+        if self.should_be_null(column_position) {
+            String::new()
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+impl TableRow for SWarehouseRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.get_string_or_null(&self.wrhs_warehouse_id, 0),
+            self.get_string_or_null(&self.wrhs_warehouse_desc, 1),
+            self.get_string_or_null(self.wrhs_warehouse_sq_ft, 2),
+        ]
+    }
+}
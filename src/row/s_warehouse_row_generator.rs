@@ -0,0 +1,106 @@
+use crate::business_key_generator::make_business_key;
+use crate::config::Session;
+use crate::error::Result;
+use crate::generator::SWarehouseGeneratorColumn;
+use crate::nulls::create_null_bit_map;
+use crate::random::RandomValueGenerator;
+use crate::row::{AbstractRowGenerator, RowGenerator, RowGeneratorResult, SWarehouseRow};
+use crate::table::Table;
+
+/// Row generator for the S_WAREHOUSE refresh table. Each row gives new attributes to an
+/// existing warehouse, named by its business key.
+/// Approximate: the warehouses changed and their new sizes are not dsdgen's, so the output is
+/// not TPC-DS compliant.
+pub struct SWarehouseRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+}
+
+impl Default for SWarehouseRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SWarehouseRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SWarehouse),
+        }
+    }
+
+    fn generate_s_warehouse_row(&mut self, session: &Session) -> Result<SWarehouseRow> {
+        let null_bit_map = create_null_bit_map(
+            Table::SWarehouse,
+            self.abstract_generator
+                .get_random_number_stream(&SWarehouseGeneratorColumn::SWrhsNulls)?,
+        );
+
+        let warehouse = RandomValueGenerator::generate_uniform_random_key(
+            1,
+            session
+                .get_scaling()
+                .get_row_count(crate::config::Table::Warehouse),
+            self.abstract_generator
+                .get_random_number_stream(&SWarehouseGeneratorColumn::SWrhsWarehouseId)?,
+        );
+
+        let wrhs_warehouse_desc = RandomValueGenerator::generate_random_text(
+            10,
+            20,
+            self.abstract_generator
+                .get_random_number_stream(&SWarehouseGeneratorColumn::SWrhsWarehouseDesc)?,
+        )?;
+        let wrhs_warehouse_sq_ft = RandomValueGenerator::generate_uniform_random_int(
+            50000,
+            1000000,
+            self.abstract_generator
+                .get_random_number_stream(&SWarehouseGeneratorColumn::SWrhsWarehouseSqFt)?,
+        );
+
+        Ok(SWarehouseRow::new(
+            null_bit_map,
+            make_business_key(warehouse),
+            wrhs_warehouse_desc,
+            wrhs_warehouse_sq_ft,
+        ))
+    }
+}
+
+impl RowGenerator for SWarehouseRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        _row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_warehouse_row(session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_warehouse_ids_exist_in_base_data() {
+        let session = Session::get_default_session();
+        let warehouse_ids: Vec<String> = (1..=5).map(make_business_key).collect();
+
+        let mut generator = SWarehouseRowGenerator::new();
+        for _ in 0..5 {
+            let row = generator.generate_s_warehouse_row(&session).unwrap();
+            assert!(warehouse_ids.contains(&row.get_wrhs_warehouse_id().to_string()));
+            generator.consume_remaining_seeds_for_row();
+        }
+    }
+}
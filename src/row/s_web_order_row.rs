@@ -0,0 +1,152 @@
+use crate::row::TableRow;
+use crate::types::{Date, Decimal};
+
+/// Row structure for the S_WEB_ORDER refresh table: a web order (SWebOrderRow)
+#[derive(Debug, Clone)]
+pub struct SWebOrderRow {
+    word_order_id: i64,
+    word_bill_customer_id: String,
+    word_ship_customer_id: String,
+    word_order_date: i64,
+    word_order_time: i32,
+    word_ship_mode_id: String,
+    word_web_site_id: String,
+    word_order_comments: String,
+}
+
+impl SWebOrderRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        word_order_id: i64,
+        word_bill_customer_id: String,
+        word_ship_customer_id: String,
+        word_order_date: i64,
+        word_order_time: i32,
+        word_ship_mode_id: String,
+        word_web_site_id: String,
+        word_order_comments: String,
+    ) -> Self {
+        Self {
+            word_order_id,
+            word_bill_customer_id,
+            word_ship_customer_id,
+            word_order_date,
+            word_order_time,
+            word_ship_mode_id,
+            word_web_site_id,
+            word_order_comments,
+        }
+    }
+
+    pub fn get_word_order_id(&self) -> i64 {
+        self.word_order_id
+    }
+
+    pub fn get_word_order_date(&self) -> i64 {
+        self.word_order_date
+    }
+}
+
+impl TableRow for SWebOrderRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.word_order_id.to_string(),
+            self.word_bill_customer_id.clone(),
+            self.word_ship_customer_id.clone(),
+            Date::julian_to_date_string(self.word_order_date),
+            self.word_order_time.to_string(),
+            self.word_ship_mode_id.clone(),
+            self.word_web_site_id.clone(),
+            self.word_order_comments.clone(),
+        ]
+    }
+}
+
+/// Row structure for the S_WEB_ORDER_LINEITEM refresh table: a line of a web order (SWebOrderLineitemRow)
+#[derive(Debug, Clone)]
+pub struct SWebOrderLineitemRow {
+    wlin_order_id: i64,
+    wlin_line_number: i32,
+    wlin_item_id: String,
+    wlin_promotion_id: String,
+    wlin_quantity: i32,
+    wlin_sales_price: Decimal,
+    wlin_coupon_amt: Decimal,
+    wlin_warehouse_id: String,
+    wlin_ship_date: i64,
+    wlin_ship_cost: Decimal,
+    wlin_web_page_id: String,
+}
+
+impl SWebOrderLineitemRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        wlin_order_id: i64,
+        wlin_line_number: i32,
+        wlin_item_id: String,
+        wlin_promotion_id: String,
+        wlin_quantity: i32,
+        wlin_sales_price: Decimal,
+        wlin_coupon_amt: Decimal,
+        wlin_warehouse_id: String,
+        wlin_ship_date: i64,
+        wlin_ship_cost: Decimal,
+        wlin_web_page_id: String,
+    ) -> Self {
+        Self {
+            wlin_order_id,
+            wlin_line_number,
+            wlin_item_id,
+            wlin_promotion_id,
+            wlin_quantity,
+            wlin_sales_price,
+            wlin_coupon_amt,
+            wlin_warehouse_id,
+            wlin_ship_date,
+            wlin_ship_cost,
+            wlin_web_page_id,
+        }
+    }
+
+    pub fn get_wlin_order_id(&self) -> i64 {
+        self.wlin_order_id
+    }
+
+    pub fn get_wlin_line_number(&self) -> i32 {
+        self.wlin_line_number
+    }
+
+    pub fn get_wlin_item_id(&self) -> &str {
+        &self.wlin_item_id
+    }
+
+    pub fn get_wlin_quantity(&self) -> i32 {
+        self.wlin_quantity
+    }
+
+    pub fn get_wlin_sales_price(&self) -> Decimal {
+        self.wlin_sales_price
+    }
+
+    pub fn get_wlin_ship_date(&self) -> i64 {
+        self.wlin_ship_date
+    }
+}
+
+impl TableRow for SWebOrderLineitemRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.wlin_order_id.to_string(),
+            self.wlin_line_number.to_string(),
+            self.wlin_item_id.clone(),
+            self.wlin_promotion_id.clone(),
+            self.wlin_quantity.to_string(),
+            self.wlin_sales_price.to_string(),
+            self.wlin_coupon_amt.to_string(),
+            self.wlin_warehouse_id.clone(),
+            Date::julian_to_date_string(self.wlin_ship_date),
+            self.wlin_ship_cost.to_string(),
+            self.wlin_web_page_id.clone(),
+        ]
+    }
+}
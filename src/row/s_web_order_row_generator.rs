@@ -0,0 +1,384 @@
+use crate::config::{Scaling, Session};
+use crate::error::Result;
+use crate::generator::{SWebOrderGeneratorColumn, SWebOrderLineitemGeneratorColumn};
+use crate::random::{RandomNumberStream, RandomValueGenerator};
+use crate::row::{
+    AbstractRowGenerator, RowGenerator, RowGeneratorResult, SWebOrderLineitemRow, SWebOrderRow,
+};
+use crate::table::Table;
+use crate::update::{self, SoldLine, UpdateDates};
+
+const MIN_LINES_PER_ORDER: i32 = 8;
+const MAX_LINES_PER_ORDER: i32 = 16;
+
+/// Orders ship within three months, but never on the day they are placed
+const MIN_SHIP_DELAY: i32 = 2;
+const MAX_SHIP_DELAY: i32 = 90;
+
+/// Row generator for the S_WEB_ORDER refresh table: the web orders of a refresh, one row
+/// per order. Their lines are generated by `SWebOrderLineitemRowGenerator`.
+/// Approximate: order dates come from `UpdateDates`, and customers, ship modes and web sites are
+/// picked uniformly, unlike dsdgen; the output is not TPC-DS compliant.
+pub struct SWebOrderRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+}
+
+impl Default for SWebOrderRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SWebOrderRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SWebOrder),
+            update_dates: None,
+        }
+    }
+
+    /// Date of order `order_id`, as generated in s_web_order
+    pub fn get_order_date(order_id: i64, update_dates: &UpdateDates) -> Result<i64> {
+        let mut stream =
+            update::get_line_stream(&SWebOrderGeneratorColumn::WordOrderDate, order_id, 1)?;
+        Ok(update::pick_sales_date(update_dates, &mut stream))
+    }
+
+    fn generate_s_web_order_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SWebOrderRow> {
+        let scaling = session.get_scaling();
+        let word_bill_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderGeneratorColumn::WordBillCustomerId)?,
+        )?;
+        let word_ship_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderGeneratorColumn::WordShipCustomerId)?,
+        )?;
+
+        let update = session.get_update();
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let word_order_date = update::pick_sales_date(
+            update_dates,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderGeneratorColumn::WordOrderDate)?,
+        );
+        let word_order_time = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            86399,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderGeneratorColumn::WordOrderTime)?,
+        );
+        let word_ship_mode_id = update::pick_business_key(
+            crate::config::Table::ShipMode,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderGeneratorColumn::WordShipModeId)?,
+        )?;
+        let word_web_site_id = update::pick_business_key(
+            crate::config::Table::WebSite,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderGeneratorColumn::WordWebSiteId)?,
+        )?;
+        let word_order_comments = RandomValueGenerator::generate_random_text(
+            20,
+            100,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderGeneratorColumn::WordOrderComments)?,
+        )?;
+
+        Ok(SWebOrderRow::new(
+            row_number,
+            word_bill_customer_id,
+            word_ship_customer_id,
+            word_order_date,
+            word_order_time,
+            word_ship_mode_id,
+            word_web_site_id,
+            word_order_comments,
+        ))
+    }
+}
+
+impl RowGenerator for SWebOrderRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_web_order_row(row_number, session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+/// Row generator for the S_WEB_ORDER_LINEITEM refresh table. A row number is an order: each
+/// call generates one of its lines and the row ends with the last line.
+/// Approximate: lines sell consecutive items and use simplified prices, ship dates and costs
+/// instead of dsdgen's pricing, so the output is not TPC-DS compliant.
+pub struct SWebOrderLineitemRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+    /// Lines of the current order
+    line_count: i32,
+    /// Last line generated for the current order, 0 before its first line
+    line_number: i32,
+    /// Item of the first line of the current order
+    first_item: i64,
+}
+
+impl Default for SWebOrderLineitemRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SWebOrderLineitemRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SWebOrderLineitem),
+            update_dates: None,
+            line_count: 0,
+            line_number: 0,
+            first_item: 0,
+        }
+    }
+
+    /// Number of lines of order `order_id`, as generated in s_web_order_lineitem
+    pub fn get_line_count(order_id: i64, scaling: &Scaling) -> Result<i32> {
+        let mut stream = update::get_line_stream(
+            &SWebOrderLineitemGeneratorColumn::WlinLineCount,
+            order_id,
+            1,
+        )?;
+        Ok(Self::pick_line_count(scaling, &mut stream))
+    }
+
+    /// Item, quantity and sales price of line `line_number` of order `order_id`, as generated in
+    /// s_web_order_lineitem
+    pub fn get_sold_line(order_id: i64, line_number: i32, scaling: &Scaling) -> Result<SoldLine> {
+        let mut item_stream =
+            update::get_line_stream(&SWebOrderLineitemGeneratorColumn::WlinItemId, order_id, 1)?;
+        let first_item = Self::pick_first_item(scaling, &mut item_stream);
+        let mut quantity_stream = update::get_line_stream(
+            &SWebOrderLineitemGeneratorColumn::WlinQuantity,
+            order_id,
+            line_number,
+        )?;
+        let mut price_stream = update::get_line_stream(
+            &SWebOrderLineitemGeneratorColumn::WlinSalesPrice,
+            order_id,
+            line_number,
+        )?;
+        Ok(SoldLine::new(
+            update::get_line_item_id(first_item, line_number, scaling)?,
+            update::pick_quantity(&mut quantity_stream),
+            update::pick_sales_price(&mut price_stream)?,
+        ))
+    }
+
+    /// Ship date of line `line_number` of order `order_id`, as generated in
+    /// s_web_order_lineitem
+    pub fn get_ship_date(
+        order_id: i64,
+        line_number: i32,
+        update_dates: &UpdateDates,
+    ) -> Result<i64> {
+        let order_date = SWebOrderRowGenerator::get_order_date(order_id, update_dates)?;
+        let mut stream = update::get_line_stream(
+            &SWebOrderLineitemGeneratorColumn::WlinShipDate,
+            order_id,
+            line_number,
+        )?;
+        Ok(order_date + Self::pick_ship_delay(&mut stream) as i64)
+    }
+
+    fn pick_line_count(scaling: &Scaling, stream: &mut dyn RandomNumberStream) -> i32 {
+        update::pick_line_count(MIN_LINES_PER_ORDER, MAX_LINES_PER_ORDER, scaling, stream)
+    }
+
+    fn pick_first_item(scaling: &Scaling, stream: &mut dyn RandomNumberStream) -> i64 {
+        RandomValueGenerator::generate_uniform_random_key(
+            1,
+            scaling.get_id_count(crate::config::Table::Item),
+            stream,
+        )
+    }
+
+    fn pick_ship_delay(stream: &mut dyn RandomNumberStream) -> i32 {
+        RandomValueGenerator::generate_uniform_random_int(MIN_SHIP_DELAY, MAX_SHIP_DELAY, stream)
+    }
+
+    fn generate_s_web_order_lineitem_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SWebOrderLineitemRow> {
+        let scaling = session.get_scaling();
+        if self.line_number == 0 {
+            self.line_count = Self::pick_line_count(
+                scaling,
+                self.abstract_generator
+                    .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinLineCount)?,
+            );
+            self.first_item = Self::pick_first_item(
+                scaling,
+                self.abstract_generator
+                    .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinItemId)?,
+            );
+        }
+        self.line_number += 1;
+
+        let wlin_item_id = update::get_line_item_id(self.first_item, self.line_number, scaling)?;
+        let wlin_promotion_id = update::pick_business_key(
+            crate::config::Table::Promotion,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinPromotionId)?,
+        )?;
+        let wlin_quantity = update::pick_quantity(
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinQuantity)?,
+        );
+        let wlin_sales_price = update::pick_sales_price(
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinSalesPrice)?,
+        )?;
+        let wlin_coupon_amt = update::pick_coupon_amount(
+            wlin_sales_price,
+            wlin_quantity,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinCouponAmt)?,
+        )?;
+        let wlin_warehouse_id = update::pick_business_key(
+            crate::config::Table::Warehouse,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinWarehouseId)?,
+        )?;
+
+        let update = session.get_update();
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let order_date = SWebOrderRowGenerator::get_order_date(row_number, update_dates)?;
+        let wlin_ship_date = order_date
+            + Self::pick_ship_delay(
+                self.abstract_generator
+                    .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinShipDate)?,
+            ) as i64;
+
+        let wlin_ship_cost = update::pick_ship_cost(
+            wlin_sales_price,
+            wlin_quantity,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinShipCost)?,
+        )?;
+        let wlin_web_page_id = update::pick_business_key(
+            crate::config::Table::WebPage,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebOrderLineitemGeneratorColumn::WlinWebPageId)?,
+        )?;
+
+        Ok(SWebOrderLineitemRow::new(
+            row_number,
+            self.line_number,
+            wlin_item_id,
+            wlin_promotion_id,
+            wlin_quantity,
+            wlin_sales_price,
+            wlin_coupon_amt,
+            wlin_warehouse_id,
+            wlin_ship_date,
+            wlin_ship_cost,
+            wlin_web_page_id,
+        ))
+    }
+}
+
+impl RowGenerator for SWebOrderLineitemRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_web_order_lineitem_row(row_number, session)?;
+        let is_last_line = self.line_number >= self.line_count;
+        if is_last_line {
+            self.line_number = 0;
+        }
+        Ok(RowGeneratorResult::new_with_multiple(
+            vec![Box::new(row)],
+            is_last_line,
+        ))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines_ship_after_their_order() {
+        let session = Session::get_default_session().with_update(1);
+        let scaling = session.get_scaling();
+        let update_dates = UpdateDates::new(1).unwrap();
+        let order_date = SWebOrderRowGenerator::get_order_date(3, &update_dates).unwrap();
+
+        let mut generator = SWebOrderLineitemRowGenerator::new();
+        generator.skip_rows_until_starting_row_number(3);
+        let line_count = SWebOrderLineitemRowGenerator::get_line_count(3, scaling).unwrap();
+        for line_number in 1..=line_count {
+            let row = generator
+                .generate_s_web_order_lineitem_row(3, &session)
+                .unwrap();
+            let sold =
+                SWebOrderLineitemRowGenerator::get_sold_line(3, line_number, scaling).unwrap();
+            assert_eq!(row.get_wlin_item_id(), sold.get_item_id());
+            assert_eq!(row.get_wlin_quantity(), sold.get_quantity());
+            assert_eq!(row.get_wlin_sales_price(), sold.get_sales_price());
+            assert_eq!(
+                row.get_wlin_ship_date(),
+                SWebOrderLineitemRowGenerator::get_ship_date(3, line_number, &update_dates)
+                    .unwrap()
+            );
+            assert!(row.get_wlin_ship_date() >= order_date + MIN_SHIP_DELAY as i64);
+            assert!(row.get_wlin_ship_date() <= order_date + MAX_SHIP_DELAY as i64);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::row::TableRow;
+use crate::types::Date;
+
+/// Row structure for the S_WEB_PAGE refresh table (SWebPageRow)
+#[derive(Debug, Clone)]
+pub struct SWebPageRow {
+    null_bit_map: i64,
+    wpag_web_page_id: String,
+    wpag_create_date: i64,
+    wpag_access_date: i64,
+    wpag_autogen_flag: bool,
+    wpag_url: String,
+    wpag_type: String,
+    wpag_char_count: i32,
+    wpag_link_count: i32,
+    wpag_image_count: i32,
+    wpag_max_ad_count: i32,
+}
+
+impl SWebPageRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        null_bit_map: i64,
+        wpag_web_page_id: String,
+        wpag_create_date: i64,
+        wpag_access_date: i64,
+        wpag_autogen_flag: bool,
+        wpag_url: String,
+        wpag_type: String,
+        wpag_char_count: i32,
+        wpag_link_count: i32,
+        wpag_image_count: i32,
+        wpag_max_ad_count: i32,
+    ) -> Self {
+        Self {
+            null_bit_map,
+            wpag_web_page_id,
+            wpag_create_date,
+            wpag_access_date,
+            wpag_autogen_flag,
+            wpag_url,
+            wpag_type,
+            wpag_char_count,
+            wpag_link_count,
+            wpag_image_count,
+            wpag_max_ad_count,
+        }
+    }
+
+    pub fn get_wpag_web_page_id(&self) -> &str {
+        &self.wpag_web_page_id
+    }
+
+    /// Check if a column should be null based on the null bitmap (TableRowWithNulls logic)
+    fn should_be_null(&self, column_position: i32) -> bool {
+        ((self.null_bit_map >> column_position) & 1) == 1
+    }
+
+    fn get_string_or_null<T: ToString>(&self, value: T, column_position: i32) -> String {
+        if self.should_be_null(column_position) {
+            String::new()
+        } else {
+            value.to_string()
+        }
+    }
+
+    fn get_date_string_or_null(&self, julian_days: i64, column_position: i32) -> String {
+        if self.should_be_null(column_position) {
+            String::new()
+        } else {
+            Date::julian_to_date_string(julian_days)
+        }
+    }
+}
+
+impl TableRow for SWebPageRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.get_string_or_null(&self.wpag_web_page_id, 0),
+            self.get_date_string_or_null(self.wpag_create_date, 1),
+            self.get_date_string_or_null(self.wpag_access_date, 2),
+            self.get_string_or_null(if self.wpag_autogen_flag { "Y" } else { "N" }, 3),
+            self.get_string_or_null(&self.wpag_url, 4),
+            self.get_string_or_null(&self.wpag_type, 5),
+            self.get_string_or_null(self.wpag_char_count, 6),
+            self.get_string_or_null(self.wpag_link_count, 7),
+            self.get_string_or_null(self.wpag_image_count, 8),
+            self.get_string_or_null(self.wpag_max_ad_count, 9),
+        ]
+    }
+}
@@ -0,0 +1,176 @@
+use crate::config::Session;
+use crate::distribution::WebPageUseDistribution;
+use crate::error::Result;
+use crate::generator::SWebPageGeneratorColumn;
+use crate::nulls::create_null_bit_map;
+use crate::random::RandomValueGenerator;
+use crate::row::{
+    AbstractRowGenerator, RowGenerator, RowGeneratorResult, SWebPageRow, WebPageRowGenerator,
+};
+use crate::slowly_changing_dimension_utils::{compute_scd_key, match_surrogate_key};
+use crate::table::Table;
+use crate::types::Date;
+
+/// Row generator for the S_WEB_PAGE refresh table. Each row gives new attributes to an existing
+/// web page, named by its business key.
+/// Approximate: the dates and page counts are drawn from tpcdsgen's own ranges, not dsdgen's, so
+/// the output is not TPC-DS compliant.
+pub struct SWebPageRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+}
+
+impl Default for SWebPageRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SWebPageRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SWebPage),
+        }
+    }
+
+    fn generate_s_web_page_row(&mut self, session: &Session) -> Result<SWebPageRow> {
+        let null_bit_map = create_null_bit_map(
+            Table::SWebPage,
+            self.abstract_generator
+                .get_random_number_stream(&SWebPageGeneratorColumn::SWpagNulls)?,
+        );
+        let scaling = session.get_scaling();
+
+        let id_count = scaling.get_id_count(crate::config::Table::WebPage);
+        let unique = RandomValueGenerator::generate_uniform_random_key(
+            1,
+            id_count,
+            self.abstract_generator
+                .get_random_number_stream(&SWebPageGeneratorColumn::SWpagWebPageId)?,
+        );
+        let first_revision = match_surrogate_key(
+            unique,
+            Date::JULIAN_DATA_START_DATE,
+            crate::config::Table::WebPage,
+            scaling,
+        )?;
+        let wpag_web_page_id = compute_scd_key(Table::WebPage, first_revision)?
+            .get_business_key()
+            .to_string();
+
+        // The page was created before it was last accessed
+        let wpag_access_date = Date::JULIAN_TODAYS_DATE as i64
+            - RandomValueGenerator::generate_uniform_random_int(
+                0,
+                100,
+                self.abstract_generator
+                    .get_random_number_stream(&SWebPageGeneratorColumn::SWpagAccessDate)?,
+            ) as i64;
+        let wpag_create_date = Date::JULIAN_DATA_START_DATE
+            + RandomValueGenerator::generate_uniform_random_int(
+                0,
+                (wpag_access_date - Date::JULIAN_DATA_START_DATE) as i32,
+                self.abstract_generator
+                    .get_random_number_stream(&SWebPageGeneratorColumn::SWpagCreateDate)?,
+            ) as i64;
+
+        let wpag_autogen_flag = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            99,
+            self.abstract_generator
+                .get_random_number_stream(&SWebPageGeneratorColumn::SWpagAutogenFlag)?,
+        ) < WebPageRowGenerator::WP_AUTOGEN_PERCENT;
+        let wpag_url = RandomValueGenerator::generate_random_url(
+            self.abstract_generator
+                .get_random_number_stream(&SWebPageGeneratorColumn::SWpagUrl)?,
+        );
+        let wpag_type = WebPageUseDistribution::pick_random_web_page_use_type(
+            self.abstract_generator
+                .get_random_number_stream(&SWebPageGeneratorColumn::SWpagType)?,
+        )?;
+
+        let wpag_link_count = RandomValueGenerator::generate_uniform_random_int(
+            2,
+            25,
+            self.abstract_generator
+                .get_random_number_stream(&SWebPageGeneratorColumn::SWpagLinkCount)?,
+        );
+        let wpag_image_count = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            7,
+            self.abstract_generator
+                .get_random_number_stream(&SWebPageGeneratorColumn::SWpagImageCount)?,
+        );
+        let wpag_max_ad_count = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            4,
+            self.abstract_generator
+                .get_random_number_stream(&SWebPageGeneratorColumn::SWpagMaxAdCount)?,
+        );
+        let wpag_char_count = RandomValueGenerator::generate_uniform_random_int(
+            wpag_link_count * 125 + wpag_image_count * 50,
+            wpag_link_count * 300 + wpag_image_count * 150,
+            self.abstract_generator
+                .get_random_number_stream(&SWebPageGeneratorColumn::SWpagCharCount)?,
+        );
+
+        Ok(SWebPageRow::new(
+            null_bit_map,
+            wpag_web_page_id,
+            wpag_create_date,
+            wpag_access_date,
+            wpag_autogen_flag,
+            wpag_url,
+            wpag_type,
+            wpag_char_count,
+            wpag_link_count,
+            wpag_image_count,
+            wpag_max_ad_count,
+        ))
+    }
+}
+
+impl RowGenerator for SWebPageRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        _row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_web_page_row(session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_web_page_ids_exist_in_base_data() {
+        let session = Session::get_default_session();
+        let web_page_ids: Vec<String> = (1..=60)
+            .map(|row| {
+                compute_scd_key(Table::WebPage, row)
+                    .unwrap()
+                    .get_business_key()
+                    .to_string()
+            })
+            .collect();
+
+        let mut generator = SWebPageRowGenerator::new();
+        for _ in 0..5 {
+            let row = generator.generate_s_web_page_row(&session).unwrap();
+            assert!(web_page_ids.contains(&row.get_wpag_web_page_id().to_string()));
+            generator.consume_remaining_seeds_for_row();
+        }
+    }
+}
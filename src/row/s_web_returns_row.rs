@@ -0,0 +1,115 @@
+use crate::row::TableRow;
+use crate::types::{Date, Decimal};
+
+/// Row structure for the S_WEB_RETURNS refresh table: a returned web order line (SWebReturnsRow)
+#[derive(Debug, Clone)]
+pub struct SWebReturnsRow {
+    wret_web_page_id: String,
+    wret_order_id: i64,
+    wret_line_number: i32,
+    wret_item_id: String,
+    wret_return_customer_id: String,
+    wret_refund_customer_id: String,
+    wret_return_date: i64,
+    wret_return_time: i32,
+    wret_return_qty: i32,
+    wret_return_amt: Decimal,
+    wret_return_tax: Decimal,
+    wret_return_fee: Decimal,
+    wret_return_ship_cost: Decimal,
+    wret_refunded_cash: Decimal,
+    wret_reversed_charge: Decimal,
+    wret_account_credit: Decimal,
+    wret_reason_id: String,
+}
+
+impl SWebReturnsRow {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        wret_web_page_id: String,
+        wret_order_id: i64,
+        wret_line_number: i32,
+        wret_item_id: String,
+        wret_return_customer_id: String,
+        wret_refund_customer_id: String,
+        wret_return_date: i64,
+        wret_return_time: i32,
+        wret_return_qty: i32,
+        wret_return_amt: Decimal,
+        wret_return_tax: Decimal,
+        wret_return_fee: Decimal,
+        wret_return_ship_cost: Decimal,
+        wret_refunded_cash: Decimal,
+        wret_reversed_charge: Decimal,
+        wret_account_credit: Decimal,
+        wret_reason_id: String,
+    ) -> Self {
+        Self {
+            wret_web_page_id,
+            wret_order_id,
+            wret_line_number,
+            wret_item_id,
+            wret_return_customer_id,
+            wret_refund_customer_id,
+            wret_return_date,
+            wret_return_time,
+            wret_return_qty,
+            wret_return_amt,
+            wret_return_tax,
+            wret_return_fee,
+            wret_return_ship_cost,
+            wret_refunded_cash,
+            wret_reversed_charge,
+            wret_account_credit,
+            wret_reason_id,
+        }
+    }
+
+    pub fn get_wret_order_id(&self) -> i64 {
+        self.wret_order_id
+    }
+
+    pub fn get_wret_line_number(&self) -> i32 {
+        self.wret_line_number
+    }
+
+    pub fn get_wret_item_id(&self) -> &str {
+        &self.wret_item_id
+    }
+
+    pub fn get_wret_return_date(&self) -> i64 {
+        self.wret_return_date
+    }
+
+    pub fn get_wret_return_qty(&self) -> i32 {
+        self.wret_return_qty
+    }
+
+    pub fn get_wret_return_amt(&self) -> Decimal {
+        self.wret_return_amt
+    }
+}
+
+impl TableRow for SWebReturnsRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.wret_web_page_id.clone(),
+            self.wret_order_id.to_string(),
+            self.wret_line_number.to_string(),
+            self.wret_item_id.clone(),
+            self.wret_return_customer_id.clone(),
+            self.wret_refund_customer_id.clone(),
+            Date::julian_to_date_string(self.wret_return_date),
+            self.wret_return_time.to_string(),
+            self.wret_return_qty.to_string(),
+            self.wret_return_amt.to_string(),
+            self.wret_return_tax.to_string(),
+            self.wret_return_fee.to_string(),
+            self.wret_return_ship_cost.to_string(),
+            self.wret_refunded_cash.to_string(),
+            self.wret_reversed_charge.to_string(),
+            self.wret_account_credit.to_string(),
+            self.wret_reason_id.clone(),
+        ]
+    }
+}
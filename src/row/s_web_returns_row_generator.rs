@@ -0,0 +1,185 @@
+use crate::config::Session;
+use crate::error::Result;
+use crate::generator::SWebReturnsGeneratorColumn;
+use crate::random::RandomValueGenerator;
+use crate::row::{
+    AbstractRowGenerator, RowGenerator, RowGeneratorResult, SWebOrderLineitemRowGenerator,
+    SWebReturnsRow,
+};
+use crate::table::Table;
+use crate::update::{self, ReturnAmounts, UpdateDates};
+use crate::TpcdsError;
+
+/// Items are returned within three months of their delivery
+const MAX_DAYS_TO_RETURN: i32 = 90;
+
+/// Row generator for the S_WEB_RETURNS refresh table: a line of a different web order of
+/// the refresh for each row, returned after it shipped.
+/// Approximate: the returned lines and the return amounts do not follow dsdgen's sales pricing,
+/// so the output is not TPC-DS compliant.
+pub struct SWebReturnsRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+    /// Update dates of the refresh being generated
+    update_dates: Option<(i32, UpdateDates)>,
+    /// Orders returned in the refresh being generated
+    returned_orders: Option<(i32, Vec<i64>)>,
+}
+
+impl Default for SWebReturnsRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SWebReturnsRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SWebReturns),
+            update_dates: None,
+            returned_orders: None,
+        }
+    }
+
+    fn generate_s_web_returns_row(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+    ) -> Result<SWebReturnsRow> {
+        let scaling = session.get_scaling();
+        let update = session.get_update();
+        let returned_orders = match &mut self.returned_orders {
+            Some((cached, orders)) if *cached == update => orders,
+            cache => {
+                let orders = update::get_returned_orders(
+                    Table::SWebReturns,
+                    Table::SWebOrder,
+                    &SWebReturnsGeneratorColumn::WretOrderId,
+                    scaling,
+                    update,
+                )?;
+                &mut cache.insert((update, orders)).1
+            }
+        };
+        let index = row_number - 1 - update::get_row_offset(Table::SWebReturns, scaling, update)?;
+        let wret_order_id = *returned_orders.get(index as usize).ok_or_else(|| {
+            TpcdsError::new(&format!(
+                "Row {} of {} is not part of refresh {}",
+                row_number,
+                Table::SWebReturns,
+                update
+            ))
+        })?;
+
+        let wret_web_page_id = update::pick_business_key(
+            crate::config::Table::WebPage,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebReturnsGeneratorColumn::WretWebPageId)?,
+        )?;
+        let line_count = SWebOrderLineitemRowGenerator::get_line_count(wret_order_id, scaling)?;
+        let wret_line_number = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            line_count,
+            self.abstract_generator
+                .get_random_number_stream(&SWebReturnsGeneratorColumn::WretLineNumber)?,
+        );
+        let sold_line =
+            SWebOrderLineitemRowGenerator::get_sold_line(wret_order_id, wret_line_number, scaling)?;
+
+        let wret_return_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebReturnsGeneratorColumn::WretReturnCustomerId)?,
+        )?;
+        let wret_refund_customer_id = update::pick_business_key(
+            crate::config::Table::Customer,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebReturnsGeneratorColumn::WretRefundCustomerId)?,
+        )?;
+
+        let update_dates = match &mut self.update_dates {
+            Some((cached, update_dates)) if *cached == update => update_dates,
+            cache => &mut cache.insert((update, UpdateDates::new(update)?)).1,
+        };
+        let ship_date = SWebOrderLineitemRowGenerator::get_ship_date(
+            wret_order_id,
+            wret_line_number,
+            update_dates,
+        )?;
+        let wret_return_date = ship_date
+            + RandomValueGenerator::generate_uniform_random_int(
+                1,
+                MAX_DAYS_TO_RETURN,
+                self.abstract_generator
+                    .get_random_number_stream(&SWebReturnsGeneratorColumn::WretReturnDate)?,
+            ) as i64;
+        let wret_return_time = RandomValueGenerator::generate_uniform_random_int(
+            0,
+            86399,
+            self.abstract_generator
+                .get_random_number_stream(&SWebReturnsGeneratorColumn::WretReturnTime)?,
+        );
+
+        let wret_return_qty = RandomValueGenerator::generate_uniform_random_int(
+            1,
+            sold_line.get_quantity(),
+            self.abstract_generator
+                .get_random_number_stream(&SWebReturnsGeneratorColumn::WretReturnQty)?,
+        );
+        let amounts = ReturnAmounts::new(
+            sold_line.get_sales_price(),
+            wret_return_qty,
+            self.abstract_generator
+                .get_random_number_stream(&SWebReturnsGeneratorColumn::WretAmounts)?,
+        )?;
+        let wret_reason_id = update::pick_business_key(
+            crate::config::Table::Reason,
+            scaling,
+            self.abstract_generator
+                .get_random_number_stream(&SWebReturnsGeneratorColumn::WretReasonId)?,
+        )?;
+
+        Ok(SWebReturnsRow::new(
+            wret_web_page_id,
+            wret_order_id,
+            wret_line_number,
+            sold_line.get_item_id().to_string(),
+            wret_return_customer_id,
+            wret_refund_customer_id,
+            wret_return_date,
+            wret_return_time,
+            wret_return_qty,
+            amounts.get_return_amount(),
+            amounts.get_return_tax(),
+            amounts.get_fee(),
+            amounts.get_ship_cost(),
+            amounts.get_refunded_cash(),
+            amounts.get_reversed_charge(),
+            amounts.get_credit(),
+            wret_reason_id,
+        ))
+    }
+}
+
+impl RowGenerator for SWebReturnsRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_web_returns_row(row_number, session)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
@@ -0,0 +1,49 @@
+use crate::row::TableRow;
+
+/// Row structure for the S_ZIP_TO_GMT refresh table (SZipToGmtRow)
+#[derive(Debug, Clone)]
+pub struct SZipToGmtRow {
+    null_bit_map: i64,
+    zipg_zip: String,
+    zipg_gmt_offset: i32,
+}
+
+impl SZipToGmtRow {
+    pub fn new(null_bit_map: i64, zipg_zip: String, zipg_gmt_offset: i32) -> Self {
+        Self {
+            null_bit_map,
+            zipg_zip,
+            zipg_gmt_offset,
+        }
+    }
+
+    pub fn get_zipg_zip(&self) -> &str {
+        &self.zipg_zip
+    }
+
+    pub fn get_zipg_gmt_offset(&self) -> i32 {
+        self.zipg_gmt_offset
+    }
+
+    /// Check if a column should be null based on the null bitmap (TableRowWithNulls logic)
+    fn should_be_null(&self, column_position: i32) -> bool {
+        ((self.null_bit_map >> column_position) & 1) == 1
+    }
+
+    fn get_string_or_null<T: ToString>(&self, value: T, column_position: i32) -> String {
+        if self.should_be_null(column_position) {
+            String::new()
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+impl TableRow for SZipToGmtRow {
+    fn get_values(&self) -> Vec<String> {
+        vec![
+            self.get_string_or_null(&self.zipg_zip, 0),
+            self.get_string_or_null(self.zipg_gmt_offset, 1),
+        ]
+    }
+}
@@ -0,0 +1,102 @@
+use crate::config::Session;
+use crate::distribution::{FipsCountyDistribution, FipsWeights};
+use crate::error::Result;
+use crate::generator::SZipToGmtGeneratorColumn;
+use crate::nulls::create_null_bit_map;
+use crate::row::{AbstractRowGenerator, RowGenerator, RowGeneratorResult, SZipToGmtRow};
+use crate::table::Table;
+
+const MAX_ZIP: i64 = 99999;
+
+/// Row generator for the S_ZIP_TO_GMT refresh table. Rows walk through the zip codes in order and
+/// give each the GMT offset of a county in the same zip code region.
+/// Approximate: dsdgen does not pick the county this way, so the output is not TPC-DS
+/// compliant.
+pub struct SZipToGmtRowGenerator {
+    abstract_generator: AbstractRowGenerator,
+}
+
+impl Default for SZipToGmtRowGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SZipToGmtRowGenerator {
+    pub fn new() -> Self {
+        Self {
+            abstract_generator: AbstractRowGenerator::new(Table::SZipToGmt),
+        }
+    }
+
+    fn generate_s_zip_to_gmt_row(&mut self, row_number: i64) -> Result<SZipToGmtRow> {
+        let null_bit_map = create_null_bit_map(
+            Table::SZipToGmt,
+            self.abstract_generator
+                .get_random_number_stream(&SZipToGmtGeneratorColumn::SZipgNulls)?,
+        );
+
+        let zip = (row_number - 1) % MAX_ZIP + 1;
+
+        // Addresses use the county's zip prefix as the first digit of their zip code, so start
+        // at a random county and take the first one whose prefix matches
+        let county_count = FipsCountyDistribution::get_size()?;
+        let start = FipsCountyDistribution::pick_random_index(
+            FipsWeights::Uniform,
+            self.abstract_generator
+                .get_random_number_stream(&SZipToGmtGeneratorColumn::SZipgGmtOffset)?,
+        )?;
+        let mut county = start;
+        for offset in 0..county_count {
+            let index = (start + offset) % county_count;
+            if FipsCountyDistribution::get_zip_prefix_at_index(index)? as i64 == zip / 10000 {
+                county = index;
+                break;
+            }
+        }
+
+        Ok(SZipToGmtRow::new(
+            null_bit_map,
+            format!("{:05}", zip),
+            FipsCountyDistribution::get_gmt_offset_at_index(county)?,
+        ))
+    }
+}
+
+impl RowGenerator for SZipToGmtRowGenerator {
+    fn generate_row_and_child_rows(
+        &mut self,
+        row_number: i64,
+        _session: &Session,
+        _parent_row_generator: Option<&mut dyn RowGenerator>,
+        _child_row_generator: Option<&mut dyn RowGenerator>,
+    ) -> Result<RowGeneratorResult> {
+        let row = self.generate_s_zip_to_gmt_row(row_number)?;
+        Ok(RowGeneratorResult::new(Box::new(row)))
+    }
+
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
+    }
+
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_codes_wrap_around() {
+        let mut generator = SZipToGmtRowGenerator::new();
+        let first = generator.generate_s_zip_to_gmt_row(1).unwrap();
+        assert_eq!(first.get_zipg_zip(), "00001");
+        assert!((-10..=-5).contains(&first.get_zipg_gmt_offset()));
+        generator.consume_remaining_seeds_for_row();
+
+        let wrapped = generator.generate_s_zip_to_gmt_row(MAX_ZIP + 2).unwrap();
+        assert_eq!(wrapped.get_zipg_zip(), "00002");
+    }
+}
@@ -35,7 +35,7 @@ impl ShipModeRowGenerator {
         // Create null bit map (createNullBitMap call)
         let nulls_stream = self
             .abstract_generator
-            .get_random_number_stream(&ShipModeGeneratorColumn::SmNulls);
+            .get_random_number_stream(&ShipModeGeneratorColumn::SmNulls)?;
         let threshold = RandomValueGenerator::generate_uniform_random_int(0, 9999, nulls_stream);
         let bit_map = RandomValueGenerator::generate_uniform_random_int(1, i32::MAX, nulls_stream);
 
@@ -52,7 +52,7 @@ impl ShipModeRowGenerator {
         let sm_type = ShipModeDistributions::get_ship_mode_type_for_index_mod_size(row_number)?;
 
         // Calculate index for code (divide by type distribution size)
-        let type_distribution_size = ShipModeDistributions::get_ship_mode_type_size() as i64;
+        let type_distribution_size = ShipModeDistributions::get_ship_mode_type_size()? as i64;
         let index = row_number / type_distribution_size;
 
         let sm_code = ShipModeDistributions::get_ship_mode_code_for_index_mod_size(index)?;
@@ -62,7 +62,7 @@ impl ShipModeRowGenerator {
 
         let contract_stream = self
             .abstract_generator
-            .get_random_number_stream(&ShipModeGeneratorColumn::SmContract);
+            .get_random_number_stream(&ShipModeGeneratorColumn::SmContract)?;
         let sm_contract = RandomValueGenerator::generate_random_charset(
             RandomValueGenerator::ALPHA_NUMERIC,
             1,
@@ -94,12 +94,11 @@ impl RowGenerator for ShipModeRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row)))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.abstract_generator.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.abstract_generator
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
     }
 }
@@ -47,7 +47,7 @@ impl RowGenerator for TimeDimRowGenerator {
         let t_hour = (time_temp % 24) as i32;
 
         // Get hour information for shift and meal time
-        let hour_info = HoursDistribution::get_hour_info_for_hour(t_hour);
+        let hour_info = HoursDistribution::get_hour_info_for_hour(t_hour)?;
         let t_am_pm = hour_info.get_am_pm().to_string();
         let t_shift = hour_info.get_shift().to_string();
         let t_sub_shift = hour_info.get_sub_shift().to_string();
@@ -71,12 +71,11 @@ impl RowGenerator for TimeDimRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row) as Box<dyn TableRow>))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.base.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.base
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.base
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.base
     }
 }
@@ -35,7 +35,7 @@ impl WarehouseRowGenerator {
         // Create null bit map (createNullBitMap call)
         let nulls_stream = self
             .abstract_generator
-            .get_random_number_stream(&WarehouseGeneratorColumn::WNulls);
+            .get_random_number_stream(&WarehouseGeneratorColumn::WNulls)?;
         let threshold = RandomValueGenerator::generate_uniform_random_int(0, 9999, nulls_stream);
         let bit_map = RandomValueGenerator::generate_uniform_random_int(1, i32::MAX, nulls_stream);
 
@@ -51,19 +51,19 @@ impl WarehouseRowGenerator {
 
         let name_stream = self
             .abstract_generator
-            .get_random_number_stream(&WarehouseGeneratorColumn::WWarehouseName);
-        let w_warehouse_name = RandomValueGenerator::generate_random_text(10, 20, name_stream);
+            .get_random_number_stream(&WarehouseGeneratorColumn::WWarehouseName)?;
+        let w_warehouse_name = RandomValueGenerator::generate_random_text(10, 20, name_stream)?;
 
         let sq_ft_stream = self
             .abstract_generator
-            .get_random_number_stream(&WarehouseGeneratorColumn::WWarehouseSqFt);
+            .get_random_number_stream(&WarehouseGeneratorColumn::WWarehouseSqFt)?;
         let w_warehouse_sq_ft =
             RandomValueGenerator::generate_uniform_random_int(50000, 1000000, sq_ft_stream);
 
         let scaling = session.get_scaling();
         let address_stream = self
             .abstract_generator
-            .get_random_number_stream(&WarehouseGeneratorColumn::WWarehouseAddress);
+            .get_random_number_stream(&WarehouseGeneratorColumn::WWarehouseAddress)?;
         let w_address =
             Address::make_address_for_column(Table::Warehouse, address_stream, scaling)?;
 
@@ -90,12 +90,11 @@ impl RowGenerator for WarehouseRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row)))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.abstract_generator.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.abstract_generator
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
     }
 }
@@ -25,7 +25,7 @@ impl Default for WebPageRowGenerator {
 }
 
 impl WebPageRowGenerator {
-    const WP_AUTOGEN_PERCENT: i32 = 30;
+    pub(crate) const WP_AUTOGEN_PERCENT: i32 = 30;
 
     /// Create a new WebPageRowGenerator
     pub fn new() -> Self {
@@ -40,7 +40,7 @@ impl WebPageRowGenerator {
         // Create null bit map
         let nulls_stream = self
             .abstract_generator
-            .get_random_number_stream(&WebPageGeneratorColumn::WpNulls);
+            .get_random_number_stream(&WebPageGeneratorColumn::WpNulls)?;
         let threshold = RandomValueGenerator::generate_uniform_random_int(0, 9999, nulls_stream);
         let bit_map = RandomValueGenerator::generate_uniform_random_int(1, i32::MAX, nulls_stream);
 
@@ -53,7 +53,7 @@ impl WebPageRowGenerator {
         let wp_page_sk = row_number;
 
         // Compute SCD key information
-        let scd_key = compute_scd_key(Table::WebPage, row_number);
+        let scd_key = compute_scd_key(Table::WebPage, row_number)?;
         let wp_page_id = scd_key.get_business_key().to_string();
         let wp_rec_start_date_id = scd_key.get_start_date();
         let wp_rec_end_date_id = scd_key.get_end_date();
@@ -62,14 +62,14 @@ impl WebPageRowGenerator {
         // Get field change flags for SCD
         let mut field_change_flags = self
             .abstract_generator
-            .get_random_number_stream(&WebPageGeneratorColumn::WpScd)
+            .get_random_number_stream(&WebPageGeneratorColumn::WpScd)?
             .next_random() as i32;
 
         // wp_creation_date_sk - join to DATE_DIM
         let mut wp_creation_date_sk = generate_join_key(
             &WebPageGeneratorColumn::WpCreationDateSk,
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpCreationDateSk),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpCreationDateSk)?,
             ConfigTable::DateDim,
             row_number,
             session.get_scaling(),
@@ -89,7 +89,7 @@ impl WebPageRowGenerator {
             0,
             100,
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpAccessDateSk),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpAccessDateSk)?,
         );
         let mut wp_access_date_sk = Date::JULIAN_TODAYS_DATE as i64 - last_access as i64;
         if let Some(prev) = &self.previous_row {
@@ -107,7 +107,7 @@ impl WebPageRowGenerator {
             0,
             99,
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpAutogenFlag),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpAutogenFlag)?,
         );
         let mut wp_autogen_flag = random_int < Self::WP_AUTOGEN_PERCENT;
         if let Some(prev) = &self.previous_row {
@@ -124,7 +124,7 @@ impl WebPageRowGenerator {
         let mut wp_customer_sk = generate_join_key(
             &WebPageGeneratorColumn::WpCustomerSk,
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpCustomerSk),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpCustomerSk)?,
             ConfigTable::Customer,
             1,
             session.get_scaling(),
@@ -142,14 +142,14 @@ impl WebPageRowGenerator {
         // wp_url - always returns the same value, so no need to check if it should change
         let wp_url = RandomValueGenerator::generate_random_url(
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpUrl),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpUrl)?,
         );
         field_change_flags >>= 1;
 
         // wp_type - always uses a new value due to a bug in the C code
         let wp_type = WebPageUseDistribution::pick_random_web_page_use_type(
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpType),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpType)?,
         )?;
         field_change_flags >>= 1;
 
@@ -158,7 +158,7 @@ impl WebPageRowGenerator {
             2,
             25,
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpLinkCount),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpLinkCount)?,
         );
         if let Some(prev) = &self.previous_row {
             wp_link_count = get_value_for_slowly_changing_dimension(
@@ -175,7 +175,7 @@ impl WebPageRowGenerator {
             1,
             7,
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpImageCount),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpImageCount)?,
         );
         if let Some(prev) = &self.previous_row {
             wp_image_count = get_value_for_slowly_changing_dimension(
@@ -192,7 +192,7 @@ impl WebPageRowGenerator {
             0,
             4,
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpMaxAdCount),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpMaxAdCount)?,
         );
         if let Some(prev) = &self.previous_row {
             wp_max_ad_count = get_value_for_slowly_changing_dimension(
@@ -209,7 +209,7 @@ impl WebPageRowGenerator {
             wp_link_count * 125 + wp_image_count * 50,
             wp_link_count * 300 + wp_image_count * 150,
             self.abstract_generator
-                .get_random_number_stream(&WebPageGeneratorColumn::WpCharCount),
+                .get_random_number_stream(&WebPageGeneratorColumn::WpCharCount)?,
         );
         if let Some(prev) = &self.previous_row {
             wp_char_count = get_value_for_slowly_changing_dimension(
@@ -272,12 +272,11 @@ impl RowGenerator for WebPageRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row)))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.abstract_generator.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.abstract_generator
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
     }
 }
@@ -58,13 +58,13 @@ impl RowGenerator for WebSiteRowGenerator {
         let null_bit_map = create_null_bit_map(
             Table::WebSite,
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebNulls),
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebNulls)?,
         );
 
         let web_site_sk = row_number;
         let web_class = "Unknown".to_string();
 
-        let scd_key = compute_scd_key(Table::WebSite, row_number);
+        let scd_key = compute_scd_key(Table::WebSite, row_number)?;
         let web_site_id = scd_key.get_business_key().to_string();
         let web_rec_start_date_id = scd_key.get_start_date();
         let web_rec_end_date_id = scd_key.get_end_date();
@@ -75,7 +75,7 @@ impl RowGenerator for WebSiteRowGenerator {
             let open_date = generate_join_key(
                 &WebSiteGeneratorColumn::WebOpenDate,
                 self.abstract_generator
-                    .get_random_number_stream(&WebSiteGeneratorColumn::WebOpenDate),
+                    .get_random_number_stream(&WebSiteGeneratorColumn::WebOpenDate)?,
                 ConfigTable::DateDim,
                 row_number,
                 scaling,
@@ -84,7 +84,7 @@ impl RowGenerator for WebSiteRowGenerator {
             let close_date = generate_join_key(
                 &WebSiteGeneratorColumn::WebCloseDate,
                 self.abstract_generator
-                    .get_random_number_stream(&WebSiteGeneratorColumn::WebCloseDate),
+                    .get_random_number_stream(&WebSiteGeneratorColumn::WebCloseDate)?,
                 ConfigTable::DateDim,
                 row_number,
                 scaling,
@@ -100,7 +100,9 @@ impl RowGenerator for WebSiteRowGenerator {
 
             (open_date, close_date, name)
         } else {
-            let prev = self.previous_row.as_ref().unwrap();
+            let prev = self.previous_row.as_ref().ok_or_else(|| {
+                crate::TpcdsError::invalid_state("previousRow has not yet been initialized")
+            })?;
             (
                 prev.web_open_date(),
                 prev.web_close_date(),
@@ -111,7 +113,7 @@ impl RowGenerator for WebSiteRowGenerator {
         // Field change flags control whether a field changes from one row to the next
         let mut field_change_flags = self
             .abstract_generator
-            .get_random_number_stream(&WebSiteGeneratorColumn::WebScd)
+            .get_random_number_stream(&WebSiteGeneratorColumn::WebScd)?
             .next_random() as i32;
 
         // Generate web_manager
@@ -122,11 +124,11 @@ impl RowGenerator for WebSiteRowGenerator {
                 FirstNamesWeights::GeneralFrequency
             },
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebManager),
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebManager)?,
         )?;
         let last_name = NamesDistributions::pick_random_last_name(
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebManager),
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebManager)?,
         )?;
         let mut web_manager = format!("{} {}", first_name, last_name);
         if let Some(ref prev) = self.previous_row {
@@ -144,7 +146,7 @@ impl RowGenerator for WebSiteRowGenerator {
             1,
             6,
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketId),
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketId)?,
         );
         if let Some(ref prev) = self.previous_row {
             web_market_id = get_value_for_slowly_changing_dimension(
@@ -161,8 +163,8 @@ impl RowGenerator for WebSiteRowGenerator {
             20,
             50,
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketClass),
-        );
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketClass)?,
+        )?;
         if let Some(ref prev) = self.previous_row {
             web_market_class = get_value_for_slowly_changing_dimension(
                 field_change_flags,
@@ -178,8 +180,8 @@ impl RowGenerator for WebSiteRowGenerator {
             20,
             100,
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketDesc),
-        );
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketDesc)?,
+        )?;
         if let Some(ref prev) = self.previous_row {
             web_market_desc = get_value_for_slowly_changing_dimension(
                 field_change_flags,
@@ -198,11 +200,11 @@ impl RowGenerator for WebSiteRowGenerator {
                 FirstNamesWeights::GeneralFrequency
             },
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketManager),
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketManager)?,
         )?;
         let last_name = NamesDistributions::pick_random_last_name(
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketManager),
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebMarketManager)?,
         )?;
         let mut web_market_manager = format!("{} {}", first_name, last_name);
         if let Some(ref prev) = self.previous_row {
@@ -220,7 +222,7 @@ impl RowGenerator for WebSiteRowGenerator {
             1,
             6,
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebCompanyId),
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebCompanyId)?,
         );
         if let Some(ref prev) = self.previous_row {
             web_company_id = get_value_for_slowly_changing_dimension(
@@ -237,8 +239,8 @@ impl RowGenerator for WebSiteRowGenerator {
             web_company_id,
             100,
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebCompanyName),
-        );
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebCompanyName)?,
+        )?;
         if let Some(ref prev) = self.previous_row {
             web_company_name = get_value_for_slowly_changing_dimension(
                 field_change_flags,
@@ -253,7 +255,7 @@ impl RowGenerator for WebSiteRowGenerator {
         let mut web_address = Address::make_address_for_column(
             Table::WebSite,
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebAddress),
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebAddress)?,
             scaling,
         )?;
 
@@ -322,8 +324,8 @@ impl RowGenerator for WebSiteRowGenerator {
             Decimal::ZERO,
             Decimal::new(12, 2)?,
             self.abstract_generator
-                .get_random_number_stream(&WebSiteGeneratorColumn::WebTaxPercentage),
-        );
+                .get_random_number_stream(&WebSiteGeneratorColumn::WebTaxPercentage)?,
+        )?;
         if let Some(ref prev) = self.previous_row {
             web_tax_percentage = get_value_for_slowly_changing_dimension(
                 field_change_flags,
@@ -358,17 +360,17 @@ impl RowGenerator for WebSiteRowGenerator {
         Ok(RowGeneratorResult::new(Box::new(row)))
     }
 
-    fn consume_remaining_seeds_for_row(&mut self) {
-        self.abstract_generator.consume_remaining_seeds_for_row();
+    fn get_abstract_generator(&self) -> &AbstractRowGenerator {
+        &self.abstract_generator
     }
 
-    fn skip_rows_until_starting_row_number(&mut self, starting_row_number: i64) {
-        self.abstract_generator
-            .skip_rows_until_starting_row_number(starting_row_number);
+    fn get_abstract_generator_mut(&mut self) -> &mut AbstractRowGenerator {
+        &mut self.abstract_generator
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
     use super::*;
 
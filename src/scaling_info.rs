@@ -157,6 +157,7 @@ impl ScalingInfo {
 }
 
 #[cfg(test)]
+#[allow(clippy::manual_range_contains)]
 mod tests {
     use super::*;
 
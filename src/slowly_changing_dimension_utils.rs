@@ -1,4 +1,6 @@
 use crate::business_key_generator::make_business_key;
+use crate::check_argument;
+use crate::error::{Result, TpcdsError};
 use crate::table::Table;
 use crate::types::Date;
 
@@ -48,7 +50,7 @@ impl SlowlyChangingDimensionKey {
     }
 }
 
-pub fn compute_scd_key(table: Table, row_number: i64) -> SlowlyChangingDimensionKey {
+pub fn compute_scd_key(table: Table, row_number: i64) -> Result<SlowlyChangingDimensionKey> {
     let modulo = (row_number % 6) as i32;
     let table_number = table.get_ordinal(); // Use Java ordinal, not Rust enum discriminant
 
@@ -95,16 +97,24 @@ pub fn compute_scd_key(table: Table, row_number: i64) -> SlowlyChangingDimension
             let end_date = -1;
             (business_key, start_date, end_date, false)
         }
-        _ => panic!(
-            "Something's wrong. Positive integers % 6 should always be covered by one of the cases"
-        ),
+        _ => {
+            return Err(TpcdsError::new(&format!(
+                "Row number must be positive, but was {}",
+                row_number
+            )))
+        }
     };
 
     if end_date > Date::JULIAN_DATA_END_DATE {
         end_date = -1;
     }
 
-    SlowlyChangingDimensionKey::new(business_key, start_date, end_date, is_new_key)
+    Ok(SlowlyChangingDimensionKey::new(
+        business_key,
+        start_date,
+        end_date,
+        is_new_key,
+    ))
 }
 
 pub fn get_value_for_slowly_changing_dimension<T>(
@@ -136,13 +146,15 @@ pub fn should_change_dimension(flags: i32, is_new_key: bool) -> bool {
 /// * `scaling` - Scaling information for the table
 ///
 /// # Returns
-/// The surrogate key (row number) that matches the business key at the given date
+/// The surrogate key (row number) that matches the business key at the given date, or an error
+/// if `unique` is not positive
 pub fn match_surrogate_key(
     unique: i64,
     julian_date: i64,
     table: crate::config::Table,
     scaling: &crate::config::Scaling,
-) -> i64 {
+) -> Result<i64> {
+    check_argument!(unique >= 1, &format!("unique must be >= 1, got {}", unique));
     let mut surrogate_key = (unique / 3) * 6;
 
     match unique % 3 {
@@ -157,7 +169,7 @@ pub fn match_surrogate_key(
                 surrogate_key += 1;
             }
         }
-        0 => {
+        _ => {
             // Three revisions of this ID
             surrogate_key -= 2;
             if julian_date > ONE_THIRD_DATE {
@@ -167,7 +179,6 @@ pub fn match_surrogate_key(
                 surrogate_key += 1;
             }
         }
-        _ => panic!("unique % 3 did not equal 0, 1, or 2"),
     }
 
     let row_count = scaling.get_row_count(table);
@@ -175,7 +186,7 @@ pub fn match_surrogate_key(
         surrogate_key = row_count;
     }
 
-    surrogate_key
+    Ok(surrogate_key)
 }
 
 #[cfg(test)]
@@ -200,7 +211,8 @@ mod tests {
             Date::JULIAN_DATA_START_DATE,
             crate::config::Table::Item,
             &scaling,
-        );
+        )
+        .unwrap();
         assert_eq!(surrogate, 1); // (1/3)*6 + 1 = 0 + 1 = 1
     }
 
@@ -214,12 +226,14 @@ mod tests {
             Date::JULIAN_DATA_START_DATE,
             crate::config::Table::Item,
             &scaling,
-        );
+        )
+        .unwrap();
         assert_eq!(surrogate, 2); // (2/3)*6 + 2 = 0 + 2 = 2
 
         // After half date: surrogate_key = (unique/3)*6 + 2 + 1
         let surrogate =
-            match_surrogate_key(2, ONE_HALF_DATE + 1, crate::config::Table::Item, &scaling);
+            match_surrogate_key(2, ONE_HALF_DATE + 1, crate::config::Table::Item, &scaling)
+                .unwrap();
         assert_eq!(surrogate, 3); // (2/3)*6 + 2 + 1 = 0 + 3 = 3
     }
 
@@ -233,17 +247,20 @@ mod tests {
             Date::JULIAN_DATA_START_DATE,
             crate::config::Table::Item,
             &scaling,
-        );
+        )
+        .unwrap();
         assert_eq!(surrogate, 4); // (3/3)*6 - 2 = 6 - 2 = 4
 
         // Between one-third and two-thirds: (unique/3)*6 - 2 + 1
         let surrogate =
-            match_surrogate_key(3, ONE_THIRD_DATE + 1, crate::config::Table::Item, &scaling);
+            match_surrogate_key(3, ONE_THIRD_DATE + 1, crate::config::Table::Item, &scaling)
+                .unwrap();
         assert_eq!(surrogate, 5); // (3/3)*6 - 2 + 1 = 5
 
         // After two-thirds: (unique/3)*6 - 2 + 1 + 1
         let surrogate =
-            match_surrogate_key(3, TWO_THIRDS_DATE + 1, crate::config::Table::Item, &scaling);
+            match_surrogate_key(3, TWO_THIRDS_DATE + 1, crate::config::Table::Item, &scaling)
+                .unwrap();
         assert_eq!(surrogate, 6); // (3/3)*6 - 2 + 2 = 6
     }
 
@@ -258,7 +275,24 @@ mod tests {
             Date::JULIAN_DATA_START_DATE,
             crate::config::Table::Item,
             &scaling,
-        );
+        )
+        .unwrap();
         assert_eq!(surrogate, row_count);
     }
+
+    #[test]
+    fn test_match_surrogate_key_rejects_non_positive_unique() {
+        let scaling = Scaling::new(1.0);
+        for unique in [0, -1, -2] {
+            assert!(matches!(
+                match_surrogate_key(
+                    unique,
+                    Date::JULIAN_DATA_START_DATE,
+                    crate::config::Table::Item,
+                    &scaling,
+                ),
+                Err(crate::TpcdsError::InvalidArgument(_))
+            ));
+        }
+    }
 }
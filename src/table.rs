@@ -1,13 +1,36 @@
 use crate::column::{
-    CallCenterColumn, Column, HouseholdDemographicsColumn, PromotionColumn, WebSiteColumn,
+    CallCenterColumn, Column, CustomerDemographicsColumn, DateDimColumn, DeleteColumn,
+    HouseholdDemographicsColumn, IncomeBandColumn, InventoryDeleteColumn, PromotionColumn,
+    ReasonColumn, SCallCenterColumn, SCatalogOrderColumn, SCatalogOrderLineitemColumn,
+    SCatalogReturnsColumn, SInventoryColumn, SItemColumn, SPromotionColumn, SPurchaseColumn,
+    SPurchaseLineitemColumn, SStoreReturnsColumn, SWarehouseColumn, SWebOrderColumn,
+    SWebOrderLineitemColumn, SWebPageColumn, SWebReturnsColumn, SZipToGmtColumn, ShipModeColumn,
+    TimeDimColumn, WarehouseColumn, WebPageColumn, WebSiteColumn,
 };
 use crate::error::Result;
 use crate::generator::{
     CallCenterGeneratorColumn, CustomerDemographicsGeneratorColumn, DateDimGeneratorColumn,
-    GeneratorColumn, HouseholdDemographicsGeneratorColumn, IncomeBandGeneratorColumn,
-    PromotionGeneratorColumn, ReasonGeneratorColumn, ShipModeGeneratorColumn,
-    TimeDimGeneratorColumn, WarehouseGeneratorColumn, WebPageGeneratorColumn,
-    WebSiteGeneratorColumn,
+    DeleteGeneratorColumn, GeneratorColumn, HouseholdDemographicsGeneratorColumn,
+    IncomeBandGeneratorColumn, InventoryDeleteGeneratorColumn, PromotionGeneratorColumn,
+    ReasonGeneratorColumn, SCallCenterGeneratorColumn, SCatalogOrderGeneratorColumn,
+    SCatalogOrderLineitemGeneratorColumn, SCatalogReturnsGeneratorColumn,
+    SInventoryGeneratorColumn, SItemGeneratorColumn, SPromotionGeneratorColumn,
+    SPurchaseGeneratorColumn, SPurchaseLineitemGeneratorColumn, SStoreReturnsGeneratorColumn,
+    SWarehouseGeneratorColumn, SWebOrderGeneratorColumn, SWebOrderLineitemGeneratorColumn,
+    SWebPageGeneratorColumn, SWebReturnsGeneratorColumn, SZipToGmtGeneratorColumn,
+    ShipModeGeneratorColumn, TimeDimGeneratorColumn, WarehouseGeneratorColumn,
+    WebPageGeneratorColumn, WebSiteGeneratorColumn,
+};
+use crate::row::{
+    CallCenterRowGenerator, CustomerDemographicsRowGenerator, DateDimRowGenerator,
+    DeleteRowGenerator, HouseholdDemographicsRowGenerator, IncomeBandRowGenerator,
+    PromotionRowGenerator, ReasonRowGenerator, RowGenerator, SCallCenterRowGenerator,
+    SCatalogOrderLineitemRowGenerator, SCatalogOrderRowGenerator, SCatalogReturnsRowGenerator,
+    SInventoryRowGenerator, SItemRowGenerator, SPromotionRowGenerator,
+    SPurchaseLineitemRowGenerator, SPurchaseRowGenerator, SStoreReturnsRowGenerator,
+    SWarehouseRowGenerator, SWebOrderLineitemRowGenerator, SWebOrderRowGenerator,
+    SWebPageRowGenerator, SWebReturnsRowGenerator, SZipToGmtRowGenerator, ShipModeRowGenerator,
+    TimeDimRowGenerator, WarehouseRowGenerator, WebPageRowGenerator, WebSiteRowGenerator,
 };
 use crate::scaling_info::{ScalingInfo, ScalingModel};
 use crate::table_flags::{TableFlags, TableFlagsBuilder};
@@ -29,6 +52,25 @@ pub enum Table {
     Promotion,
     WebPage,
     WebSite,
+    // Refresh (data maintenance) tables
+    SCallCenter,
+    SCatalogOrder,
+    SCatalogOrderLineitem,
+    SCatalogReturns,
+    SInventory,
+    SItem,
+    SPromotion,
+    SPurchase,
+    SPurchaseLineitem,
+    SStoreReturns,
+    SWarehouse,
+    SWebOrder,
+    SWebOrderLineitem,
+    SWebPage,
+    SWebReturns,
+    SZipToGmt,
+    Delete,
+    InventoryDelete,
     // TODO: Add other tables as they are implemented
 }
 
@@ -49,6 +91,24 @@ impl Table {
             Table::Promotion => "promotion",
             Table::WebPage => "web_page",
             Table::WebSite => "web_site",
+            Table::SCallCenter => "s_call_center",
+            Table::SCatalogOrder => "s_catalog_order",
+            Table::SCatalogOrderLineitem => "s_catalog_order_lineitem",
+            Table::SCatalogReturns => "s_catalog_returns",
+            Table::SPromotion => "s_promotion",
+            Table::SItem => "s_item",
+            Table::SInventory => "s_inventory",
+            Table::SPurchase => "s_purchase",
+            Table::SPurchaseLineitem => "s_purchase_lineitem",
+            Table::SWarehouse => "s_warehouse",
+            Table::SStoreReturns => "s_store_returns",
+            Table::SWebOrder => "s_web_order",
+            Table::SWebPage => "s_web_page",
+            Table::SWebOrderLineitem => "s_web_order_lineitem",
+            Table::SZipToGmt => "s_zip_to_gmt",
+            Table::SWebReturns => "s_web_returns",
+            Table::Delete => "delete",
+            Table::InventoryDelete => "inventory_delete",
         }
     }
 
@@ -83,6 +143,25 @@ impl Table {
             // WEB_SALES => 22,
             Table::WebSite => 23,
             // DBGEN_VERSION => 24,
+            // Source tables follow in dsdgen order
+            Table::SCallCenter => 27,
+            Table::SCatalogOrder => 29,
+            Table::SCatalogOrderLineitem => 30,
+            Table::SCatalogReturns => 33,
+            Table::SInventory => 39,
+            Table::SItem => 40,
+            Table::SPromotion => 45,
+            Table::SPurchase => 46,
+            Table::SPurchaseLineitem => 47,
+            Table::SStoreReturns => 51,
+            Table::SWarehouse => 54,
+            Table::SWebOrder => 55,
+            Table::SWebOrderLineitem => 56,
+            Table::SWebPage => 57,
+            Table::SWebReturns => 59,
+            Table::SZipToGmt => 61,
+            Table::Delete => 62,
+            Table::InventoryDelete => 63,
         }
     }
 
@@ -151,6 +230,78 @@ impl Table {
                         .build()
                 })
             }
+            Table::SCallCenter => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().set_is_small().build())
+            }
+            Table::SCatalogOrder => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SCatalogOrderLineitem => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SCatalogReturns => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SInventory => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SItem => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SPromotion => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SPurchase => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SPurchaseLineitem => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SStoreReturns => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SWarehouse => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SWebOrder => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SWebOrderLineitem => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SWebPage => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SWebReturns => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::SZipToGmt => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().build())
+            }
+            Table::Delete => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().set_is_small().build())
+            }
+            Table::InventoryDelete => {
+                static FLAGS: OnceLock<TableFlags> = OnceLock::new();
+                FLAGS.get_or_init(|| TableFlagsBuilder::new().set_is_small().build())
+            }
         }
     }
 
@@ -170,6 +321,24 @@ impl Table {
             Table::Promotion => 200,
             Table::WebPage => 250,
             Table::WebSite => 100,
+            Table::SCallCenter => 100,
+            Table::SCatalogOrder => 0,
+            Table::SCatalogOrderLineitem => 0,
+            Table::SCatalogReturns => 0,
+            Table::SInventory => 0,
+            Table::SItem => 50,
+            Table::SPromotion => 200,
+            Table::SPurchase => 0,
+            Table::SPurchaseLineitem => 0,
+            Table::SStoreReturns => 0,
+            Table::SWarehouse => 100,
+            Table::SWebOrder => 0,
+            Table::SWebOrderLineitem => 0,
+            Table::SWebPage => 250,
+            Table::SWebReturns => 0,
+            Table::SZipToGmt => 0,
+            Table::Delete => 0,
+            Table::InventoryDelete => 0,
         }
     }
 
@@ -189,6 +358,24 @@ impl Table {
             Table::Promotion => 0x3,
             Table::WebPage => 0xB,
             Table::WebSite => 0xB,
+            Table::SCallCenter => 0x1,
+            Table::SCatalogOrder => 0x1,
+            Table::SCatalogOrderLineitem => 0x7,
+            Table::SCatalogReturns => 0xe,
+            Table::SInventory => 0x7,
+            Table::SItem => 0x1,
+            Table::SPromotion => 0x1,
+            Table::SPurchase => 0x7,
+            Table::SPurchaseLineitem => 0x7,
+            Table::SStoreReturns => 0xf,
+            Table::SWarehouse => 0x1,
+            Table::SWebOrder => 0x1,
+            Table::SWebOrderLineitem => 0x7,
+            Table::SWebPage => 0x1,
+            Table::SWebReturns => 0xe,
+            Table::SZipToGmt => 0x1,
+            Table::Delete => 0x3,
+            Table::InventoryDelete => 0x3,
         }
     }
 
@@ -305,6 +492,165 @@ impl Table {
                         .expect("WebSite ScalingInfo creation should not fail")
                 })
             }
+            Table::SCallCenter => {
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0, 3, 12, 15, 18, 21, 24, 27, 30, 30];
+                    ScalingInfo::new(0, ScalingModel::Logarithmic, &row_counts, 50)
+                        .expect("SCallCenter ScalingInfo creation should not fail")
+                })
+            }
+            Table::SCatalogOrder => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SCatalogOrder ScalingInfo creation should not fail")
+                })
+            }
+            Table::SCatalogOrderLineitem => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SCatalogOrderLineitem ScalingInfo creation should not fail")
+                })
+            }
+            Table::SCatalogReturns => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SCatalogReturns ScalingInfo creation should not fail")
+                })
+            }
+            Table::SInventory => {
+                // One row per item and warehouse, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 100)
+                        .expect("SInventory ScalingInfo creation should not fail")
+                })
+            }
+            Table::SItem => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SItem ScalingInfo creation should not fail")
+                })
+            }
+            Table::SPromotion => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SPromotion ScalingInfo creation should not fail")
+                })
+            }
+            Table::SPurchase => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SPurchase ScalingInfo creation should not fail")
+                })
+            }
+            Table::SPurchaseLineitem => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SPurchaseLineitem ScalingInfo creation should not fail")
+                })
+            }
+            Table::SStoreReturns => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SStoreReturns ScalingInfo creation should not fail")
+                })
+            }
+            Table::SWarehouse => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 20)
+                        .expect("SWarehouse ScalingInfo creation should not fail")
+                })
+            }
+            Table::SWebOrder => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SWebOrder ScalingInfo creation should not fail")
+                })
+            }
+            Table::SWebOrderLineitem => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SWebOrderLineitem ScalingInfo creation should not fail")
+                })
+            }
+            Table::SWebPage => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 5)
+                        .expect("SWebPage ScalingInfo creation should not fail")
+                })
+            }
+            Table::SWebReturns => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SWebReturns ScalingInfo creation should not fail")
+                })
+            }
+            Table::SZipToGmt => {
+                // A percent of the source table, see update::get_row_count
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0; 10];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 1)
+                        .expect("SZipToGmt ScalingInfo creation should not fail")
+                })
+            }
+            Table::Delete => {
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0, 3, 3, 3, 3, 3, 3, 3, 3, 3];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 100)
+                        .expect("Delete ScalingInfo creation should not fail")
+                })
+            }
+            Table::InventoryDelete => {
+                static SCALING: OnceLock<ScalingInfo> = OnceLock::new();
+                SCALING.get_or_init(|| {
+                    let row_counts = [0, 3, 3, 3, 3, 3, 3, 3, 3, 3];
+                    ScalingInfo::new(0, ScalingModel::Static, &row_counts, 100)
+                        .expect("InventoryDelete ScalingInfo creation should not fail")
+                })
+            }
         }
     }
 
@@ -312,18 +658,36 @@ impl Table {
     pub fn get_column_count(&self) -> usize {
         match self {
             Table::CallCenter => CallCenterColumn::values().len(),
-            Table::Warehouse => 0, // TODO: Return WarehouseColumn::values().len() once WarehouseColumn is implemented
-            Table::ShipMode => 0, // TODO: Return ShipModeColumn::values().len() once ShipModeColumn is implemented
-            Table::Reason => 0, // TODO: Return ReasonColumn::values().len() once ReasonColumn is implemented
-            Table::IncomeBand => 0, // TODO: Return IncomeBandColumn::values().len() once IncomeBandColumn is implemented
+            Table::Warehouse => WarehouseColumn::values().len(),
+            Table::ShipMode => ShipModeColumn::values().len(),
+            Table::Reason => ReasonColumn::values().len(),
+            Table::IncomeBand => IncomeBandColumn::values().len(),
             Table::HouseholdDemographics => HouseholdDemographicsColumn::values().len(),
-            Table::CustomerDemographics => 0, // TODO: Return CustomerDemographicsColumn::values().len() once CustomerDemographicsColumn is implemented
-            Table::DateDim => 0, // TODO: Return DateDimColumn::values().len() once DateDimColumn is implemented
-            Table::TimeDim => 0, // TODO: Return TimeDimColumn::values().len() once TimeDimColumn is implemented
+            Table::CustomerDemographics => CustomerDemographicsColumn::values().len(),
+            Table::DateDim => DateDimColumn::values().len(),
+            Table::TimeDim => TimeDimColumn::values().len(),
             Table::Item => 0, // TODO: Return ItemColumn::values().len() once ItemColumn is implemented
             Table::Promotion => PromotionColumn::values().len(),
-            Table::WebPage => 0, // TODO: Return WebPageColumn::values().len() once WebPageColumn is implemented
+            Table::WebPage => WebPageColumn::values().len(),
             Table::WebSite => WebSiteColumn::values().len(),
+            Table::SCallCenter => SCallCenterColumn::values().len(),
+            Table::SCatalogOrder => SCatalogOrderColumn::values().len(),
+            Table::SCatalogOrderLineitem => SCatalogOrderLineitemColumn::values().len(),
+            Table::SCatalogReturns => SCatalogReturnsColumn::values().len(),
+            Table::SInventory => SInventoryColumn::values().len(),
+            Table::SItem => SItemColumn::values().len(),
+            Table::SPromotion => SPromotionColumn::values().len(),
+            Table::SPurchase => SPurchaseColumn::values().len(),
+            Table::SPurchaseLineitem => SPurchaseLineitemColumn::values().len(),
+            Table::SStoreReturns => SStoreReturnsColumn::values().len(),
+            Table::SWarehouse => SWarehouseColumn::values().len(),
+            Table::SWebOrder => SWebOrderColumn::values().len(),
+            Table::SWebOrderLineitem => SWebOrderLineitemColumn::values().len(),
+            Table::SWebPage => SWebPageColumn::values().len(),
+            Table::SWebReturns => SWebReturnsColumn::values().len(),
+            Table::SZipToGmt => SZipToGmtColumn::values().len(),
+            Table::Delete => DeleteColumn::values().len(),
+            Table::InventoryDelete => InventoryDeleteColumn::values().len(),
         }
     }
 
@@ -343,6 +707,24 @@ impl Table {
             Table::Promotion => PromotionGeneratorColumn::values().len(),
             Table::WebPage => WebPageGeneratorColumn::values().len(),
             Table::WebSite => WebSiteGeneratorColumn::values().len(),
+            Table::SCallCenter => SCallCenterGeneratorColumn::values().len(),
+            Table::SCatalogOrder => SCatalogOrderGeneratorColumn::values().len(),
+            Table::SCatalogOrderLineitem => SCatalogOrderLineitemGeneratorColumn::values().len(),
+            Table::SCatalogReturns => SCatalogReturnsGeneratorColumn::values().len(),
+            Table::SInventory => SInventoryGeneratorColumn::values().len(),
+            Table::SItem => SItemGeneratorColumn::values().len(),
+            Table::SPromotion => SPromotionGeneratorColumn::values().len(),
+            Table::SPurchase => SPurchaseGeneratorColumn::values().len(),
+            Table::SPurchaseLineitem => SPurchaseLineitemGeneratorColumn::values().len(),
+            Table::SStoreReturns => SStoreReturnsGeneratorColumn::values().len(),
+            Table::SWarehouse => SWarehouseGeneratorColumn::values().len(),
+            Table::SWebOrder => SWebOrderGeneratorColumn::values().len(),
+            Table::SWebOrderLineitem => SWebOrderLineitemGeneratorColumn::values().len(),
+            Table::SWebPage => SWebPageGeneratorColumn::values().len(),
+            Table::SWebReturns => SWebReturnsGeneratorColumn::values().len(),
+            Table::SZipToGmt => SZipToGmtGeneratorColumn::values().len(),
+            Table::Delete => DeleteGeneratorColumn::values().len(),
+            Table::InventoryDelete => InventoryDeleteGeneratorColumn::values().len(),
         }
     }
 
@@ -354,36 +736,36 @@ impl Table {
                 columns.get(index).map(|col| col as &dyn Column)
             }
             Table::Warehouse => {
-                // TODO: Implement once WarehouseColumn is created
-                None
+                let columns = WarehouseColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
             }
             Table::ShipMode => {
-                // TODO: Implement once ShipModeColumn is created
-                None
+                let columns = ShipModeColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
             }
             Table::Reason => {
-                // TODO: Implement once ReasonColumn is created
-                None
+                let columns = ReasonColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
             }
             Table::IncomeBand => {
-                // TODO: Implement once IncomeBandColumn is created
-                None
+                let columns = IncomeBandColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
             }
             Table::HouseholdDemographics => {
                 let columns = HouseholdDemographicsColumn::values();
                 columns.get(index).map(|col| col as &dyn Column)
             }
             Table::CustomerDemographics => {
-                // TODO: Implement once CustomerDemographicsColumn is created
-                None
+                let columns = CustomerDemographicsColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
             }
             Table::DateDim => {
-                // TODO: Implement once DateDimColumn is created
-                None
+                let columns = DateDimColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
             }
             Table::TimeDim => {
-                // TODO: Implement once TimeDimColumn is created
-                None
+                let columns = TimeDimColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
             }
             Table::Item => {
                 // TODO: Implement once ItemColumn is created
@@ -394,13 +776,85 @@ impl Table {
                 columns.get(index).map(|col| col as &dyn Column)
             }
             Table::WebPage => {
-                // TODO: Implement once WebPageColumn is created
-                None
+                let columns = WebPageColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
             }
             Table::WebSite => {
                 let columns = WebSiteColumn::values();
                 columns.get(index).map(|col| col as &dyn Column)
             }
+            Table::SCallCenter => {
+                let columns = SCallCenterColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SCatalogOrder => {
+                let columns = SCatalogOrderColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SCatalogOrderLineitem => {
+                let columns = SCatalogOrderLineitemColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SCatalogReturns => {
+                let columns = SCatalogReturnsColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SInventory => {
+                let columns = SInventoryColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SItem => {
+                let columns = SItemColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SPromotion => {
+                let columns = SPromotionColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SPurchase => {
+                let columns = SPurchaseColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SPurchaseLineitem => {
+                let columns = SPurchaseLineitemColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SStoreReturns => {
+                let columns = SStoreReturnsColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SWarehouse => {
+                let columns = SWarehouseColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SWebOrder => {
+                let columns = SWebOrderColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SWebOrderLineitem => {
+                let columns = SWebOrderLineitemColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SWebPage => {
+                let columns = SWebPageColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SWebReturns => {
+                let columns = SWebReturnsColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::SZipToGmt => {
+                let columns = SZipToGmtColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::Delete => {
+                let columns = DeleteColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
+            Table::InventoryDelete => {
+                let columns = InventoryDeleteColumn::values();
+                columns.get(index).map(|col| col as &dyn Column)
+            }
         }
     }
 
@@ -464,9 +918,88 @@ impl Table {
                 let columns = COLUMNS.get_or_init(|| WebSiteGeneratorColumn::values().to_vec());
                 columns.get(index).map(|col| col as &dyn GeneratorColumn)
             }
+            Table::SCallCenter => {
+                let columns = SCallCenterGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SCatalogOrder => {
+                let columns = SCatalogOrderGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SCatalogOrderLineitem => {
+                let columns = SCatalogOrderLineitemGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SCatalogReturns => {
+                let columns = SCatalogReturnsGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SInventory => {
+                let columns = SInventoryGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SItem => {
+                let columns = SItemGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SPromotion => {
+                let columns = SPromotionGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SPurchase => {
+                let columns = SPurchaseGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SPurchaseLineitem => {
+                let columns = SPurchaseLineitemGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SStoreReturns => {
+                let columns = SStoreReturnsGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SWarehouse => {
+                let columns = SWarehouseGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SWebOrder => {
+                let columns = SWebOrderGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SWebOrderLineitem => {
+                let columns = SWebOrderLineitemGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SWebPage => {
+                let columns = SWebPageGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SWebReturns => {
+                let columns = SWebReturnsGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::SZipToGmt => {
+                let columns = SZipToGmtGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::Delete => {
+                let columns = DeleteGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
+            Table::InventoryDelete => {
+                let columns = InventoryDeleteGeneratorColumn::values();
+                columns.get(index).map(|col| col as &dyn GeneratorColumn)
+            }
         }
     }
 
+    /// Get all regular columns in output order
+    pub fn get_columns(&self) -> Vec<&'static dyn Column> {
+        (0..self.get_column_count())
+            .filter_map(|index| self.get_column_by_index(index))
+            .collect()
+    }
+
     /// Get a specific column by name (case-insensitive)
     pub fn get_column(&self, column_name: &str) -> Result<&'static dyn Column> {
         let column_name_lower = column_name.to_lowercase();
@@ -512,6 +1045,54 @@ impl Table {
         self.get_table_flags().is_date_based()
     }
 
+    /// Check if this table is generated for a refresh (`--update`) instead of the base data
+    pub fn is_refresh_table(&self) -> bool {
+        Self::get_refresh_tables().contains(self)
+    }
+
+    /// Check if a row generator has been ported for this table
+    pub fn has_row_generator(&self) -> bool {
+        !matches!(self, Table::Item)
+    }
+
+    /// Create the row generator for this table (getRowGeneratorClass)
+    pub fn create_row_generator(&self) -> Option<Box<dyn RowGenerator>> {
+        let generator: Box<dyn RowGenerator> = match self {
+            Table::CallCenter => Box::new(CallCenterRowGenerator::new()),
+            Table::Warehouse => Box::new(WarehouseRowGenerator::new()),
+            Table::ShipMode => Box::new(ShipModeRowGenerator::new()),
+            Table::Reason => Box::new(ReasonRowGenerator::new()),
+            Table::IncomeBand => Box::new(IncomeBandRowGenerator::new()),
+            Table::HouseholdDemographics => Box::new(HouseholdDemographicsRowGenerator::new()),
+            Table::CustomerDemographics => Box::new(CustomerDemographicsRowGenerator::new()),
+            Table::DateDim => Box::new(DateDimRowGenerator::new()),
+            Table::TimeDim => Box::new(TimeDimRowGenerator::new()),
+            Table::Item => return None, // TODO: Add ItemRowGenerator once implemented
+            Table::Promotion => Box::new(PromotionRowGenerator::new()),
+            Table::WebPage => Box::new(WebPageRowGenerator::new()),
+            Table::WebSite => Box::new(WebSiteRowGenerator::new()),
+            Table::SCallCenter => Box::new(SCallCenterRowGenerator::new()),
+            Table::SCatalogOrder => Box::new(SCatalogOrderRowGenerator::new()),
+            Table::SCatalogOrderLineitem => Box::new(SCatalogOrderLineitemRowGenerator::new()),
+            Table::SCatalogReturns => Box::new(SCatalogReturnsRowGenerator::new()),
+            Table::SInventory => Box::new(SInventoryRowGenerator::new()),
+            Table::SItem => Box::new(SItemRowGenerator::new()),
+            Table::SPromotion => Box::new(SPromotionRowGenerator::new()),
+            Table::SPurchase => Box::new(SPurchaseRowGenerator::new()),
+            Table::SPurchaseLineitem => Box::new(SPurchaseLineitemRowGenerator::new()),
+            Table::SStoreReturns => Box::new(SStoreReturnsRowGenerator::new()),
+            Table::SWarehouse => Box::new(SWarehouseRowGenerator::new()),
+            Table::SWebOrder => Box::new(SWebOrderRowGenerator::new()),
+            Table::SWebOrderLineitem => Box::new(SWebOrderLineitemRowGenerator::new()),
+            Table::SWebPage => Box::new(SWebPageRowGenerator::new()),
+            Table::SWebReturns => Box::new(SWebReturnsRowGenerator::new()),
+            Table::SZipToGmt => Box::new(SZipToGmtRowGenerator::new()),
+            Table::Delete => Box::new(DeleteRowGenerator::new(Table::Delete)),
+            Table::InventoryDelete => Box::new(DeleteRowGenerator::new(Table::InventoryDelete)),
+        };
+        Some(generator)
+    }
+
     /// Get all base tables (non-source tables)
     pub fn get_base_tables() -> Vec<Table> {
         vec![
@@ -531,12 +1112,40 @@ impl Table {
         ] // TODO: Add other tables as implemented
     }
 
+    /// Get all tables of a refresh data set. The other source tables of `config::Table`, such
+    /// as `s_brand`, `s_customer` and `s_store`, are not generated.
+    pub fn get_refresh_tables() -> Vec<Table> {
+        vec![
+            Table::SCallCenter,
+            Table::SCatalogOrder,
+            Table::SCatalogOrderLineitem,
+            Table::SCatalogReturns,
+            Table::SInventory,
+            Table::SItem,
+            Table::SPromotion,
+            Table::SPurchase,
+            Table::SPurchaseLineitem,
+            Table::SStoreReturns,
+            Table::SWarehouse,
+            Table::SWebOrder,
+            Table::SWebOrderLineitem,
+            Table::SWebPage,
+            Table::SWebReturns,
+            Table::SZipToGmt,
+            Table::Delete,
+            Table::InventoryDelete,
+        ]
+    }
+
     /// Get a table by name (case-insensitive)
     pub fn get_table(table_name: &str) -> Result<Table> {
         let table_name_lower = table_name.to_lowercase();
-        let base_tables = Self::get_base_tables();
+        let tables: Vec<_> = Self::get_base_tables()
+            .into_iter()
+            .chain(Self::get_refresh_tables())
+            .collect();
 
-        let matches: Vec<_> = base_tables
+        let matches: Vec<_> = tables
             .iter()
             .filter(|table| table.get_name() == table_name_lower)
             .collect();
@@ -580,6 +1189,62 @@ impl From<Table> for crate::column::Table {
             Table::Promotion => crate::column::Table::Promotion,
             Table::WebPage => crate::column::Table::WebPage,
             Table::WebSite => crate::column::Table::WebSite,
+            Table::SCallCenter => crate::column::Table::SCallCenter,
+            Table::SCatalogOrder => crate::column::Table::SCatalogOrder,
+            Table::SCatalogOrderLineitem => crate::column::Table::SCatalogOrderLineitem,
+            Table::SCatalogReturns => crate::column::Table::SCatalogReturns,
+            Table::SInventory => crate::column::Table::SInventory,
+            Table::SItem => crate::column::Table::SItem,
+            Table::SPromotion => crate::column::Table::SPromotion,
+            Table::SPurchase => crate::column::Table::SPurchase,
+            Table::SPurchaseLineitem => crate::column::Table::SPurchaseLineitem,
+            Table::SStoreReturns => crate::column::Table::SStoreReturns,
+            Table::SWarehouse => crate::column::Table::SWarehouse,
+            Table::SWebOrder => crate::column::Table::SWebOrder,
+            Table::SWebOrderLineitem => crate::column::Table::SWebOrderLineitem,
+            Table::SWebPage => crate::column::Table::SWebPage,
+            Table::SWebReturns => crate::column::Table::SWebReturns,
+            Table::SZipToGmt => crate::column::Table::SZipToGmt,
+            Table::Delete => crate::column::Table::Delete,
+            Table::InventoryDelete => crate::column::Table::InventoryDelete,
+        }
+    }
+}
+
+impl From<Table> for crate::config::Table {
+    fn from(table: Table) -> Self {
+        match table {
+            Table::CallCenter => crate::config::Table::CallCenter,
+            Table::Warehouse => crate::config::Table::Warehouse,
+            Table::ShipMode => crate::config::Table::ShipMode,
+            Table::Reason => crate::config::Table::Reason,
+            Table::IncomeBand => crate::config::Table::IncomeBand,
+            Table::HouseholdDemographics => crate::config::Table::HouseholdDemographics,
+            Table::CustomerDemographics => crate::config::Table::CustomerDemographics,
+            Table::DateDim => crate::config::Table::DateDim,
+            Table::TimeDim => crate::config::Table::TimeDim,
+            Table::Item => crate::config::Table::Item,
+            Table::Promotion => crate::config::Table::Promotion,
+            Table::WebPage => crate::config::Table::WebPage,
+            Table::WebSite => crate::config::Table::WebSite,
+            Table::SCallCenter => crate::config::Table::SCallCenter,
+            Table::SCatalogOrder => crate::config::Table::SCatalogOrder,
+            Table::SCatalogOrderLineitem => crate::config::Table::SCatalogOrderLineitem,
+            Table::SCatalogReturns => crate::config::Table::SCatalogReturns,
+            Table::SInventory => crate::config::Table::SInventory,
+            Table::SItem => crate::config::Table::SItem,
+            Table::SPromotion => crate::config::Table::SPromotion,
+            Table::SPurchase => crate::config::Table::SPurchase,
+            Table::SPurchaseLineitem => crate::config::Table::SPurchaseLineitem,
+            Table::SStoreReturns => crate::config::Table::SStoreReturns,
+            Table::SWarehouse => crate::config::Table::SWarehouse,
+            Table::SWebOrder => crate::config::Table::SWebOrder,
+            Table::SWebOrderLineitem => crate::config::Table::SWebOrderLineitem,
+            Table::SWebPage => crate::config::Table::SWebPage,
+            Table::SWebReturns => crate::config::Table::SWebReturns,
+            Table::SZipToGmt => crate::config::Table::SZipToGmt,
+            Table::Delete => crate::config::Table::Delete,
+            Table::InventoryDelete => crate::config::Table::InventoryDelete,
         }
     }
 }
@@ -600,6 +1265,24 @@ impl From<crate::column::Table> for Table {
             crate::column::Table::Promotion => Table::Promotion,
             crate::column::Table::WebPage => Table::WebPage,
             crate::column::Table::WebSite => Table::WebSite,
+            crate::column::Table::SCallCenter => Table::SCallCenter,
+            crate::column::Table::SCatalogOrder => Table::SCatalogOrder,
+            crate::column::Table::SCatalogOrderLineitem => Table::SCatalogOrderLineitem,
+            crate::column::Table::SCatalogReturns => Table::SCatalogReturns,
+            crate::column::Table::SInventory => Table::SInventory,
+            crate::column::Table::SItem => Table::SItem,
+            crate::column::Table::SPromotion => Table::SPromotion,
+            crate::column::Table::SPurchase => Table::SPurchase,
+            crate::column::Table::SPurchaseLineitem => Table::SPurchaseLineitem,
+            crate::column::Table::SStoreReturns => Table::SStoreReturns,
+            crate::column::Table::SWarehouse => Table::SWarehouse,
+            crate::column::Table::SWebOrder => Table::SWebOrder,
+            crate::column::Table::SWebOrderLineitem => Table::SWebOrderLineitem,
+            crate::column::Table::SWebPage => Table::SWebPage,
+            crate::column::Table::SWebReturns => Table::SWebReturns,
+            crate::column::Table::SZipToGmt => Table::SZipToGmt,
+            crate::column::Table::Delete => Table::Delete,
+            crate::column::Table::InventoryDelete => Table::InventoryDelete,
         }
     }
 }
@@ -655,6 +1338,37 @@ mod tests {
         assert_eq!(column_table, Table::CallCenter);
     }
 
+    #[test]
+    fn test_column_counts_match_row_values() {
+        let session = crate::config::Session::get_default_session();
+        for table in Table::get_base_tables() {
+            let Some(mut generator) = table.create_row_generator() else {
+                continue;
+            };
+            let result = generator
+                .generate_row_and_child_rows(1, &session, None, None)
+                .unwrap();
+            let values = result.get_rows()[0].get_values();
+            assert_eq!(table.get_columns().len(), values.len(), "{}", table);
+        }
+    }
+
+    #[test]
+    fn test_refresh_table_column_counts_match_row_values() {
+        let session = crate::config::Session::get_default_session().with_update(1);
+        for table in Table::get_refresh_tables() {
+            assert!(table.is_refresh_table());
+            let mut generator = table.create_row_generator().unwrap();
+            let result = generator
+                .generate_row_and_child_rows(1, &session, None, None)
+                .unwrap();
+            let values = result.get_rows()[0].get_values();
+            assert_eq!(table.get_columns().len(), values.len(), "{}", table);
+        }
+        assert!(!Table::CallCenter.is_refresh_table());
+        assert_eq!(Table::get_table("S_INVENTORY").unwrap(), Table::SInventory);
+    }
+
     #[test]
     fn test_get_generator_columns() {
         let table = Table::CallCenter;
@@ -0,0 +1,397 @@
+use crate::config::Session;
+use crate::error::Result;
+use crate::manifest::{ChunkMarker, Manifest, ManifestFile};
+use crate::output::{FileDigest, TableWriter};
+use crate::parallel::{split_work, ChunkBoundaries};
+use crate::progress::{ChunkProgress, ProgressReporter};
+use crate::row::RowGenerator;
+use crate::table::Table;
+use crate::TpcdsError;
+use std::sync::Arc;
+
+/// Generates the session's chunk of a table and writes it to disk (TableGenerator)
+pub struct TableGenerator {
+    session: Session,
+    progress: Option<Arc<ProgressReporter>>,
+    /// Manifest of a previous run in the target directory, used by `--resume`
+    previous_manifest: Option<Arc<Manifest>>,
+}
+
+impl TableGenerator {
+    /// Rows written between two checks of the progress reporting interval
+    const PROGRESS_CHECK_ROWS: i64 = 10_000;
+
+    pub fn new(session: Session) -> Self {
+        Self {
+            session,
+            progress: None,
+            previous_manifest: None,
+        }
+    }
+
+    /// Report the progress of the generated chunks to `progress`
+    pub fn with_progress(self, progress: Arc<ProgressReporter>) -> Self {
+        Self {
+            progress: Some(progress),
+            ..self
+        }
+    }
+
+    /// Keep the chunks listed in the manifest of a previous run when resuming
+    pub fn with_previous_manifest(self, previous_manifest: Arc<Manifest>) -> Self {
+        Self {
+            previous_manifest: Some(previous_manifest),
+            ..self
+        }
+    }
+
+    /// Generate the rows of `table` assigned to this session's chunk (generateTable).
+    /// Returns the manifest entry of the written file; chunks with no rows do not create a file.
+    /// With `--resume` a chunk completed by a previous run is kept instead.
+    pub fn generate_table(&self, table: Table) -> Result<Option<ManifestFile>> {
+        let boundaries = split_work(table, &self.session)?;
+        let mut chunk_progress = ChunkProgress::new(
+            table,
+            self.session.get_chunk_number(),
+            self.session.get_parallelism(),
+            boundaries.get_row_count(),
+        );
+        if boundaries.is_empty() {
+            if let Some(progress) = &self.progress {
+                progress.finish_chunk(&chunk_progress);
+            }
+            return Ok(None);
+        }
+        if self.session.should_resume() {
+            if let Some(file) = self.find_completed_file(table, boundaries) {
+                if let Some(progress) = &self.progress {
+                    chunk_progress.update(boundaries.get_row_count(), file.rows, file.bytes);
+                    progress.resume_chunk(&chunk_progress);
+                }
+                return Ok(Some(file));
+            }
+        }
+
+        let mut row_generator = table
+            .create_row_generator()
+            .ok_or_else(|| TpcdsError::new(&format!("Table {} has not been ported yet", table)))?;
+        row_generator.skip_rows_until_starting_row_number(boundaries.get_start_row());
+        if self.session.should_audit_seeds() {
+            row_generator.enable_seed_audit();
+        }
+
+        let mut writer = TableWriter::create(table, &self.session)?;
+        let mut row_number = boundaries.get_start_row();
+        let mut rows_written = 0;
+        while row_number <= boundaries.get_end_row() {
+            let result = row_generator
+                .generate_row_and_child_rows(row_number, &self.session, None, None)
+                .map_err(|e| TpcdsError::generation(table, row_number, e))?;
+
+            // The first row belongs to this table; child tables are not ported yet.
+            if let Some(row) = result.get_rows().first() {
+                writer.write_row(&row.get_values())?;
+                rows_written += 1;
+                if let Some(progress) = &self.progress {
+                    if rows_written % Self::PROGRESS_CHECK_ROWS == 0 {
+                        chunk_progress.update(
+                            row_number - boundaries.get_start_row(),
+                            rows_written,
+                            writer.get_bytes_written(),
+                        );
+                        progress.report(&mut chunk_progress);
+                    }
+                }
+            }
+
+            if result.should_end_row() {
+                row_generator.consume_remaining_seeds_for_row();
+                Self::check_seed_budgets(row_generator.as_ref())?;
+                row_number += 1;
+            }
+        }
+        let path = writer.path().clone();
+        let digest = writer.finish()?;
+        let file = ManifestFile::new(
+            table,
+            &self.session,
+            &path,
+            boundaries,
+            rows_written,
+            digest,
+        );
+        if !self.session.write_to_stdout() {
+            ChunkMarker::new(&self.session, file.clone()).write(&path)?;
+        }
+
+        if let Some(progress) = &self.progress {
+            chunk_progress.update(boundaries.get_row_count(), rows_written, file.bytes);
+            progress.finish_chunk(&chunk_progress);
+        }
+        Ok(Some(file))
+    }
+
+    /// Find the file of a chunk completed by a previous run of the same options, from its marker
+    /// or from the previous manifest. The data file must still have the size and checksum that
+    /// were recorded, or only the size with `--resume-skip-checksums`.
+    fn find_completed_file(
+        &self,
+        table: Table,
+        boundaries: ChunkBoundaries,
+    ) -> Option<ManifestFile> {
+        let path = TableWriter::get_path(table, &self.session);
+        let chunk = self.session.get_chunk_number();
+        let file = ChunkMarker::read(&path)
+            .filter(|marker| marker.matches(&self.session))
+            .map(|marker| marker.file)
+            .or_else(|| {
+                self.previous_manifest
+                    .as_ref()
+                    .filter(|manifest| manifest.matches(&self.session))
+                    .and_then(|manifest| manifest.find_file(table, chunk))
+                    .cloned()
+            })?;
+
+        let size = std::fs::metadata(&path).map(|metadata| metadata.len()).ok();
+        let complete = file.table == table.get_name()
+            && file.chunk == chunk
+            && file.start_row == boundaries.get_start_row()
+            && file.end_row == boundaries.get_end_row()
+            && size == Some(file.bytes)
+            && (self.session.should_skip_resume_checksums()
+                || FileDigest::of_file(&path).is_ok_and(|digest| digest.sha256 == file.sha256));
+        complete.then_some(file)
+    }
+
+    /// Fail on the first row in which a column exceeded its seed budget (`--audit-seeds`)
+    fn check_seed_budgets(row_generator: &dyn RowGenerator) -> Result<()> {
+        let violations = row_generator.get_seed_budget_violations();
+        if violations.is_empty() {
+            return Ok(());
+        }
+        let details: Vec<String> = violations.iter().map(ToString::to_string).collect();
+        Err(TpcdsError::new(&format!(
+            "Seed budget exceeded: {}",
+            details.join("; ")
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Compression;
+    use std::io::Read;
+
+    fn session_for(dir: &std::path::Path) -> Session {
+        let mut options = crate::config::Options::new();
+        options.directory = dir.to_string_lossy().to_string();
+        options.overwrite = true;
+        options.to_session().unwrap()
+    }
+
+    #[test]
+    fn test_chunks_concatenate_to_full_table() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let session = session_for(dir);
+
+        TableGenerator::new(session.clone())
+            .generate_table(Table::Promotion)
+            .unwrap();
+        let full = std::fs::read_to_string(dir.join("promotion.dat")).unwrap();
+
+        let chunked_session = session.with_parallelism(3);
+        let mut chunked = String::new();
+        for chunk in 1..=3 {
+            TableGenerator::new(chunked_session.with_chunk_number(chunk))
+                .generate_table(Table::Promotion)
+                .unwrap();
+            chunked.push_str(
+                &std::fs::read_to_string(dir.join(format!("promotion_{}_3.dat", chunk))).unwrap(),
+            );
+        }
+
+        assert_eq!(full.lines().count(), 300);
+        assert_eq!(chunked, full);
+    }
+
+    #[test]
+    fn test_progress_collects_table_stats() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let session = session_for(dir).with_parallelism(3);
+        let progress = Arc::new(ProgressReporter::new(crate::progress::ProgressMode::None));
+        for chunk in 1..=3 {
+            TableGenerator::new(session.with_chunk_number(chunk))
+                .with_progress(Arc::clone(&progress))
+                .generate_table(Table::Promotion)
+                .unwrap();
+        }
+
+        let bytes: u64 = (1..=3)
+            .map(|chunk| {
+                std::fs::metadata(dir.join(format!("promotion_{}_3.dat", chunk)))
+                    .unwrap()
+                    .len()
+            })
+            .sum();
+        let stats = progress.get_table_stats(Table::Promotion).unwrap();
+        assert_eq!(stats.rows, 300);
+        assert_eq!(stats.bytes, bytes);
+    }
+
+    #[test]
+    fn test_null_string() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let mut options = crate::config::Options::new();
+        options.directory = dir.to_string_lossy().to_string();
+        options.null_string = "NULL".to_string();
+        TableGenerator::new(options.to_session().unwrap())
+            .generate_table(Table::CallCenter)
+            .unwrap();
+
+        // The first call center is still open: no end date and no closed date
+        let data = std::fs::read_to_string(dir.join("call_center.dat")).unwrap();
+        assert!(data.starts_with("1|AAAAAAAABAAAAAAA|1998-01-01|NULL|NULL|2450952|"));
+    }
+
+    #[test]
+    fn test_compressed_output_matches_plain_output() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let session = session_for(dir);
+
+        TableGenerator::new(session.clone())
+            .generate_table(Table::Reason)
+            .unwrap();
+        TableGenerator::new(session.with_compression(Compression::Gzip))
+            .generate_table(Table::Reason)
+            .unwrap();
+
+        let plain = std::fs::read_to_string(dir.join("reason.dat")).unwrap();
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(std::fs::File::open(dir.join("reason.dat.gz")).unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+
+        assert_eq!(decoded, plain);
+    }
+
+    #[test]
+    fn test_seed_audit_passes_for_ported_tables() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let session = session_for(dir).with_seed_audit(true);
+        for table in [Table::CallCenter, Table::WebSite, Table::Promotion] {
+            assert!(TableGenerator::new(session.clone())
+                .generate_table(table)
+                .is_ok());
+        }
+    }
+
+    #[test]
+    fn test_existing_file_requires_overwrite() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let session = session_for(dir);
+        std::fs::write(dir.join("ship_mode.dat"), "").unwrap();
+
+        let mut options = crate::config::Options::new();
+        options.directory = dir.to_string_lossy().to_string();
+        let no_overwrite = options.to_session().unwrap();
+        assert!(TableGenerator::new(no_overwrite)
+            .generate_table(Table::ShipMode)
+            .is_err());
+        assert!(TableGenerator::new(session)
+            .generate_table(Table::ShipMode)
+            .is_ok());
+    }
+
+    #[test]
+    fn test_resume_keeps_completed_chunks() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let session = session_for(dir).with_parallelism(2);
+        let generate = |session: &Session, chunk: i32| {
+            TableGenerator::new(session.with_chunk_number(chunk))
+                .generate_table(Table::Promotion)
+                .unwrap()
+                .unwrap()
+        };
+        let files: Vec<ManifestFile> = (1..=2).map(|chunk| generate(&session, chunk)).collect();
+        let path = |chunk: i32| dir.join(format!("promotion_{}_2.dat", chunk));
+        let expected: Vec<Vec<u8>> = (1..=2)
+            .map(|chunk| std::fs::read(path(chunk)).unwrap())
+            .collect();
+
+        // Chunk 1 has its marker but not its checksum and chunk 2 lost its marker, both are
+        // generated again
+        let modified = vec![b'x'; files[0].bytes as usize];
+        std::fs::write(path(1), &modified).unwrap();
+        std::fs::write(path(2), b"partial").unwrap();
+        std::fs::remove_file(ChunkMarker::get_path(&path(2))).unwrap();
+
+        let session = session.with_resume(true);
+        assert_eq!(generate(&session, 1), files[0]);
+        assert_eq!(generate(&session, 2), files[1]);
+        assert_eq!(std::fs::read(path(1)).unwrap(), expected[0]);
+        assert_eq!(std::fs::read(path(2)).unwrap(), expected[1]);
+
+        // Skipping checksums keeps a file of the recorded size
+        let kept = modified;
+        std::fs::write(path(1), &kept).unwrap();
+        let session = session.with_resume_skip_checksums(true);
+        assert_eq!(generate(&session, 1), files[0]);
+        assert_eq!(std::fs::read(path(1)).unwrap(), kept);
+
+        // Without markers the chunks of the previous manifest are kept
+        for chunk in 1..=2 {
+            ChunkMarker::remove(&path(chunk)).unwrap();
+        }
+        let manifest = Arc::new(Manifest::new(&session).with_files(files.clone()));
+        let file = TableGenerator::new(session.with_chunk_number(1))
+            .with_previous_manifest(manifest)
+            .generate_table(Table::Promotion)
+            .unwrap();
+        assert_eq!(file.as_ref(), Some(&files[0]));
+        assert_eq!(std::fs::read(path(1)).unwrap(), kept);
+    }
+
+    #[test]
+    fn test_refresh_data_sets() {
+        let temp = tempfile::tempdir().unwrap();
+        let dir = temp.path();
+        let session = session_for(dir).with_seed_audit(true);
+        for update in 1..=2 {
+            for table in Table::get_refresh_tables() {
+                TableGenerator::new(session.with_update(update))
+                    .generate_table(table)
+                    .unwrap();
+            }
+        }
+
+        let read = |name: &str| std::fs::read_to_string(dir.join(name)).unwrap();
+        assert_eq!(read("s_call_center_1.dat").lines().count(), 3);
+        assert_eq!(read("s_inventory_1.dat").lines().count(), 45000);
+        assert_eq!(read("delete_1.dat").lines().count(), 3);
+        assert_eq!(read("inventory_delete_1.dat").lines().count(), 3);
+        assert_eq!(read("s_purchase_1.dat").lines().count(), 2400);
+        assert_eq!(read("s_store_returns_1.dat").lines().count(), 240);
+        assert_eq!(read("s_catalog_order_1.dat").lines().count(), 1000);
+        assert_eq!(read("s_web_returns_1.dat").lines().count(), 60);
+        assert_eq!(read("s_catalog_returns_1.dat").lines().count(), 2);
+        assert_eq!(read("s_item_1.dat").lines().count(), 180);
+        assert_eq!(read("s_promotion_1.dat").lines().count(), 3);
+        assert_eq!(read("s_warehouse_1.dat").lines().count(), 1);
+        assert_eq!(read("s_web_page_1.dat").lines().count(), 3);
+        assert_eq!(read("s_zip_to_gmt_1.dat").lines().count(), 1000);
+        assert!(read("s_zip_to_gmt_2.dat").starts_with("01001|"));
+        let lines = read("s_purchase_lineitem_1.dat").lines().count();
+        assert!((8 * 2400..=16 * 2400).contains(&lines));
+        assert_ne!(read("s_call_center_1.dat"), read("s_call_center_2.dat"));
+        assert_ne!(read("s_purchase_1.dat"), read("s_purchase_2.dat"));
+        assert_ne!(read("delete_1.dat"), read("delete_2.dat"));
+    }
+}
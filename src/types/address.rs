@@ -138,15 +138,11 @@ impl Address {
         use crate::random::RandomValueGenerator;
 
         let street_number = RandomValueGenerator::generate_uniform_random_int(1, 1000, stream);
-        let street_name1 = pick_random_street_name(StreetNamesWeights::Default, stream)
-            .unwrap_or("Main")
-            .to_string();
-        let street_name2 = pick_random_street_name(StreetNamesWeights::HalfEmpty, stream)
-            .unwrap_or("")
-            .to_string();
-        let street_type = pick_random_street_type(stream)
-            .unwrap_or("Street")
-            .to_string();
+        let street_name1 =
+            pick_random_street_name(StreetNamesWeights::Default, stream)?.to_string();
+        let street_name2 =
+            pick_random_street_name(StreetNamesWeights::HalfEmpty, stream)?.to_string();
+        let street_type = pick_random_street_type(stream)?.to_string();
 
         let random_int = RandomValueGenerator::generate_uniform_random_int(1, 100, stream);
         let suite_number = if random_int % 2 == 1 {
@@ -164,16 +160,18 @@ impl Address {
             crate::table::Table::CallCenter => crate::config::table::Table::CallCenter,
             crate::table::Table::WebSite => crate::config::table::Table::WebSite,
             crate::table::Table::Warehouse => crate::config::table::Table::Warehouse,
-            _ => panic!(
-                "Table {:?} not yet supported in Address::make_address_for_column",
-                table
-            ),
+            _ => {
+                return Err(TpcdsError::new(&format!(
+                    "Table {} not yet supported in Address::make_address_for_column",
+                    table
+                )))
+            }
         };
         let row_count = scaling.get_row_count(config_table) as i32;
         let city = if table.is_small() {
-            let max_cities =
-                PseudoTableScalingInfos::get_active_cities_row_count_for_scale(scaling.get_scale())
-                    as i32;
+            let max_cities = PseudoTableScalingInfos::get_active_cities_row_count_for_scale(
+                scaling.get_scale(),
+            )? as i32;
             let random_int = RandomValueGenerator::generate_uniform_random_int(
                 0,
                 if max_cities > row_count {
@@ -183,20 +181,16 @@ impl Address {
                 },
                 stream,
             );
-            get_city_at_index(random_int as usize)
-                .unwrap_or("Midway")
-                .to_string()
+            get_city_at_index(random_int as usize)?.to_string()
         } else {
-            pick_random_city(CitiesWeights::UnifiedStepFunction, stream)
-                .unwrap_or("Midway")
-                .to_string()
+            pick_random_city(CitiesWeights::UnifiedStepFunction, stream)?.to_string()
         };
 
         // county is picked from a distribution, based on population and keys the rest
         let region_number = if table.is_small() {
             let max_counties = PseudoTableScalingInfos::get_active_counties_row_count_for_scale(
                 scaling.get_scale(),
-            ) as i32;
+            )? as i32;
             RandomValueGenerator::generate_uniform_random_int(
                 0,
                 if max_counties > row_count {
@@ -207,30 +201,19 @@ impl Address {
                 stream,
             ) as usize
         } else {
-            FipsCountyDistribution::pick_random_index(FipsWeights::Uniform, stream).unwrap_or(0)
+            FipsCountyDistribution::pick_random_index(FipsWeights::Uniform, stream)?
         };
 
-        let county = FipsCountyDistribution::get_county_at_index(region_number)
-            .unwrap_or("Williamson County");
-        // let county = if table.is_small() {
-        //     FipsCountyDistribution::get_county_at_index(region_number)
-        //         .unwrap_or("Williamson County")
-        // } else {
-        //     // stubbed.
-        //     FipsCountyDistribution::get_county_at_index(region_number)
-        //         .unwrap_or("Williamson County")
-        // };
+        let county = FipsCountyDistribution::get_county_at_index(region_number)?;
 
         // match state with the selected region/county
-        let state =
-            FipsCountyDistribution::get_state_abbreviation_at_index(region_number).unwrap_or("TN");
+        let state = FipsCountyDistribution::get_state_abbreviation_at_index(region_number)?;
 
         // match the zip prefix with the selected region/county
         let mut zip = Self::compute_city_hash(&city);
 
         // 00000 - 00600 are unused. Avoid them
-        let zip_prefix =
-            FipsCountyDistribution::get_zip_prefix_at_index(region_number).unwrap_or(0);
+        let zip_prefix = FipsCountyDistribution::get_zip_prefix_at_index(region_number)?;
         if zip_prefix == 0 && zip < 9400 {
             zip += 600;
         }
@@ -355,6 +338,7 @@ impl AddressBuilder {
 }
 
 #[cfg(test)]
+#[allow(clippy::manual_range_contains)]
 mod tests {
     use super::*;
 
@@ -399,4 +383,16 @@ mod tests {
         assert_eq!(address.get_city(), "TestCity");
         assert_eq!(address.get_zip(), 54321);
     }
+
+    #[test]
+    fn test_make_address_for_unsupported_table() {
+        let mut stream = crate::random::RandomNumberStreamImpl::new_with_column(1, 1).unwrap();
+        let scaling = crate::config::Scaling::new(1.0);
+        assert!(Address::make_address_for_column(
+            crate::table::Table::Reason,
+            &mut stream,
+            &scaling
+        )
+        .is_err());
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{check_argument, check_state, error::Result, TpcdsError};
+use crate::{check_argument, check_state, error::Result};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Date {
@@ -1,4 +1,4 @@
-use crate::{check_argument, error::Result, TpcdsError};
+use crate::{check_argument, error::Result};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub struct Decimal {
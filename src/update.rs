@@ -0,0 +1,449 @@
+use crate::business_key_generator::make_business_key;
+use crate::config::Scaling;
+use crate::distribution::calendar_distribution::{CalendarDistribution, CalendarWeights};
+use crate::error::Result;
+use crate::generator::GeneratorColumn;
+use crate::permutations::make_permutation;
+use crate::random::{RandomNumberStream, RandomNumberStreamImpl, RandomValueGenerator};
+use crate::slowly_changing_dimension_utils::match_surrogate_key;
+use crate::table::Table;
+use crate::types::{Date, Decimal};
+use crate::TpcdsError;
+use crate::{check_argument, check_state};
+
+/// Global column number of the stream the update dates are drawn from. dsdgen draws them
+/// differently; this stream is specific to tpcdsgen.
+const UPDATE_DATES_COLUMN: i32 = 400;
+
+/// Random numbers drawn for the dates of one refresh: a day and a year per zone
+const SEEDS_PER_UPDATE: i32 = 6;
+
+/// Calendar zones of the refresh date ranges, from the least to the most sales
+const ZONES: [CalendarWeights; 3] = [
+    CalendarWeights::Low,
+    CalendarWeights::Medium,
+    CalendarWeights::High,
+];
+
+const YEAR_MINIMUM: i32 = 1998;
+const YEAR_MAXIMUM: i32 = 2002;
+
+/// Julian dates deleted and reloaded by a refresh: per calendar zone, two adjacent sales days and
+/// the inventory week starting on the Thursday of the first of them. This has the shape of dsdgen
+/// setUpdateDates but not its draws, so the dates differ from dsdgen's.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateDates {
+    sales_date_ranges: Vec<(i64, i64)>,
+    inventory_date_ranges: Vec<(i64, i64)>,
+}
+
+impl UpdateDates {
+    /// Dates of refresh `update` (1-based)
+    pub fn new(update: i32) -> Result<Self> {
+        check_argument!(update >= 1, "update must be >= 1");
+        let mut stream =
+            RandomNumberStreamImpl::new_with_column(UPDATE_DATES_COLUMN, SEEDS_PER_UPDATE)?;
+        stream.skip_rows((update - 1) as i64);
+
+        let mut sales_date_ranges = Vec::with_capacity(ZONES.len());
+        let mut inventory_date_ranges = Vec::with_capacity(ZONES.len());
+        for zone in ZONES {
+            let date = pick_date(zone, &mut stream)?;
+            if is_in_zone(zone, date + 1)? {
+                sales_date_ranges.push((date, date + 1));
+            } else {
+                sales_date_ranges.push((date - 1, date));
+            }
+
+            // Inventory is counted on Thursdays
+            let day_of_week = Date::from_julian_days(date as i32).compute_day_of_week() as i64;
+            let mut thursday = date + 4 - day_of_week;
+            if !is_in_zone(zone, thursday)? {
+                thursday -= 7;
+                if !is_in_zone(zone, thursday)? {
+                    thursday += 14;
+                }
+            }
+            inventory_date_ranges.push((thursday, thursday + 7));
+        }
+
+        Ok(Self {
+            sales_date_ranges,
+            inventory_date_ranges,
+        })
+    }
+
+    /// Sales date ranges (first and last julian date) deleted by the refresh
+    pub fn get_sales_date_ranges(&self) -> &[(i64, i64)] {
+        &self.sales_date_ranges
+    }
+
+    /// Inventory date ranges (first and last julian date) deleted by the refresh
+    pub fn get_inventory_date_ranges(&self) -> &[(i64, i64)] {
+        &self.inventory_date_ranges
+    }
+}
+
+/// Pick a day of `zone` in a random year of the data
+fn pick_date(zone: CalendarWeights, stream: &mut dyn RandomNumberStream) -> Result<i64> {
+    let day_index = CalendarDistribution::pick_random_day_of_year(zone, stream)?;
+    let year =
+        RandomValueGenerator::generate_uniform_random_int(YEAR_MINIMUM, YEAR_MAXIMUM, stream);
+
+    // The calendar is indexed by the days of a leap year
+    let day = Date::from_julian_days(Date::new(2000, 1, 1).to_julian_days() + day_index - 1);
+    Ok(Date::new(year, day.get_month(), day.get_day()).to_julian_days() as i64)
+}
+
+fn is_in_zone(zone: CalendarWeights, julian_date: i64) -> Result<bool> {
+    let date = Date::from_julian_days(julian_date as i32);
+    let day_index = Date::new(2000, date.get_month(), date.get_day()).get_day_index();
+    Ok(CalendarDistribution::get_weight_for_day_of_year(zone, day_index)? > 0)
+}
+
+/// Number of rows of a refresh table in each refresh: the update percentage of the table it
+/// refreshes, except for `s_inventory`, which has a row for every item in every warehouse.
+/// A row of a line item table is an order with all its lines.
+pub fn get_row_count(table: Table, scaling: &Scaling) -> Result<i64> {
+    check_argument!(
+        table.is_refresh_table(),
+        &format!("{} is not a refresh table", table)
+    );
+    let table = match table {
+        Table::SPurchaseLineitem => Table::SPurchase,
+        Table::SCatalogOrderLineitem => Table::SCatalogOrder,
+        Table::SWebOrderLineitem => Table::SWebOrder,
+        table => table,
+    };
+    let update_percentage = table.get_scaling_info().get_update_percentage() as i64;
+    let row_count = match table {
+        Table::SInventory => {
+            scaling.get_id_count(crate::config::Table::Item)
+                * scaling.get_row_count(crate::config::Table::Warehouse)
+        }
+        _ => scaling.get_row_count(table.into()),
+    };
+    Ok(((row_count * update_percentage + 99) / 100).max(1))
+}
+
+/// Row number before the first row of refresh `update`. The rows of each refresh follow the
+/// rows of the previous ones, so every refresh continues the random number streams.
+pub fn get_row_offset(table: Table, scaling: &Scaling, update: i32) -> Result<i64> {
+    if update < 1 {
+        return Err(TpcdsError::new(&format!(
+            "{} is only generated with --update",
+            table
+        )));
+    }
+    Ok((update - 1) as i64 * get_row_count(table, scaling)?)
+}
+
+/// Stream of `column` at the draws of line `line_number` (1-based) of row `row_number`, for
+/// columns that draw one random number per line. The orders, lines and returns of a refresh are
+/// generated as separate tables; this lets each of them compute the values they share.
+pub fn get_line_stream(
+    column: &dyn GeneratorColumn,
+    row_number: i64,
+    line_number: i32,
+) -> Result<RandomNumberStreamImpl> {
+    let mut stream = RandomNumberStreamImpl::new_with_column(
+        column.get_global_column_number(),
+        column.get_seeds_per_row(),
+    )?;
+    stream.skip_rows(row_number - 1);
+    for _ in 1..line_number {
+        stream.next_random();
+    }
+    Ok(stream)
+}
+
+/// Business key of a random row of `table` in the base data. For tables that keep history all
+/// revisions share the key, so the key of a random business key's first revision is used.
+pub fn pick_business_key(
+    table: crate::config::Table,
+    scaling: &Scaling,
+    stream: &mut dyn RandomNumberStream,
+) -> Result<String> {
+    let unique =
+        RandomValueGenerator::generate_uniform_random_key(1, scaling.get_id_count(table), stream);
+    if table.keeps_history() {
+        Ok(make_business_key(match_surrogate_key(
+            unique,
+            Date::JULIAN_DATA_START_DATE,
+            table,
+            scaling,
+        )?))
+    } else {
+        Ok(make_business_key(unique))
+    }
+}
+
+/// Pick a day of the refresh's sales date ranges, the days whose sales the refresh replaces
+pub fn pick_sales_date(update_dates: &UpdateDates, stream: &mut dyn RandomNumberStream) -> i64 {
+    let ranges = update_dates.get_sales_date_ranges();
+    let range =
+        RandomValueGenerator::generate_uniform_random_int(0, ranges.len() as i32 - 1, stream);
+    let (first, last) = ranges[range as usize];
+    first
+        + RandomValueGenerator::generate_uniform_random_int(0, (last - first) as i32, stream) as i64
+}
+
+/// Pick the number of lines of an order. An item is sold at most once per order, so small scales
+/// with fewer items than `max_lines` have shorter orders.
+pub fn pick_line_count(
+    min_lines: i32,
+    max_lines: i32,
+    scaling: &Scaling,
+    stream: &mut dyn RandomNumberStream,
+) -> i32 {
+    let line_count =
+        RandomValueGenerator::generate_uniform_random_int(min_lines, max_lines, stream);
+    line_count.min(scaling.get_id_count(crate::config::Table::Item) as i32)
+}
+
+/// Business key of the item sold on line `line_number` of an order whose first line sells item
+/// `first_item`. The lines sell consecutive items, so no item is sold twice in an order; dsdgen
+/// picks the items of an order differently.
+pub fn get_line_item_id(first_item: i64, line_number: i32, scaling: &Scaling) -> Result<String> {
+    let item_count = scaling.get_id_count(crate::config::Table::Item);
+    let unique = (first_item - 1 + (line_number - 1) as i64) % item_count + 1;
+    Ok(make_business_key(match_surrogate_key(
+        unique,
+        Date::JULIAN_DATA_START_DATE,
+        crate::config::Table::Item,
+        scaling,
+    )?))
+}
+
+/// Pick the quantity of an order line
+pub fn pick_quantity(stream: &mut dyn RandomNumberStream) -> i32 {
+    RandomValueGenerator::generate_uniform_random_int(1, 100, stream)
+}
+
+/// Pick the unit sales price of an order line
+pub fn pick_sales_price(stream: &mut dyn RandomNumberStream) -> Result<Decimal> {
+    RandomValueGenerator::generate_uniform_random_decimal(
+        Decimal::ONE,
+        Decimal::ONE_HUNDRED,
+        stream,
+    )
+}
+
+/// Pick the coupon amount of an order line, up to a tenth of its extended sales price
+pub fn pick_coupon_amount(
+    sales_price: Decimal,
+    quantity: i32,
+    stream: &mut dyn RandomNumberStream,
+) -> Result<Decimal> {
+    let ext_sales_price = Decimal::multiply(sales_price, Decimal::from_integer(quantity));
+    RandomValueGenerator::generate_uniform_random_decimal(
+        Decimal::ZERO,
+        Decimal::multiply(ext_sales_price, Decimal::new(10, 2)?),
+        stream,
+    )
+}
+
+/// Pick the shipping cost of a catalog or web order line, up to half its extended sales price
+pub fn pick_ship_cost(
+    sales_price: Decimal,
+    quantity: i32,
+    stream: &mut dyn RandomNumberStream,
+) -> Result<Decimal> {
+    let ext_sales_price = Decimal::multiply(sales_price, Decimal::from_integer(quantity));
+    RandomValueGenerator::generate_uniform_random_decimal(
+        Decimal::ZERO,
+        Decimal::multiply(ext_sales_price, Decimal::new(50, 2)?),
+        stream,
+    )
+}
+
+/// Item, quantity and unit sales price of an order line, which a return of the line refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SoldLine {
+    item_id: String,
+    quantity: i32,
+    sales_price: Decimal,
+}
+
+impl SoldLine {
+    pub fn new(item_id: String, quantity: i32, sales_price: Decimal) -> Self {
+        Self {
+            item_id,
+            quantity,
+            sales_price,
+        }
+    }
+
+    pub fn get_item_id(&self) -> &str {
+        &self.item_id
+    }
+
+    pub fn get_quantity(&self) -> i32 {
+        self.quantity
+    }
+
+    pub fn get_sales_price(&self) -> Decimal {
+        self.sales_price
+    }
+}
+
+/// Orders returned in refresh `update`, one per row of `returns_table`. The orders of the
+/// refresh are permuted with the stream of `column`, so no order is returned twice.
+pub fn get_returned_orders(
+    returns_table: Table,
+    order_table: Table,
+    column: &dyn GeneratorColumn,
+    scaling: &Scaling,
+    update: i32,
+) -> Result<Vec<i64>> {
+    let order_count = get_row_count(order_table, scaling)?;
+    let order_offset = get_row_offset(order_table, scaling, update)?;
+    let return_count = get_row_count(returns_table, scaling)?;
+    check_state!(
+        return_count <= order_count,
+        &format!("{} has more rows than {}", returns_table, order_table)
+    );
+
+    let mut stream = RandomNumberStreamImpl::new_with_column(
+        column.get_global_column_number(),
+        order_count as i32,
+    )?;
+    stream.skip_rows((update - 1) as i64);
+    Ok(make_permutation(order_count as usize, &mut stream)
+        .into_iter()
+        .take(return_count as usize)
+        .map(|order| order_offset + order as i64 + 1)
+        .collect())
+}
+
+/// Amounts of a returned line (the returns part of dsdgen's set_pricing)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReturnAmounts {
+    return_amount: Decimal,
+    return_tax: Decimal,
+    fee: Decimal,
+    ship_cost: Decimal,
+    refunded_cash: Decimal,
+    reversed_charge: Decimal,
+    credit: Decimal,
+}
+
+impl ReturnAmounts {
+    /// Amounts of returning `quantity` units sold at `sales_price`. The return amount is paid
+    /// back partly in cash, partly by reversing the charge and the rest as a credit.
+    pub fn new(
+        sales_price: Decimal,
+        quantity: i32,
+        stream: &mut dyn RandomNumberStream,
+    ) -> Result<Self> {
+        let return_amount = Decimal::multiply(sales_price, Decimal::from_integer(quantity));
+        let tax_percent = RandomValueGenerator::generate_uniform_random_decimal(
+            Decimal::ZERO,
+            Decimal::NINE_PERCENT,
+            stream,
+        )?;
+        let return_tax = Decimal::multiply(return_amount, tax_percent);
+        let fee = RandomValueGenerator::generate_uniform_random_decimal(
+            Decimal::ONE_HALF,
+            Decimal::ONE_HUNDRED,
+            stream,
+        )?;
+        let ship_cost = RandomValueGenerator::generate_uniform_random_decimal(
+            Decimal::ZERO,
+            Decimal::multiply(return_amount, Decimal::new(10, 2)?),
+            stream,
+        )?;
+
+        let cash_share = RandomValueGenerator::generate_uniform_random_decimal(
+            Decimal::ZERO,
+            Decimal::ONE,
+            stream,
+        )?;
+        let refunded_cash = Decimal::multiply(return_amount, cash_share);
+        let rest = Decimal::subtract(return_amount, refunded_cash);
+        let reversed_share = RandomValueGenerator::generate_uniform_random_decimal(
+            Decimal::ZERO,
+            Decimal::ONE,
+            stream,
+        )?;
+        let reversed_charge = Decimal::multiply(rest, reversed_share);
+        let credit = Decimal::subtract(rest, reversed_charge);
+
+        Ok(Self {
+            return_amount,
+            return_tax,
+            fee,
+            ship_cost,
+            refunded_cash,
+            reversed_charge,
+            credit,
+        })
+    }
+
+    pub fn get_return_amount(&self) -> Decimal {
+        self.return_amount
+    }
+
+    pub fn get_return_tax(&self) -> Decimal {
+        self.return_tax
+    }
+
+    pub fn get_fee(&self) -> Decimal {
+        self.fee
+    }
+
+    pub fn get_ship_cost(&self) -> Decimal {
+        self.ship_cost
+    }
+
+    pub fn get_refunded_cash(&self) -> Decimal {
+        self.refunded_cash
+    }
+
+    pub fn get_reversed_charge(&self) -> Decimal {
+        self.reversed_charge
+    }
+
+    /// Store credit, merchant credit or account credit, depending on the channel
+    pub fn get_credit(&self) -> Decimal {
+        self.credit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_dates() {
+        let first = UpdateDates::new(1).unwrap();
+        assert_eq!(first, UpdateDates::new(1).unwrap());
+        assert_ne!(first, UpdateDates::new(2).unwrap());
+
+        for (i, zone) in ZONES.into_iter().enumerate() {
+            let (start, end) = first.get_sales_date_ranges()[i];
+            assert_eq!(end, start + 1);
+            assert!(is_in_zone(zone, start).unwrap() || is_in_zone(zone, end).unwrap());
+
+            let (thursday, end) = first.get_inventory_date_ranges()[i];
+            assert_eq!(end, thursday + 7);
+            assert_eq!(
+                Date::from_julian_days(thursday as i32).compute_day_of_week(),
+                4
+            );
+        }
+        assert!(UpdateDates::new(0).is_err());
+    }
+
+    #[test]
+    fn test_row_counts() {
+        let scaling = Scaling::new(1.0);
+        assert_eq!(get_row_count(Table::SCallCenter, &scaling).unwrap(), 3);
+        assert_eq!(get_row_count(Table::SInventory, &scaling).unwrap(), 45000);
+        assert_eq!(get_row_count(Table::Delete, &scaling).unwrap(), 3);
+        assert!(get_row_count(Table::CallCenter, &scaling).is_err());
+
+        assert_eq!(get_row_offset(Table::Delete, &scaling, 3).unwrap(), 6);
+        assert!(get_row_offset(Table::Delete, &scaling, 0).is_err());
+    }
+}
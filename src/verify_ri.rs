@@ -0,0 +1,796 @@
+use crate::error::{Result, TpcdsError};
+use crate::table::Table;
+use crate::types::Date;
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+/// How the values of a foreign key column refer to the parent
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForeignKeyKind {
+    /// The value is a key of the parent
+    Key,
+    /// The value is a business key of a slowly changing dimension (see `VERSIONED_TABLES`),
+    /// which must have a revision valid on the date in `date_column` of the referencing row:
+    /// the revision `match_surrogate_key` resolves the key to
+    Revision { date_column: &'static str },
+}
+
+/// A foreign key: every non-null value of `table.column` must be a `parent.parent_column` key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForeignKey {
+    pub table: &'static str,
+    pub column: &'static str,
+    pub parent: &'static str,
+    pub parent_column: &'static str,
+    pub kind: ForeignKeyKind,
+}
+
+const fn foreign_key(
+    table: &'static str,
+    column: &'static str,
+    parent: &'static str,
+    parent_column: &'static str,
+) -> ForeignKey {
+    ForeignKey {
+        table,
+        column,
+        parent,
+        parent_column,
+        kind: ForeignKeyKind::Key,
+    }
+}
+
+const fn revision_key(
+    table: &'static str,
+    column: &'static str,
+    date_column: &'static str,
+    parent: &'static str,
+    parent_column: &'static str,
+) -> ForeignKey {
+    ForeignKey {
+        table,
+        column,
+        parent,
+        parent_column,
+        kind: ForeignKeyKind::Revision { date_column },
+    }
+}
+
+/// Foreign keys of the generated tables. Date keys must fall within the range of `date_dim`.
+/// The refresh tables (`s_*`) refer to the base tables by business key; keys of base tables
+/// that have not been ported (item, customer, store, catalog_page, ...) are not checked.
+pub const FOREIGN_KEYS: &[ForeignKey] = &[
+    foreign_key("call_center", "cc_closed_date_sk", "date_dim", "d_date_sk"),
+    foreign_key("call_center", "cc_open_date_sk", "date_dim", "d_date_sk"),
+    foreign_key(
+        "household_demographics",
+        "hd_income_band_sk",
+        "income_band",
+        "ib_income_band_sk",
+    ),
+    foreign_key("promotion", "p_start_date_sk", "date_dim", "d_date_sk"),
+    foreign_key("promotion", "p_end_date_sk", "date_dim", "d_date_sk"),
+    foreign_key("promotion", "p_item_sk", "item", "i_item_sk"),
+    foreign_key("web_page", "wp_creation_date_sk", "date_dim", "d_date_sk"),
+    foreign_key("web_page", "wp_access_date_sk", "date_dim", "d_date_sk"),
+    foreign_key("web_page", "wp_customer_sk", "customer", "c_customer_sk"),
+    foreign_key("web_site", "web_open_date_sk", "date_dim", "d_date_sk"),
+    foreign_key("web_site", "web_close_date_sk", "date_dim", "d_date_sk"),
+    revision_key(
+        "s_catalog_order",
+        "cord_call_center_id",
+        "cord_order_date",
+        "call_center",
+        "cc_call_center_id",
+    ),
+    foreign_key(
+        "s_catalog_order",
+        "cord_ship_mode_id",
+        "ship_mode",
+        "sm_ship_mode_id",
+    ),
+    foreign_key(
+        "s_catalog_order_lineitem",
+        "clin_promotion_id",
+        "promotion",
+        "p_promo_id",
+    ),
+    foreign_key(
+        "s_catalog_order_lineitem",
+        "clin_warehouse_id",
+        "warehouse",
+        "w_warehouse_id",
+    ),
+    revision_key(
+        "s_catalog_returns",
+        "cret_call_center_id",
+        "cret_return_date",
+        "call_center",
+        "cc_call_center_id",
+    ),
+    foreign_key(
+        "s_catalog_returns",
+        "cret_reason_id",
+        "reason",
+        "r_reason_id",
+    ),
+    foreign_key(
+        "s_catalog_returns",
+        "cret_shipmode_id",
+        "ship_mode",
+        "sm_ship_mode_id",
+    ),
+    foreign_key(
+        "s_catalog_returns",
+        "cret_warehouse_id",
+        "warehouse",
+        "w_warehouse_id",
+    ),
+    foreign_key(
+        "s_inventory",
+        "invn_warehouse_id",
+        "warehouse",
+        "w_warehouse_id",
+    ),
+    foreign_key(
+        "s_purchase_lineitem",
+        "plin_promotion_id",
+        "promotion",
+        "p_promo_id",
+    ),
+    foreign_key("s_store_returns", "sret_reason_id", "reason", "r_reason_id"),
+    revision_key(
+        "s_web_order",
+        "word_web_site_id",
+        "word_order_date",
+        "web_site",
+        "web_site_id",
+    ),
+    foreign_key(
+        "s_web_order",
+        "word_ship_mode_id",
+        "ship_mode",
+        "sm_ship_mode_id",
+    ),
+    foreign_key(
+        "s_web_order_lineitem",
+        "wlin_promotion_id",
+        "promotion",
+        "p_promo_id",
+    ),
+    foreign_key(
+        "s_web_order_lineitem",
+        "wlin_warehouse_id",
+        "warehouse",
+        "w_warehouse_id",
+    ),
+    revision_key(
+        "s_web_order_lineitem",
+        "wlin_web_page_id",
+        "wlin_ship_date",
+        "web_page",
+        "wp_web_page_id",
+    ),
+    revision_key(
+        "s_web_returns",
+        "wret_web_page_id",
+        "wret_return_date",
+        "web_page",
+        "wp_web_page_id",
+    ),
+    foreign_key("s_web_returns", "wret_reason_id", "reason", "r_reason_id"),
+];
+
+/// Slowly changing dimensions: table, business key, and the columns of the validity range of
+/// each revision. `match_surrogate_key` relies on the revisions of a business key being
+/// consecutive rows whose ranges follow each other without gaps or overlaps.
+pub const VERSIONED_TABLES: &[(&str, &str, &str, &str)] = &[
+    (
+        "call_center",
+        "cc_call_center_id",
+        "cc_rec_start_date",
+        "cc_rec_end_date",
+    ),
+    (
+        "web_page",
+        "wp_web_page_id",
+        "wp_rec_start_date",
+        "wp_rec_end_date",
+    ),
+    (
+        "web_site",
+        "web_site_id",
+        "web_rec_start_date",
+        "web_rec_end_date",
+    ),
+];
+
+/// Outcome of one check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckResult {
+    pub name: String,
+    /// Rows examined
+    pub rows: u64,
+    /// NULL keys, which are allowed
+    pub nulls: u64,
+    /// Rows breaking the check: orphans, duplicate keys or broken revision ranges
+    pub violations: u64,
+    /// Keys written as -1 instead of NULL (`get_string_or_null_for_key`)
+    pub minus_ones: u64,
+    /// Why the check could not run, e.g. a table that was not generated
+    pub skipped: Option<String>,
+}
+
+impl CheckResult {
+    fn new(name: String) -> Self {
+        Self {
+            name,
+            rows: 0,
+            nulls: 0,
+            violations: 0,
+            minus_ones: 0,
+            skipped: None,
+        }
+    }
+
+    fn skipped(name: String, reason: String) -> Self {
+        Self {
+            skipped: Some(reason),
+            ..Self::new(name)
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.violations == 0 && self.minus_ones == 0
+    }
+}
+
+/// Rows of a data file, split into fields
+type Rows = Vec<Vec<String>>;
+
+/// Julian days of the first and last day of a revision; no last day while it is current
+type DateRange = (Option<i32>, Option<i32>);
+
+/// Checks the referential integrity of generated flat files (`tpcdsgen verify-ri`)
+pub struct ReferentialIntegrityChecker {
+    directory: PathBuf,
+    separator: char,
+    suffix: String,
+    tables: HashMap<&'static str, Option<Rows>>,
+}
+
+impl ReferentialIntegrityChecker {
+    pub fn new(directory: &Path, separator: char, suffix: &str) -> Self {
+        Self {
+            directory: directory.to_path_buf(),
+            separator,
+            suffix: suffix.to_string(),
+            tables: HashMap::new(),
+        }
+    }
+
+    /// Run every check: primary key uniqueness of the referenced tables, revision ranges of
+    /// the slowly changing dimensions and all foreign keys
+    pub fn check(&mut self) -> Result<Vec<CheckResult>> {
+        if !self.directory.is_dir() {
+            return Err(TpcdsError::new(&format!(
+                "Directory {} does not exist",
+                self.directory.display()
+            )));
+        }
+        let mut results = Vec::new();
+
+        // Business keys of slowly changing dimensions repeat, once per revision
+        let mut parents: Vec<(&str, &str)> = FOREIGN_KEYS
+            .iter()
+            .filter(|key| key.kind == ForeignKeyKind::Key)
+            .map(|key| (key.parent, key.parent_column))
+            .collect();
+        parents.sort();
+        parents.dedup();
+        for (parent, parent_column) in parents {
+            results.push(self.check_primary_key(parent, parent_column)?);
+        }
+        for &(table, business_key, start, end) in VERSIONED_TABLES {
+            results.push(self.check_revisions(table, business_key, start, end)?);
+        }
+        for key in FOREIGN_KEYS {
+            results.push(match key.kind {
+                ForeignKeyKind::Key => self.check_foreign_key(key)?,
+                ForeignKeyKind::Revision { date_column } => {
+                    self.check_revision_key(key, date_column)?
+                }
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Write one line per check and return whether all of them passed
+    pub fn write_report(out: &mut dyn Write, results: &[CheckResult]) -> Result<bool> {
+        writeln!(out, "check\trows\tnulls\tviolations\tminus_ones\tstatus")?;
+        for result in results {
+            let status = match &result.skipped {
+                Some(reason) => format!("skipped: {}", reason),
+                None if result.is_ok() => "ok".to_string(),
+                None => "FAILED".to_string(),
+            };
+            writeln!(
+                out,
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                result.name,
+                result.rows,
+                result.nulls,
+                result.violations,
+                result.minus_ones,
+                status
+            )?;
+        }
+        Ok(results.iter().all(CheckResult::is_ok))
+    }
+
+    fn check_primary_key(&mut self, table: &'static str, column: &str) -> Result<CheckResult> {
+        let name = format!("{}.{} unique", table, column);
+        let (rows, position) = match self.get_column(table, column)? {
+            Ok(found) => found,
+            Err(reason) => return Ok(CheckResult::skipped(name, reason)),
+        };
+
+        let mut result = CheckResult::new(name);
+        let mut keys = HashSet::new();
+        for row in rows {
+            result.rows += 1;
+            match row.get(position).map(String::as_str) {
+                None | Some("") => result.violations += 1,
+                Some(key) if !keys.insert(key) => result.violations += 1,
+                _ => {}
+            }
+        }
+        Ok(result)
+    }
+
+    fn check_revisions(
+        &mut self,
+        table: &'static str,
+        business_key: &str,
+        start: &str,
+        end: &str,
+    ) -> Result<CheckResult> {
+        let name = format!("{} revisions of {}", table, business_key);
+        let mut positions = Vec::new();
+        for column in [business_key, start, end] {
+            match self.get_column(table, column)? {
+                Ok((_, position)) => positions.push(position),
+                Err(reason) => return Ok(CheckResult::skipped(name, reason)),
+            }
+        }
+        let rows = self.tables[table].as_ref().unwrap();
+        let field = |row: &Vec<String>, index: usize| -> String {
+            row.get(positions[index]).cloned().unwrap_or_default()
+        };
+
+        let mut result = CheckResult::new(name);
+        let mut seen = HashSet::new();
+        let mut previous: Option<&Vec<String>> = None;
+        for row in rows {
+            result.rows += 1;
+            let key = field(row, 0);
+            let continues = previous.is_some_and(|previous| field(previous, 0) == key);
+            let valid = if continues {
+                // The previous revision ends the day before this one starts
+                let previous = previous.unwrap();
+                match (parse_date(&field(previous, 2)), parse_date(&field(row, 1))) {
+                    (Some(previous_end), Some(start)) => previous_end + 1 == start,
+                    _ => false,
+                }
+            } else {
+                // The revisions of a business key are consecutive, and the previous business
+                // key's last revision is still current
+                seen.insert(key.clone())
+                    && previous.is_none_or(|previous| field(previous, 2).is_empty())
+                    && parse_date(&field(row, 1)).is_some()
+            };
+            if !valid {
+                result.violations += 1;
+            }
+            previous = Some(row);
+        }
+        if previous.is_some_and(|previous| !field(previous, 2).is_empty()) {
+            result.violations += 1;
+        }
+        Ok(result)
+    }
+
+    fn check_foreign_key(&mut self, key: &ForeignKey) -> Result<CheckResult> {
+        let name = format!(
+            "{}.{} -> {}.{}",
+            key.table, key.column, key.parent, key.parent_column
+        );
+        let parent_position = match self.get_column(key.parent, key.parent_column)? {
+            Ok((_, position)) => position,
+            Err(reason) => return Ok(CheckResult::skipped(name, reason)),
+        };
+        let position = match self.get_column(key.table, key.column)? {
+            Ok((_, position)) => position,
+            Err(reason) => return Ok(CheckResult::skipped(name, reason)),
+        };
+
+        let parent_keys: HashSet<&str> = self.tables[key.parent]
+            .as_ref()
+            .unwrap()
+            .iter()
+            .filter_map(|row| row.get(parent_position).map(String::as_str))
+            .collect();
+
+        let mut result = CheckResult::new(name);
+        for row in self.tables[key.table].as_ref().unwrap() {
+            result.rows += 1;
+            match row.get(position).map(String::as_str) {
+                None | Some("") => result.nulls += 1,
+                Some("-1") => result.minus_ones += 1,
+                Some(value) if !parent_keys.contains(value) => result.violations += 1,
+                _ => {}
+            }
+        }
+        Ok(result)
+    }
+
+    fn check_revision_key(&mut self, key: &ForeignKey, date_column: &str) -> Result<CheckResult> {
+        let name = format!(
+            "{}.{} -> {}.{} on {}",
+            key.table, key.column, key.parent, key.parent_column, date_column
+        );
+        let (start, end) = match VERSIONED_TABLES.iter().find(|(table, business_key, _, _)| {
+            *table == key.parent && *business_key == key.parent_column
+        }) {
+            Some(&(_, _, start, end)) => (start, end),
+            None => {
+                return Err(TpcdsError::invalid_state(&format!(
+                    "{}.{} is not the business key of a slowly changing dimension",
+                    key.parent, key.parent_column
+                )))
+            }
+        };
+
+        let mut parent_positions = Vec::new();
+        for column in [key.parent_column, start, end] {
+            match self.get_column(key.parent, column)? {
+                Ok((_, position)) => parent_positions.push(position),
+                Err(reason) => return Ok(CheckResult::skipped(name, reason)),
+            }
+        }
+        let mut positions = Vec::new();
+        for column in [key.column, date_column] {
+            match self.get_column(key.table, column)? {
+                Ok((_, position)) => positions.push(position),
+                Err(reason) => return Ok(CheckResult::skipped(name, reason)),
+            }
+        }
+
+        // Validity range of every revision, by business key; an open end is still current
+        let mut revisions: HashMap<&str, Vec<DateRange>> = HashMap::new();
+        for row in self.tables[key.parent].as_ref().unwrap() {
+            let field = |index: usize| row.get(parent_positions[index]).map(String::as_str);
+            if let Some(business_key) = field(0) {
+                let range = (field(1).and_then(parse_date), field(2).and_then(parse_date));
+                revisions.entry(business_key).or_default().push(range);
+            }
+        }
+
+        let mut result = CheckResult::new(name);
+        for row in self.tables[key.table].as_ref().unwrap() {
+            result.rows += 1;
+            let field = |index: usize| row.get(positions[index]).map(String::as_str);
+            let value = match field(0) {
+                None | Some("") => {
+                    result.nulls += 1;
+                    continue;
+                }
+                Some("-1") => {
+                    result.minus_ones += 1;
+                    continue;
+                }
+                Some(value) => value,
+            };
+            let valid = match (revisions.get(value), field(1).and_then(parse_date)) {
+                (Some(ranges), Some(date)) => ranges.iter().any(|&(start, end)| {
+                    start.is_some_and(|start| start <= date) && end.is_none_or(|end| date <= end)
+                }),
+                _ => false,
+            };
+            if !valid {
+                result.violations += 1;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Get the rows of `table` and the position of `column`, or why they are not available
+    fn get_column(
+        &mut self,
+        table: &'static str,
+        column: &str,
+    ) -> Result<std::result::Result<(&Rows, usize), String>> {
+        let position = match Table::get_table(table).and_then(|t| t.get_column(column)) {
+            Ok(column) => column.get_position() as usize,
+            Err(_) => return Ok(Err(format!("{} has not been ported yet", table))),
+        };
+        if !self.tables.contains_key(table) {
+            let rows = self.load_table(table)?;
+            self.tables.insert(table, rows);
+        }
+        Ok(match &self.tables[table] {
+            Some(rows) => Ok((rows, position)),
+            None => Err(format!("no data file for {}", table)),
+        })
+    }
+
+    /// Read every data file of `table`: the single file or the chunks of a parallel run. The
+    /// files of a refresh table are named after their refresh, and those of every refresh in
+    /// the directory are read.
+    fn load_table(&self, table: &str) -> Result<Option<Rows>> {
+        let names = if Table::get_table(table).is_ok_and(|t| t.is_refresh_table()) {
+            self.find_updates(table)?
+                .into_iter()
+                .map(|update| format!("{}_{}", table, update))
+                .collect()
+        } else {
+            vec![table.to_string()]
+        };
+
+        let mut paths = Vec::new();
+        for name in &names {
+            let single = self.directory.join(format!("{}{}", name, self.suffix));
+            if single.is_file() {
+                paths.push(single);
+            } else {
+                let mut chunks = self.find_chunk_files(name)?;
+                chunks.sort_by_key(|path| Self::get_chunk_number(name, &self.suffix, path));
+                paths.extend(chunks);
+            }
+        }
+        if paths.is_empty() {
+            return Ok(None);
+        }
+
+        let mut rows = Vec::new();
+        for path in paths {
+            let reader =
+                BufReader::new(File::open(&path).map_err(|e| {
+                    TpcdsError::io(&format!("Failed to open {}", path.display()), e)
+                })?);
+            for line in reader.split(b'\n') {
+                // Data files use the encoding of the distributions, ISO-8859-1
+                let line: String = line?.iter().map(|&b| b as char).collect();
+                rows.push(line.split(self.separator).map(str::to_string).collect());
+            }
+        }
+        Ok(Some(rows))
+    }
+
+    fn find_chunk_files(&self, table: &str) -> Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(&self.directory)? {
+            let path = entry?.path();
+            if Self::get_chunk_number(table, &self.suffix, &path).is_some() {
+                paths.push(path);
+            }
+        }
+        Ok(paths)
+    }
+
+    /// Refreshes with data files of the refresh table `table`, whole or in chunks
+    fn find_updates(&self, table: &str) -> Result<Vec<i32>> {
+        let mut updates = Vec::new();
+        for entry in std::fs::read_dir(&self.directory)? {
+            if let Some(update) = Self::get_update(table, &self.suffix, &entry?.path()) {
+                updates.push(update);
+            }
+        }
+        updates.sort();
+        updates.dedup();
+        Ok(updates)
+    }
+
+    /// Refresh number of a `<table>_<update><suffix>` or
+    /// `<table>_<update>_<chunk>_<parallelism><suffix>` file name
+    fn get_update(table: &str, suffix: &str, path: &Path) -> Option<i32> {
+        let file_name = path.file_name()?.to_str()?;
+        let numbers = file_name
+            .strip_prefix(table)?
+            .strip_prefix('_')?
+            .strip_suffix(suffix)?
+            .split('_')
+            .map(str::parse::<i32>)
+            .collect::<std::result::Result<Vec<_>, _>>()
+            .ok()?;
+        match numbers[..] {
+            [update] | [update, _, _] => Some(update),
+            _ => None,
+        }
+    }
+
+    /// Chunk number of a `<table>_<chunk>_<parallelism><suffix>` file name
+    fn get_chunk_number(table: &str, suffix: &str, path: &Path) -> Option<i32> {
+        let file_name = path.file_name()?.to_str()?;
+        let chunk = file_name
+            .strip_prefix(table)?
+            .strip_prefix('_')?
+            .strip_suffix(suffix)?;
+        let (number, parallelism) = chunk.split_once('_')?;
+        parallelism.parse::<i32>().ok()?;
+        number.parse().ok()
+    }
+}
+
+/// Julian day of a `YYYY-MM-DD` date
+fn parse_date(value: &str) -> Option<i32> {
+    let mut parts = value.splitn(3, '-').map(str::parse::<i32>);
+    let (year, month, day) = (
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+        parts.next()?.ok()?,
+    );
+    Date::new_validated(year, month, day)
+        .ok()
+        .map(|date| date.to_julian_days())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn check(files: &[(&str, &str)]) -> Vec<CheckResult> {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        for (name, content) in files {
+            fs::write(directory.join(name), content).unwrap();
+        }
+        ReferentialIntegrityChecker::new(directory, '|', ".dat")
+            .check()
+            .unwrap()
+    }
+
+    fn find<'a>(results: &'a [CheckResult], name: &str) -> &'a CheckResult {
+        results.iter().find(|result| result.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_foreign_keys_and_primary_keys() {
+        let results = check(&[
+            (
+                "income_band.dat",
+                "1|0|10000|\n2|10001|20000|\n2|20001|30000|\n",
+            ),
+            (
+                "household_demographics_1_2.dat",
+                "1|2|0-500|0|0|\n2||0-500|0|0|\n",
+            ),
+            (
+                "household_demographics_2_2.dat",
+                "3|7|0-500|0|0|\n4|-1|0-500|0|0|\n",
+            ),
+        ]);
+
+        let key = find(
+            &results,
+            "household_demographics.hd_income_band_sk -> income_band.ib_income_band_sk",
+        );
+        assert_eq!(
+            (key.rows, key.nulls, key.violations, key.minus_ones),
+            (4, 1, 1, 1)
+        );
+        assert_eq!(
+            find(&results, "income_band.ib_income_band_sk unique").violations,
+            1
+        );
+
+        // Tables without data files or row generators are skipped
+        let date_key = find(&results, "web_site.web_open_date_sk -> date_dim.d_date_sk");
+        assert_eq!(
+            date_key.skipped.as_deref(),
+            Some("no data file for date_dim")
+        );
+        let item_key = find(&results, "promotion.p_item_sk -> item.i_item_sk");
+        assert!(item_key.skipped.is_some());
+    }
+
+    #[test]
+    fn test_revisions() {
+        let row = |sk: i32, id: &str, start: &str, end: &str| {
+            format!("{}|{}|{}|{}|{}\n", sk, id, start, end, "|".repeat(27))
+        };
+        let call_centers = [
+            row(1, "A", "1998-01-01", ""),
+            row(2, "B", "1998-01-01", "2000-12-31"),
+            row(3, "B", "2001-01-01", ""),
+            row(4, "C", "1998-01-01", "1999-12-31"),
+            row(5, "C", "2000-01-02", ""),
+            row(6, "A", "2001-01-01", ""),
+        ]
+        .concat();
+        let results = check(&[("call_center.dat", &call_centers)]);
+
+        let revisions = find(&results, "call_center revisions of cc_call_center_id");
+        assert_eq!(revisions.rows, 6);
+        // The gap before C's second revision and the reappearing business key A
+        assert_eq!(revisions.violations, 2);
+    }
+
+    #[test]
+    fn test_revision_keys() {
+        let call_center = |sk: i32, id: &str, start: &str, end: &str| {
+            format!("{}|{}|{}|{}|{}\n", sk, id, start, end, "|".repeat(27))
+        };
+        let call_centers = [
+            call_center(1, "A", "1998-01-01", ""),
+            call_center(2, "B", "1998-01-01", "2000-12-31"),
+            call_center(3, "B", "2001-01-01", ""),
+            call_center(4, "C", "1999-01-01", ""),
+        ]
+        .concat();
+        let order = |id: &str, date: &str| format!("1|||{}|0||{}||\n", date, id);
+        let orders = [
+            order("A", "2002-06-01"),
+            order("B", "1999-06-01"),
+            order("C", "1998-06-01"),
+            order("D", "2002-06-01"),
+            order("", "2002-06-01"),
+        ];
+        let results = check(&[
+            ("call_center.dat", &call_centers),
+            ("s_catalog_order_1.dat", &orders[..3].concat()),
+            ("s_catalog_order_2_1_2.dat", &orders[3..].concat()),
+        ]);
+
+        let key = find(
+            &results,
+            "s_catalog_order.cord_call_center_id -> call_center.cc_call_center_id \
+             on cord_order_date",
+        );
+        // C has no revision before 1999 and D none at all
+        assert_eq!((key.rows, key.nulls, key.violations), (5, 1, 2));
+        // Business keys of slowly changing dimensions are not unique
+        assert!(!results
+            .iter()
+            .any(|result| result.name == "call_center.cc_call_center_id unique"));
+    }
+
+    #[test]
+    fn test_revision_key_parents_are_versioned() {
+        for key in FOREIGN_KEYS {
+            if let ForeignKeyKind::Revision { .. } = key.kind {
+                assert!(VERSIONED_TABLES
+                    .iter()
+                    .any(|(table, business_key, _, _)| *table == key.parent
+                        && *business_key == key.parent_column));
+            }
+        }
+    }
+
+    #[test]
+    fn test_refresh_file_names() {
+        let update = |name: &str| {
+            ReferentialIntegrityChecker::get_update("s_web_order", ".dat", Path::new(name))
+        };
+        assert_eq!(update("s_web_order_2.dat"), Some(2));
+        assert_eq!(update("s_web_order_2_3_4.dat"), Some(2));
+        assert_eq!(update("s_web_order_lineitem_2.dat"), None);
+        assert_eq!(update("s_web_order_2_3.dat"), None);
+        assert_eq!(update("s_web_order.dat"), None);
+    }
+
+    #[test]
+    fn test_chunk_file_names() {
+        let chunk = |name: &str| {
+            ReferentialIntegrityChecker::get_chunk_number("web_page", ".dat", Path::new(name))
+        };
+        assert_eq!(chunk("web_page_3_4.dat"), Some(3));
+        assert_eq!(chunk("web_page.dat"), None);
+        assert_eq!(chunk("web_page_3_4.dat.gz"), None);
+        assert_eq!(chunk("web_site_1_2.dat"), None);
+    }
+}
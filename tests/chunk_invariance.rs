@@ -0,0 +1,210 @@
+//! Chunk invariance of the row generators.
+//!
+//! A chunk starts by calling `skip_rows_until_starting_row_number` on a fresh generator, so every
+//! slice of a table must be reproducible from a skip alone. For every ported table these tests
+//! draw random `(start, len)` pairs and compare the slice generated after the skip with the same
+//! rows of a sequential run from row 1. Any disagreement between `skip_rows`,
+//! `consume_remaining_seeds_for_row` and `advance_to_next_row` about how many seeds a row uses
+//! shows up as a mismatch. The rows are generated with the seed audit enabled, so a column that
+//! draws more than its `get_seeds_per_row` fails with the row it happened in.
+//!
+//! Tables that keep history build each revision from the previous one, so slices of those start
+//! at the first revision of a business key. They are small tables, which `split_work` never
+//! splits, so no chunk starts anywhere else.
+//!
+//! `TPCDSGEN_PROPERTY_CASES` (Default: 16) sets the number of pairs per table and
+//! `TPCDSGEN_PROPERTY_SEED` the seed they are drawn from; failures report both so they can be
+//! replayed.
+
+use tpcdsgen::config::Session;
+use tpcdsgen::random::{RandomNumberStream, RandomNumberStreamImpl};
+use tpcdsgen::row::AbstractRowGenerator;
+use tpcdsgen::table::Table;
+
+const CASES_VARIABLE: &str = "TPCDSGEN_PROPERTY_CASES";
+const SEED_VARIABLE: &str = "TPCDSGEN_PROPERTY_SEED";
+const DEFAULT_CASES: usize = 16;
+const DEFAULT_SEED: i32 = 19620718;
+
+/// Rows of the sequential reference run; larger tables are only checked within this prefix
+const MAX_ROWS: i64 = 2000;
+
+fn cases() -> usize {
+    std::env::var(CASES_VARIABLE)
+        .map(|value| value.parse().expect("Invalid number of property cases"))
+        .unwrap_or(DEFAULT_CASES)
+}
+
+fn seed() -> i32 {
+    std::env::var(SEED_VARIABLE)
+        .map(|value| value.parse().expect("Invalid property seed"))
+        .unwrap_or(DEFAULT_SEED)
+}
+
+fn ported_tables() -> Vec<Table> {
+    Table::get_base_tables()
+        .into_iter()
+        .filter(Table::has_row_generator)
+        .collect()
+}
+
+/// Uniform value in `[low, high]`
+fn draw(stream: &mut RandomNumberStreamImpl, low: i64, high: i64) -> i64 {
+    low + stream.next_random() % (high - low + 1)
+}
+
+/// Rows a slice may start at: every row, or for tables that keep history the first revision of
+/// each business key (the second column)
+fn slice_starts(table: Table, sequential: &[Vec<String>]) -> Vec<i64> {
+    (1..=sequential.len() as i64)
+        .filter(|&row_number| {
+            let index = (row_number - 1) as usize;
+            !table.keeps_history() || index == 0 || sequential[index][1] != sequential[index - 1][1]
+        })
+        .collect()
+}
+
+/// Generate `count` rows starting at `start` the way a chunk does: skip, then generate. The
+/// sequential run starting at row 1 does not skip at all.
+fn generate_rows(table: Table, session: &Session, start: i64, count: i64) -> Vec<Vec<String>> {
+    let mut row_generator = table.create_row_generator().unwrap();
+    row_generator.enable_seed_audit();
+    if start > 1 {
+        row_generator.skip_rows_until_starting_row_number(start);
+    }
+
+    let mut rows = Vec::new();
+    let mut row_number = start;
+    while row_number < start + count {
+        let result = row_generator
+            .generate_row_and_child_rows(row_number, session, None, None)
+            .unwrap();
+        if let Some(row) = result.get_rows().first() {
+            rows.push(row.get_values());
+        }
+        if result.should_end_row() {
+            row_generator.consume_remaining_seeds_for_row();
+            if let Some(violation) = row_generator.get_seed_budget_violations().first() {
+                panic!("{}", violation);
+            }
+            row_number += 1;
+        }
+    }
+    rows
+}
+
+#[test]
+fn test_skipped_slices_match_sequential_run() {
+    let session = Session::get_default_session();
+    let seed = seed();
+    let mut stream = RandomNumberStreamImpl::new_with_base(0, seed, 0).unwrap();
+
+    for table in ported_tables() {
+        let row_count = session
+            .get_scaling()
+            .get_row_count(table.into())
+            .min(MAX_ROWS);
+        let sequential = generate_rows(table, &session, 1, row_count);
+        assert_eq!(sequential.len() as i64, row_count, "{}", table);
+
+        let starts = slice_starts(table, &sequential);
+        for case in 0..cases() {
+            let start = starts[draw(&mut stream, 0, starts.len() as i64 - 1) as usize];
+            let len = draw(&mut stream, 1, row_count - start + 1);
+            let slice = generate_rows(table, &session, start, len);
+            let expected = &sequential[(start - 1) as usize..(start - 1 + len) as usize];
+            if let Some(offset) = (0..len as usize).find(|&i| slice[i] != expected[i]) {
+                panic!(
+                    "{}: rows {}..={} generated after a skip differ from the sequential run \
+                     at row {} (case {}, {}={})\n  expected: {:?}\n  actual:   {:?}",
+                    table,
+                    start,
+                    start + len - 1,
+                    start + offset as i64,
+                    case,
+                    SEED_VARIABLE,
+                    seed,
+                    expected[offset],
+                    slice[offset]
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn test_stream_skip_matches_drawing_every_seed() {
+    let seed = seed();
+    let mut stream = RandomNumberStreamImpl::new_with_base(0, seed, 0).unwrap();
+
+    for _ in 0..cases() {
+        let column = draw(&mut stream, 0, 799) as i32;
+        let seeds_per_row = draw(&mut stream, 0, 300) as i32;
+        let rows = draw(&mut stream, 0, 1000);
+
+        let mut skipped = RandomNumberStreamImpl::new_with_column(column, seeds_per_row).unwrap();
+        skipped.skip_rows(rows);
+        let mut stepped = RandomNumberStreamImpl::new_with_column(column, seeds_per_row).unwrap();
+        for _ in 0..rows * seeds_per_row as i64 {
+            stepped.next_random();
+        }
+        assert_eq!(
+            skipped.next_random(),
+            stepped.next_random(),
+            "column {}, {} seeds per row, {} rows ({}={})",
+            column,
+            seeds_per_row,
+            rows,
+            SEED_VARIABLE,
+            seed
+        );
+    }
+}
+
+#[test]
+fn test_row_advances_agree_on_seed_budgets() {
+    let seed = seed();
+    let mut stream = RandomNumberStreamImpl::new_with_base(0, seed, 0).unwrap();
+
+    for table in ported_tables() {
+        for _ in 0..cases() {
+            let rows = draw(&mut stream, 0, 50);
+
+            let mut advanced = AbstractRowGenerator::new(table);
+            let mut consumed = AbstractRowGenerator::new(table);
+            for row_number in 1..=rows {
+                advanced.advance_to_next_row(row_number);
+                consumed.consume_remaining_seeds_for_row();
+            }
+            let mut skipped = AbstractRowGenerator::new(table);
+            skipped.skip_rows_until_starting_row_number(rows + 1);
+
+            for index in 0..table.get_generator_column_count() {
+                let column = table.get_generator_column_by_index(index).unwrap();
+                let next = |generator: &mut AbstractRowGenerator| {
+                    generator
+                        .get_random_number_stream(column)
+                        .unwrap()
+                        .next_random()
+                };
+                let expected = next(&mut skipped);
+                assert_eq!(
+                    next(&mut advanced),
+                    expected,
+                    "{}: advance_to_next_row after {} rows, column {}",
+                    table,
+                    rows,
+                    column.get_global_column_number()
+                );
+                assert_eq!(
+                    next(&mut consumed),
+                    expected,
+                    "{}: consume_remaining_seeds_for_row after {} rows, column {}",
+                    table,
+                    rows,
+                    column.get_global_column_number()
+                );
+            }
+        }
+    }
+}
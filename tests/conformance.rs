@@ -0,0 +1,433 @@
+//! Conformance of the generated tables with reference data from the Java implementation.
+//!
+//! Fixtures are read from `tests/fixtures/scale-<N>/` (see `scripts/generate-fixtures.sh`):
+//! `<table>.dat` holds a whole table and `<table>_<chunk>_<parallelism>.dat` a single chunk.
+//! Every ported table with a whole-table fixture is generated with each chunk count in
+//! `TPCDSGEN_CONFORMANCE_CHUNKS` (Default: 1,4) and the concatenated chunks are compared with the
+//! fixture; chunk fixtures are compared with the same chunk. Without fixtures the test is skipped.
+//!
+//! Runners without the Java reference rely on `tests/golden/checksums.tsv` instead: the row count
+//! and SHA-256 of every table, checked on every run. Each entry names its source: `java` for the
+//! checksum of a Java fixture, `tpcdsgen` for one recorded from this generator's own output, which
+//! only guards against regressions. With fixtures present, setting `TPCDSGEN_WRITE_GOLDEN=1`
+//! records their checksums in that file as `java` entries. Every ported table needs a `java` entry
+//! at scale 1: a table checked only against its own output fails.
+
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tpcdsgen::config::{Options, Session};
+use tpcdsgen::table::Table;
+use tpcdsgen::table_generator::TableGenerator;
+
+const CHUNKS_VARIABLE: &str = "TPCDSGEN_CONFORMANCE_CHUNKS";
+const DEFAULT_CHUNKS: &[i32] = &[1, 4];
+const GOLDEN_CHECKSUMS: &str = "tests/golden/checksums.tsv";
+const WRITE_GOLDEN_VARIABLE: &str = "TPCDSGEN_WRITE_GOLDEN";
+/// Source of checksums recorded from the Java fixtures
+const JAVA_SOURCE: &str = "java";
+
+/// Row count and SHA-256 of a table file
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Checksum {
+    rows: usize,
+    sha256: String,
+}
+
+/// Golden checksum with the output it was recorded from
+#[derive(Debug, Clone)]
+struct GoldenChecksum {
+    checksum: Checksum,
+    source: String,
+}
+
+impl Checksum {
+    fn of(contents: &[u8]) -> Self {
+        Self {
+            rows: contents.iter().filter(|&&b| b == b'\n').count(),
+            sha256: format!("{:x}", Sha256::digest(contents)),
+        }
+    }
+}
+
+/// Golden checksums by scale (as written in the fixture directory name) and table name
+type GoldenChecksums = BTreeMap<(String, String), GoldenChecksum>;
+
+fn golden_checksums_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(GOLDEN_CHECKSUMS)
+}
+
+/// Read the golden checksums: `scale<TAB>table<TAB>rows<TAB>sha256<TAB>source` lines, `#` starts a
+/// comment
+fn read_golden_checksums() -> GoldenChecksums {
+    let Ok(contents) = fs::read_to_string(golden_checksums_path()) else {
+        return GoldenChecksums::new();
+    };
+    contents
+        .lines()
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let fields: Vec<&str> = line.split('\t').collect();
+            assert_eq!(fields.len(), 5, "Invalid golden checksum line: {}", line);
+            (
+                (fields[0].to_string(), fields[1].to_string()),
+                GoldenChecksum {
+                    checksum: Checksum {
+                        rows: fields[2].parse().unwrap(),
+                        sha256: fields[3].to_string(),
+                    },
+                    source: fields[4].to_string(),
+                },
+            )
+        })
+        .collect()
+}
+
+fn write_golden_checksums(checksums: &GoldenChecksums) {
+    let mut contents = String::from(
+        "# Row count and SHA-256 of the output of each table. Source java: recorded from the Java\n\
+         # fixtures with TPCDSGEN_WRITE_GOLDEN=1 cargo test --test conformance. Source tpcdsgen:\n\
+         # recorded from this generator's output, a regression check until the fixtures replace it.\n\
+         # scale\ttable\trows\tsha256\tsource\n",
+    );
+    for ((scale, table), golden) in checksums {
+        writeln!(
+            contents,
+            "{}\t{}\t{}\t{}\t{}",
+            scale, table, golden.checksum.rows, golden.checksum.sha256, golden.source
+        )
+        .unwrap();
+    }
+    let path = golden_checksums_path();
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, contents).unwrap();
+}
+
+/// Fixture directories with their scale factor, ordered by scale
+fn fixture_directories() -> Vec<(f64, PathBuf)> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+
+    let mut directories: Vec<(f64, PathBuf)> = entries
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let scale = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("scale-")?
+                .parse()
+                .ok()?;
+            path.is_dir().then_some((scale, path))
+        })
+        .collect();
+    directories.sort_by(|a, b| a.0.total_cmp(&b.0));
+    directories
+}
+
+/// Scale as written in fixture directory names and the golden checksums
+fn scale_name(fixtures: &Path) -> String {
+    let name = fixtures.file_name().unwrap().to_string_lossy();
+    name.strip_prefix("scale-").unwrap().to_string()
+}
+
+fn chunk_counts() -> Vec<i32> {
+    match std::env::var(CHUNKS_VARIABLE) {
+        Ok(value) => value
+            .split(',')
+            .map(|count| {
+                count
+                    .trim()
+                    .parse()
+                    .unwrap_or_else(|_| panic!("Invalid {}: {}", CHUNKS_VARIABLE, value))
+            })
+            .collect(),
+        Err(_) => DEFAULT_CHUNKS.to_vec(),
+    }
+}
+
+fn ported_tables() -> Vec<Table> {
+    Table::get_base_tables()
+        .into_iter()
+        .filter(|table| table.has_row_generator())
+        .collect()
+}
+
+/// Generate chunk `chunk` of `parallelism` of `table` in-process and return the file contents
+fn generate(table: Table, scale: f64, parallelism: i32, chunk: i32, directory: &Path) -> Vec<u8> {
+    let mut options = Options::new();
+    options.scale = scale;
+    options.directory = directory.to_string_lossy().to_string();
+    options.overwrite = true;
+    options.parallelism = parallelism;
+    let session: Session = options.to_session().unwrap().with_chunk_number(chunk);
+
+    TableGenerator::new(session.clone())
+        .generate_table(table)
+        .unwrap_or_else(|e| panic!("Failed to generate {}: {}", table.get_name(), e));
+    let path = tpcdsgen::output::TableWriter::get_path(table, &session);
+    // Chunks without rows do not create a file
+    let contents = fs::read(&path).unwrap_or_default();
+    let _ = fs::remove_file(path);
+    contents
+}
+
+/// Describe the first difference between the expected and actual rows of `table`, if any
+fn first_difference(table: Table, expected: &[u8], actual: &[u8]) -> Option<String> {
+    if expected == actual {
+        return None;
+    }
+
+    // Data files are ISO-8859-1 encoded
+    let decode = |bytes: &[u8]| -> String { bytes.iter().map(|&b| b as char).collect() };
+    let expected = decode(expected);
+    let actual = decode(actual);
+    let expected_rows: Vec<&str> = expected.lines().collect();
+    let actual_rows: Vec<&str> = actual.lines().collect();
+
+    let Some(index) = expected_rows
+        .iter()
+        .zip(&actual_rows)
+        .position(|(expected, actual)| expected != actual)
+    else {
+        return Some(if expected_rows.len() == actual_rows.len() {
+            "line endings differ".to_string()
+        } else {
+            format!(
+                "expected {} rows but generated {}",
+                expected_rows.len(),
+                actual_rows.len()
+            )
+        });
+    };
+
+    let mut message = format!("first difference in row {}", index + 1);
+    let columns = table.get_columns();
+    let expected_fields: Vec<&str> = expected_rows[index].split('|').collect();
+    let actual_fields: Vec<&str> = actual_rows[index].split('|').collect();
+    for position in 0..expected_fields.len().max(actual_fields.len()) {
+        let expected_field = expected_fields.get(position).copied();
+        let actual_field = actual_fields.get(position).copied();
+        if expected_field != actual_field {
+            let column = columns
+                .get(position)
+                .map_or(format!("field {}", position + 1), |column| {
+                    column.get_name().to_string()
+                });
+            write!(
+                message,
+                "\n    {}: expected {:?}, actual {:?}",
+                column,
+                expected_field.unwrap_or("<missing>"),
+                actual_field.unwrap_or("<missing>")
+            )
+            .unwrap();
+        }
+    }
+    write!(
+        message,
+        "\n    expected row: {}\n    actual row:   {}",
+        expected_rows[index], actual_rows[index]
+    )
+    .unwrap();
+    Some(message)
+}
+
+/// Chunk fixtures of `table`: (chunk, parallelism, path)
+fn chunk_fixtures(table: Table, fixtures: &Path) -> Vec<(i32, i32, PathBuf)> {
+    let mut chunks: Vec<(i32, i32, PathBuf)> = fs::read_dir(fixtures)
+        .unwrap()
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            let name = path.file_name()?.to_str()?.to_string();
+            let (chunk, parallelism) = name
+                .strip_prefix(table.get_name())?
+                .strip_prefix('_')?
+                .strip_suffix(".dat")?
+                .split_once('_')?;
+            Some((chunk.parse().ok()?, parallelism.parse().ok()?, path))
+        })
+        .collect();
+    chunks.sort();
+    chunks
+}
+
+#[test]
+fn test_tables_match_fixtures() {
+    let directories = fixture_directories();
+    if directories.is_empty() {
+        eprintln!("skipping conformance test: no fixtures in tests/fixtures/scale-N");
+        return;
+    }
+
+    let temp = tempfile::tempdir().unwrap();
+    let output = temp.path();
+
+    let mut golden = read_golden_checksums();
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for (scale, fixtures) in &directories {
+        for table in ported_tables() {
+            let whole = fixtures.join(format!("{}.dat", table.get_name()));
+            if whole.is_file() {
+                let expected = fs::read(&whole).unwrap();
+                golden.insert(
+                    (scale_name(fixtures), table.get_name().to_string()),
+                    GoldenChecksum {
+                        checksum: Checksum::of(&expected),
+                        source: JAVA_SOURCE.to_string(),
+                    },
+                );
+                for parallelism in chunk_counts() {
+                    let actual: Vec<u8> = (1..=parallelism)
+                        .flat_map(|chunk| generate(table, *scale, parallelism, chunk, output))
+                        .collect();
+                    checked += 1;
+                    if let Some(difference) = first_difference(table, &expected, &actual) {
+                        failures.push(format!(
+                            "{} at scale {} in {} chunk(s): {}",
+                            table.get_name(),
+                            scale,
+                            parallelism,
+                            difference
+                        ));
+                    }
+                }
+            }
+
+            for (chunk, parallelism, path) in chunk_fixtures(table, fixtures) {
+                let expected = fs::read(&path).unwrap();
+                let actual = generate(table, *scale, parallelism, chunk, output);
+                checked += 1;
+                if let Some(difference) = first_difference(table, &expected, &actual) {
+                    failures.push(format!(
+                        "{} at scale {}, chunk {} of {}: {}",
+                        table.get_name(),
+                        scale,
+                        chunk,
+                        parallelism,
+                        difference
+                    ));
+                }
+            }
+        }
+    }
+    if std::env::var_os(WRITE_GOLDEN_VARIABLE).is_some() {
+        write_golden_checksums(&golden);
+        eprintln!("wrote {}", GOLDEN_CHECKSUMS);
+    }
+
+    eprintln!(
+        "checked {} table outputs against fixtures for scale(s) {:?}",
+        checked,
+        directories
+            .iter()
+            .map(|(scale, _)| scale)
+            .collect::<Vec<_>>()
+    );
+    assert!(
+        failures.is_empty(),
+        "{} of {} table outputs differ from the fixtures:\n{}",
+        failures.len(),
+        checked,
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_tables_match_golden_checksums() {
+    let golden = read_golden_checksums();
+    assert!(!golden.is_empty(), "{} is missing", GOLDEN_CHECKSUMS);
+
+    let temp = tempfile::tempdir().unwrap();
+    let output = temp.path();
+
+    let mut checked = 0;
+    let mut failures = Vec::new();
+    for (
+        (scale, table_name),
+        GoldenChecksum {
+            checksum: expected,
+            source,
+        },
+    ) in &golden
+    {
+        // Checksums may be recorded for tables that have not been ported yet
+        let Some(table) = Table::get_table(table_name)
+            .ok()
+            .filter(|table| table.has_row_generator())
+        else {
+            continue;
+        };
+
+        let actual = Checksum::of(&generate(table, scale.parse().unwrap(), 1, 1, output));
+        checked += 1;
+        if actual != *expected {
+            failures.push(format!(
+                "{} at scale {}: expected {} rows with SHA-256 {} ({}), generated {} rows with SHA-256 {}",
+                table_name, scale, expected.rows, expected.sha256, source, actual.rows, actual.sha256
+            ));
+        }
+    }
+
+    // Every ported table is covered at scale 1
+    for table in ported_tables() {
+        assert!(
+            golden.contains_key(&("1".to_string(), table.get_name().to_string())),
+            "{} has no golden checksum at scale 1 in {}",
+            table.get_name(),
+            GOLDEN_CHECKSUMS
+        );
+    }
+    assert!(
+        failures.is_empty(),
+        "{} of {} tables differ from the golden checksums:\n{}",
+        failures.len(),
+        checked,
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn test_ported_tables_have_java_checksums() {
+    let golden = read_golden_checksums();
+    let missing: Vec<&str> = ported_tables()
+        .into_iter()
+        .map(|table| table.get_name())
+        .filter(|table_name| {
+            golden
+                .get(&("1".to_string(), table_name.to_string()))
+                .is_none_or(|checksum| checksum.source != JAVA_SOURCE)
+        })
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "{} of the ported tables have no {} checksum at scale 1 in {}: {}. Record them from the \
+         Java fixtures with {}=1 cargo test --test conformance",
+        missing.len(),
+        JAVA_SOURCE,
+        GOLDEN_CHECKSUMS,
+        missing.join(", "),
+        WRITE_GOLDEN_VARIABLE
+    );
+}
+
+#[test]
+fn test_first_difference_names_the_column() {
+    let expected =
+        b"1|AAAAAAAABAAAAAAA|Package was damaged|\n2|AAAAAAAACAAAAAAA|Stopped working|\n";
+    let actual = b"1|AAAAAAAABAAAAAAA|Package was damaged|\n2|AAAAAAAACAAAAAAA|Did not fit|\n";
+
+    assert_eq!(first_difference(Table::Reason, expected, expected), None);
+    let difference = first_difference(Table::Reason, expected, actual).unwrap();
+    assert!(difference.starts_with("first difference in row 2"));
+    assert!(
+        difference.contains("r_reason_desc: expected \"Stopped working\", actual \"Did not fit\"")
+    );
+
+    let difference = first_difference(Table::Reason, expected, &expected[..40]).unwrap();
+    assert_eq!(difference, "expected 2 rows but generated 1");
+}